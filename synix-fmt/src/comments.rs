@@ -0,0 +1,48 @@
+use std::ops::Range;
+
+use synix::cst::{SyntaxKind, SyntaxNode};
+
+/// The comments in the input, which the formatter takes in
+/// source order as it passes them.
+pub(crate) struct Comments {
+    ranges: Vec<Range<usize>>,
+    next: usize,
+}
+
+impl Comments {
+    pub(crate) fn new(root: &SyntaxNode) -> Self {
+        let mut ranges = root
+            .descendants()
+            .iter()
+            .flat_map(|node| node.tokens().collect::<Vec<_>>())
+            .filter(|token| token.kind() == SyntaxKind::Comment)
+            .map(|token| token.text_range())
+            .collect::<Vec<_>>();
+
+        ranges.sort_by_key(|range| range.start);
+
+        Self { ranges, next: 0 }
+    }
+
+    /// The next comment if it starts before `offset`, without
+    /// taking it.
+    pub(crate) fn peek_before(&self, offset: usize) -> Option<Range<usize>> {
+        self.ranges
+            .get(self.next)
+            .filter(|r| r.start < offset)
+            .cloned()
+    }
+
+    /// Take the next comment if it starts before `offset`.
+    pub(crate) fn next_before(&mut self, offset: usize) -> Option<Range<usize>> {
+        let range = self.peek_before(offset)?;
+        self.next += 1;
+        Some(range)
+    }
+
+    /// Drop the comments that start before `offset`, because they are
+    /// part of text that is printed as-is.
+    pub(crate) fn skip_before(&mut self, offset: usize) {
+        while self.next_before(offset).is_some() {}
+    }
+}
@@ -0,0 +1,153 @@
+//! A small document model in the style of Wadler's "prettier printer".
+//!
+//! A [`Doc`] describes all the ways some output may be laid out: every
+//! [`Doc::Group`] is printed on a single line if it fits, and otherwise
+//! all of its [`Doc::Line`]s become line breaks.
+
+/// The number of spaces per indentation level.
+const INDENT: usize = 2;
+
+#[derive(Debug, Clone)]
+pub(crate) enum Doc {
+    Text(String),
+    /// A space, or a line break if the group is broken.
+    Line,
+    /// Nothing, or a line break if the group is broken.
+    SoftLine,
+    /// A line break that also breaks all enclosing groups.
+    HardLine,
+    /// Breaks all enclosing groups without printing anything.
+    BreakParent,
+    /// Text that is only printed if the group is broken.
+    IfBreak(&'static str),
+    Group(Vec<Doc>),
+    /// Indent the line breaks in the contents by one level.
+    Nest(Vec<Doc>),
+    Concat(Vec<Doc>),
+}
+
+impl Doc {
+    pub(crate) fn text(text: impl Into<String>) -> Self {
+        Doc::Text(text.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+/// Render `doc`, breaking groups that do not fit in `width` columns.
+pub(crate) fn render(doc: &Doc, width: usize) -> String {
+    let mut output = String::new();
+    let mut column = 0;
+
+    // The documents that remain to be printed, last one first.
+    let mut stack = vec![(0, Mode::Break, doc)];
+
+    while let Some((indent, mode, doc)) = stack.pop() {
+        match doc {
+            Doc::Text(text) => {
+                output.push_str(text);
+
+                column = match text.rfind('\n') {
+                    Some(idx) => text[idx + 1..].chars().count(),
+                    None => column + text.chars().count(),
+                };
+            }
+            Doc::Line if mode == Mode::Flat => {
+                output.push(' ');
+                column += 1;
+            }
+            Doc::SoftLine | Doc::IfBreak(_) | Doc::BreakParent if mode == Mode::Flat => {}
+            Doc::Line | Doc::SoftLine | Doc::HardLine => {
+                // Do not leave indentation behind on empty lines.
+                output.truncate(output.trim_end_matches(' ').len());
+                output.push('\n');
+                output.extend(std::iter::repeat_n(' ', indent));
+                column = indent;
+            }
+            Doc::BreakParent => {}
+            Doc::IfBreak(text) => {
+                output.push_str(text);
+                column += text.chars().count();
+            }
+            Doc::Group(docs) => {
+                let mode =
+                    if mode == Mode::Break && !fits(docs, &stack, width.saturating_sub(column)) {
+                        Mode::Break
+                    } else {
+                        Mode::Flat
+                    };
+
+                stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+            }
+            Doc::Nest(docs) => {
+                stack.extend(docs.iter().rev().map(|doc| (indent + INDENT, mode, doc)));
+            }
+            Doc::Concat(docs) => {
+                stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+            }
+        }
+    }
+
+    output.truncate(output.trim_end_matches(' ').len());
+    output
+}
+
+/// Whether `docs` fit on the rest of the line when printed flat, up to
+/// the next line break in `rest`.
+fn fits(docs: &[Doc], rest: &[(usize, Mode, &Doc)], mut width: usize) -> bool {
+    let mut stack: Vec<(Mode, &Doc)> = docs.iter().rev().map(|doc| (Mode::Flat, doc)).collect();
+    let mut rest = rest.iter().rev();
+
+    loop {
+        let (mode, doc) = match stack.pop() {
+            Some(next) => next,
+            None => match rest.next() {
+                Some((_, mode, doc)) => (*mode, *doc),
+                None => return true,
+            },
+        };
+
+        match doc {
+            Doc::Text(text) => {
+                // Only the first line of multi-line text has to fit.
+                let first = text.split('\n').next().unwrap_or_default();
+                let len = first.chars().count();
+
+                if len > width {
+                    return false;
+                }
+
+                if first.len() < text.len() {
+                    return true;
+                }
+
+                width -= len;
+            }
+            Doc::Line if mode == Mode::Flat => {
+                if width == 0 {
+                    return false;
+                }
+
+                width -= 1;
+            }
+            Doc::HardLine | Doc::BreakParent if mode == Mode::Flat => return false,
+            Doc::SoftLine | Doc::IfBreak(_) if mode == Mode::Flat => {}
+            Doc::Line | Doc::SoftLine | Doc::HardLine => return true,
+            Doc::BreakParent => {}
+            Doc::IfBreak(text) => {
+                if text.len() > width {
+                    return false;
+                }
+
+                width -= text.len();
+            }
+            Doc::Group(docs) | Doc::Nest(docs) | Doc::Concat(docs) => {
+                stack.extend(docs.iter().rev().map(|doc| (mode, doc)));
+            }
+        }
+    }
+}
@@ -0,0 +1,712 @@
+use std::ops::Range;
+
+use synix::{
+    Assignment, AssignmentInherit, AssignmentNamed, Expr, ExprFunctionCall, ExprIf, ExprLet,
+    LiteralOrInterpolatedIdent,
+    binary::{Associativity, ExprBinary},
+    lambda::{ArgAttrSet, ArgAttrSetValue, ExprLambda, LambdaArg},
+};
+use synix_lexer::{LineColumn, Span};
+
+use crate::{comments::Comments, doc::Doc};
+
+/// Builds the [`Doc`] for an expression, taking text that is printed
+/// as-is, such as strings and paths, from the input.
+pub(crate) struct Formatter<'a> {
+    input: &'a str,
+    line_starts: Vec<usize>,
+    comments: Comments,
+}
+
+impl<'a> Formatter<'a> {
+    pub(crate) fn new(input: &'a str, comments: Comments) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(input.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        Self {
+            input,
+            line_starts,
+            comments,
+        }
+    }
+
+    fn offset(&self, position: LineColumn) -> usize {
+        let start = self
+            .line_starts
+            .get(position.line)
+            .copied()
+            .unwrap_or(self.input.len());
+
+        let column: usize = self.input[start..]
+            .chars()
+            .take(position.column)
+            .map(char::len_utf8)
+            .sum();
+
+        start + column
+    }
+
+    fn range(&self, span: &Span) -> Range<usize> {
+        self.offset(span.start())..self.offset(span.end())
+    }
+
+    /// The input covered by `span`, printed as-is.
+    fn verbatim(&mut self, span: &Span) -> Doc {
+        let range = self.range(span);
+        self.comments.skip_before(range.end);
+        Doc::text(&self.input[range])
+    }
+
+    /// The top-level expression, with the comments around it.
+    pub(crate) fn root(&mut self, expr: &Expr) -> Doc {
+        let end = self.input.len();
+        let docs = self.sequence(
+            std::slice::from_ref(expr),
+            Expr::span,
+            |f, expr, _| f.expr(expr),
+            Doc::HardLine,
+            end,
+        );
+
+        Doc::Concat(docs)
+    }
+
+    /// Comments before `offset` that are not handled by an enclosing
+    /// sequence, each on a line of its own.
+    fn leading(&mut self, offset: usize) -> Doc {
+        let mut docs = Vec::new();
+
+        while let Some(comment) = self.comments.next_before(offset) {
+            docs.push(self.comment(comment));
+            docs.push(Doc::HardLine);
+        }
+
+        Doc::Concat(docs)
+    }
+
+    /// Comments before `offset` at the end of a group, which breaks it.
+    fn dangling(&mut self, offset: usize) -> Doc {
+        let mut docs = Vec::new();
+
+        while let Some(comment) = self.comments.next_before(offset) {
+            docs.push(Doc::text(" "));
+            docs.push(self.comment(comment));
+            docs.push(Doc::BreakParent);
+        }
+
+        Doc::Concat(docs)
+    }
+
+    /// Comments before `offset` inside of an expression, such as the one
+    /// in `a ? # c\n b`, each followed by a line break. `None` if there
+    /// are none, in which case the caller prints its usual separator.
+    fn inner_comments(&mut self, offset: usize) -> Option<Doc> {
+        let mut docs = vec![Doc::text(" ")];
+
+        while let Some(comment) = self.comments.next_before(offset) {
+            docs.extend([self.comment(comment), Doc::HardLine]);
+        }
+
+        (docs.len() > 1).then_some(Doc::Concat(docs))
+    }
+
+    fn comment(&self, range: Range<usize>) -> Doc {
+        Doc::text(self.input[range].trim_end())
+    }
+
+    /// Format `items`, separated by `separator`, together with the
+    /// comments between them and before `end`.
+    ///
+    /// Comments on the same line as the preceding item stay there, and
+    /// a single empty line between items is kept.
+    fn sequence<T>(
+        &mut self,
+        items: &[T],
+        span: impl Fn(&T) -> Span,
+        mut format: impl FnMut(&mut Self, &T, bool) -> Doc,
+        separator: Doc,
+        end: usize,
+    ) -> Vec<Doc> {
+        let mut docs = Vec::new();
+        // The end of the last item or comment.
+        let mut last = None;
+
+        for (idx, item) in items.iter().map(Some).chain([None]).enumerate() {
+            let range = item.map(|item| self.range(&span(item)));
+            let start = range.as_ref().map_or(end, |range| range.start);
+
+            while let Some(comment) = self.comments.next_before(start) {
+                match last {
+                    Some(last) if !self.input[last..comment.start].contains('\n') => {
+                        docs.push(Doc::text(" "))
+                    }
+                    Some(last) => docs.extend(self.separator(last..comment.start, &separator)),
+                    None => {}
+                }
+
+                docs.push(self.comment(comment.clone()));
+                docs.push(Doc::BreakParent);
+                last = Some(comment.end);
+            }
+
+            let (Some(item), Some(range)) = (item, range) else {
+                break;
+            };
+
+            if let Some(last) = last {
+                docs.extend(self.separator(last..range.start, &separator));
+            }
+
+            docs.push(format(self, item, idx + 1 == items.len()));
+            last = Some(range.end);
+        }
+
+        docs
+    }
+
+    /// `separator`, or an empty line if there is one in `gap`.
+    fn separator(&self, gap: Range<usize>, separator: &Doc) -> Vec<Doc> {
+        if self.input[gap].matches('\n').count() > 1 {
+            vec![Doc::HardLine, Doc::HardLine]
+        } else {
+            vec![separator.clone()]
+        }
+    }
+
+    /// Surround `docs` by `open` and `close`, on one line if they fit
+    /// and `expand` is not set.
+    fn delimited(&self, open: &str, docs: Vec<Doc>, close: &str, expand: bool) -> Doc {
+        if docs.is_empty() {
+            return Doc::text(format!("{open} {close}"));
+        }
+
+        let mut group = vec![Doc::text(open), Doc::Nest([vec![Doc::Line], docs].concat())];
+
+        if expand {
+            group.push(Doc::BreakParent);
+        }
+
+        group.extend([Doc::Line, Doc::text(close)]);
+        Doc::Group(group)
+    }
+
+    /// Whether the input has a line break in `range`, which keeps
+    /// attribute sets and lists that were written expanded that way.
+    fn has_line_break(&self, range: Range<usize>) -> bool {
+        self.input[range].contains('\n')
+    }
+
+    pub(crate) fn expr(&mut self, expr: &Expr) -> Doc {
+        let range = self.range(&expr.span());
+        let leading = self.leading(range.start);
+
+        let doc = match expr {
+            Expr::Lit(_) | Expr::InterpolatedStr(_) | Expr::Ident(_) | Expr::Path(_) => {
+                self.verbatim(&expr.span())
+            }
+            // The formatter only runs on input without errors.
+            Expr::Error(span) => self.verbatim(span),
+            Expr::Parenthesized(paren) => {
+                let inner = self.expr(&paren.inner);
+                let dangling = self.dangling(range.end - 1);
+
+                let absorbable = match &paren.inner {
+                    Expr::Lambda(lambda) => {
+                        matches!(lambda.arg, LambdaArg::Ident(_))
+                            && is_absorbable(lambda_body(lambda))
+                    }
+                    inner => is_absorbable(inner),
+                };
+
+                if absorbable {
+                    Doc::Concat(vec![Doc::text("("), inner, dangling, Doc::text(")")])
+                } else {
+                    Doc::Group(vec![
+                        Doc::text("("),
+                        Doc::Nest(vec![Doc::SoftLine, inner, dangling]),
+                        Doc::SoftLine,
+                        Doc::text(")"),
+                    ])
+                }
+            }
+            Expr::AttrSet(set) => {
+                let mut docs = Vec::new();
+
+                if set.rec.is_some() {
+                    docs.push(Doc::text("rec "));
+                }
+
+                docs.push(self.bindings(&set.assignments, range.clone(), false));
+                Doc::Concat(docs)
+            }
+            Expr::LegacyLet(let_) => {
+                let set = self.range(&let_.let_.span).end..range.end;
+                let bindings = self.bindings(&let_.assignments, set, true);
+                Doc::Concat(vec![Doc::text("let "), bindings])
+            }
+            Expr::List(list) => {
+                let expand = match list.entries.first() {
+                    Some(first) => {
+                        self.has_line_break(range.start..self.range(&first.span()).start)
+                    }
+                    None => false,
+                };
+
+                let docs = self.sequence(
+                    &list.entries,
+                    Expr::span,
+                    |f, entry, _| f.expr(entry),
+                    Doc::Line,
+                    range.end - 1,
+                );
+
+                self.delimited("[", docs, "]", expand)
+            }
+            Expr::Let(let_) => self.let_(let_),
+            Expr::Lambda(lambda) => self.lambda(lambda),
+            Expr::With(with) => {
+                let mut docs = vec![Doc::text("with "), self.expr(&with.expr), Doc::text(";")];
+
+                let body = self.expr(&with.body);
+
+                if is_absorbable(&with.body) {
+                    docs.extend([Doc::text(" "), body]);
+                } else {
+                    docs.push(Doc::Group(vec![Doc::Line, body]));
+                }
+
+                Doc::Concat(docs)
+            }
+            Expr::If(if_) => self.if_(if_),
+            Expr::FunctionCall(call) => self.function_call(call),
+            Expr::Binary(binary) => self.binary(binary),
+            Expr::Unary(unary) => {
+                let mut docs = vec![Doc::text(unary.operator.as_str())];
+
+                // Nested prefix operators are kept apart, as in `- -a` and `! !a`.
+                if matches!(unary.expr, Expr::Unary(_)) {
+                    docs.push(Doc::text(" "));
+                }
+
+                docs.push(self.expr(&unary.expr));
+                Doc::Concat(docs)
+            }
+            Expr::HasAttr(has_attr) => {
+                let set = self.expr(&has_attr.set);
+                let question = self.range(&has_attr.question.span).start;
+                let before_question = self.inner_comments(question);
+                let head = self.range(&has_attr.head.span()).start;
+                let after_question = self.inner_comments(head);
+
+                Doc::Concat(vec![
+                    set,
+                    Doc::Nest(vec![before_question.unwrap_or(Doc::text(" "))]),
+                    Doc::text("?"),
+                    Doc::Nest(vec![after_question.unwrap_or(Doc::text(" "))]),
+                    self.attr_path(&has_attr.head, &has_attr.tail),
+                ])
+            }
+            Expr::AttributeAccess(access) => {
+                let mut docs = vec![self.expr(&access.set), self.attr_names(&access.accessors)];
+
+                if let Some((or, default)) = &access.default {
+                    let or = self.range(&or.span).start;
+                    let before_or = self.inner_comments(or);
+                    docs.push(Doc::Nest(vec![before_or.unwrap_or(Doc::text(" "))]));
+                    docs.push(Doc::text("or "));
+                    docs.push(self.expr(default));
+                }
+
+                Doc::Concat(docs)
+            }
+        };
+
+        Doc::Concat(vec![leading, doc])
+    }
+
+    /// The bindings of an attribute set or legacy `let`, where `range`
+    /// covers the braces.
+    fn bindings(&mut self, assignments: &[Assignment], range: Range<usize>, expand: bool) -> Doc {
+        // Sets with more than one binding are always expanded, and so are
+        // sets that were expanded in the input.
+        let expand = expand
+            || assignments.len() > 1
+            || match assignments.first() {
+                Some(first) => self.has_line_break(range.start..self.range(&first.span()).start),
+                None => false,
+            };
+
+        let docs = self.sequence(
+            assignments,
+            Assignment::span,
+            |f, assignment, _| f.assignment(assignment),
+            Doc::Line,
+            range.end - 1,
+        );
+
+        self.delimited("{", docs, "}", expand)
+    }
+
+    fn assignment(&mut self, assignment: &Assignment) -> Doc {
+        match assignment {
+            Assignment::Named(named) => self.assignment_named(named),
+            Assignment::Inherit(inherit) => self.assignment_inherit(inherit),
+        }
+    }
+
+    fn assignment_named(&mut self, named: &AssignmentNamed) -> Doc {
+        let mut docs = vec![self.attr_path(&named.head, &named.tail), Doc::text(" =")];
+        let value = self.expr(&named.value);
+
+        // Values that start with a keyword or may span multiple lines
+        // go on a line of their own if they do not fit.
+        let own_line = match &named.value {
+            Expr::Let(_) | Expr::If(_) | Expr::Binary(_) | Expr::Unary(_) | Expr::HasAttr(_) => {
+                true
+            }
+            Expr::Lambda(lambda) => !is_absorbable(lambda_body(lambda)),
+            _ => false,
+        };
+
+        if own_line {
+            docs.push(Doc::Group(vec![Doc::Nest(vec![Doc::Line, value])]));
+        } else {
+            docs.extend([Doc::text(" "), value]);
+        }
+
+        docs.push(Doc::text(";"));
+        Doc::Concat(docs)
+    }
+
+    fn assignment_inherit(&mut self, inherit: &AssignmentInherit) -> Doc {
+        let mut docs = vec![Doc::text("inherit")];
+
+        // Comments between the base and the first name are kept
+        // before the closing parenthesis.
+        let first = match inherit.names.first() {
+            Some(name) => name.span(),
+            None => inherit.semicolon.span.clone(),
+        };
+        let first = self.range(&first).start;
+
+        if let Some(base) = &inherit.base {
+            let start = self.range(&base.span()).start;
+            let before = self.inner_comments(start).unwrap_or(Doc::text(" "));
+            let base = self.expr(base);
+            let comments = self.inner_comments(first);
+            docs.extend([Doc::Nest(vec![before]), Doc::text("("), base]);
+            docs.extend(comments.map(|comments| Doc::Nest(vec![comments])));
+            docs.push(Doc::text(")"));
+        }
+
+        let mut names = Vec::new();
+
+        for name in &inherit.names {
+            let start = self.range(&name.span()).start;
+            names.push(self.inner_comments(start).unwrap_or(Doc::Line));
+            names.push(self.verbatim(&name.span()));
+        }
+
+        docs.push(Doc::Nest(names));
+        docs.push(Doc::text(";"));
+        Doc::Group(docs)
+    }
+
+    fn attr_path(
+        &mut self,
+        head: &LiteralOrInterpolatedIdent,
+        tail: &[LiteralOrInterpolatedIdent],
+    ) -> Doc {
+        let head = self.verbatim(&head.span());
+        Doc::Concat(vec![head, self.attr_names(tail)])
+    }
+
+    /// The names after the first one in an attribute path, each
+    /// preceded by a `.`.
+    fn attr_names(&mut self, names: &[LiteralOrInterpolatedIdent]) -> Doc {
+        let mut docs = Vec::new();
+
+        for name in names {
+            // Comments around the `.` are kept before it.
+            let start = self.range(&name.span()).start;
+            docs.extend(
+                self.inner_comments(start)
+                    .map(|comments| Doc::Nest(vec![comments])),
+            );
+            docs.push(Doc::text("."));
+            docs.push(self.verbatim(&name.span()));
+        }
+
+        Doc::Concat(docs)
+    }
+
+    fn let_(&mut self, let_: &ExprLet) -> Doc {
+        let in_ = self.range(&let_.in_.span).start;
+
+        let bindings = self.sequence(
+            &let_.assignments,
+            Assignment::span,
+            |f, assignment, _| f.assignment(assignment),
+            Doc::HardLine,
+            in_,
+        );
+
+        let mut docs = vec![Doc::text("let")];
+
+        if !bindings.is_empty() {
+            docs.push(Doc::Nest([vec![Doc::HardLine], bindings].concat()));
+        }
+
+        docs.extend([
+            Doc::HardLine,
+            Doc::text("in"),
+            Doc::HardLine,
+            self.expr(&let_.body),
+        ]);
+
+        Doc::Concat(docs)
+    }
+
+    fn lambda(&mut self, lambda: &ExprLambda) -> Doc {
+        let mut docs = match &lambda.arg {
+            LambdaArg::Ident(ident) => vec![self.verbatim(&ident.span())],
+            LambdaArg::AttrSet(set) => self.arg_attr_set(set),
+        };
+
+        docs.push(Doc::text(":"));
+
+        // Only an empty line right after the colon counts, not one
+        // around the comments before the body, which are printed on
+        // lines of their own.
+        let colon = self.range(&lambda.colon.span).end;
+        let body = self.range(&lambda.body.span()).start;
+        let first = self.comments.peek_before(body).map_or(body, |c| c.start);
+        let blank_line = self.input[colon..first].matches('\n').count() > 1;
+
+        let body = self.expr(&lambda.body);
+
+        // An empty line after the arguments, as is common at the
+        // start of a file, is kept.
+        if blank_line {
+            docs.extend([Doc::HardLine, Doc::HardLine, body]);
+            return Doc::Concat(docs);
+        }
+
+        let inline = matches!(lambda.body, Expr::Lambda(_))
+            || (matches!(lambda.arg, LambdaArg::Ident(_)) && is_absorbable(&lambda.body));
+
+        if inline {
+            docs.extend([Doc::text(" "), body]);
+        } else {
+            docs.push(Doc::Group(vec![Doc::Line, body]));
+        }
+
+        Doc::Concat(docs)
+    }
+
+    fn arg_attr_set(&mut self, set: &ArgAttrSet) -> Vec<Doc> {
+        /// An entry of the pattern, which is followed by a comma.
+        enum Entry<'a> {
+            Arg(&'a ArgAttrSetValue),
+            Ellipsis(Span),
+        }
+
+        let range = self.range(&set.span());
+        let binds_to = set.binds_to.as_ref().map(|ident| self.range(&ident.span()));
+
+        // The binding is either before or after the braces.
+        let braces = match &binds_to {
+            Some(binds_to) if binds_to.start == range.start => {
+                let open = range.start + self.input[range.clone()].find('{').unwrap_or(0);
+                open..range.end
+            }
+            Some(binds_to) => {
+                let close = self.input[..binds_to.start]
+                    .rfind('}')
+                    .unwrap_or(range.end - 1);
+                range.start..close + 1
+            }
+            None => range.clone(),
+        };
+
+        let entries: Vec<_> = set
+            .args
+            .iter()
+            .map(Entry::Arg)
+            .chain(
+                set.ellipsis
+                    .as_ref()
+                    .map(|ellipsis| Entry::Ellipsis(ellipsis.span.clone())),
+            )
+            .collect();
+
+        let expand = match entries.first() {
+            Some(Entry::Arg(arg)) => self.range(&arg.span()).start,
+            Some(Entry::Ellipsis(span)) => self.range(span).start,
+            None => braces.start,
+        };
+        let expand = self.has_line_break(braces.start..expand);
+
+        let docs = self.sequence(
+            &entries,
+            |entry| match entry {
+                Entry::Arg(arg) => arg.span(),
+                Entry::Ellipsis(span) => span.clone(),
+            },
+            |f, entry, last| match entry {
+                Entry::Arg(arg) => {
+                    let mut docs = vec![f.verbatim(&arg.ident.span())];
+
+                    if let Some(default) = &arg.default {
+                        docs.extend([Doc::text(" ? "), f.expr(default)]);
+                    }
+
+                    // Expanded patterns have a trailing comma.
+                    docs.push(if last {
+                        Doc::IfBreak(",")
+                    } else {
+                        Doc::text(",")
+                    });
+
+                    Doc::Concat(docs)
+                }
+                Entry::Ellipsis(_) => Doc::text("..."),
+            },
+            Doc::Line,
+            braces.end - 1,
+        );
+
+        let pattern = self.delimited("{", docs, "}", expand);
+
+        match (&set.binds_to, binds_to) {
+            (Some(_), Some(ident)) if ident.start < braces.start => {
+                vec![Doc::text(&self.input[ident]), Doc::text("@"), pattern]
+            }
+            (Some(_), Some(ident)) => vec![pattern, Doc::text("@"), Doc::text(&self.input[ident])],
+            _ => vec![pattern],
+        }
+    }
+
+    fn if_(&mut self, if_: &ExprIf) -> Doc {
+        // Comments before `then` and `else` stay before them, instead of
+        // moving into the branch after them.
+        let condition = self.expr(&if_.condition);
+        let then = self.range(&if_.then.span).start;
+        let before_then = self.inner_comments(then).unwrap_or(Doc::text(" "));
+        let then_branch = self.expr(&if_.then_branch);
+        let else_ = self.range(&if_.else_.span).start;
+        let before_else = self.inner_comments(else_).unwrap_or(Doc::Line);
+        let else_branch = self.expr(&if_.else_branch);
+
+        // `else if` chains are kept at the same level.
+        let else_branch = if matches!(if_.else_branch, Expr::If(_)) {
+            Doc::Concat(vec![Doc::text(" "), else_branch])
+        } else {
+            Doc::Nest(vec![Doc::Line, else_branch])
+        };
+
+        Doc::Group(vec![
+            Doc::text("if "),
+            condition,
+            before_then,
+            Doc::text("then"),
+            Doc::Nest(vec![Doc::Line, then_branch]),
+            before_else,
+            Doc::text("else"),
+            else_branch,
+        ])
+    }
+
+    fn function_call(&mut self, call: &ExprFunctionCall) -> Doc {
+        // `f a b` is `(f a) b`, which is printed as a single call.
+        let mut args = vec![&call.tail];
+        let mut head = &call.head;
+
+        while let Expr::FunctionCall(call) = head {
+            args.push(&call.tail);
+            head = &call.head;
+        }
+
+        args.reverse();
+
+        let mut docs = vec![self.expr(head)];
+        let last = args.pop().expect("A call has at least one argument.");
+
+        for arg in args {
+            docs.push(Doc::Nest(vec![Doc::Line, self.expr(arg)]));
+        }
+
+        // A trailing attribute set, list or parenthesized expression
+        // starts on the line of the function, as in `mkDerivation {`.
+        if is_absorbable(last) || matches!(last, Expr::Parenthesized(_)) {
+            let last = self.expr(last);
+            Doc::Concat(vec![Doc::Group(docs), Doc::text(" "), last])
+        } else {
+            docs.push(Doc::Nest(vec![Doc::Line, self.expr(last)]));
+            Doc::Group(docs)
+        }
+    }
+
+    fn binary(&mut self, binary: &ExprBinary) -> Doc {
+        // Chains of the same operator are printed as a single group,
+        // with the operators at the start of the lines.
+        let mut operands = vec![&binary.rhs];
+        let mut first = &binary.lhs;
+
+        match binary.operator.associativity() {
+            Associativity::Left => {
+                while let Expr::Binary(lhs) = first
+                    && lhs.operator == binary.operator
+                {
+                    operands.push(&lhs.rhs);
+                    first = &lhs.lhs;
+                }
+
+                operands.reverse();
+            }
+            Associativity::Right => {
+                let mut last = &binary.rhs;
+                operands.clear();
+
+                while let Expr::Binary(rhs) = last
+                    && rhs.operator == binary.operator
+                {
+                    operands.push(&rhs.lhs);
+                    last = &rhs.rhs;
+                }
+
+                operands.push(last);
+            }
+            Associativity::None => {}
+        }
+
+        let mut docs = vec![self.expr(first)];
+
+        for operand in operands {
+            let operand = self.expr(operand);
+            docs.extend([
+                Doc::Line,
+                Doc::text(binary.operator.as_str()),
+                Doc::text(" "),
+                operand,
+            ]);
+        }
+
+        Doc::Group(docs)
+    }
+}
+
+/// The body of the innermost lambda in `x: y: body`.
+fn lambda_body(lambda: &ExprLambda) -> &Expr {
+    match &lambda.body {
+        Expr::Lambda(lambda) => lambda_body(lambda),
+        body => body,
+    }
+}
+
+/// Expressions that may start on the line of whatever is before them,
+/// even if they span multiple lines.
+fn is_absorbable(expr: &Expr) -> bool {
+    matches!(expr, Expr::AttrSet(_) | Expr::List(_))
+}
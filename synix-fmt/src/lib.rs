@@ -0,0 +1,34 @@
+//! An opinionated formatter for Nix, following the standard style
+//! of [RFC 166](https://github.com/NixOS/rfcs/pull/166).
+//!
+//! Comments are kept, and so are empty lines between bindings and
+//! list entries. Strings, paths and other literals are printed
+//! exactly as they were written.
+
+mod comments;
+mod doc;
+mod format;
+
+use synix::{ParseOptions, Result};
+
+use crate::{comments::Comments, format::Formatter};
+
+/// The width that lines are kept within, where possible.
+pub const WIDTH: usize = 100;
+
+/// Format `input`, which must parse without errors.
+pub fn format(input: &str) -> Result<String> {
+    format_with_options(input, ParseOptions::default())
+}
+
+pub fn format_with_options(input: &str, options: ParseOptions) -> Result<String> {
+    let expr = synix::parse_with_options(input, options)?;
+    let root = synix::cst::parse_with_options(input, options)?;
+
+    let mut formatter = Formatter::new(input, Comments::new(&root));
+    let doc = formatter.root(&expr);
+
+    let mut output = doc::render(&doc, WIDTH);
+    output.push('\n');
+    Ok(output)
+}
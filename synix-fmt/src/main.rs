@@ -0,0 +1,94 @@
+//! `synix-fmt [--check] [FILE]...`
+//!
+//! Formats the given files in place, or standard input to standard
+//! output if no files (or `-`) are given. With `--check`, nothing is
+//! written and the exit code is `1` if any input is not formatted.
+
+use std::{
+    io::{IsTerminal, Read, Write},
+    process::ExitCode,
+};
+
+use synix::render::{Renderer, SourceMap};
+
+const USAGE: &str = "Usage: synix-fmt [--check] [FILE]...";
+
+fn main() -> ExitCode {
+    let mut check = false;
+    let mut files = Vec::new();
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            flag if flag.starts_with("--") => {
+                eprintln!("Unknown option `{flag}`.\n{USAGE}");
+                return ExitCode::from(2);
+            }
+            _ => files.push(arg),
+        }
+    }
+
+    if files.is_empty() {
+        files.push("-".to_string());
+    }
+
+    let mut status = ExitCode::SUCCESS;
+
+    for file in &files {
+        match run(file, check) {
+            Ok(true) => {}
+            Ok(false) => {
+                eprintln!("{file}: not formatted");
+                status = ExitCode::FAILURE;
+            }
+            Err(message) => {
+                eprint!("{message}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    status
+}
+
+/// Format `file`, returning whether it was already formatted. Errors
+/// are returned ready to print, including a trailing line break.
+fn run(file: &str, check: bool) -> Result<bool, String> {
+    let input = if file == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("{file}: {e}\n"))?;
+        input
+    } else {
+        std::fs::read_to_string(file).map_err(|e| format!("{file}: {e}\n"))?
+    };
+
+    let output = synix_fmt::format(&input).map_err(|e| {
+        let name = if file == "-" { "<stdin>" } else { file };
+        let color = std::io::stderr().is_terminal();
+        Renderer::new()
+            .color(color)
+            .render(&SourceMap::new(name, &input), &e)
+    })?;
+
+    let formatted = input == output;
+
+    if check {
+        return Ok(formatted);
+    }
+
+    if file == "-" {
+        std::io::stdout()
+            .write_all(output.as_bytes())
+            .map_err(|e| format!("{file}: {e}\n"))?;
+    } else if !formatted {
+        std::fs::write(file, output).map_err(|e| format!("{file}: {e}\n"))?;
+    }
+
+    Ok(true)
+}
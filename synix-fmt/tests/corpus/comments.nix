@@ -0,0 +1,17 @@
+# A file with comments in various places.
+{
+  # Before the first binding.
+  a = 1; # After a binding.
+
+
+  # Two empty lines above are kept as one.
+  b = [ # After the bracket.
+    1
+    2 # After an entry.
+    # Before the closing bracket.
+  ];
+  c = { x, # After an argument.
+    y ? 2,
+  }: x + y;
+}
+# At the end of the file.
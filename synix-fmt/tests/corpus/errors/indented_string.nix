@@ -0,0 +1,7 @@
+# Indented strings are not supported yet, which must be an error
+# rather than a crash.
+{
+  script = ''
+    echo hello
+  '';
+}
@@ -0,0 +1,18 @@
+{
+  description = "An example flake";
+
+  inputs = {
+    nixpkgs.url = "github:NixOS/nixpkgs/nixos-unstable";
+    utils.url = "github:numtide/flake-utils";
+  };
+
+  outputs = { self, nixpkgs, utils }:
+    utils.lib.eachDefaultSystem (system:
+      let
+        pkgs = import nixpkgs { inherit system; };
+      in
+      {
+        packages.default = pkgs.callPackage ./package.nix { };
+        devShells.default = pkgs.mkShell { packages = [ pkgs.cargo pkgs.rustc ]; };
+      });
+}
@@ -0,0 +1,9 @@
+# Comments between the arguments of a function and its body.
+{
+  a = x: # After the colon.
+    x;
+  b =
+    { y }:
+    # Before the body.
+    y;
+}
@@ -0,0 +1,24 @@
+# Small helpers in the style of nixpkgs' lib.
+{ lib }:
+
+rec {
+  # Apply `f` to every element of `list`.
+  map' = f: list: if list == [ ] then [ ] else [ (f (builtins.head list)) ] ++ map' f (builtins.tail list);
+
+  optional = cond: elem: if cond then [ elem ] else [ ];
+
+  optionalAttrs = cond: attrs: if cond then attrs else { };
+
+  getAttrOr = name: default: set: if set ? ${name} then set.${name} else default;
+
+  isEnabled = set: set.enable or false;
+
+  id = x: x;
+  const = x: _: x;
+  flip = f: a: b: f b a;
+
+  # Keep the operators as they are.
+  implies = a: b: !a || b;
+  between = low: high: x: x >= low && x <= high;
+  merge = a: b: a // b // { merged = true; };
+}
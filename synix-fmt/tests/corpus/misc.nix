@@ -0,0 +1,19 @@
+let a = 1; # c1
+# c2
+in # c3
+{ x = [ # c4
+  a # c5
+  b
+  # c6
+]; y = f (a # c7
+); z = someFunctionWithAVeryLongName argumentNumberOne argumentNumberTwo argumentNumberThree argumentFour;
+w = if someCondition && someOtherCondition then someValueThatIsLong else someOtherValueThatIsLonger;
+v = aaaaaaaaaaaaaaaaaaaaaaaaa + bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb + ccccccccccccccccccccccccccccccccc + dddddd;
+u = [ aaaaaaaaaaaaaaaaaaaaaaaaa bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb ccccccccccccccccccccccccccccccccc dddddd ];
+t = { a, b ? c, ... }@args: a;
+s = args@{ aaaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccc ? ddddddddddd }: a;
+r = x: let y = x; in y;
+q = !(a -> b);
+inherit (pkgs) aaaaaaaaaaaaaaaaaaaaaaaaa bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb ccccccccccccccccccccccccccccccccc;
+} # end
+# eof
@@ -0,0 +1,31 @@
+{ config, lib, pkgs, ... }:
+
+let
+  cfg = config.services.example;
+  inherit (lib) mkEnableOption mkOption mkIf types;
+in
+{
+  options.services.example = {
+    enable = mkEnableOption "the example service";
+
+    port = mkOption {
+      type = types.port;
+      default = 8080;
+      description = "The port to listen on.";
+    };
+  };
+
+  config = mkIf cfg.enable {
+    systemd.services.example = {
+      wantedBy = [ "multi-user.target" ];
+      after = [ "network.target" ];
+      serviceConfig = {
+        ExecStart = "${pkgs.example}/bin/example --port ${toString cfg.port}";
+        DynamicUser = true;
+        Restart = if cfg.port < 1024 then "always" else "on-failure";
+      };
+    };
+
+    networking.firewall.allowedTCPPorts = [ cfg.port ];
+  };
+}
@@ -0,0 +1,10 @@
+final: prev: {
+  example = prev.example.overrideAttrs (old: {
+    patches = (old.patches or [ ]) ++ [ ./fix-build.patch ];
+    env.NIX_CFLAGS_COMPILE = "-O2";
+  });
+
+  withExample = prev.hello.override { enableExample = true; };
+
+  scripts = with final; [ example (writeShellScriptBin "run-example" "exec ${example}/bin/example") ];
+}
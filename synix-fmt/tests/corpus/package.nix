@@ -0,0 +1,29 @@
+{ lib, stdenv, fetchFromGitHub, cmake, pkg-config, openssl, zlib, enableTests ? true }:
+
+stdenv.mkDerivation rec {
+  pname = "example";
+  version = "2.4.1";
+
+  src = fetchFromGitHub {
+    owner = "example";
+    repo = pname;
+    rev = "v${version}";
+    hash = "sha256-AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=";
+  };
+
+  nativeBuildInputs = [ cmake pkg-config ];
+  buildInputs = [ openssl zlib ];
+
+  # Tests need network access on darwin.
+  doCheck = enableTests && !stdenv.isDarwin;
+
+  cmakeFlags = [ "-DBUILD_SHARED_LIBS=ON" ] ++ lib.optional doCheck "-DBUILD_TESTING=ON";
+
+  meta = with lib; {
+    description = "An example package";
+    homepage = "https://example.org";
+    license = licenses.mit;
+    maintainers = [ ];
+    platforms = platforms.unix;
+  };
+}
@@ -0,0 +1,179 @@
+use std::{fs, path::Path};
+
+use synix::{
+    cst::{self, SyntaxKind},
+    print,
+};
+use synix_fmt::format;
+
+/// Check that every comment in `input` is kept in `formatted`.
+fn assert_comments_kept(input: &str, formatted: &str) {
+    let root = cst::parse(input).unwrap();
+
+    for node in root.descendants() {
+        for token in node.tokens() {
+            if token.kind() == SyntaxKind::Comment {
+                let comment = token.text().trim_end();
+                assert!(formatted.contains(comment), "{comment:?} in {formatted:?}");
+            }
+        }
+    }
+}
+
+/// Check that `input` formats to `expected`, which is
+/// itself formatted.
+macro_rules! fmt {
+    ($name:ident, $input:expr, $expected:expr) => {
+        #[test]
+        pub fn $name() {
+            let formatted = format($input).unwrap();
+            assert_eq!(formatted, $expected);
+            assert_eq!(format(&formatted).unwrap(), formatted);
+            assert_comments_kept($input, &formatted);
+        }
+    };
+}
+
+fmt!(simple, "1+2", "1 + 2\n");
+fmt!(empty_set, "{}", "{ }\n");
+fmt!(empty_list, "[]", "[ ]\n");
+fmt!(single_binding, "{a=1;}", "{ a = 1; }\n");
+fmt!(
+    multiple_bindings,
+    "{a=1;b=2;}",
+    "{\n  a = 1;\n  b = 2;\n}\n"
+);
+fmt!(expanded_in_input, "{\na=1;}", "{\n  a = 1;\n}\n");
+fmt!(list, "[1 2  3]", "[ 1 2 3 ]\n");
+fmt!(let_, "let a = 1; in a", "let\n  a = 1;\nin\na\n");
+fmt!(pattern, "{a,b?1,...}@args:a", "{ a, b ? 1, ... }@args: a\n");
+fmt!(
+    pattern_body,
+    "{ a }: { x = a; y = a; }",
+    "{ a }:\n{\n  x = a;\n  y = a;\n}\n"
+);
+fmt!(
+    lambda_absorbs_set,
+    "map (x: { a = x; b = x; })",
+    "map (x: {\n  a = x;\n  b = x;\n})\n"
+);
+fmt!(
+    call_absorbs_set,
+    "f a { b = 1; c = 2; }",
+    "f a {\n  b = 1;\n  c = 2;\n}\n"
+);
+fmt!(if_, "if a then b else c", "if a then b else c\n");
+fmt!(with, "with a; [b]", "with a; [ b ]\n");
+fmt!(
+    inherit,
+    "{inherit a b; inherit (c) d;}",
+    "{\n  inherit a b;\n  inherit (c) d;\n}\n"
+);
+fmt!(
+    verbatim_literals,
+    "[\"a\\tb\" ./a/b.nix <c>]",
+    "[ \"a\\tb\" ./a/b.nix <c> ]\n"
+);
+fmt!(trailing_comment, "[ a # a\n b ]", "[\n  a # a\n  b\n]\n");
+fmt!(has_attr_comment, "a ? # c\n b", "a ? # c\n  b\n");
+fmt!(attr_path_comment, "a.b.c # c\n .d", "a.b.c # c\n  .d\n");
+fmt!(
+    inherit_comment,
+    "let inherit (a # c\n ) b; in b",
+    "let\n  inherit (a # c\n    )\n    b;\nin\nb\n"
+);
+fmt!(
+    if_comments,
+    "if a # c\n then b # d\n else c",
+    "if a # c\nthen\n  b # d\nelse\n  c\n"
+);
+fmt!(lambda_comment, "x: # c\nx", "x:\n# c\nx\n");
+fmt!(
+    lambda_comment_in_binding,
+    "{ a = x: # c\n x; }",
+    "{\n  a =\n    x:\n    # c\n    x;\n}\n"
+);
+fmt!(lambda_empty_line, "x:\n\n# c\nx", "x:\n\n# c\nx\n");
+fmt!(
+    empty_lines,
+    "{\n  a = 1;\n\n\n\n  b = 2;\n}",
+    "{\n  a = 1;\n\n  b = 2;\n}\n"
+);
+
+#[test]
+pub fn long_lines_break() {
+    let input = format!("f {}", ["argument"; 20].join(" "));
+    let formatted = format(&input).unwrap();
+
+    assert!(formatted.lines().all(|line| line.len() <= synix_fmt::WIDTH));
+    assert_eq!(formatted.lines().count(), 21);
+}
+
+#[test]
+pub fn rejects_errors() {
+    assert!(format("{ a = ; }").is_err());
+    assert!(format("a b; c").is_err());
+}
+
+#[test]
+pub fn corpus() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+
+    let mut cases: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "nix"))
+        .collect();
+    cases.sort();
+
+    assert!(!cases.is_empty());
+
+    for case in cases {
+        let input = fs::read_to_string(&case).unwrap();
+        let expected = fs::read_to_string(case.with_extension("exp")).unwrap();
+
+        let formatted = format(&input).unwrap();
+        assert_eq!(formatted, expected, "{}", case.display());
+        assert_comments_kept(&input, &formatted);
+        assert_eq!(format(&formatted).unwrap(), formatted, "{}", case.display());
+
+        // Formatting does not change the meaning.
+        let before = print(&synix::parse(&input).unwrap());
+        let after = print(&synix::parse(&formatted).unwrap());
+        assert_eq!(before, after, "{}", case.display());
+    }
+}
+
+/// Files in `tests/corpus/errors` are rejected rather than formatted.
+#[test]
+pub fn corpus_errors() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus/errors");
+
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let input = fs::read_to_string(&path).unwrap();
+
+        assert!(format(&input).is_err(), "{}", path.display());
+    }
+}
+
+#[test]
+pub fn precedence_corpus() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../synix/tests/precedence");
+
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.extension().is_some_and(|ext| ext == "nix") {
+            let input = fs::read_to_string(&path).unwrap();
+            let formatted = format(&input).unwrap();
+
+            assert_eq!(format(&formatted).unwrap(), formatted, "{}", path.display());
+            assert_comments_kept(&input, &formatted);
+
+            let before = print(&synix::parse(&input).unwrap());
+            let after = print(&synix::parse(&formatted).unwrap());
+            assert_eq!(before, after, "{}", path.display());
+        }
+    }
+}
@@ -1,23 +1,33 @@
 use std::{iter::Peekable, str::Chars};
 
-use crate::{Lex, LineColumn, Span};
+use crate::{Lex, LexOptions, LineColumn, Span};
 
 #[derive(Debug)]
 pub struct LexBuffer<'a> {
     inner: Peekable<Chars<'a>>,
     current_offset: usize,
     current: LineColumn,
+    options: LexOptions,
 }
 
 impl<'a> LexBuffer<'a> {
     pub fn new(str: &'a str) -> Self {
+        Self::new_with_options(str, LexOptions::default())
+    }
+
+    pub fn new_with_options(str: &'a str, options: LexOptions) -> Self {
         Self {
             inner: str.chars().peekable(),
             current_offset: 0,
             current: Default::default(),
+            options,
         }
     }
 
+    pub fn options(&self) -> &LexOptions {
+        &self.options
+    }
+
     pub fn current(&self) -> LineColumn {
         self.current
     }
@@ -44,6 +54,7 @@ impl<'a> LexBuffer<'a> {
             inner: self.inner.clone(),
             current_offset: self.current_offset,
             current: self.current,
+            options: self.options,
         }
     }
 
@@ -52,49 +63,44 @@ impl<'a> LexBuffer<'a> {
     }
 
     pub fn skip_ws_and_comments(&mut self) -> bool {
-        let mut fork = self.fork();
-
         let mut any = false;
 
         loop {
-            let next = fork.next();
-
-            if next.is_some_and(|n| n.is_whitespace()) {
-                self.next();
-                any = true;
-            }
-            // TODO: deal with comments
-            else if next.is_some_and(|n| n == '#') {
-                self.next();
-
-                while fork.next() != Some('\n') {
+            match self.peek() {
+                Some(next) if next.is_whitespace() => {
                     self.next();
                 }
-                any = true;
-            } else {
-                break;
+                // TODO: deal with `/* */` comments
+                Some('#') => {
+                    // The newline is skipped as whitespace.
+                    while self.peek().is_some_and(|n| n != '\n') {
+                        self.next();
+                    }
+                }
+                _ => break,
             }
+
+            any = true;
         }
 
         any
     }
 
+    #[expect(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Option<char> {
-        loop {
-            let value = self.inner.next()?;
+        let value = self.inner.next()?;
 
-            self.current_offset += value.len_utf8();
-            self.current.column += 1;
+        self.current_offset += value.len_utf8();
+        self.current.column += 1;
 
-            if value == '\n' {
-                self.current = LineColumn {
-                    line: self.current.line + 1,
-                    column: 0,
-                };
-            }
-
-            return Some(value);
+        if value == '\n' {
+            self.current = LineColumn {
+                line: self.current.line + 1,
+                column: 0,
+            };
         }
+
+        Some(value)
     }
 
     pub fn lex<T: Lex>(&mut self) -> crate::Result<T> {
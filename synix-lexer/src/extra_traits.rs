@@ -0,0 +1,47 @@
+//! `PartialEq`, `Eq` and `Hash` for tokens that have a span, which
+//! is ignored: tokens are equal if they have the same contents.
+
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    Ident, TokenStream,
+    group::Group,
+    literal::{LitFloat, LitInt, LitInterpolatedStr, LitStr, LitUrl},
+    punct::Punct,
+};
+
+/// Implement the traits by comparing and hashing the key that
+/// `$key` returns for a node.
+macro_rules! by_key {
+    ($($ty:ty => fn($node:ident) $key:block)*) => {
+        $(
+            impl PartialEq for $ty {
+                fn eq(&self, other: &Self) -> bool {
+                    fn key($node: &$ty) -> impl PartialEq + '_ $key
+                    key(self) == key(other)
+                }
+            }
+
+            impl Eq for $ty {}
+
+            impl Hash for $ty {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    let $node = self;
+                    $key.hash(state);
+                }
+            }
+        )*
+    };
+}
+
+by_key! {
+    Ident => fn(node) { &node.ident }
+    Punct => fn(node) { (&node.spacing, node.ch) }
+    Group => fn(node) { (node.delimiter, &node.inner) }
+    TokenStream => fn(node) { node.as_ref() }
+    LitStr => fn(node) { &node.value }
+    LitInt => fn(node) { &node.digits }
+    LitFloat => fn(node) { &node.digits }
+    LitInterpolatedStr => fn(node) { &node.parts }
+    LitUrl => fn(node) { &node.url }
+}
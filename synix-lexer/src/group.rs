@@ -1,6 +1,7 @@
 use crate::{Error, Lex, LexBuffer, Span, TokenStream};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Group {
     pub delimiter: Delimiter,
     pub inner: TokenStream,
@@ -13,15 +14,10 @@ impl Group {
     }
 
     pub fn starts(buf: &mut LexBuffer) -> bool {
-        match buf.peek() {
-            Some('[') | Some('(') | Some('{') => true,
-            _ => false,
-        }
+        matches!(buf.peek(), Some('[') | Some('(') | Some('{'))
     }
-}
 
-impl Lex for Group {
-    fn lex(buffer: &mut LexBuffer) -> crate::Result<Self> {
+    fn lex_delimited(buffer: &mut LexBuffer) -> crate::Result<Self> {
         use Delimiter::*;
 
         let start = buffer.current();
@@ -56,25 +52,21 @@ impl Lex for Group {
             Default::default()
         };
 
-        let span = buffer.span_from(start);
-
-        let me = Self {
-            delimiter,
-            inner,
-            span: span.clone(),
-        };
-
         let next = buffer.next();
+        let span = buffer.span_from(start);
 
         if next == Some(closing) {
-            Ok(me)
+            Ok(Self {
+                delimiter,
+                inner,
+                span,
+            })
         } else if let Some(next) = next {
             Err(Error::new(
                 span,
                 format!("Unclosed group. Expecting {}, got {}", closing, next),
             ))
         } else {
-            let span = buffer.span_from(start);
             Err(Error::new(
                 span,
                 format!("Unclosed group. Expecting {}, got EOF", closing),
@@ -83,7 +75,17 @@ impl Lex for Group {
     }
 }
 
+impl Lex for Group {
+    fn lex(buffer: &mut LexBuffer) -> crate::Result<Self> {
+        // Groups can be nested arbitrarily deep, so each one is lexed
+        // on a new stack if the current one runs low.
+        crate::ensure_stack(|| Self::lex_delimited(buffer))
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "extra-traits", derive(Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Delimiter {
     Brace,
     Paren,
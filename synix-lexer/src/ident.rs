@@ -1,6 +1,7 @@
 use crate::{Error, Lex, LexBuffer, Span};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ident {
     pub ident: String,
     span: Span,
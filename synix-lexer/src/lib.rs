@@ -1,8 +1,11 @@
 mod buffer;
 mod error;
+#[cfg(feature = "extra-traits")]
+mod extra_traits;
 pub mod group;
 mod ident;
 pub mod literal;
+mod options;
 pub mod punct;
 mod span;
 mod token_stream;
@@ -11,14 +14,29 @@ pub use buffer::LexBuffer;
 pub use error::Error;
 use group::Group;
 pub use ident::Ident;
-use literal::Literal;
+use literal::{LitUrl, Literal};
+pub use options::LexOptions;
 use punct::Punct;
 pub use span::Span;
 pub use token_stream::{IntoIter, TokenStream};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Run `f`, on a new stack if less than `RED_ZONE` bytes of the
+/// current one are left. Called before every level of recursion that
+/// depends on the input, so that deeply nested input does not
+/// overflow the stack.
+fn ensure_stack<R>(f: impl FnOnce() -> R) -> R {
+    const RED_ZONE: usize = 128 * 1024;
+    const STACK_SIZE: usize = 2 * 1024 * 1024;
+
+    stacker::maybe_grow(RED_ZONE, STACK_SIZE, f)
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "extra-traits", derive(PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum TokenTree {
     Group(Group),
     Ident(Ident),
@@ -39,7 +57,10 @@ impl TokenTree {
 
 impl Lex for TokenTree {
     fn lex(input: &mut LexBuffer) -> Result<Self> {
-        let tree = if Group::starts(input) {
+        let tree = if LitUrl::starts(input) {
+            let url = input.lex()?;
+            TokenTree::Literal(Literal::Url(url))
+        } else if Group::starts(input) {
             let group = input.lex()?;
             TokenTree::Group(group)
         } else if Literal::starts(input.peek()) {
@@ -59,7 +80,8 @@ impl Lex for TokenTree {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineColumn {
     pub line: usize,
     pub column: usize,
@@ -73,6 +95,11 @@ impl LineColumn {
     pub const fn new(line: usize, column: usize) -> Self {
         Self { line, column }
     }
+
+    /// Whether `self` comes before `other` in the source.
+    pub const fn is_before(&self, other: &Self) -> bool {
+        self.line < other.line || (self.line == other.line && self.column < other.column)
+    }
 }
 
 pub trait Lex: Sized {
@@ -1,10 +1,16 @@
-use crate::{Error, Lex, LexBuffer, Result, Span};
+use crate::{Error, Lex, LexBuffer, Result, Span, group::Group};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "extra-traits", derive(PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum Literal {
     Int(LitInt),
     Float(LitFloat),
     Str(LitStr),
+    InterpolatedStr(LitInterpolatedStr),
+    /// Only lexed with [`LexOptions::url_literals`](crate::LexOptions::url_literals).
+    Url(LitUrl),
 }
 
 impl Literal {
@@ -13,6 +19,8 @@ impl Literal {
             Literal::Int(lit_int) => lit_int.span(),
             Literal::Float(lit_float) => lit_float.span(),
             Literal::Str(lit_str) => lit_str.span(),
+            Literal::InterpolatedStr(lit_str) => lit_str.span(),
+            Literal::Url(lit_url) => lit_url.span(),
         }
     }
 
@@ -32,8 +40,7 @@ impl Lex for Literal {
         let peeked = buffer.peek();
 
         if peeked == Some('"') || peeked == Some('\'') {
-            let str = buffer.lex()?;
-            Ok(Self::Str(str))
+            lex_string(buffer)
         } else if peeked.is_some_and(|v| v.is_numeric()) {
             let num: IntOrFloat = buffer.lex()?;
 
@@ -57,6 +64,7 @@ macro_rules! literal {
     ($($name:ident, $value_name:ident = $value:ty),*$(,)?) => {
         $(
             #[derive(Debug, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct $name {
                 pub $value_name: $value,
                 span: Span,
@@ -75,55 +83,192 @@ literal! {
     LitStr, value = String,
     LitInt, digits = String,
     LitFloat, digits = String,
+    LitInterpolatedStr, parts = Vec<StrPart>,
+    LitUrl, url = String,
+}
+
+impl LitUrl {
+    fn is_scheme_char(char: char) -> bool {
+        char.is_ascii_alphanumeric() || matches!(char, '+' | '-' | '.')
+    }
+
+    fn is_url_char(char: char) -> bool {
+        char.is_ascii_alphanumeric()
+            || matches!(
+                char,
+                '%' | '/'
+                    | '?'
+                    | ':'
+                    | '@'
+                    | '&'
+                    | '='
+                    | '+'
+                    | '$'
+                    | ','
+                    | '-'
+                    | '_'
+                    | '.'
+                    | '!'
+                    | '~'
+                    | '*'
+                    | '\''
+            )
+    }
+
+    /// Whether the buffer starts with a URL, and URL
+    /// literals are enabled.
+    pub fn starts(buffer: &LexBuffer) -> bool {
+        buffer.options().has_url_literals() && Self::lex(&mut buffer.fork()).is_ok()
+    }
+}
+
+impl Lex for LitUrl {
+    fn lex(buffer: &mut LexBuffer) -> Result<Self> {
+        let start = buffer.current();
+        let mut url = String::new();
+
+        match buffer.next() {
+            Some(char) if char.is_ascii_alphabetic() => url.push(char),
+            _ => return Err(Error::new(buffer.span_from(start), "Expected URL.")),
+        }
+
+        while let Some(char) = buffer.peek()
+            && Self::is_scheme_char(char)
+        {
+            let _ = buffer.next();
+            url.push(char);
+        }
+
+        if buffer.next() != Some(':') {
+            return Err(Error::new(buffer.span_from(start), "Expected URL scheme."));
+        }
+        url.push(':');
+
+        let scheme_len = url.len();
+        while let Some(char) = buffer.peek()
+            && Self::is_url_char(char)
+        {
+            let _ = buffer.next();
+            url.push(char);
+        }
+
+        let span = buffer.span_from(start);
+
+        if url.len() == scheme_len {
+            return Err(Error::new(span, "Expected URL."));
+        }
+
+        Ok(Self { url, span })
+    }
 }
 
 impl Lex for LitStr {
     fn lex(buffer: &mut LexBuffer) -> crate::Result<Self> {
-        if buffer.peek() == Some('"') {
-            let start = buffer.current();
-            let _ = buffer.next();
+        let span = buffer.span();
 
-            // TODO: handle escape sequences other than \"
-            let mut escaped = false;
-            let mut finished = false;
-            let mut value = String::new();
-            while let Some(char) = buffer.next() {
-                if char == '\\' {
-                    escaped = true;
-                    continue;
-                } else if char == '\r' || char == '\n' {
+        match lex_string(buffer)? {
+            Literal::Str(str) => Ok(str),
+            _ => Err(Error::new(
+                span,
+                "Expected string literal, got interpolated string.",
+            )),
+        }
+    }
+}
+
+/// A part of a [`LitInterpolatedStr`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "extra-traits", derive(PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
+pub enum StrPart {
+    Str(String),
+    /// The braced group following a `$`.
+    Interpolation(Group),
+}
+
+/// Lex a string literal, which may contain interpolations.
+fn lex_string(buffer: &mut LexBuffer) -> Result<Literal> {
+    if buffer.peek() == Some('"') {
+        let start = buffer.current();
+        let _ = buffer.next();
+
+        let mut finished = false;
+        let mut parts = Vec::new();
+        let mut value = String::new();
+        while let Some(char) = buffer.next() {
+            match char {
+                '\\' => match buffer.next() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some(char @ ('\\' | '"' | '$')) => value.push(char),
+                    Some(char) if buffer.options().is_strict() => {
+                        let msg = format!("Unknown escape sequence `\\{char}`.");
+                        return Err(Error::new(buffer.span_from(start), msg));
+                    }
+                    Some(char) => value.push(char),
+                    None => break,
+                },
+                '\r' | '\n' => {
                     return Err(Error::new(
                         buffer.span_from(start),
                         "Unterminated string. For multiline strings, use `'''`",
                     ));
-                } else if char == '"' {
-                    if escaped {
-                        escaped = false;
-                    } else {
-                        finished = true;
-                        break;
-                    }
                 }
+                '"' => {
+                    finished = true;
+                    break;
+                }
+                // `$${` does not start an interpolation.
+                '$' if buffer.peek() == Some('$') => {
+                    let _ = buffer.next();
+                    value.push_str("$$");
+                }
+                '$' if buffer.peek() == Some('{') => {
+                    let group = buffer.lex()?;
+
+                    if !value.is_empty() {
+                        parts.push(StrPart::Str(core::mem::take(&mut value)));
+                    }
 
-                value.push(char);
+                    parts.push(StrPart::Interpolation(group));
+                }
+                char => value.push(char),
             }
+        }
 
-            let span = buffer.span_from(start);
+        let span = buffer.span_from(start);
 
-            if !finished {
-                return Err(Error::new(span, "Unterminated string."));
-            }
+        if !finished {
+            return Err(Error::new(span, "Unterminated string."));
+        }
 
-            // Nix does not require string literals to be whitespace-separated from
-            // successive token trees.
+        // Nix does not require string literals to be whitespace-separated from
+        // successive token trees.
 
-            Ok(Self { value, span })
-        } else if buffer.peek() == Some('\'') {
-            let _ = buffer.next();
-            todo!("Multiline strings")
+        if parts.is_empty() {
+            Ok(Literal::Str(LitStr { value, span }))
         } else {
-            Err(Error::new(buffer.span(), "Expected string literal"))
+            if !value.is_empty() {
+                parts.push(StrPart::Str(value));
+            }
+
+            Ok(Literal::InterpolatedStr(LitInterpolatedStr { parts, span }))
         }
+    } else if buffer.peek() == Some('\'') {
+        let start = buffer.current();
+        let _ = buffer.next();
+
+        let msg = if buffer.peek() == Some('\'') {
+            "Indented strings (`''`) are not supported yet."
+        } else {
+            "Unexpected `'`."
+        };
+
+        Err(Error::new(buffer.span_from(start), msg))
+    } else {
+        Err(Error::new(buffer.span(), "Expected string literal"))
     }
 }
 
@@ -152,7 +297,7 @@ impl Lex for IntOrFloat {
                     peeked
                 )
             } else {
-                format!("Expected integer (floats are not supported yet), got end of input.")
+                "Expected integer (floats are not supported yet), got end of input.".to_string()
             };
 
             return Err(Error::new(buffer.span(), msg));
@@ -0,0 +1,41 @@
+/// Options that control what input the lexer accepts.
+///
+/// The default options are permissive, and do not lex URL literals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LexOptions {
+    url_literals: bool,
+    strict: bool,
+}
+
+impl LexOptions {
+    pub const fn new() -> Self {
+        Self {
+            url_literals: false,
+            strict: false,
+        }
+    }
+
+    /// Lex unquoted URLs such as `https://nixos.org` as a single
+    /// [`LitUrl`](crate::literal::LitUrl).
+    ///
+    /// Note that this makes `x:x` a URL instead of a lambda.
+    pub const fn url_literals(mut self, enabled: bool) -> Self {
+        self.url_literals = enabled;
+        self
+    }
+
+    /// Reject input that Nix accepts but that is most likely a
+    /// mistake, such as unknown escape sequences in strings.
+    pub const fn strict(mut self, enabled: bool) -> Self {
+        self.strict = enabled;
+        self
+    }
+
+    pub const fn has_url_literals(&self) -> bool {
+        self.url_literals
+    }
+
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt::Write;
 use crate::{Error, Lex, LexBuffer, Span};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Punct {
     pub spacing: Spacing,
     pub ch: Char,
@@ -72,6 +73,8 @@ impl Lex for Punct {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "extra-traits", derive(Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Spacing {
     Alone,
     Joint,
@@ -84,6 +87,8 @@ impl Spacing {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "extra-traits", derive(Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Char {
     Semicolon,
@@ -110,11 +115,9 @@ impl Char {
     pub const fn eq(&self, other: &Self) -> bool {
         *self as u8 == *other as u8
     }
-}
 
-impl core::fmt::Display for Char {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let v = match self {
+    pub const fn as_char(&self) -> char {
+        match self {
             Char::Semicolon => ';',
             Char::Colon => ':',
             Char::Comma => ',',
@@ -133,8 +136,12 @@ impl core::fmt::Display for Char {
             Char::Exclamation => '!',
             Char::Dollar => '$',
             Char::Tilde => '~',
-        };
+        }
+    }
+}
 
-        f.write_char(v)
+impl core::fmt::Display for Char {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.as_char())
     }
 }
@@ -1,6 +1,7 @@
 use crate::LineColumn;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Span {
     pub(crate) start: LineColumn,
     pub(crate) end: LineColumn,
@@ -15,14 +16,15 @@ impl Span {
         Self { start, end }
     }
 
+    /// Returns a span that covers both `self` and `other`.
     pub const fn join(&self, other: &Span) -> Span {
-        let start = if self.start.line < other.start.line {
+        let start = if self.start.is_before(&other.start) {
             self.start
         } else {
             other.start
         };
 
-        let end = if self.end.line > other.end.line {
+        let end = if other.end.is_before(&self.end) {
             self.end
         } else {
             other.end
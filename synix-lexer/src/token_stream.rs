@@ -1,8 +1,9 @@
 use std::str::FromStr;
 
-use crate::{Error, LexBuffer, TokenTree};
+use crate::{Error, LexBuffer, LexOptions, TokenTree};
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenStream {
     trees: Vec<TokenTree>,
 }
@@ -19,6 +20,27 @@ impl TokenStream {
     pub fn is_empty(&self) -> bool {
         self.trees.is_empty()
     }
+
+    /// Lex `s` using `options`.
+    pub fn lex_with_options(s: &str, options: LexOptions) -> crate::Result<Self> {
+        let mut buffer = LexBuffer::new_with_options(s, options);
+        buffer.lex()
+    }
+}
+
+impl Drop for TokenStream {
+    /// Groups are nested arbitrarily deep, so the trees of nested
+    /// groups are moved here and dropped in a loop rather than
+    /// recursively.
+    fn drop(&mut self) {
+        let mut trees = std::mem::take(&mut self.trees);
+
+        while let Some(tree) = trees.pop() {
+            if let TokenTree::Group(mut group) = tree {
+                trees.append(&mut group.inner.trees);
+            }
+        }
+    }
 }
 
 impl AsRef<[TokenTree]> for TokenStream {
@@ -45,9 +67,9 @@ impl IntoIterator for TokenStream {
 
     type IntoIter = IntoIter;
 
-    fn into_iter(self) -> Self::IntoIter {
+    fn into_iter(mut self) -> Self::IntoIter {
         IntoIter {
-            inner: self.trees.into_iter(),
+            inner: std::mem::take(&mut self.trees).into_iter(),
         }
     }
 }
@@ -56,7 +78,6 @@ impl FromStr for TokenStream {
     type Err = Error;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let mut buffer = LexBuffer::new(s);
-        buffer.lex()
+        Self::lex_with_options(s, LexOptions::default())
     }
 }
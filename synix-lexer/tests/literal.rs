@@ -1,6 +1,6 @@
 use synix_lexer::{
-    Lex, LexBuffer,
-    literal::{LitStr, Literal},
+    Lex, LexBuffer, LexOptions, TokenTree,
+    literal::{LitStr, Literal, StrPart},
 };
 
 #[test]
@@ -34,3 +34,73 @@ pub fn long_integer() {
 
     assert_eq!(output.digits, digits);
 }
+
+#[test]
+pub fn escape_sequences() {
+    let mut buffer = LexBuffer::new(r#""a\nb\tc\\d\${e}""#);
+
+    let output = LitStr::lex(&mut buffer).unwrap();
+
+    assert_eq!(output.value, "a\nb\tc\\d${e}");
+}
+
+#[test]
+pub fn interpolated_string() {
+    let mut buffer = LexBuffer::new(r#""a ${b} c$${d}""#);
+
+    let output = match Literal::lex(&mut buffer) {
+        Ok(Literal::InterpolatedStr(str)) => str,
+        v => panic!("Expected interpolated string, got {v:?}"),
+    };
+
+    assert!(buffer.is_empty());
+    assert_eq!(output.parts.len(), 3);
+    assert!(matches!(&output.parts[0], StrPart::Str(str) if str == "a "));
+    assert!(matches!(&output.parts[1], StrPart::Interpolation(group) if !group.inner.is_empty()));
+    assert!(matches!(&output.parts[2], StrPart::Str(str) if str == " c$${d}"));
+}
+
+#[test]
+pub fn url() {
+    let options = LexOptions::new().url_literals(true);
+    let input = "https://example.org/a?b=c&d=e";
+    let mut buffer = LexBuffer::new_with_options(input, options);
+
+    let output = match TokenTree::lex(&mut buffer) {
+        Ok(TokenTree::Literal(Literal::Url(url))) => url,
+        v => panic!("Expected literal url, got {v:?}"),
+    };
+
+    assert_eq!(output.url, input);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+pub fn url_requires_option() {
+    let mut buffer = LexBuffer::new("x:x");
+    assert!(matches!(
+        TokenTree::lex(&mut buffer),
+        Ok(TokenTree::Ident(_))
+    ));
+
+    // A lambda with whitespace after the colon is never a URL.
+    let options = LexOptions::new().url_literals(true);
+    let mut buffer = LexBuffer::new_with_options("x: x", options);
+    assert!(matches!(
+        TokenTree::lex(&mut buffer),
+        Ok(TokenTree::Ident(_))
+    ));
+}
+
+#[test]
+pub fn strict_escape_sequences() {
+    let mut buffer = LexBuffer::new(r#""\q""#);
+    assert_eq!(LitStr::lex(&mut buffer).unwrap().value, "q");
+
+    let options = LexOptions::new().strict(true);
+    let mut buffer = LexBuffer::new_with_options(r#""\q""#, options);
+    assert!(LitStr::lex(&mut buffer).is_err());
+
+    let mut buffer = LexBuffer::new_with_options(r#""\"\\\$\n""#, options);
+    assert_eq!(LitStr::lex(&mut buffer).unwrap().value, "\"\\$\n");
+}
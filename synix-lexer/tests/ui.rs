@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use synix_lexer::{
-    Ident, TokenStream, TokenTree,
+    TokenStream, TokenTree,
     literal::{LitInt, LitStr, Literal},
 };
 
@@ -23,8 +23,31 @@ pub fn chained_idents_literals() {
 
     assert!(matches!(
         parse.next(),
-        Some(TokenTree::Literal(Literal::Str(LitStr { value, .. }))) if value == ""
+        Some(TokenTree::Literal(Literal::Str(LitStr { value, .. }))) if value.is_empty()
     ));
 
     assert!(parse.next().is_none());
 }
+
+#[test]
+pub fn comments() {
+    let input = "# leading\n  a # trailing\n\n  # own line\n  b # at end of input";
+
+    let trees: Vec<_> = TokenStream::from_str(input).unwrap().into_iter().collect();
+
+    assert!(matches!(
+        trees.as_slice(),
+        [TokenTree::Ident(a), TokenTree::Ident(b)] if a.ident() == "a" && b.ident() == "b"
+    ));
+}
+
+#[test]
+pub fn indented_strings_are_errors() {
+    let error = TokenStream::from_str("''\n  a\n''").unwrap_err();
+    assert_eq!(
+        error.message(),
+        "Indented strings (`''`) are not supported yet."
+    );
+
+    assert!(TokenStream::from_str("'").is_err());
+}
@@ -0,0 +1,9721 @@
+# Generated package set, in the style of nixpkgs' hackage-packages.nix.
+{ pkgs, lib, callPackage }:
+
+self: {
+
+  "async-0" = callPackage
+    ({ aeson, async, binary, deepseq, lib, megaparsec, mkDerivation, mtl, time }:
+     mkDerivation {
+       pname = "async-0";
+       version = "2.4.1";
+       sha256 = "zfd9yd14v48za83jmm6gih0j8nvbcri1p6jjz77f9mylv70pvsl0";
+       libraryHaskellDepends = [
+         async binary mtl time
+       ];
+       testHaskellDepends = [ aeson binary deepseq megaparsec ];
+       homepage = "https://github.com/example/async-0";
+       description = "Package number 0";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "megaparsec-1" = callPackage
+    ({ QuickCheck, aeson, criterion, hashable, lib, mkDerivation, network }:
+     mkDerivation {
+       pname = "megaparsec-1";
+       version = "9.5.3";
+       sha256 = "9aivrv0xj26110mp5ww6w5yad4cgn2rv2i1lrv5fxl734c8d68gh";
+       libraryHaskellDepends = [
+         aeson criterion hashable network
+       ];
+       testHaskellDepends = [ QuickCheck ];
+       homepage = "https://github.com/example/megaparsec-1";
+       description = "Package number 1";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "network-2" = callPackage
+    ({ QuickCheck, base, lib, mkDerivation, parsec, process, tls, vector }:
+     mkDerivation {
+       pname = "network-2";
+       version = "9.4.1";
+       sha256 = "856qxmxrkjyc80xsfk7c2br6mr2nadm9c23w9zp63f1439fq62wz";
+       libraryHaskellDepends = [
+         QuickCheck process
+       ];
+       testHaskellDepends = [ base parsec tls vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/network-2";
+       description = "Package number 2";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "megaparsec-3" = callPackage
+    ({ deepseq, filepath, hspec, lens, lib, mkDerivation, vector, zlib }:
+     mkDerivation {
+       pname = "megaparsec-3";
+       version = "9.17.4";
+       sha256 = "bgmsrmnndaz5fbkv4ghyvhx5r7ki3qnsbilbq4306ms9gkjsajbl";
+       libraryHaskellDepends = [
+         deepseq filepath hspec lens vector zlib
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace megaparsec-3.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/megaparsec-3";
+       description = "Package number 3";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "directory-4" = callPackage
+    ({ binary, criterion, filepath, hspec, http_client, lib, mkDerivation, parsec, tasty, time, transformers }:
+     mkDerivation {
+       pname = "directory-4";
+       version = "8.1.7";
+       sha256 = "vfr52x8kfd1qjs4z1p5abr61p6fz6yi42zdx6lzsx697y9ly07w1";
+       libraryHaskellDepends = [
+         binary criterion hspec http_client tasty time
+       ];
+       testHaskellDepends = [ filepath parsec transformers ];
+       homepage = "https://github.com/example/directory-4";
+       description = "Package number 4";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "vector-5" = callPackage
+    ({ containers, http_client, lib, mkDerivation, parsec, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "vector-5";
+       version = "3.7.4";
+       sha256 = "ign8w01675pwd3a4jmva07a30gssd2yc71v8fwpcrpq675zm7jkg";
+       libraryHaskellDepends = [
+         containers http_client parsec tasty unordered_containers
+       ];
+       postPatch = "substituteInPlace vector-5.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/vector-5";
+       description = "Package number 5";
+       license = lib.licenses.mit;
+     }) {};
+
+  "process-6" = callPackage
+    ({ aeson, binary, lens, lib, mkDerivation, process, time, tls, zlib }:
+     mkDerivation {
+       pname = "process-6";
+       version = "4.20.4";
+       sha256 = "rp2xmx5pdrq9fbnlqn496lg1kfqb965f3rkg81x6k7g2wrkxwcg9";
+       libraryHaskellDepends = [
+         binary lens process tls zlib
+       ];
+       testHaskellDepends = [ aeson time ];
+       homepage = "https://github.com/example/process-6";
+       description = "Package number 6";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "deepseq-7" = callPackage
+    ({ base, bytestring, filepath, lib, megaparsec, mkDerivation, process, time, zlib }:
+     mkDerivation {
+       pname = "deepseq-7";
+       version = "6.12.7";
+       sha256 = "zazxzkfv45k2bfdg80skyjyagbn8wb4g4zv8a5bmz39zvii2s5wj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base bytestring filepath process time
+       ];
+       testHaskellDepends = [ filepath megaparsec zlib ];
+       homepage = "https://github.com/example/deepseq-7";
+       description = "Package number 7";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "aeson-8" = callPackage
+    ({ binary, bytestring, containers, lens, lib, mkDerivation, network, tls, vector }:
+     mkDerivation {
+       pname = "aeson-8";
+       version = "1.1.6";
+       sha256 = "76cmlnvxrjf6aykrw8l98xgk48lb3zfpr8k6v97dvdkz9f5648fp";
+       libraryHaskellDepends = [
+         binary containers lens network tls vector
+       ];
+       testHaskellDepends = [ bytestring ];
+       postPatch = "substituteInPlace aeson-8.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/aeson-8";
+       description = "Package number 8";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "tasty-9" = callPackage
+    ({ QuickCheck, criterion, filepath, lib, mkDerivation, time, tls }:
+     mkDerivation {
+       pname = "tasty-9";
+       version = "9.6.6";
+       sha256 = "l7s3vfp22y0g3fp41xp23w5l35jm6yc64xfrlpb367r6wd8r0ysv";
+       libraryHaskellDepends = [
+         QuickCheck criterion filepath time tls
+       ];
+       postPatch = "substituteInPlace tasty-9.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/tasty-9";
+       description = "Package number 9";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "text-10" = callPackage
+    ({ http_client, lib, mkDerivation, parsec }:
+     mkDerivation {
+       pname = "text-10";
+       version = "2.0.8";
+       sha256 = "5pg152n5dfxprrpw92ib3vyl3dx6n4j73fyqvq8rnhhqk52xvhcy";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         http_client parsec
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace text-10.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/text-10";
+       description = "Package number 10";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tasty-11" = callPackage
+    ({ containers, deepseq, filepath, lens, lib, mkDerivation, mtl, network, process, stm, text, vector }:
+     mkDerivation {
+       pname = "tasty-11";
+       version = "9.6.0";
+       sha256 = "avaw93hpsjlb1na8my239lpddll1k96nb25jyq1zl7yl0kynpymp";
+       libraryHaskellDepends = [
+         containers deepseq lens mtl network process text vector
+       ];
+       testHaskellDepends = [ filepath stm text ];
+       postPatch = "substituteInPlace tasty-11.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/tasty-11";
+       description = "Package number 11";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "text-12" = callPackage
+    ({ directory, lib, mkDerivation, parsec, process, unordered_containers }:
+     mkDerivation {
+       pname = "text-12";
+       version = "9.0.3";
+       sha256 = "hh02fk34i6maga60iimyh1k51wpd5jkbvqmypwdnfm1fqaxwc71k";
+       libraryHaskellDepends = [
+         directory parsec process
+       ];
+       testHaskellDepends = [ unordered_containers ];
+       postPatch = "substituteInPlace text-12.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/text-12";
+       description = "Package number 12";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "aeson-13" = callPackage
+    ({ QuickCheck, containers, hashable, hspec, lib, mkDerivation, mtl, random, stm, transformers, unordered_containers, vector }:
+     mkDerivation {
+       pname = "aeson-13";
+       version = "2.9.0";
+       sha256 = "wr96nimlzczjb8cc5qpascxa1g4vvyfibvmg7k8m9yjh6n6zwydi";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck containers hashable hspec mtl transformers unordered_containers vector
+       ];
+       testHaskellDepends = [ QuickCheck random stm transformers ];
+       postPatch = "substituteInPlace aeson-13.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/aeson-13";
+       description = "Package number 13";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "containers-14" = callPackage
+    ({ QuickCheck, aeson, filepath, hspec, lib, mkDerivation, parsec, text, time, vector }:
+     mkDerivation {
+       pname = "containers-14";
+       version = "3.6.6";
+       sha256 = "299bfmbsnh34d8fy3by7mbx1iskn4v286822wpkkqkyxbxizvy3n";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck filepath hspec parsec text vector
+       ];
+       testHaskellDepends = [ aeson hspec parsec time ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/containers-14";
+       description = "Package number 14";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "network-15" = callPackage
+    ({ base, criterion, http_client, lib, mkDerivation, network, zlib }:
+     mkDerivation {
+       pname = "network-15";
+       version = "8.5.5";
+       sha256 = "vnan01zaj0np8iczw7sikz82w0lvv41pjjv9lbgbvn27lw2cmla6";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base criterion http_client network zlib
+       ];
+       homepage = "https://github.com/example/network-15";
+       description = "Package number 15";
+       license = lib.licenses.mit;
+     }) {};
+
+  "containers-16" = callPackage
+    ({ containers, hashable, lib, mkDerivation, random, time, vector }:
+     mkDerivation {
+       pname = "containers-16";
+       version = "7.15.3";
+       sha256 = "bl1bqy7s7wdxki0bmivm1wlsmw24xw2ra2xkxw97ymlfhc5q29xf";
+       libraryHaskellDepends = [
+         containers hashable time vector
+       ];
+       testHaskellDepends = [ random ];
+       homepage = "https://github.com/example/containers-16";
+       description = "Package number 16";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "deepseq-17" = callPackage
+    ({ binary, criterion, hspec, lib, mkDerivation, mtl, tls, zlib }:
+     mkDerivation {
+       pname = "deepseq-17";
+       version = "2.9.6";
+       sha256 = "c04mvjixppd6vzil8sh2fyck7i3wanf1br34a63s7sm1r8gclskp";
+       libraryHaskellDepends = [
+         binary criterion hspec zlib
+       ];
+       testHaskellDepends = [ mtl tls zlib ];
+       homepage = "https://github.com/example/deepseq-17";
+       description = "Package number 17";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "megaparsec-18" = callPackage
+    ({ QuickCheck, aeson, binary, bytestring, directory, lib, mkDerivation, parsec, random, tls, unordered_containers }:
+     mkDerivation {
+       pname = "megaparsec-18";
+       version = "9.7.1";
+       sha256 = "2mgrfw0v1dkcv0qwmg06qkxnpa6n01h2ijgdjmg5kza9ycwczaza";
+       libraryHaskellDepends = [
+         QuickCheck aeson binary bytestring random unordered_containers
+       ];
+       testHaskellDepends = [ aeson directory parsec tls ];
+       postPatch = "substituteInPlace megaparsec-18.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/megaparsec-18";
+       description = "Package number 18";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-19" = callPackage
+    ({ directory, hashable, lib, mkDerivation, text, time }:
+     mkDerivation {
+       pname = "filepath-19";
+       version = "8.4.0";
+       sha256 = "mxx8l2m2bl56dhh74jvqzn9990f3szkssaqjxk04jfcm1cjxrv32";
+       libraryHaskellDepends = [
+         directory hashable time
+       ];
+       testHaskellDepends = [ text ];
+       postPatch = "substituteInPlace filepath-19.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/filepath-19";
+       description = "Package number 19";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "time-20" = callPackage
+    ({ aeson, base, criterion, lib, mkDerivation, mtl, random, tls }:
+     mkDerivation {
+       pname = "time-20";
+       version = "6.2.2";
+       sha256 = "1fjggh5m4xghk9ci8pcs0nw2l5d3qx8gxppv71asf7q8vzg8zqwd";
+       libraryHaskellDepends = [
+         aeson base criterion mtl tls
+       ];
+       testHaskellDepends = [ random ];
+       postPatch = "substituteInPlace time-20.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/time-20";
+       description = "Package number 20";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "mtl-21" = callPackage
+    ({ aeson, async, base, criterion, deepseq, hashable, hspec, lens, lib, megaparsec, mkDerivation }:
+     mkDerivation {
+       pname = "mtl-21";
+       version = "2.14.3";
+       sha256 = "jjb450h60pf6axd16scpdrrcgkcqc1k0z8gpiysnpncr5slwdb1l";
+       libraryHaskellDepends = [
+         aeson async base criterion hashable hspec megaparsec
+       ];
+       testHaskellDepends = [ deepseq lens ];
+       homepage = "https://github.com/example/mtl-21";
+       description = "Package number 21";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "lens-22" = callPackage
+    ({ hashable, lib, mkDerivation, mtl, process, unordered_containers, vector }:
+     mkDerivation {
+       pname = "lens-22";
+       version = "2.17.9";
+       sha256 = "al9fi2b3fj5y4lbx9x5yl0zkqcmmaq9m4kksbff34zk92x1bchnk";
+       libraryHaskellDepends = [
+         process unordered_containers
+       ];
+       testHaskellDepends = [ hashable mtl vector ];
+       homepage = "https://github.com/example/lens-22";
+       description = "Package number 22";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "zlib-23" = callPackage
+    ({ async, containers, hashable, hspec, lens, lib, mkDerivation, random, stm, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "zlib-23";
+       version = "0.9.4";
+       sha256 = "8vr74p8m80hn8q0f82qaw0aqsxr5kl3pij196p69zr1wn69axhqn";
+       libraryHaskellDepends = [
+         async containers hashable hspec lens random
+       ];
+       testHaskellDepends = [ lens stm tasty unordered_containers ];
+       postPatch = "substituteInPlace zlib-23.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/zlib-23";
+       description = "Package number 23";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "network-24" = callPackage
+    ({ base, containers, directory, filepath, hashable, lib, mkDerivation, mtl, process, text }:
+     mkDerivation {
+       pname = "network-24";
+       version = "9.18.9";
+       sha256 = "n38w9v1w3r19gp2qal7b5cwqapzicg1bj91fbxjjb8w019cdrmxy";
+       libraryHaskellDepends = [
+         containers filepath hashable mtl process
+       ];
+       testHaskellDepends = [ base directory text ];
+       postPatch = "substituteInPlace network-24.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/network-24";
+       description = "Package number 24";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "bytestring-25" = callPackage
+    ({ http_client, lib, mkDerivation, zlib }:
+     mkDerivation {
+       pname = "bytestring-25";
+       version = "1.7.8";
+       sha256 = "sbl87ihcs43kdznj68z9jn3nxazcigq0iqxs68g6mzk3piwvnpla";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         http_client zlib
+       ];
+       testHaskellDepends = [ zlib ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/bytestring-25";
+       description = "Package number 25";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "binary-26" = callPackage
+    ({ async, binary, criterion, lib, mkDerivation, stm }:
+     mkDerivation {
+       pname = "binary-26";
+       version = "0.13.8";
+       sha256 = "gln93h2x99jh9y7kaacyqsaf74qqbw3x8lq8p7kd3za74clvcjiw";
+       libraryHaskellDepends = [
+         async binary criterion stm
+       ];
+       homepage = "https://github.com/example/binary-26";
+       description = "Package number 26";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "mtl-27" = callPackage
+    ({ QuickCheck, binary, bytestring, hashable, lib, mkDerivation, time }:
+     mkDerivation {
+       pname = "mtl-27";
+       version = "6.12.0";
+       sha256 = "6sy79vhck0zqdcd5arjr3l3nbbngpfi70h0kqh14zjrd9i2ny9kq";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary bytestring hashable time
+       ];
+       testHaskellDepends = [ QuickCheck ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace mtl-27.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/mtl-27";
+       description = "Package number 27";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "hspec-28" = callPackage
+    ({ QuickCheck, aeson, directory, hashable, http_client, lens, lib, mkDerivation, mtl, text, unordered_containers }:
+     mkDerivation {
+       pname = "hspec-28";
+       version = "1.7.6";
+       sha256 = "iknkc5q0hrlja11ic3js56yx7fdsxp33hqgq1v6zcprf8wagp840";
+       libraryHaskellDepends = [
+         QuickCheck aeson hashable lens mtl text unordered_containers
+       ];
+       testHaskellDepends = [ QuickCheck directory http_client ];
+       homepage = "https://github.com/example/hspec-28";
+       description = "Package number 28";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "vector-29" = callPackage
+    ({ binary, containers, criterion, deepseq, hashable, lib, mkDerivation, time, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "vector-29";
+       version = "6.0.1";
+       sha256 = "5hqwag4kj2kpxirwl608jkwqi4fxy31fp54jaw7n2l70jjmgqkp0";
+       libraryHaskellDepends = [
+         binary containers criterion deepseq time transformers
+       ];
+       testHaskellDepends = [ hashable time unordered_containers ];
+       homepage = "https://github.com/example/vector-29";
+       description = "Package number 29";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "zlib-30" = callPackage
+    ({ aeson, binary, directory, filepath, lib, mkDerivation, mtl, network, tasty, text, unordered_containers, vector }:
+     mkDerivation {
+       pname = "zlib-30";
+       version = "0.2.9";
+       sha256 = "ncs354yw69ymzd1siwfgjdg6sd4kh8hijshqfk3852h1gxar6001";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary directory filepath mtl tasty unordered_containers
+       ];
+       testHaskellDepends = [ aeson network text vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/zlib-30";
+       description = "Package number 30";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "time-31" = callPackage
+    ({ hashable, lib, megaparsec, mkDerivation, process, tls, unordered_containers }:
+     mkDerivation {
+       pname = "time-31";
+       version = "5.6.3";
+       sha256 = "c2y858q0whp55yps5d7ymlyzfwx4fqqr9brv9im7k12hgl9hajwy";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         process tls
+       ];
+       testHaskellDepends = [ hashable megaparsec unordered_containers ];
+       postPatch = "substituteInPlace time-31.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/time-31";
+       description = "Package number 31";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "unordered-containers-32" = callPackage
+    ({ aeson, base, criterion, deepseq, filepath, hashable, http_client, lens, lib, mkDerivation, network, text }:
+     mkDerivation {
+       pname = "unordered-containers-32";
+       version = "0.10.1";
+       sha256 = "0cnnzz6nybz3s42gy5rnz6iy5v1dcj0s3134l9dfgzw9l56smx1l";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson criterion deepseq filepath hashable lens network text
+       ];
+       testHaskellDepends = [ base http_client ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/unordered-containers-32";
+       description = "Package number 32";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-33" = callPackage
+    ({ deepseq, directory, hashable, lib, mkDerivation, network, tasty, time, transformers }:
+     mkDerivation {
+       pname = "stm-33";
+       version = "9.16.1";
+       sha256 = "1fy5l6rzfbj1ikcpgmwf3nsg750j1i5qanqf71hylmhxlvgfkj82";
+       libraryHaskellDepends = [
+         deepseq hashable tasty time transformers
+       ];
+       testHaskellDepends = [ directory network tasty ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/stm-33";
+       description = "Package number 33";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "criterion-34" = callPackage
+    ({ lens, lib, mkDerivation, tasty, tls }:
+     mkDerivation {
+       pname = "criterion-34";
+       version = "2.0.0";
+       sha256 = "8vr99g7igwbsvp8k10v3jagg35znf5b8m3p5cfdy73p3rkrkgdqf";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         tasty tls
+       ];
+       testHaskellDepends = [ lens ];
+       postPatch = "substituteInPlace criterion-34.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/criterion-34";
+       description = "Package number 34";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "base-35" = callPackage
+    ({ criterion, filepath, hashable, lens, lib, megaparsec, mkDerivation, mtl, time }:
+     mkDerivation {
+       pname = "base-35";
+       version = "2.19.4";
+       sha256 = "sjg9n44jlhp76vrhg2rz7a6kbh8hly8qqaj9n7p915k8h91nca9k";
+       libraryHaskellDepends = [
+         criterion filepath lens megaparsec mtl
+       ];
+       testHaskellDepends = [ hashable lens time ];
+       postPatch = "substituteInPlace base-35.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/base-35";
+       description = "Package number 35";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "parsec-36" = callPackage
+    ({ base, hspec, http_client, lib, megaparsec, mkDerivation, time }:
+     mkDerivation {
+       pname = "parsec-36";
+       version = "1.17.8";
+       sha256 = "qc5p3y0qy2264a0lmwfpq3rq6kmaqw9va2lp2wj25dvsqxa9skrx";
+       libraryHaskellDepends = [
+         hspec megaparsec time
+       ];
+       testHaskellDepends = [ base http_client time ];
+       homepage = "https://github.com/example/parsec-36";
+       description = "Package number 36";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "QuickCheck-37" = callPackage
+    ({ aeson, containers, lib, mkDerivation, parsec, process, stm, time, vector, zlib }:
+     mkDerivation {
+       pname = "QuickCheck-37";
+       version = "1.13.7";
+       sha256 = "syq3jz9ndixz2rs9v3wnb6nfg2qmrc5iqzvs1zxs38m0dn7jcw4d";
+       libraryHaskellDepends = [
+         aeson containers parsec process stm vector zlib
+       ];
+       testHaskellDepends = [ time ];
+       homepage = "https://github.com/example/QuickCheck-37";
+       description = "Package number 37";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "directory-38" = callPackage
+    ({ base, binary, containers, criterion, http_client, lib, mkDerivation, network, process, zlib }:
+     mkDerivation {
+       pname = "directory-38";
+       version = "9.7.6";
+       sha256 = "cswk8k0ch3h85s5srdfxrs6wh47igx9fljrzbl5kwjfjy1dhaddc";
+       libraryHaskellDepends = [
+         base binary containers criterion http_client network process
+       ];
+       testHaskellDepends = [ network zlib ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace directory-38.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/directory-38";
+       description = "Package number 38";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "deepseq-39" = callPackage
+    ({ QuickCheck, criterion, filepath, hspec, lib, mkDerivation, parsec, tls, transformers }:
+     mkDerivation {
+       pname = "deepseq-39";
+       version = "3.6.9";
+       sha256 = "nrkd2yxw0fbsy4dq5xrw84k7k14i6245nkvs70q1p6yx79yk2v38";
+       libraryHaskellDepends = [
+         criterion filepath parsec
+       ];
+       testHaskellDepends = [ QuickCheck hspec tls transformers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/deepseq-39";
+       description = "Package number 39";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "transformers-40" = callPackage
+    ({ aeson, async, deepseq, directory, hashable, lib, mkDerivation, network, parsec, random, tls, vector }:
+     mkDerivation {
+       pname = "transformers-40";
+       version = "6.3.2";
+       sha256 = "kd1pa5ggmiww046j8msvjqic6dsnrzmj2ig13hrf47l40xyyzvlq";
+       libraryHaskellDepends = [
+         aeson async deepseq hashable network random tls vector
+       ];
+       testHaskellDepends = [ async directory parsec ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/transformers-40";
+       description = "Package number 40";
+       license = lib.licenses.mit;
+     }) {};
+
+  "unordered-containers-41" = callPackage
+    ({ async, containers, deepseq, http_client, lens, lib, megaparsec, mkDerivation, network, parsec, time, tls, zlib }:
+     mkDerivation {
+       pname = "unordered-containers-41";
+       version = "3.12.3";
+       sha256 = "bj54vxn0bqq2a69wl2rcfhna3ya3cn5msbzk9bc1x4265vl59jbp";
+       libraryHaskellDepends = [
+         async containers deepseq http_client parsec time tls
+       ];
+       testHaskellDepends = [ lens megaparsec network zlib ];
+       homepage = "https://github.com/example/unordered-containers-41";
+       description = "Package number 41";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "http-client-42" = callPackage
+    ({ base, bytestring, lens, lib, mkDerivation, mtl, parsec, tasty, tls, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "http-client-42";
+       version = "8.7.5";
+       sha256 = "j10145yh9cz8yzyr0k33cfzgknzg516dk590226fw1qf1f56f7mk";
+       libraryHaskellDepends = [
+         base bytestring lens mtl parsec tls unordered_containers zlib
+       ];
+       testHaskellDepends = [ tasty ];
+       homepage = "https://github.com/example/http-client-42";
+       description = "Package number 42";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hashable-43" = callPackage
+    ({ aeson, binary, deepseq, hashable, lib, mkDerivation, process, tls }:
+     mkDerivation {
+       pname = "hashable-43";
+       version = "8.11.0";
+       sha256 = "vjqahf3im2159x2z9rlvhkscr6l61q0r608n6n3zrv8fcq98wnqz";
+       libraryHaskellDepends = [
+         deepseq process
+       ];
+       testHaskellDepends = [ aeson binary hashable tls ];
+       homepage = "https://github.com/example/hashable-43";
+       description = "Package number 43";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "async-44" = callPackage
+    ({ QuickCheck, bytestring, hashable, lib, megaparsec, mkDerivation, mtl, network, tasty, text, tls }:
+     mkDerivation {
+       pname = "async-44";
+       version = "3.2.4";
+       sha256 = "lpcmk89n57jcp9wh7bxmn8g29qgi414z2z8vigmdcx106ckirq9v";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck megaparsec mtl tasty text
+       ];
+       testHaskellDepends = [ bytestring hashable network tls ];
+       homepage = "https://github.com/example/async-44";
+       description = "Package number 44";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "unordered-containers-45" = callPackage
+    ({ deepseq, lib, mkDerivation, random, transformers, vector, zlib }:
+     mkDerivation {
+       pname = "unordered-containers-45";
+       version = "1.7.8";
+       sha256 = "zms38n7asg5zypl0qwhhfss3r3picqbcbvqlcmjr48y4725wn42w";
+       libraryHaskellDepends = [
+         deepseq random transformers vector
+       ];
+       testHaskellDepends = [ deepseq zlib ];
+       homepage = "https://github.com/example/unordered-containers-45";
+       description = "Package number 45";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "deepseq-46" = callPackage
+    ({ deepseq, hspec, lib, mkDerivation, stm, tasty }:
+     mkDerivation {
+       pname = "deepseq-46";
+       version = "2.7.5";
+       sha256 = "czc8f8m7kv4r7sjrb05bwxr252qfrl2r8h8iah8jfd98pasckbgj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq hspec stm tasty
+       ];
+       homepage = "https://github.com/example/deepseq-46";
+       description = "Package number 46";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "time-47" = callPackage
+    ({ criterion, lib, mkDerivation, mtl, transformers }:
+     mkDerivation {
+       pname = "time-47";
+       version = "3.10.7";
+       sha256 = "ssh84msikgr6xp4h42nzz6aarmhl78xasjxg719pms93gckq5gc5";
+       libraryHaskellDepends = [
+         criterion mtl
+       ];
+       testHaskellDepends = [ transformers ];
+       homepage = "https://github.com/example/time-47";
+       description = "Package number 47";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "process-48" = callPackage
+    ({ deepseq, lens, lib, megaparsec, mkDerivation }:
+     mkDerivation {
+       pname = "process-48";
+       version = "5.17.7";
+       sha256 = "6jd5klr5g853273ghmacxci7768n58fpmffabw96q98ihvxk8klf";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq megaparsec
+       ];
+       testHaskellDepends = [ lens ];
+       homepage = "https://github.com/example/process-48";
+       description = "Package number 48";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "text-49" = callPackage
+    ({ aeson, base, directory, http_client, lib, mkDerivation, mtl, network, vector }:
+     mkDerivation {
+       pname = "text-49";
+       version = "8.2.2";
+       sha256 = "l43vkqbq7mpjgz45bgxmcqxpv8b03l1n9kv1y7mvy3pqsmcnr2qz";
+       libraryHaskellDepends = [
+         aeson base directory http_client mtl network vector
+       ];
+       homepage = "https://github.com/example/text-49";
+       description = "Package number 49";
+       license = lib.licenses.mit;
+     }) {};
+
+  "criterion-50" = callPackage
+    ({ criterion, hspec, lib, mkDerivation, network, tls, vector }:
+     mkDerivation {
+       pname = "criterion-50";
+       version = "4.7.3";
+       sha256 = "5bc1j7v97d5cbg66h2w74gr5rgmd3r4rxjcxqp960bpqlqfljxs2";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         tls vector
+       ];
+       testHaskellDepends = [ criterion hspec network vector ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/criterion-50";
+       description = "Package number 50";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "unordered-containers-51" = callPackage
+    ({ QuickCheck, aeson, binary, lib, megaparsec, mkDerivation, parsec, tls }:
+     mkDerivation {
+       pname = "unordered-containers-51";
+       version = "7.11.6";
+       sha256 = "398ngza3rxbp9j9l7rjil0ah28fpbkdl91nixpyvkz6ga9rfi92r";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson binary megaparsec parsec tls
+       ];
+       testHaskellDepends = [ QuickCheck ];
+       homepage = "https://github.com/example/unordered-containers-51";
+       description = "Package number 51";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "vector-52" = callPackage
+    ({ base, hashable, hspec, http_client, lens, lib, mkDerivation, mtl, network, process, transformers }:
+     mkDerivation {
+       pname = "vector-52";
+       version = "8.1.8";
+       sha256 = "qz6pv6npx9jr3dasnkkpws1p2k2lrkcawdfbdlwcfd51h6ayza9h";
+       libraryHaskellDepends = [
+         base hspec http_client mtl network
+       ];
+       testHaskellDepends = [ hashable lens process transformers ];
+       homepage = "https://github.com/example/vector-52";
+       description = "Package number 52";
+       license = lib.licenses.mit;
+     }) {};
+
+  "mtl-53" = callPackage
+    ({ QuickCheck, base, hashable, lib, mkDerivation, random, text, time }:
+     mkDerivation {
+       pname = "mtl-53";
+       version = "7.8.2";
+       sha256 = "hcn8qf353h5bcspq5k5ybj6cyr18vgpsq05a7pnw6ml7cji4kjdi";
+       libraryHaskellDepends = [
+         QuickCheck hashable text time
+       ];
+       testHaskellDepends = [ base random text ];
+       homepage = "https://github.com/example/mtl-53";
+       description = "Package number 53";
+       license = lib.licenses.mit;
+     }) {};
+
+  "binary-54" = callPackage
+    ({ aeson, bytestring, directory, hashable, lib, mkDerivation, time, vector, zlib }:
+     mkDerivation {
+       pname = "binary-54";
+       version = "9.6.0";
+       sha256 = "x49nj12cidjr5vix2vj6ll41y9d0z94smss8rgpwz70swx313ac5";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson bytestring hashable time vector
+       ];
+       testHaskellDepends = [ directory time zlib ];
+       postPatch = "substituteInPlace binary-54.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/binary-54";
+       description = "Package number 54";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "transformers-55" = callPackage
+    ({ containers, filepath, hspec, lens, lib, megaparsec, mkDerivation, parsec, vector }:
+     mkDerivation {
+       pname = "transformers-55";
+       version = "6.17.2";
+       sha256 = "1fk7nniv7zvaxjzbjgc7qjg0hz2969h9j9jz9w9a87yq1zymn1sq";
+       libraryHaskellDepends = [
+         containers filepath hspec lens megaparsec parsec vector
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/transformers-55";
+       description = "Package number 55";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "async-56" = callPackage
+    ({ containers, lib, mkDerivation, stm, zlib }:
+     mkDerivation {
+       pname = "async-56";
+       version = "8.5.6";
+       sha256 = "fi6v5dap127ggny9rr69xqaniqrnndxxhyl1xad4nsd94c4sfaa6";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         containers stm
+       ];
+       testHaskellDepends = [ zlib ];
+       homepage = "https://github.com/example/async-56";
+       description = "Package number 56";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tasty-57" = callPackage
+    ({ QuickCheck, aeson, directory, lens, lib, mkDerivation, process, tasty, text, time, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "tasty-57";
+       version = "8.10.5";
+       sha256 = "kh7nanygdjzcp5i7dn53saf56pzl6srshjk7klj6i58cws4car0b";
+       libraryHaskellDepends = [
+         QuickCheck aeson directory lens tasty text transformers
+       ];
+       testHaskellDepends = [ process time unordered_containers ];
+       homepage = "https://github.com/example/tasty-57";
+       description = "Package number 57";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "vector-58" = callPackage
+    ({ base, binary, bytestring, hashable, http_client, lens, lib, megaparsec, mkDerivation, stm, text, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "vector-58";
+       version = "3.13.9";
+       sha256 = "049fjgx48z4fbhvh5vpw7n4gfgcb490a4s126zssxv9v9nn40s1d";
+       libraryHaskellDepends = [
+         base hashable http_client lens stm text unordered_containers zlib
+       ];
+       testHaskellDepends = [ binary bytestring hashable megaparsec ];
+       homepage = "https://github.com/example/vector-58";
+       description = "Package number 58";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "http-client-59" = callPackage
+    ({ QuickCheck, aeson, criterion, hspec, lib, megaparsec, mkDerivation, vector, zlib }:
+     mkDerivation {
+       pname = "http-client-59";
+       version = "7.16.5";
+       sha256 = "v5bgi86jwikyqc0hddqs5byc609a2spzcbn7zzjv5b3rglpzh53w";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck aeson criterion hspec megaparsec zlib
+       ];
+       testHaskellDepends = [ vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/http-client-59";
+       description = "Package number 59";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "binary-60" = callPackage
+    ({ criterion, lens, lib, mkDerivation, parsec, time }:
+     mkDerivation {
+       pname = "binary-60";
+       version = "0.16.5";
+       sha256 = "7pc9q57f2yr2gxs1v1wvmnsdbmrggvz41fsj95rbd66rs2cp2bab";
+       libraryHaskellDepends = [
+         criterion time
+       ];
+       testHaskellDepends = [ lens parsec ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/binary-60";
+       description = "Package number 60";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "binary-61" = callPackage
+    ({ aeson, deepseq, hspec, http_client, lib, mkDerivation, network, parsec, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "binary-61";
+       version = "2.12.1";
+       sha256 = "k049l4bs8ljvkkdnhjk0xh45sq01jfy4bp31isipiyf5ficx4sza";
+       libraryHaskellDepends = [
+         aeson deepseq hspec http_client network parsec unordered_containers zlib
+       ];
+       homepage = "https://github.com/example/binary-61";
+       description = "Package number 61";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "transformers-62" = callPackage
+    ({ QuickCheck, deepseq, hspec, lib, megaparsec, mkDerivation, stm, tls, transformers, zlib }:
+     mkDerivation {
+       pname = "transformers-62";
+       version = "4.12.4";
+       sha256 = "l03ih8kglxjgx2fa4j87w8n1r83n4ihqxc9zy0nixibq10q13ngh";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq megaparsec tls zlib
+       ];
+       testHaskellDepends = [ QuickCheck hspec stm transformers ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/transformers-62";
+       description = "Package number 62";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "QuickCheck-63" = callPackage
+    ({ async, bytestring, directory, filepath, hashable, hspec, lib, mkDerivation, process, tls, vector }:
+     mkDerivation {
+       pname = "QuickCheck-63";
+       version = "7.12.8";
+       sha256 = "nva59ixppz52xx3sinvnddqc9b77axbacxcmba8yxx7lak92grh7";
+       libraryHaskellDepends = [
+         async bytestring directory filepath hashable hspec
+       ];
+       testHaskellDepends = [ bytestring process tls vector ];
+       homepage = "https://github.com/example/QuickCheck-63";
+       description = "Package number 63";
+       license = lib.licenses.mit;
+     }) {};
+
+  "tls-64" = callPackage
+    ({ binary, criterion, lens, lib, megaparsec, mkDerivation, network, process, tls, vector }:
+     mkDerivation {
+       pname = "tls-64";
+       version = "7.0.1";
+       sha256 = "96pkgsqgz42957k6dc10hliygb4mzj07qrjc0azf9r8xnkxci4g1";
+       libraryHaskellDepends = [
+         binary criterion megaparsec network process tls
+       ];
+       testHaskellDepends = [ criterion lens tls vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tls-64";
+       description = "Package number 64";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "time-65" = callPackage
+    ({ async, deepseq, hspec, lib, mkDerivation, random, stm, tasty, tls, zlib }:
+     mkDerivation {
+       pname = "time-65";
+       version = "4.16.4";
+       sha256 = "zwp9as7gczwg3dp7dwwlib377h1y5n9h3h7spgacziyv6rkdni0i";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         random tasty tls zlib
+       ];
+       testHaskellDepends = [ async deepseq hspec stm ];
+       homepage = "https://github.com/example/time-65";
+       description = "Package number 65";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "transformers-66" = callPackage
+    ({ criterion, deepseq, filepath, lib, mkDerivation, mtl, parsec, unordered_containers }:
+     mkDerivation {
+       pname = "transformers-66";
+       version = "6.14.0";
+       sha256 = "wsxfig8r4zm14kgy832dzvsqwgrmy5853jy6d20924w133xy97rn";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         criterion parsec
+       ];
+       testHaskellDepends = [ deepseq filepath mtl unordered_containers ];
+       postPatch = "substituteInPlace transformers-66.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/transformers-66";
+       description = "Package number 66";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tls-67" = callPackage
+    ({ binary, bytestring, filepath, lib, mkDerivation, mtl, process, stm, tasty }:
+     mkDerivation {
+       pname = "tls-67";
+       version = "8.3.4";
+       sha256 = "70ci5b1i77njdb99p47bz1i8ldgivwryknlrqxaqx248bjvhin33";
+       libraryHaskellDepends = [
+         binary bytestring filepath process stm tasty
+       ];
+       testHaskellDepends = [ mtl ];
+       homepage = "https://github.com/example/tls-67";
+       description = "Package number 67";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hashable-68" = callPackage
+    ({ criterion, http_client, lib, mkDerivation }:
+     mkDerivation {
+       pname = "hashable-68";
+       version = "7.3.5";
+       sha256 = "q9la9gnwx5v1ph35xlv1p9k660pc6bqkw856j8ncagrj2k69iqv6";
+       libraryHaskellDepends = [
+         criterion http_client
+       ];
+       homepage = "https://github.com/example/hashable-68";
+       description = "Package number 68";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "stm-69" = callPackage
+    ({ base, containers, criterion, hashable, lens, lib, mkDerivation, mtl, tasty, text, zlib }:
+     mkDerivation {
+       pname = "stm-69";
+       version = "7.0.2";
+       sha256 = "60xnfn8bg9rwbsmrmvx8csa0qid8kpp5fwklifcx6d2dzrac63q5";
+       libraryHaskellDepends = [
+         base containers criterion hashable lens mtl text zlib
+       ];
+       testHaskellDepends = [ tasty ];
+       homepage = "https://github.com/example/stm-69";
+       description = "Package number 69";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "time-70" = callPackage
+    ({ async, deepseq, http_client, lib, mkDerivation, network, tls }:
+     mkDerivation {
+       pname = "time-70";
+       version = "9.14.9";
+       sha256 = "js20a6l4mqvd6zjcpsqgfynmzk3cipaag01jxj7my2ymljqjnrkn";
+       libraryHaskellDepends = [
+         async deepseq
+       ];
+       testHaskellDepends = [ http_client network tls ];
+       homepage = "https://github.com/example/time-70";
+       description = "Package number 70";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "directory-71" = callPackage
+    ({ QuickCheck, containers, hspec, lib, mkDerivation, mtl, parsec, tasty }:
+     mkDerivation {
+       pname = "directory-71";
+       version = "9.12.2";
+       sha256 = "m5xv81vq6xnib714dwvj1076smwbc0p2ybfrwfgy2jdygw5px8jz";
+       libraryHaskellDepends = [
+         containers hspec parsec
+       ];
+       testHaskellDepends = [ QuickCheck mtl tasty ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/directory-71";
+       description = "Package number 71";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "transformers-72" = callPackage
+    ({ base, hashable, lib, mkDerivation, mtl, network, transformers }:
+     mkDerivation {
+       pname = "transformers-72";
+       version = "9.15.9";
+       sha256 = "4m3hak4fgaw1qvjy0hs4rpm5sbm1fb9vlfj2x0haqb2a2f6835lj";
+       libraryHaskellDepends = [
+         base hashable
+       ];
+       testHaskellDepends = [ base mtl network transformers ];
+       homepage = "https://github.com/example/transformers-72";
+       description = "Package number 72";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "mtl-73" = callPackage
+    ({ QuickCheck, async, deepseq, lib, megaparsec, mkDerivation, zlib }:
+     mkDerivation {
+       pname = "mtl-73";
+       version = "0.10.5";
+       sha256 = "7q23r3kbxiryx67335f72jyd0l5affdr483grxsv60l2rv926qwb";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck async deepseq zlib
+       ];
+       testHaskellDepends = [ megaparsec ];
+       homepage = "https://github.com/example/mtl-73";
+       description = "Package number 73";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "QuickCheck-74" = callPackage
+    ({ aeson, binary, criterion, lens, lib, mkDerivation, network, parsec, process, transformers, unordered_containers, vector }:
+     mkDerivation {
+       pname = "QuickCheck-74";
+       version = "6.20.9";
+       sha256 = "lxrsr2dr5bh5p7dbxj1k5660p3wqrxlyh81d1y7i8cagf0wa7rzv";
+       libraryHaskellDepends = [
+         aeson criterion lens network parsec transformers unordered_containers vector
+       ];
+       testHaskellDepends = [ binary parsec process vector ];
+       homepage = "https://github.com/example/QuickCheck-74";
+       description = "Package number 74";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-75" = callPackage
+    ({ QuickCheck, bytestring, hspec, lens, lib, mkDerivation, network, parsec, process, random, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "stm-75";
+       version = "1.15.9";
+       sha256 = "3lgzcbn4wfmllhvq1kyc50c96lygd7anjl9cc0qswn8amy597fln";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck hspec lens network random tasty unordered_containers
+       ];
+       testHaskellDepends = [ bytestring parsec process ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/stm-75";
+       description = "Package number 75";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "transformers-76" = callPackage
+    ({ QuickCheck, bytestring, http_client, lib, mkDerivation, mtl, random, vector }:
+     mkDerivation {
+       pname = "transformers-76";
+       version = "6.14.2";
+       sha256 = "cbp79k7fp2zv4wwvqyjayvjj771yrc0vmm9hfbs4wqgi3kbjkkac";
+       libraryHaskellDepends = [
+         QuickCheck bytestring mtl
+       ];
+       testHaskellDepends = [ http_client random vector ];
+       homepage = "https://github.com/example/transformers-76";
+       description = "Package number 76";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "zlib-77" = callPackage
+    ({ aeson, bytestring, lens, lib, mkDerivation, mtl, network, process, tasty, tls, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "zlib-77";
+       version = "9.20.6";
+       sha256 = "lyn0bs2lkzimf9m8gzh980zylq9yykh0javdfg3y0idab60lzbsa";
+       libraryHaskellDepends = [
+         aeson bytestring lens network tls unordered_containers
+       ];
+       testHaskellDepends = [ mtl process tasty zlib ];
+       homepage = "https://github.com/example/zlib-77";
+       description = "Package number 77";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "tls-78" = callPackage
+    ({ aeson, lib, mkDerivation, random, text, transformers, zlib }:
+     mkDerivation {
+       pname = "tls-78";
+       version = "3.8.9";
+       sha256 = "1a63fr1i343rc58n1psvva0ba1jd8cngqmj8dkxnsw3zcc6wyx5j";
+       libraryHaskellDepends = [
+         aeson text zlib
+       ];
+       testHaskellDepends = [ random transformers ];
+       homepage = "https://github.com/example/tls-78";
+       description = "Package number 78";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "async-79" = callPackage
+    ({ filepath, hspec, http_client, lib, mkDerivation, mtl, random, tasty, time, tls }:
+     mkDerivation {
+       pname = "async-79";
+       version = "1.4.1";
+       sha256 = "9swipfs0ga02hr6m2xckv5wxh5wqlh24l4vcn1jrr0dilmv8isl4";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         filepath hspec mtl random tasty time
+       ];
+       testHaskellDepends = [ http_client tls ];
+       homepage = "https://github.com/example/async-79";
+       description = "Package number 79";
+       license = lib.licenses.mit;
+     }) {};
+
+  "network-80" = callPackage
+    ({ deepseq, lib, mkDerivation, parsec, text, time, tls }:
+     mkDerivation {
+       pname = "network-80";
+       version = "7.20.6";
+       sha256 = "mc8qf4cx4qyl30xnc3varbjw2mcpzkbha80199xn3yj6w7nn0r8l";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq parsec text time
+       ];
+       testHaskellDepends = [ deepseq tls ];
+       homepage = "https://github.com/example/network-80";
+       description = "Package number 80";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tasty-81" = callPackage
+    ({ bytestring, containers, directory, lib, mkDerivation, parsec, transformers }:
+     mkDerivation {
+       pname = "tasty-81";
+       version = "6.19.2";
+       sha256 = "nnqbh3g3rlcyi299x6lgk5vfsnjfsg52fsrwmallcrahz8ab8ap7";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         parsec transformers
+       ];
+       testHaskellDepends = [ bytestring containers directory ];
+       postPatch = "substituteInPlace tasty-81.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/tasty-81";
+       description = "Package number 81";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "tls-82" = callPackage
+    ({ criterion, filepath, lib, mkDerivation, process, tasty }:
+     mkDerivation {
+       pname = "tls-82";
+       version = "9.2.8";
+       sha256 = "6m4dgdsn3292x02s38q30l34hyh6n54yrl9zhrcmv5647y99cp8k";
+       libraryHaskellDepends = [
+         criterion filepath process tasty
+       ];
+       homepage = "https://github.com/example/tls-82";
+       description = "Package number 82";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-83" = callPackage
+    ({ aeson, base, directory, hashable, hspec, lib, megaparsec, mkDerivation, text, time, vector }:
+     mkDerivation {
+       pname = "filepath-83";
+       version = "7.11.1";
+       sha256 = "5kkgfdlznhhqd7mp0lld8n5v660drhfqzf7bs24fwx42cmag93qm";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson directory hashable hspec megaparsec text time
+       ];
+       testHaskellDepends = [ base hspec vector ];
+       postPatch = "substituteInPlace filepath-83.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/filepath-83";
+       description = "Package number 83";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "deepseq-84" = callPackage
+    ({ async, bytestring, criterion, deepseq, hashable, lib, mkDerivation, process, random, stm, tls, transformers }:
+     mkDerivation {
+       pname = "deepseq-84";
+       version = "8.13.4";
+       sha256 = "5a4prcjwqm3ryfyzzgymkhm5i87yl1mh86r9f08lsays5ijly16k";
+       libraryHaskellDepends = [
+         async bytestring criterion deepseq hashable process tls
+       ];
+       testHaskellDepends = [ random stm transformers ];
+       homepage = "https://github.com/example/deepseq-84";
+       description = "Package number 84";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "base-85" = callPackage
+    ({ base, deepseq, filepath, hashable, lib, megaparsec, mkDerivation, stm, text, time, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "base-85";
+       version = "0.9.2";
+       sha256 = "2av4pq9gkd332sk1ql0fgvxq8cg24n8qrkbr99ivn1jcgfyycm0a";
+       libraryHaskellDepends = [
+         base deepseq filepath hashable stm text unordered_containers
+       ];
+       testHaskellDepends = [ megaparsec time zlib ];
+       postPatch = "substituteInPlace base-85.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/base-85";
+       description = "Package number 85";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "tls-86" = callPackage
+    ({ QuickCheck, async, base, binary, bytestring, criterion, directory, lib, mkDerivation, parsec, random, tls }:
+     mkDerivation {
+       pname = "tls-86";
+       version = "5.15.5";
+       sha256 = "2ff282smijcppzm6g50c1jy85kcqggsgfhcav8rcs6hhr6dlxhl3";
+       libraryHaskellDepends = [
+         async base binary bytestring criterion parsec random
+       ];
+       testHaskellDepends = [ QuickCheck directory tls ];
+       homepage = "https://github.com/example/tls-86";
+       description = "Package number 86";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tasty-87" = callPackage
+    ({ binary, bytestring, criterion, deepseq, directory, lib, mkDerivation, mtl, network, process }:
+     mkDerivation {
+       pname = "tasty-87";
+       version = "8.16.7";
+       sha256 = "jvcm6ry1y0m2d2wd96w665nrihwfv5sn07l9msshnbr6cs4c8gf2";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary bytestring criterion deepseq directory mtl network process
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tasty-87";
+       description = "Package number 87";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "http-client-88" = callPackage
+    ({ QuickCheck, hashable, hspec, lib, megaparsec, mkDerivation, parsec, process, random }:
+     mkDerivation {
+       pname = "http-client-88";
+       version = "5.9.6";
+       sha256 = "mc5iilqxkwsqqvj0a513kpvxbmr8hsvkwcfp61p5lfwsvcr72fx3";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck hspec megaparsec parsec process random
+       ];
+       testHaskellDepends = [ hashable ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/http-client-88";
+       description = "Package number 88";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "bytestring-89" = callPackage
+    ({ async, containers, directory, hspec, lib, mkDerivation, parsec, random, text }:
+     mkDerivation {
+       pname = "bytestring-89";
+       version = "9.14.1";
+       sha256 = "n6ciicc4gnrs63g6bhjqjr6k1yljh6zib10135abkaj09bbqdxnp";
+       libraryHaskellDepends = [
+         async containers directory hspec parsec random text
+       ];
+       homepage = "https://github.com/example/bytestring-89";
+       description = "Package number 89";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "lens-90" = callPackage
+    ({ QuickCheck, directory, lib, megaparsec, mkDerivation, tasty, vector }:
+     mkDerivation {
+       pname = "lens-90";
+       version = "5.6.3";
+       sha256 = "sknyzhlqx5gvfr76yy2wnpc6207baq2wz2mvwbs51jb7lbs01ci3";
+       libraryHaskellDepends = [
+         QuickCheck directory tasty vector
+       ];
+       testHaskellDepends = [ megaparsec ];
+       homepage = "https://github.com/example/lens-90";
+       description = "Package number 90";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "tls-91" = callPackage
+    ({ async, deepseq, lens, lib, mkDerivation, random, stm }:
+     mkDerivation {
+       pname = "tls-91";
+       version = "1.6.7";
+       sha256 = "5xk6ff3qz82d4v6vbnfg7j2npsiv2rmwqyhiijy4j3v2k61ps6ky";
+       libraryHaskellDepends = [
+         deepseq random stm
+       ];
+       testHaskellDepends = [ async lens ];
+       homepage = "https://github.com/example/tls-91";
+       description = "Package number 91";
+       license = lib.licenses.mit;
+     }) {};
+
+  "bytestring-92" = callPackage
+    ({ async, bytestring, filepath, hspec, lib, mkDerivation, mtl, parsec, time, zlib }:
+     mkDerivation {
+       pname = "bytestring-92";
+       version = "2.7.6";
+       sha256 = "3frv5lpz44znhwqdpac97bxi56c71bc013g5dsyn6wvqblyh8a08";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async bytestring filepath hspec mtl parsec time zlib
+       ];
+       homepage = "https://github.com/example/bytestring-92";
+       description = "Package number 92";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "network-93" = callPackage
+    ({ base, binary, deepseq, filepath, hspec, lens, lib, mkDerivation, parsec, stm, tls }:
+     mkDerivation {
+       pname = "network-93";
+       version = "5.12.4";
+       sha256 = "v6442hjxlmya9zwp5bwl05l6skbb8y1rf7jfk7hczr0mp6yiha2j";
+       libraryHaskellDepends = [
+         base binary deepseq filepath hspec lens
+       ];
+       testHaskellDepends = [ parsec stm tls ];
+       homepage = "https://github.com/example/network-93";
+       description = "Package number 93";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "random-94" = callPackage
+    ({ base, criterion, deepseq, lib, mkDerivation, random, text, time, tls, unordered_containers, vector }:
+     mkDerivation {
+       pname = "random-94";
+       version = "7.14.0";
+       sha256 = "wz1wflai70kgbmj00f2nblca9daqj3fsz5y1z8wa9h0lp1n92g3m";
+       libraryHaskellDepends = [
+         base random time tls vector
+       ];
+       testHaskellDepends = [ criterion deepseq text unordered_containers ];
+       postPatch = "substituteInPlace random-94.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/random-94";
+       description = "Package number 94";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "async-95" = callPackage
+    ({ containers, deepseq, directory, hashable, lens, lib, mkDerivation, mtl, time, tls, zlib }:
+     mkDerivation {
+       pname = "async-95";
+       version = "6.17.1";
+       sha256 = "i4xsh1f1zwm998zh9ilflvylq15bf4zzs5iyhdqsda396p2xsj40";
+       libraryHaskellDepends = [
+         deepseq directory hashable lens time tls
+       ];
+       testHaskellDepends = [ containers hashable mtl zlib ];
+       postPatch = "substituteInPlace async-95.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/async-95";
+       description = "Package number 95";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "parsec-96" = callPackage
+    ({ async, containers, lib, megaparsec, mkDerivation, process, text, unordered_containers }:
+     mkDerivation {
+       pname = "parsec-96";
+       version = "5.2.5";
+       sha256 = "rbr2an20hmjwncbdnlx54w71snbv97fziz3p0vhg04n6xw38i778";
+       libraryHaskellDepends = [
+         async containers megaparsec process text unordered_containers
+       ];
+       homepage = "https://github.com/example/parsec-96";
+       description = "Package number 96";
+       license = lib.licenses.mit;
+     }) {};
+
+  "unordered-containers-97" = callPackage
+    ({ async, binary, lib, mkDerivation, text, time, tls }:
+     mkDerivation {
+       pname = "unordered-containers-97";
+       version = "2.1.0";
+       sha256 = "vjx3nn7047v59ashdwydyi9gw0kxhk1kg4378rv9v0z5g4fs3p64";
+       libraryHaskellDepends = [
+         async binary text time tls
+       ];
+       homepage = "https://github.com/example/unordered-containers-97";
+       description = "Package number 97";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "process-98" = callPackage
+    ({ async, containers, deepseq, directory, lib, mkDerivation, network, random, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "process-98";
+       version = "9.8.5";
+       sha256 = "jxbh2n6c1d0xfrbklqakla6dwvrqn0kcfs20lj5p9fv2ykqmc9m7";
+       libraryHaskellDepends = [
+         async containers deepseq directory network random transformers unordered_containers
+       ];
+       homepage = "https://github.com/example/process-98";
+       description = "Package number 98";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "mtl-99" = callPackage
+    ({ async, base, binary, directory, filepath, hashable, hspec, lens, lib, megaparsec, mkDerivation, vector }:
+     mkDerivation {
+       pname = "mtl-99";
+       version = "7.20.2";
+       sha256 = "aq8gfcybv35i4jj7m9ilqifb2kj4qsjirbxlzp7s24ss8fbblr0l";
+       libraryHaskellDepends = [
+         async binary directory filepath hashable lens megaparsec vector
+       ];
+       testHaskellDepends = [ base hspec ];
+       homepage = "https://github.com/example/mtl-99";
+       description = "Package number 99";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "random-100" = callPackage
+    ({ QuickCheck, aeson, binary, filepath, hspec, http_client, lens, lib, mkDerivation, mtl, parsec, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "random-100";
+       version = "0.3.8";
+       sha256 = "kqrdqk3qaidq0dqkqdn0almc2is9jdppkgsf41h55l39l34qshjc";
+       libraryHaskellDepends = [
+         QuickCheck aeson binary hspec lens parsec tasty unordered_containers
+       ];
+       testHaskellDepends = [ filepath http_client mtl unordered_containers ];
+       homepage = "https://github.com/example/random-100";
+       description = "Package number 100";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "aeson-101" = callPackage
+    ({ base, containers, filepath, lens, lib, megaparsec, mkDerivation, network, tasty, transformers, vector }:
+     mkDerivation {
+       pname = "aeson-101";
+       version = "2.8.5";
+       sha256 = "rbsgaa8p7fsnkq6qzd6mwwkbkhx0pqjqwilxqh4mcjwfjpqy5zr4";
+       libraryHaskellDepends = [
+         base containers filepath lens network vector
+       ];
+       testHaskellDepends = [ megaparsec tasty transformers ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/aeson-101";
+       description = "Package number 101";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "aeson-102" = callPackage
+    ({ aeson, filepath, hspec, lib, mkDerivation, parsec, tasty, vector }:
+     mkDerivation {
+       pname = "aeson-102";
+       version = "5.16.0";
+       sha256 = "hvs0wb0jl1hlq5v6piyb56m6dkl5fksan5bmcmlhbvk7mwml83bi";
+       libraryHaskellDepends = [
+         aeson hspec parsec tasty vector
+       ];
+       testHaskellDepends = [ filepath ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/aeson-102";
+       description = "Package number 102";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "zlib-103" = callPackage
+    ({ aeson, base, criterion, deepseq, directory, lib, megaparsec, mkDerivation, process, stm, time, tls }:
+     mkDerivation {
+       pname = "zlib-103";
+       version = "2.7.4";
+       sha256 = "b6gh58yqg1vr85ajds8xsi68z2505k4mc4wjvyc8spf7xai8wzl8";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson base criterion deepseq directory megaparsec process tls
+       ];
+       testHaskellDepends = [ base stm time ];
+       homepage = "https://github.com/example/zlib-103";
+       description = "Package number 103";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "transformers-104" = callPackage
+    ({ bytestring, directory, hashable, http_client, lib, mkDerivation, mtl, network, tasty, vector, zlib }:
+     mkDerivation {
+       pname = "transformers-104";
+       version = "2.2.8";
+       sha256 = "7rsm8zq60f1hh380s3g7mcizqhpj8gx3n3ls25hrdf72sxpf6ng3";
+       libraryHaskellDepends = [
+         bytestring directory mtl tasty vector
+       ];
+       testHaskellDepends = [ hashable http_client network zlib ];
+       homepage = "https://github.com/example/transformers-104";
+       description = "Package number 104";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "transformers-105" = callPackage
+    ({ aeson, http_client, lib, mkDerivation, tls, vector }:
+     mkDerivation {
+       pname = "transformers-105";
+       version = "3.6.2";
+       sha256 = "xz6gz9zrnvl6064zpz56liqyfjjf9ri96974rqjp1jdpz18m9f7x";
+       libraryHaskellDepends = [
+         tls vector
+       ];
+       testHaskellDepends = [ aeson http_client tls ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/transformers-105";
+       description = "Package number 105";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "async-106" = callPackage
+    ({ deepseq, directory, lib, mkDerivation, mtl, stm, time, vector, zlib }:
+     mkDerivation {
+       pname = "async-106";
+       version = "2.11.2";
+       sha256 = "i01ap41wiklqyn7si4v1a5vwca0yjgifr9bzhdh39an5c35sbbnj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq mtl vector zlib
+       ];
+       testHaskellDepends = [ directory stm time ];
+       homepage = "https://github.com/example/async-106";
+       description = "Package number 106";
+       license = lib.licenses.mit;
+     }) {};
+
+  "process-107" = callPackage
+    ({ bytestring, containers, criterion, deepseq, lib, mkDerivation, parsec, vector, zlib }:
+     mkDerivation {
+       pname = "process-107";
+       version = "3.13.8";
+       sha256 = "kbcmpa5d0g2dllry68prz4cw53p1kk81kf5dq1zjlj9hjc9r4jb6";
+       libraryHaskellDepends = [
+         bytestring containers criterion deepseq parsec vector zlib
+       ];
+       homepage = "https://github.com/example/process-107";
+       description = "Package number 107";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "criterion-108" = callPackage
+    ({ binary, bytestring, containers, filepath, lib, mkDerivation, parsec, process, text }:
+     mkDerivation {
+       pname = "criterion-108";
+       version = "1.19.4";
+       sha256 = "0ipqf67radslq9sz6l7myxdw42xshf9v09ipgd7yv0vpkszlw4gy";
+       libraryHaskellDepends = [
+         binary bytestring parsec text
+       ];
+       testHaskellDepends = [ binary containers filepath process ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/criterion-108";
+       description = "Package number 108";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "mtl-109" = callPackage
+    ({ aeson, base, directory, filepath, lens, lib, mkDerivation, process, time }:
+     mkDerivation {
+       pname = "mtl-109";
+       version = "8.12.8";
+       sha256 = "vdhx4clhjamv6fsgm4m0fdidpcvsg5j484rv4dc5ai9glcqr9qlv";
+       libraryHaskellDepends = [
+         aeson directory filepath process
+       ];
+       testHaskellDepends = [ base lens time ];
+       homepage = "https://github.com/example/mtl-109";
+       description = "Package number 109";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "process-110" = callPackage
+    ({ QuickCheck, async, bytestring, hashable, lens, lib, mkDerivation, network, stm, tasty }:
+     mkDerivation {
+       pname = "process-110";
+       version = "4.5.3";
+       sha256 = "y1f4g0xgfnjbiaszqc4bwjicv63b1xjwjvf2ns2qnbhk5rigypx1";
+       libraryHaskellDepends = [
+         async hashable lens tasty
+       ];
+       testHaskellDepends = [ QuickCheck bytestring network stm ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/process-110";
+       description = "Package number 110";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "bytestring-111" = callPackage
+    ({ aeson, base, criterion, directory, hashable, lib, mkDerivation, tasty, text, transformers }:
+     mkDerivation {
+       pname = "bytestring-111";
+       version = "6.2.9";
+       sha256 = "53xjpiqbzcvbgccha1cyz1zla9l4awp37r7sysgajjpn6yp8hg0p";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base criterion directory hashable tasty
+       ];
+       testHaskellDepends = [ aeson text transformers ];
+       homepage = "https://github.com/example/bytestring-111";
+       description = "Package number 111";
+       license = lib.licenses.mit;
+     }) {};
+
+  "network-112" = callPackage
+    ({ QuickCheck, aeson, async, binary, criterion, deepseq, directory, hashable, lib, mkDerivation, network, process, unordered_containers }:
+     mkDerivation {
+       pname = "network-112";
+       version = "9.7.2";
+       sha256 = "5kg51x0pr1g1ysapdq6zrnlm5hd4wam73wh425chckrfgh1spplj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson async binary criterion deepseq directory hashable
+       ];
+       testHaskellDepends = [ QuickCheck network process unordered_containers ];
+       postPatch = "substituteInPlace network-112.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/network-112";
+       description = "Package number 112";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "network-113" = callPackage
+    ({ QuickCheck, async, base, bytestring, directory, lib, mkDerivation, process, text }:
+     mkDerivation {
+       pname = "network-113";
+       version = "6.5.2";
+       sha256 = "vm03vwqd18iihw7ib8k48az02qad3g42zh7i3p8i1ww0am64s4sa";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck async base bytestring text
+       ];
+       testHaskellDepends = [ async base directory process ];
+       homepage = "https://github.com/example/network-113";
+       description = "Package number 113";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "QuickCheck-114" = callPackage
+    ({ aeson, deepseq, hashable, hspec, lens, lib, mkDerivation, time, zlib }:
+     mkDerivation {
+       pname = "QuickCheck-114";
+       version = "6.4.4";
+       sha256 = "pr74m52x6523yfvwx7nicj4agb8w0qy368gl0ifg0vm7jsn6cq2m";
+       libraryHaskellDepends = [
+         hashable hspec lens
+       ];
+       testHaskellDepends = [ aeson deepseq time zlib ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/QuickCheck-114";
+       description = "Package number 114";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "network-115" = callPackage
+    ({ bytestring, criterion, directory, http_client, lib, mkDerivation }:
+     mkDerivation {
+       pname = "network-115";
+       version = "4.20.5";
+       sha256 = "s5k442dqwcjzgid0frbfm1fmrjga6kv027q8n4mzk8jigxks5kpk";
+       libraryHaskellDepends = [
+         criterion directory
+       ];
+       testHaskellDepends = [ bytestring http_client ];
+       homepage = "https://github.com/example/network-115";
+       description = "Package number 115";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "QuickCheck-116" = callPackage
+    ({ hspec, lib, mkDerivation, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "QuickCheck-116";
+       version = "4.10.2";
+       sha256 = "d5rgjmbqrn80kpdlqbms0gmf95f1qb82z6r2jvq2iw4mcc7dfy7z";
+       libraryHaskellDepends = [
+         hspec tasty unordered_containers
+       ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/QuickCheck-116";
+       description = "Package number 116";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "containers-117" = callPackage
+    ({ aeson, containers, deepseq, directory, lib, mkDerivation, stm, tasty, text, transformers, vector }:
+     mkDerivation {
+       pname = "containers-117";
+       version = "9.19.2";
+       sha256 = "l1qyfzrl52nl8yv9mn0skydx4gwapak3v82h5nk6hip7s2p8db4c";
+       libraryHaskellDepends = [
+         aeson containers deepseq tasty transformers vector
+       ];
+       testHaskellDepends = [ aeson directory stm text ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/containers-117";
+       description = "Package number 117";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "http-client-118" = callPackage
+    ({ QuickCheck, base, criterion, hspec, lib, mkDerivation, random, tasty, text, zlib }:
+     mkDerivation {
+       pname = "http-client-118";
+       version = "9.6.9";
+       sha256 = "syp66cjsw7f558w623zhlkmkpfmw0bh20y00sggs2mq56m5ywb05";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck base random tasty text zlib
+       ];
+       testHaskellDepends = [ criterion hspec text ];
+       homepage = "https://github.com/example/http-client-118";
+       description = "Package number 118";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "time-119" = callPackage
+    ({ async, binary, lib, mkDerivation, stm, transformers }:
+     mkDerivation {
+       pname = "time-119";
+       version = "2.20.8";
+       sha256 = "gmrsjhb081npkk86n41lspicz8cc0cw94yx338kbizdpy5k8kkrb";
+       libraryHaskellDepends = [
+         async binary stm
+       ];
+       testHaskellDepends = [ transformers ];
+       homepage = "https://github.com/example/time-119";
+       description = "Package number 119";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "tasty-120" = callPackage
+    ({ async, directory, hashable, lib, mkDerivation, random, tasty, time }:
+     mkDerivation {
+       pname = "tasty-120";
+       version = "7.3.0";
+       sha256 = "g8k3yyi4w6n1j4mkwfkfd85r86dp7pqdyw5hnpn2d57hq6w560z3";
+       libraryHaskellDepends = [
+         async directory random tasty
+       ];
+       testHaskellDepends = [ directory hashable time ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tasty-120";
+       description = "Package number 120";
+       license = lib.licenses.mit;
+     }) {};
+
+  "process-121" = callPackage
+    ({ async, base, binary, bytestring, criterion, http_client, lib, mkDerivation, mtl, text, tls }:
+     mkDerivation {
+       pname = "process-121";
+       version = "6.11.5";
+       sha256 = "k79ga5vlfz7f6ddpfj2s29qkz4zm64p2yywzcn3yrnjzx5yqi3bd";
+       libraryHaskellDepends = [
+         async base binary bytestring criterion mtl text tls
+       ];
+       testHaskellDepends = [ http_client text ];
+       homepage = "https://github.com/example/process-121";
+       description = "Package number 121";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "QuickCheck-122" = callPackage
+    ({ aeson, binary, lib, megaparsec, mkDerivation, process, time }:
+     mkDerivation {
+       pname = "QuickCheck-122";
+       version = "6.17.5";
+       sha256 = "rzqznsgwdlpjdn5xzfma37aip4g375k5yplb67c2p60l4xciczfw";
+       libraryHaskellDepends = [
+         aeson binary megaparsec process time
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace QuickCheck-122.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/QuickCheck-122";
+       description = "Package number 122";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "zlib-123" = callPackage
+    ({ base, containers, criterion, deepseq, lib, megaparsec, mkDerivation, mtl, network, stm, text, unordered_containers }:
+     mkDerivation {
+       pname = "zlib-123";
+       version = "6.12.9";
+       sha256 = "ga8gm3dgiz2m895yhn7hp4zgi6ic2nkbcylv986lzscbyjivzhnm";
+       libraryHaskellDepends = [
+         base criterion megaparsec network text unordered_containers
+       ];
+       testHaskellDepends = [ containers deepseq mtl stm ];
+       homepage = "https://github.com/example/zlib-123";
+       description = "Package number 123";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "hspec-124" = callPackage
+    ({ QuickCheck, binary, bytestring, criterion, hashable, http_client, lens, lib, mkDerivation, stm, unordered_containers }:
+     mkDerivation {
+       pname = "hspec-124";
+       version = "8.3.5";
+       sha256 = "r4hnfnphdavchd5w5zm16fxq7w49f9x12ij6m7h6mpqb2i50spp4";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary criterion hashable lens stm unordered_containers
+       ];
+       testHaskellDepends = [ QuickCheck bytestring http_client ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/hspec-124";
+       description = "Package number 124";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "parsec-125" = callPackage
+    ({ criterion, deepseq, hashable, hspec, lib, mkDerivation, parsec, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "parsec-125";
+       version = "3.19.8";
+       sha256 = "vsnkzrlzdjbk325vdvnl7nfxq081hm5ba71xmh1ig8g646byzi5y";
+       libraryHaskellDepends = [
+         criterion hashable hspec unordered_containers zlib
+       ];
+       testHaskellDepends = [ deepseq parsec ];
+       postPatch = "substituteInPlace parsec-125.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/parsec-125";
+       description = "Package number 125";
+       license = lib.licenses.mit;
+     }) {};
+
+  "aeson-126" = callPackage
+    ({ binary, criterion, lib, megaparsec, mkDerivation, time, tls }:
+     mkDerivation {
+       pname = "aeson-126";
+       version = "1.13.6";
+       sha256 = "2pwhil8fvd0v8lh8bca34fj2pa15az2r3acfrpbwf23hw2zrbbcm";
+       libraryHaskellDepends = [
+         binary criterion megaparsec time tls
+       ];
+       homepage = "https://github.com/example/aeson-126";
+       description = "Package number 126";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "aeson-127" = callPackage
+    ({ QuickCheck, aeson, base, binary, bytestring, deepseq, hashable, lens, lib, mkDerivation, mtl, tasty }:
+     mkDerivation {
+       pname = "aeson-127";
+       version = "1.5.5";
+       sha256 = "jhb1jidx2gblgdabvy061x08426jq9jhcwi2pcdplx167rddbi7v";
+       libraryHaskellDepends = [
+         QuickCheck aeson base bytestring deepseq hashable lens mtl
+       ];
+       testHaskellDepends = [ binary mtl tasty ];
+       homepage = "https://github.com/example/aeson-127";
+       description = "Package number 127";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "deepseq-128" = callPackage
+    ({ QuickCheck, async, criterion, hashable, lib, mkDerivation, network, process, zlib }:
+     mkDerivation {
+       pname = "deepseq-128";
+       version = "7.20.4";
+       sha256 = "9isa6zwyma9i494q5y89jd1gsm3fgkzkqqcvxaiylhv6jy2nkvz5";
+       libraryHaskellDepends = [
+         QuickCheck async criterion hashable network process zlib
+       ];
+       testHaskellDepends = [ async criterion zlib ];
+       homepage = "https://github.com/example/deepseq-128";
+       description = "Package number 128";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "time-129" = callPackage
+    ({ QuickCheck, async, binary, containers, directory, filepath, lib, mkDerivation, stm, time }:
+     mkDerivation {
+       pname = "time-129";
+       version = "6.3.0";
+       sha256 = "wa8yksrjab20l6zsxvafavpmkprkcbzrpms9swcarmqm1msxyi32";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary containers filepath time
+       ];
+       testHaskellDepends = [ QuickCheck async directory stm ];
+       homepage = "https://github.com/example/time-129";
+       description = "Package number 129";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "criterion-130" = callPackage
+    ({ base, lib, mkDerivation, network, time, vector, zlib }:
+     mkDerivation {
+       pname = "criterion-130";
+       version = "4.16.6";
+       sha256 = "r8ay8bvxf3w2pm98jr0v9xwf5dm6z0gyh9dkyvwqs7yxrii7xm9s";
+       libraryHaskellDepends = [
+         time vector zlib
+       ];
+       testHaskellDepends = [ base network ];
+       homepage = "https://github.com/example/criterion-130";
+       description = "Package number 130";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "deepseq-131" = callPackage
+    ({ QuickCheck, async, binary, bytestring, criterion, hashable, lib, mkDerivation, tasty, text, time }:
+     mkDerivation {
+       pname = "deepseq-131";
+       version = "7.1.6";
+       sha256 = "7c4iv481jx5wzj5sl668waknmkish94m8wx2qi99vyrkl4mn8wf0";
+       libraryHaskellDepends = [
+         QuickCheck async binary bytestring criterion hashable text time
+       ];
+       testHaskellDepends = [ async tasty ];
+       homepage = "https://github.com/example/deepseq-131";
+       description = "Package number 131";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "hashable-132" = callPackage
+    ({ aeson, containers, hashable, lib, megaparsec, mkDerivation, process }:
+     mkDerivation {
+       pname = "hashable-132";
+       version = "4.10.3";
+       sha256 = "bmi6gm3xmahyq69pbphcaxfsif61bpsc6is6xl3zz4jyz1dlahdj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         containers megaparsec process
+       ];
+       testHaskellDepends = [ aeson hashable ];
+       homepage = "https://github.com/example/hashable-132";
+       description = "Package number 132";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "transformers-133" = callPackage
+    ({ QuickCheck, aeson, binary, deepseq, lib, mkDerivation, mtl, tls, vector }:
+     mkDerivation {
+       pname = "transformers-133";
+       version = "2.12.9";
+       sha256 = "p9k67y1ihy5n1q2dccrxvl6j568lv2rhjaglkvzlmc736jisq8ar";
+       libraryHaskellDepends = [
+         QuickCheck aeson binary deepseq mtl tls vector
+       ];
+       homepage = "https://github.com/example/transformers-133";
+       description = "Package number 133";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "random-134" = callPackage
+    ({ QuickCheck, binary, criterion, hashable, hspec, lib, mkDerivation, mtl, text, transformers, vector }:
+     mkDerivation {
+       pname = "random-134";
+       version = "1.3.8";
+       sha256 = "l5ck6l0n8p4m9d78nw4qbj5xy29a9508racnbpyrscm9pf0bjah2";
+       libraryHaskellDepends = [
+         QuickCheck criterion hspec mtl text vector
+       ];
+       testHaskellDepends = [ QuickCheck binary hashable transformers ];
+       homepage = "https://github.com/example/random-134";
+       description = "Package number 134";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-135" = callPackage
+    ({ bytestring, hspec, lib, mkDerivation, mtl, random, stm, transformers }:
+     mkDerivation {
+       pname = "filepath-135";
+       version = "8.3.9";
+       sha256 = "dzycmgcw7f42zy7gfmp3jaswmbmxvpi1w7ls8jwa7j9hni38a5vj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hspec mtl random
+       ];
+       testHaskellDepends = [ bytestring stm transformers ];
+       homepage = "https://github.com/example/filepath-135";
+       description = "Package number 135";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "unordered-containers-136" = callPackage
+    ({ bytestring, containers, directory, hashable, lib, mkDerivation, process, text, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "unordered-containers-136";
+       version = "2.13.4";
+       sha256 = "cr6kkngjd0wx2qhxbyyn3k06ww2313xfkb8sfg5yl1k4l0qgxzy9";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring containers directory hashable text unordered_containers zlib
+       ];
+       testHaskellDepends = [ process ];
+       postPatch = "substituteInPlace unordered-containers-136.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/unordered-containers-136";
+       description = "Package number 136";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "vector-137" = callPackage
+    ({ base, filepath, hspec, lens, lib, mkDerivation, random, tasty, text, transformers }:
+     mkDerivation {
+       pname = "vector-137";
+       version = "8.16.2";
+       sha256 = "lj3p3hsikv1a0s99hil0fd4a8myyz1nln29cs9faf1pjr69zk50m";
+       libraryHaskellDepends = [
+         base filepath hspec lens random tasty text transformers
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace vector-137.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/vector-137";
+       description = "Package number 137";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "async-138" = callPackage
+    ({ aeson, filepath, hashable, lib, mkDerivation, random, time, unordered_containers, vector }:
+     mkDerivation {
+       pname = "async-138";
+       version = "1.2.3";
+       sha256 = "p1cp3jgjlcq2x50d3yvx4q1z6yhrm8fpdzlgi4wzxz8qgz6qayaw";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson filepath unordered_containers
+       ];
+       testHaskellDepends = [ hashable random time vector ];
+       homepage = "https://github.com/example/async-138";
+       description = "Package number 138";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "network-139" = callPackage
+    ({ aeson, deepseq, hashable, http_client, lib, mkDerivation, random, time, tls, vector }:
+     mkDerivation {
+       pname = "network-139";
+       version = "8.17.1";
+       sha256 = "s5hf40m1p357b6njb45ll9d5jisbzs1pnygvh42h3fi33hg5dw05";
+       libraryHaskellDepends = [
+         deepseq hashable http_client random time vector
+       ];
+       testHaskellDepends = [ aeson tls ];
+       homepage = "https://github.com/example/network-139";
+       description = "Package number 139";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "network-140" = callPackage
+    ({ aeson, hashable, lib, megaparsec, mkDerivation, parsec, text, time, tls }:
+     mkDerivation {
+       pname = "network-140";
+       version = "7.14.5";
+       sha256 = "9rrlki8zxqwjna6b0ywgxq3fp24g1szvh0c916pvagkjn1qv1cb1";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson hashable megaparsec parsec text
+       ];
+       testHaskellDepends = [ text time tls ];
+       postPatch = "substituteInPlace network-140.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/network-140";
+       description = "Package number 140";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "base-141" = callPackage
+    ({ base, criterion, deepseq, directory, lib, megaparsec, mkDerivation, process, stm, vector }:
+     mkDerivation {
+       pname = "base-141";
+       version = "2.7.6";
+       sha256 = "zwnb3nyp0cpnldsr0bcfjsf2g8xl2fyv6gfz8vsxd16fcvpndda0";
+       libraryHaskellDepends = [
+         base criterion deepseq directory megaparsec process stm vector
+       ];
+       testHaskellDepends = [ deepseq process ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/base-141";
+       description = "Package number 141";
+       license = lib.licenses.mit;
+     }) {};
+
+  "stm-142" = callPackage
+    ({ async, base, deepseq, directory, filepath, lib, mkDerivation, parsec }:
+     mkDerivation {
+       pname = "stm-142";
+       version = "5.20.4";
+       sha256 = "bsz7cr4a0z81yn02q5fsngrw0iha26n25777khz0kkkh2y8hqcx6";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base parsec
+       ];
+       testHaskellDepends = [ async deepseq directory filepath ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace stm-142.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/stm-142";
+       description = "Package number 142";
+       license = lib.licenses.mit;
+     }) {};
+
+  "unordered-containers-143" = callPackage
+    ({ criterion, deepseq, lens, lib, mkDerivation, transformers, zlib }:
+     mkDerivation {
+       pname = "unordered-containers-143";
+       version = "5.6.7";
+       sha256 = "cv4splqhpfdjslb6yq1d21ga3j0b9jlkc47q9sihkvsh4l4znr4x";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         criterion lens zlib
+       ];
+       testHaskellDepends = [ deepseq transformers ];
+       postPatch = "substituteInPlace unordered-containers-143.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/unordered-containers-143";
+       description = "Package number 143";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "mtl-144" = callPackage
+    ({ bytestring, lib, mkDerivation, process, tasty, vector }:
+     mkDerivation {
+       pname = "mtl-144";
+       version = "1.16.6";
+       sha256 = "lwysb8bbx20gh7m936xlhg4ry9v95bhjsk2bx6xgr4in6vrwgbq2";
+       libraryHaskellDepends = [
+         bytestring process tasty vector
+       ];
+       homepage = "https://github.com/example/mtl-144";
+       description = "Package number 144";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "parsec-145" = callPackage
+    ({ QuickCheck, aeson, lib, mkDerivation, parsec, tasty, transformers, vector }:
+     mkDerivation {
+       pname = "parsec-145";
+       version = "8.0.2";
+       sha256 = "43gj3bck0rzv4313mw20whjiq3bdkqvk4q9v5llc4qkxk6g6nk9a";
+       libraryHaskellDepends = [
+         QuickCheck aeson parsec tasty transformers vector
+       ];
+       postPatch = "substituteInPlace parsec-145.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/parsec-145";
+       description = "Package number 145";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "time-146" = callPackage
+    ({ base, containers, deepseq, hashable, lib, mkDerivation, mtl, stm, tls }:
+     mkDerivation {
+       pname = "time-146";
+       version = "3.9.7";
+       sha256 = "4z8rbwi55fhhjffinaap62v15wb8przgn4sc7gmpkzdiipya11cq";
+       libraryHaskellDepends = [
+         base containers hashable mtl
+       ];
+       testHaskellDepends = [ deepseq stm tls ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/time-146";
+       description = "Package number 146";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "megaparsec-147" = callPackage
+    ({ criterion, deepseq, lib, mkDerivation, random, text }:
+     mkDerivation {
+       pname = "megaparsec-147";
+       version = "4.8.8";
+       sha256 = "j2gz0v60c2zk4232sac0g0wdcy2zmridi61n4wjrnymaan0jrzwz";
+       libraryHaskellDepends = [
+         criterion deepseq random text
+       ];
+       homepage = "https://github.com/example/megaparsec-147";
+       description = "Package number 147";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "unordered-containers-148" = callPackage
+    ({ directory, http_client, lens, lib, megaparsec, mkDerivation, network, transformers, vector }:
+     mkDerivation {
+       pname = "unordered-containers-148";
+       version = "9.16.1";
+       sha256 = "m3v32vipchn48apmm5r2hyb4zyqa1ladqm8s6n1mflw9w091di8q";
+       libraryHaskellDepends = [
+         http_client lens transformers
+       ];
+       testHaskellDepends = [ directory megaparsec network vector ];
+       homepage = "https://github.com/example/unordered-containers-148";
+       description = "Package number 148";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tasty-149" = callPackage
+    ({ aeson, async, deepseq, hspec, lib, megaparsec, mkDerivation, text, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "tasty-149";
+       version = "2.0.0";
+       sha256 = "w0ni040dbc90si5g3pkkg082g86k9r2wqrjx2j6fq432x27q9i7k";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson async deepseq hspec megaparsec text unordered_containers zlib
+       ];
+       homepage = "https://github.com/example/tasty-149";
+       description = "Package number 149";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-150" = callPackage
+    ({ QuickCheck, binary, criterion, http_client, lib, mkDerivation, network, tasty, vector }:
+     mkDerivation {
+       pname = "stm-150";
+       version = "2.11.0";
+       sha256 = "cjncpxi9010ffsllpi21xbpg0mpr4svgmnwcmbrwqimwnx214wcs";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck binary network tasty vector
+       ];
+       testHaskellDepends = [ criterion http_client ];
+       homepage = "https://github.com/example/stm-150";
+       description = "Package number 150";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "lens-151" = callPackage
+    ({ QuickCheck, hspec, lib, mkDerivation, mtl, parsec, unordered_containers, vector, zlib }:
+     mkDerivation {
+       pname = "lens-151";
+       version = "5.13.9";
+       sha256 = "7lvxspl8gg2a91viq38n4rhjlmfvnc62mw6l957w3skxrfdl2rqj";
+       libraryHaskellDepends = [
+         QuickCheck mtl vector zlib
+       ];
+       testHaskellDepends = [ hspec parsec unordered_containers ];
+       homepage = "https://github.com/example/lens-151";
+       description = "Package number 151";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "hspec-152" = callPackage
+    ({ bytestring, filepath, http_client, lib, mkDerivation, network, text, transformers }:
+     mkDerivation {
+       pname = "hspec-152";
+       version = "7.5.0";
+       sha256 = "a9i9wbjsy7jby7rkgqsrqyn4rk62v2ng00g8h5x44n4zcw12xcbr";
+       libraryHaskellDepends = [
+         bytestring filepath http_client network transformers
+       ];
+       testHaskellDepends = [ text ];
+       homepage = "https://github.com/example/hspec-152";
+       description = "Package number 152";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "deepseq-153" = callPackage
+    ({ aeson, bytestring, deepseq, http_client, lib, mkDerivation, network, random, time, zlib }:
+     mkDerivation {
+       pname = "deepseq-153";
+       version = "4.10.7";
+       sha256 = "2pwfbslcx2f1wcm7lnh2jxm3yii497qhzb8ad93pdrz2c1f6xq3x";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson http_client network time zlib
+       ];
+       testHaskellDepends = [ bytestring deepseq random time ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/deepseq-153";
+       description = "Package number 153";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "QuickCheck-154" = callPackage
+    ({ base, filepath, hspec, http_client, lens, lib, mkDerivation, text, transformers, zlib }:
+     mkDerivation {
+       pname = "QuickCheck-154";
+       version = "0.13.4";
+       sha256 = "hf0qblzp1xzqmpl779iylm9r66bj8zvwh7xjssb9mr75pb7rap26";
+       libraryHaskellDepends = [
+         filepath hspec http_client lens text transformers zlib
+       ];
+       testHaskellDepends = [ base ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/QuickCheck-154";
+       description = "Package number 154";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "mtl-155" = callPackage
+    ({ hspec, http_client, lib, mkDerivation, random, tasty, tls, vector }:
+     mkDerivation {
+       pname = "mtl-155";
+       version = "6.19.0";
+       sha256 = "1fnwhbrcpv51g60g4pdz1prl65zd569fcsg7digg1p2a3rxiq97g";
+       libraryHaskellDepends = [
+         http_client tasty tls
+       ];
+       testHaskellDepends = [ hspec random vector ];
+       postPatch = "substituteInPlace mtl-155.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/mtl-155";
+       description = "Package number 155";
+       license = lib.licenses.mit;
+     }) {};
+
+  "stm-156" = callPackage
+    ({ QuickCheck, bytestring, lib, mkDerivation, mtl, stm, tasty, transformers, zlib }:
+     mkDerivation {
+       pname = "stm-156";
+       version = "4.1.9";
+       sha256 = "p9cb924crzcvc9d1qif8gxvil5yc6g291w43lf1i93h6zqyzq3i0";
+       libraryHaskellDepends = [
+         QuickCheck mtl stm tasty transformers zlib
+       ];
+       testHaskellDepends = [ bytestring ];
+       postPatch = "substituteInPlace stm-156.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/stm-156";
+       description = "Package number 156";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "megaparsec-157" = callPackage
+    ({ aeson, base, filepath, lib, mkDerivation, mtl, random, text, tls, unordered_containers }:
+     mkDerivation {
+       pname = "megaparsec-157";
+       version = "3.12.0";
+       sha256 = "6df6d2sy040kg8wn0cgv2z89j59vbns1vca8ky7x7y2jgipg4yp2";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base filepath mtl random text tls unordered_containers
+       ];
+       testHaskellDepends = [ aeson ];
+       postPatch = "substituteInPlace megaparsec-157.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/megaparsec-157";
+       description = "Package number 157";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "bytestring-158" = callPackage
+    ({ criterion, lens, lib, mkDerivation, network, parsec, time, tls, unordered_containers }:
+     mkDerivation {
+       pname = "bytestring-158";
+       version = "9.11.1";
+       sha256 = "0gh9war89n5pqg775a9ll3agk8g2h4x1hq8w18p40n2calyvlg5z";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         criterion lens network time tls unordered_containers
+       ];
+       testHaskellDepends = [ parsec ];
+       homepage = "https://github.com/example/bytestring-158";
+       description = "Package number 158";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "hashable-159" = callPackage
+    ({ QuickCheck, async, criterion, filepath, lib, megaparsec, mkDerivation, mtl, random, text, time, unordered_containers, vector, zlib }:
+     mkDerivation {
+       pname = "hashable-159";
+       version = "4.0.6";
+       sha256 = "i96l7abf9ykfdf9rv4p3jkcc5if4kysdgzdqb1c6fk4pf5iyxmm4";
+       libraryHaskellDepends = [
+         QuickCheck async criterion megaparsec mtl random text unordered_containers
+       ];
+       testHaskellDepends = [ filepath time vector zlib ];
+       homepage = "https://github.com/example/hashable-159";
+       description = "Package number 159";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-160" = callPackage
+    ({ directory, filepath, hashable, http_client, lib, mkDerivation, mtl, network, text, tls, unordered_containers }:
+     mkDerivation {
+       pname = "stm-160";
+       version = "1.10.1";
+       sha256 = "lpqryi7v3bwl2vsgag2f61ygxmm9pyjppbva0lfrzw9zb8i5dap2";
+       libraryHaskellDepends = [
+         directory filepath hashable mtl network text tls
+       ];
+       testHaskellDepends = [ http_client tls unordered_containers ];
+       homepage = "https://github.com/example/stm-160";
+       description = "Package number 160";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "parsec-161" = callPackage
+    ({ containers, lib, mkDerivation, mtl, transformers }:
+     mkDerivation {
+       pname = "parsec-161";
+       version = "6.2.2";
+       sha256 = "7p16x9g8kwi71cky9ryy06jfj8f8six8g1y3r41ixxaa0xbim508";
+       libraryHaskellDepends = [
+         containers mtl
+       ];
+       testHaskellDepends = [ transformers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/parsec-161";
+       description = "Package number 161";
+       license = lib.licenses.mit;
+     }) {};
+
+  "binary-162" = callPackage
+    ({ QuickCheck, aeson, async, containers, directory, lib, mkDerivation, parsec }:
+     mkDerivation {
+       pname = "binary-162";
+       version = "6.2.5";
+       sha256 = "vjfmf9f5pny92aay03y9z7955kss005lww164ln6v7xziw0qhvcs";
+       libraryHaskellDepends = [
+         QuickCheck async directory parsec
+       ];
+       testHaskellDepends = [ aeson containers parsec ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/binary-162";
+       description = "Package number 162";
+       license = lib.licenses.mit;
+     }) {};
+
+  "aeson-163" = callPackage
+    ({ binary, bytestring, containers, deepseq, lib, mkDerivation, process, stm, unordered_containers }:
+     mkDerivation {
+       pname = "aeson-163";
+       version = "8.18.6";
+       sha256 = "isbd882zsq4h1g0frjp40ai8l5dgipfb0xing1i1gch444mx211m";
+       libraryHaskellDepends = [
+         binary bytestring deepseq process stm unordered_containers
+       ];
+       testHaskellDepends = [ binary containers process ];
+       homepage = "https://github.com/example/aeson-163";
+       description = "Package number 163";
+       license = lib.licenses.mit;
+     }) {};
+
+  "aeson-164" = callPackage
+    ({ aeson, async, containers, hashable, lib, megaparsec, mkDerivation, stm }:
+     mkDerivation {
+       pname = "aeson-164";
+       version = "1.3.5";
+       sha256 = "3ivzwh4x61ikiip0a3hf62v57hmaj1ry7ymhbi8l7l5ymw1x7fkc";
+       libraryHaskellDepends = [
+         aeson async hashable megaparsec
+       ];
+       testHaskellDepends = [ containers stm ];
+       homepage = "https://github.com/example/aeson-164";
+       description = "Package number 164";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "binary-165" = callPackage
+    ({ aeson, base, binary, bytestring, containers, criterion, http_client, lens, lib, mkDerivation, transformers, unordered_containers, vector }:
+     mkDerivation {
+       pname = "binary-165";
+       version = "4.0.2";
+       sha256 = "5gclv3zv2z9aan7hyslqwi18cl4llia6qkilqxsrws1harqmv8qs";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson base binary bytestring criterion http_client transformers unordered_containers
+       ];
+       testHaskellDepends = [ containers lens vector ];
+       homepage = "https://github.com/example/binary-165";
+       description = "Package number 165";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "zlib-166" = callPackage
+    ({ deepseq, lib, megaparsec, mkDerivation, random, tls }:
+     mkDerivation {
+       pname = "zlib-166";
+       version = "1.5.6";
+       sha256 = "7hzkm6v3pn3w4p0yhm7qrsigdw2ivxqby17m6zz7y85m9k41lyqa";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         megaparsec random tls
+       ];
+       testHaskellDepends = [ deepseq ];
+       homepage = "https://github.com/example/zlib-166";
+       description = "Package number 166";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "bytestring-167" = callPackage
+    ({ QuickCheck, async, criterion, filepath, http_client, lens, lib, mkDerivation, mtl, parsec, random, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "bytestring-167";
+       version = "5.10.0";
+       sha256 = "07bfcsxp8sig7r56k13krjsk7xd25vhsmx0555f4j8010ply1nfi";
+       libraryHaskellDepends = [
+         criterion filepath http_client mtl parsec random tasty unordered_containers
+       ];
+       testHaskellDepends = [ QuickCheck async lens random ];
+       homepage = "https://github.com/example/bytestring-167";
+       description = "Package number 167";
+       license = lib.licenses.mit;
+     }) {};
+
+  "random-168" = callPackage
+    ({ base, binary, deepseq, lib, mkDerivation, mtl, transformers }:
+     mkDerivation {
+       pname = "random-168";
+       version = "6.15.3";
+       sha256 = "861s12n7g4xyq4icssrz502pns3phfb3l4649lxc2a5wicj644x8";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base binary deepseq mtl transformers
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/random-168";
+       description = "Package number 168";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "time-169" = callPackage
+    ({ criterion, deepseq, hspec, http_client, lib, mkDerivation, parsec, stm, time }:
+     mkDerivation {
+       pname = "time-169";
+       version = "0.5.0";
+       sha256 = "xp8idmiyrhkj24qrz5w7l0wa2blpx4nmh96jskkxhz6z359j1qrb";
+       libraryHaskellDepends = [
+         criterion hspec http_client parsec time
+       ];
+       testHaskellDepends = [ deepseq stm ];
+       homepage = "https://github.com/example/time-169";
+       description = "Package number 169";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "http-client-170" = callPackage
+    ({ QuickCheck, async, base, bytestring, containers, lib, mkDerivation, mtl, network, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "http-client-170";
+       version = "1.11.8";
+       sha256 = "g0i4smz8s3zykww80nay4yf0gyddazxgnchzcgrz8h5wg712gvfn";
+       libraryHaskellDepends = [
+         QuickCheck async bytestring containers network transformers
+       ];
+       testHaskellDepends = [ base mtl transformers unordered_containers ];
+       homepage = "https://github.com/example/http-client-170";
+       description = "Package number 170";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "network-171" = callPackage
+    ({ bytestring, criterion, filepath, hspec, lib, mkDerivation, random, time, vector, zlib }:
+     mkDerivation {
+       pname = "network-171";
+       version = "0.0.6";
+       sha256 = "c2lxl5r22q7r1kp00xyfjwks1llfykn4vyb1zkp6wzl0c57rrdqv";
+       libraryHaskellDepends = [
+         bytestring filepath hspec random time vector zlib
+       ];
+       testHaskellDepends = [ criterion random vector zlib ];
+       homepage = "https://github.com/example/network-171";
+       description = "Package number 171";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "bytestring-172" = callPackage
+    ({ base, lib, mkDerivation, tasty, text }:
+     mkDerivation {
+       pname = "bytestring-172";
+       version = "9.12.3";
+       sha256 = "xr4d7938djyz0bg135x34wjmfs5ysqskgln8fmm891x0rn6ybpka";
+       libraryHaskellDepends = [
+         base tasty text
+       ];
+       homepage = "https://github.com/example/bytestring-172";
+       description = "Package number 172";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "criterion-173" = callPackage
+    ({ QuickCheck, deepseq, lib, mkDerivation, parsec, random }:
+     mkDerivation {
+       pname = "criterion-173";
+       version = "3.1.4";
+       sha256 = "msihjg8acag9r1yx90k0rkm5yp2fdzkqxrw01kzyf645bvd7wxn1";
+       libraryHaskellDepends = [
+         deepseq parsec random
+       ];
+       testHaskellDepends = [ QuickCheck ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/criterion-173";
+       description = "Package number 173";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-174" = callPackage
+    ({ base, hashable, lens, lib, mkDerivation, transformers }:
+     mkDerivation {
+       pname = "filepath-174";
+       version = "6.3.6";
+       sha256 = "cx8g7kfm2nk2rms73rlvpwlx87a049biy91izlawsnns6ddi6x6a";
+       libraryHaskellDepends = [
+         base hashable lens transformers
+       ];
+       homepage = "https://github.com/example/filepath-174";
+       description = "Package number 174";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "directory-175" = callPackage
+    ({ base, hashable, lib, megaparsec, mkDerivation, process, stm, text, time }:
+     mkDerivation {
+       pname = "directory-175";
+       version = "5.7.2";
+       sha256 = "9x6w9dgy0i96na4jahpj3abfvy16gxy0ywca8hd456785vp6vxz4";
+       libraryHaskellDepends = [
+         megaparsec process stm text time
+       ];
+       testHaskellDepends = [ base hashable text ];
+       postPatch = "substituteInPlace directory-175.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/directory-175";
+       description = "Package number 175";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "stm-176" = callPackage
+    ({ binary, bytestring, deepseq, directory, hashable, hspec, lib, mkDerivation, network, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "stm-176";
+       version = "3.3.8";
+       sha256 = "n1jp8631n68j4pkbzzvvydb11hhcxhvippsdr32y4wvmr1k573r7";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary bytestring deepseq hashable hspec tasty unordered_containers
+       ];
+       testHaskellDepends = [ directory hashable hspec network ];
+       postPatch = "substituteInPlace stm-176.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/stm-176";
+       description = "Package number 176";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "lens-177" = callPackage
+    ({ aeson, lib, megaparsec, mkDerivation, random, time, tls }:
+     mkDerivation {
+       pname = "lens-177";
+       version = "3.4.0";
+       sha256 = "pnizb081c6w04xnzgjcifgwf7spbp5ciialf4156b5sx5hawnh12";
+       libraryHaskellDepends = [
+         aeson megaparsec random time tls
+       ];
+       postPatch = "substituteInPlace lens-177.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/lens-177";
+       description = "Package number 177";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "lens-178" = callPackage
+    ({ QuickCheck, base, containers, filepath, hashable, lens, lib, megaparsec, mkDerivation, mtl, tasty, tls, zlib }:
+     mkDerivation {
+       pname = "lens-178";
+       version = "2.11.8";
+       sha256 = "0kfs2jz5ixk89diidwrwkwdgl77pzgs5x7z5s10b1slyp370is86";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck base containers lens megaparsec tasty tls zlib
+       ];
+       testHaskellDepends = [ filepath hashable mtl ];
+       homepage = "https://github.com/example/lens-178";
+       description = "Package number 178";
+       license = lib.licenses.mit;
+     }) {};
+
+  "zlib-179" = callPackage
+    ({ QuickCheck, binary, hashable, lens, lib, mkDerivation, network }:
+     mkDerivation {
+       pname = "zlib-179";
+       version = "0.7.8";
+       sha256 = "cjl91djq45f260p004fmkarcp416c83wzwpdsrcvbilf0g0rl0s7";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck binary lens network
+       ];
+       testHaskellDepends = [ hashable ];
+       homepage = "https://github.com/example/zlib-179";
+       description = "Package number 179";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "binary-180" = callPackage
+    ({ base, hashable, lib, mkDerivation, parsec, tasty, time, tls }:
+     mkDerivation {
+       pname = "binary-180";
+       version = "1.19.6";
+       sha256 = "qsh4jphizqair6s2s23nl3c76yd0wfczsnbnyzqjwwmr6hqnvvn1";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hashable time tls
+       ];
+       testHaskellDepends = [ base parsec tasty ];
+       homepage = "https://github.com/example/binary-180";
+       description = "Package number 180";
+       license = lib.licenses.mit;
+     }) {};
+
+  "aeson-181" = callPackage
+    ({ base, containers, filepath, hashable, hspec, lib, mkDerivation, random, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "aeson-181";
+       version = "0.15.1";
+       sha256 = "3rvn5i4dm2kcdis9i3g7lpyjcqq9yzq80fnj5hbwqjqlvsw857sy";
+       libraryHaskellDepends = [
+         base containers filepath hashable hspec random transformers unordered_containers
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/aeson-181";
+       description = "Package number 181";
+       license = lib.licenses.mit;
+     }) {};
+
+  "binary-182" = callPackage
+    ({ bytestring, criterion, deepseq, lib, mkDerivation, process, transformers }:
+     mkDerivation {
+       pname = "binary-182";
+       version = "7.17.8";
+       sha256 = "ls8jpsb3gpqpyr29ib8lm63ysjdxwj0s0pji675hw5wn8hnkf7jm";
+       libraryHaskellDepends = [
+         criterion process
+       ];
+       testHaskellDepends = [ bytestring deepseq transformers ];
+       homepage = "https://github.com/example/binary-182";
+       description = "Package number 182";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "QuickCheck-183" = callPackage
+    ({ async, base, containers, deepseq, directory, lib, megaparsec, mkDerivation, random, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "QuickCheck-183";
+       version = "5.14.2";
+       sha256 = "afxkg5zqjkdwl9vlaxx91jb430r1z9ip8wd5darf7vfn0hpy3f7v";
+       libraryHaskellDepends = [
+         async base deepseq random transformers unordered_containers
+       ];
+       testHaskellDepends = [ containers directory megaparsec ];
+       homepage = "https://github.com/example/QuickCheck-183";
+       description = "Package number 183";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "tasty-184" = callPackage
+    ({ QuickCheck, aeson, bytestring, hashable, lib, mkDerivation, mtl, process }:
+     mkDerivation {
+       pname = "tasty-184";
+       version = "3.9.5";
+       sha256 = "a5an0xnk434zgwsp8g8xmjs9w5z70g1zm2b3jzjjfqwrfabf7mhq";
+       libraryHaskellDepends = [
+         aeson hashable mtl process
+       ];
+       testHaskellDepends = [ QuickCheck bytestring ];
+       homepage = "https://github.com/example/tasty-184";
+       description = "Package number 184";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "megaparsec-185" = callPackage
+    ({ base, bytestring, criterion, directory, hashable, lib, mkDerivation, process, random, stm, time }:
+     mkDerivation {
+       pname = "megaparsec-185";
+       version = "5.14.5";
+       sha256 = "zm6hnnbm2m89964qax21sgwvy902y5jaar7sfcb2b33pkgkq1r89";
+       libraryHaskellDepends = [
+         base bytestring criterion directory hashable process random
+       ];
+       testHaskellDepends = [ process stm time ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace megaparsec-185.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/megaparsec-185";
+       description = "Package number 185";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "lens-186" = callPackage
+    ({ binary, containers, hashable, http_client, lib, mkDerivation, parsec, stm, transformers }:
+     mkDerivation {
+       pname = "lens-186";
+       version = "5.8.2";
+       sha256 = "8pdv87rzk4jj4lgxqn1clav78da84k5l38l6snindihhvhy79g08";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary containers http_client transformers
+       ];
+       testHaskellDepends = [ hashable parsec stm ];
+       homepage = "https://github.com/example/lens-186";
+       description = "Package number 186";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "bytestring-187" = callPackage
+    ({ aeson, hashable, lib, mkDerivation, network, stm, tls, transformers }:
+     mkDerivation {
+       pname = "bytestring-187";
+       version = "3.1.8";
+       sha256 = "cvvxjn2qrqgb2d3gi17mrczpm6cdh0z7saw7yha8dy64fxq8qhvn";
+       libraryHaskellDepends = [
+         hashable network tls
+       ];
+       testHaskellDepends = [ aeson stm transformers ];
+       postPatch = "substituteInPlace bytestring-187.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/bytestring-187";
+       description = "Package number 187";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "mtl-188" = callPackage
+    ({ aeson, lib, mkDerivation, network, process, stm, text, tls, vector }:
+     mkDerivation {
+       pname = "mtl-188";
+       version = "5.19.6";
+       sha256 = "xnzp5msj3ih7djz1rxfyps3abicb52a4did3nb3rbjn21hw6xa7s";
+       libraryHaskellDepends = [
+         network process tls
+       ];
+       testHaskellDepends = [ aeson stm text vector ];
+       postPatch = "substituteInPlace mtl-188.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/mtl-188";
+       description = "Package number 188";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "stm-189" = callPackage
+    ({ deepseq, directory, filepath, http_client, lens, lib, megaparsec, mkDerivation, process, stm, tasty, text, time, tls }:
+     mkDerivation {
+       pname = "stm-189";
+       version = "4.6.0";
+       sha256 = "aivja9f8633rnvf03wp22qrz191p075b75bkar78a23i58hajskk";
+       libraryHaskellDepends = [
+         directory filepath http_client lens megaparsec stm text tls
+       ];
+       testHaskellDepends = [ deepseq process tasty time ];
+       homepage = "https://github.com/example/stm-189";
+       description = "Package number 189";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "base-190" = callPackage
+    ({ bytestring, hspec, lens, lib, megaparsec, mkDerivation, process, tasty, transformers, zlib }:
+     mkDerivation {
+       pname = "base-190";
+       version = "8.6.8";
+       sha256 = "yk0iwjzcvldx0xpfppg3c9h0azkg2n8z8qx9s07f653ldfb42fa1";
+       libraryHaskellDepends = [
+         bytestring hspec lens megaparsec process tasty
+       ];
+       testHaskellDepends = [ transformers zlib ];
+       homepage = "https://github.com/example/base-190";
+       description = "Package number 190";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "network-191" = callPackage
+    ({ directory, hspec, lib, mkDerivation, random, stm, transformers, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "network-191";
+       version = "2.9.7";
+       sha256 = "gfd32y7kph37bwnvfjd692l5p52s2jbll18rgk9qdxbj5hhliig3";
+       libraryHaskellDepends = [
+         directory hspec stm transformers zlib
+       ];
+       testHaskellDepends = [ hspec random unordered_containers ];
+       homepage = "https://github.com/example/network-191";
+       description = "Package number 191";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "containers-192" = callPackage
+    ({ aeson, bytestring, criterion, http_client, lens, lib, mkDerivation, transformers }:
+     mkDerivation {
+       pname = "containers-192";
+       version = "0.12.6";
+       sha256 = "26nzkyj3wkivch2h20y1qizg6xcijb6pv3a1rv60ysqzdnxrf07k";
+       libraryHaskellDepends = [
+         aeson http_client
+       ];
+       testHaskellDepends = [ bytestring criterion lens transformers ];
+       homepage = "https://github.com/example/containers-192";
+       description = "Package number 192";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "lens-193" = callPackage
+    ({ filepath, http_client, lens, lib, mkDerivation, unordered_containers }:
+     mkDerivation {
+       pname = "lens-193";
+       version = "9.10.3";
+       sha256 = "pc5kh2wz60kyl8940cg2msgim5y9fx0iygpla5zjyrndxk4i8rr1";
+       libraryHaskellDepends = [
+         http_client unordered_containers
+       ];
+       testHaskellDepends = [ filepath http_client lens ];
+       homepage = "https://github.com/example/lens-193";
+       description = "Package number 193";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tasty-194" = callPackage
+    ({ QuickCheck, base, hashable, lib, mkDerivation, network, stm, transformers }:
+     mkDerivation {
+       pname = "tasty-194";
+       version = "9.13.6";
+       sha256 = "wni0wd4kx1hir59ac30rq379briim9v7virpsg56sjk3kai2cimj";
+       libraryHaskellDepends = [
+         QuickCheck network
+       ];
+       testHaskellDepends = [ base hashable stm transformers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tasty-194";
+       description = "Package number 194";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "directory-195" = callPackage
+    ({ aeson, lib, mkDerivation, mtl, time }:
+     mkDerivation {
+       pname = "directory-195";
+       version = "4.2.1";
+       sha256 = "37n8hb7g0a5as4i01zrmy5pk7as15s2cww3hydb3rm2dgmmg75l6";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson time
+       ];
+       testHaskellDepends = [ mtl time ];
+       homepage = "https://github.com/example/directory-195";
+       description = "Package number 195";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "vector-196" = callPackage
+    ({ async, binary, containers, filepath, lib, mkDerivation, random, text }:
+     mkDerivation {
+       pname = "vector-196";
+       version = "7.6.6";
+       sha256 = "vymv4jdrxvmwpdhqppqxb11cji0bqwm9ikjmx76ig85glykbr4p6";
+       libraryHaskellDepends = [
+         async binary containers
+       ];
+       testHaskellDepends = [ binary filepath random text ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/vector-196";
+       description = "Package number 196";
+       license = lib.licenses.mit;
+     }) {};
+
+  "lens-197" = callPackage
+    ({ base, criterion, directory, filepath, lib, mkDerivation, process, random, vector }:
+     mkDerivation {
+       pname = "lens-197";
+       version = "1.18.5";
+       sha256 = "fkpm52rwx0axjrqh9ypbhbc91w8mzj44j404byg3qschyr12ra1a";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         criterion filepath process random vector
+       ];
+       testHaskellDepends = [ base directory ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/lens-197";
+       description = "Package number 197";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "binary-198" = callPackage
+    ({ QuickCheck, bytestring, containers, deepseq, filepath, hashable, lib, mkDerivation, mtl, random, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "binary-198";
+       version = "7.11.0";
+       sha256 = "hqpfbl8fk49rd397pm2w1q39xrqraa5w6nkgkx8y6w791an16nxf";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck containers deepseq filepath random transformers
+       ];
+       testHaskellDepends = [ bytestring hashable mtl unordered_containers ];
+       postPatch = "substituteInPlace binary-198.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/binary-198";
+       description = "Package number 198";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "async-199" = callPackage
+    ({ bytestring, containers, directory, http_client, lib, mkDerivation, process, random, text, tls }:
+     mkDerivation {
+       pname = "async-199";
+       version = "4.17.9";
+       sha256 = "7b0fzc32wb4dmdi3p0rx3dygqbgzz51avwm0bb2h9vnplx24p8dk";
+       libraryHaskellDepends = [
+         containers directory http_client random text tls
+       ];
+       testHaskellDepends = [ bytestring process text ];
+       homepage = "https://github.com/example/async-199";
+       description = "Package number 199";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "network-200" = callPackage
+    ({ QuickCheck, binary, deepseq, hashable, lib, mkDerivation, random, time, transformers, zlib }:
+     mkDerivation {
+       pname = "network-200";
+       version = "4.1.3";
+       sha256 = "09ly3r62wh5daz6jjvwvjwzlnk1sr0y5bydfbajpn3bavnv6dwbc";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck binary deepseq hashable random time transformers zlib
+       ];
+       postPatch = "substituteInPlace network-200.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/network-200";
+       description = "Package number 200";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "mtl-201" = callPackage
+    ({ async, base, binary, criterion, directory, http_client, lib, mkDerivation, parsec, transformers }:
+     mkDerivation {
+       pname = "mtl-201";
+       version = "8.11.9";
+       sha256 = "4rxb6k7sr40kl116i64kmrmr3hnjsv9yqbb8gg9v235i32m49czk";
+       libraryHaskellDepends = [
+         async base binary criterion directory parsec transformers
+       ];
+       testHaskellDepends = [ async http_client ];
+       homepage = "https://github.com/example/mtl-201";
+       description = "Package number 201";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "transformers-202" = callPackage
+    ({ hashable, lens, lib, mkDerivation, network, parsec, stm, tasty, time }:
+     mkDerivation {
+       pname = "transformers-202";
+       version = "7.4.5";
+       sha256 = "mxqngn3gqvmmlwhkjnw4barri16w1a6s54ix4vk6a74nc8z9z204";
+       libraryHaskellDepends = [
+         hashable network stm tasty time
+       ];
+       testHaskellDepends = [ lens parsec ];
+       homepage = "https://github.com/example/transformers-202";
+       description = "Package number 202";
+       license = lib.licenses.mit;
+     }) {};
+
+  "transformers-203" = callPackage
+    ({ aeson, base, hashable, lib, mkDerivation, stm, tasty, transformers }:
+     mkDerivation {
+       pname = "transformers-203";
+       version = "9.1.3";
+       sha256 = "8q3gbxx5nnla8m21g305bmiqicx14rh1gvwvpv8pznqmg0ab356r";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         stm transformers
+       ];
+       testHaskellDepends = [ aeson base hashable tasty ];
+       homepage = "https://github.com/example/transformers-203";
+       description = "Package number 203";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "parsec-204" = callPackage
+    ({ lib, mkDerivation, process, time, tls }:
+     mkDerivation {
+       pname = "parsec-204";
+       version = "1.9.4";
+       sha256 = "a8zf2qbmr49b7241ks6xjvykcn95xza0pvvgx27z9jfdqkpaa8zz";
+       libraryHaskellDepends = [
+         process time tls
+       ];
+       testHaskellDepends = [ process ];
+       homepage = "https://github.com/example/parsec-204";
+       description = "Package number 204";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "time-205" = callPackage
+    ({ hashable, lib, mkDerivation, mtl, tls, zlib }:
+     mkDerivation {
+       pname = "time-205";
+       version = "8.12.3";
+       sha256 = "chi2zwmp9k71nvyvhzslavl5jgwf0alfyd8fja7vkap0b2j3ayg0";
+       libraryHaskellDepends = [
+         mtl zlib
+       ];
+       testHaskellDepends = [ hashable mtl tls ];
+       homepage = "https://github.com/example/time-205";
+       description = "Package number 205";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "bytestring-206" = callPackage
+    ({ QuickCheck, containers, http_client, lib, mkDerivation, parsec, process, unordered_containers, vector }:
+     mkDerivation {
+       pname = "bytestring-206";
+       version = "0.5.3";
+       sha256 = "wkggrjiq0s121nv7qcamp80v64wkjb85z34xcj10difr27nz88h5";
+       libraryHaskellDepends = [
+         containers http_client parsec unordered_containers vector
+       ];
+       testHaskellDepends = [ QuickCheck process ];
+       homepage = "https://github.com/example/bytestring-206";
+       description = "Package number 206";
+       license = lib.licenses.mit;
+     }) {};
+
+  "process-207" = callPackage
+    ({ hashable, http_client, lib, mkDerivation, mtl, network, process, random, stm }:
+     mkDerivation {
+       pname = "process-207";
+       version = "9.6.6";
+       sha256 = "a5pzrj4sd4zd3dwja1bx5spss9lpq8l1yr7rzc159l2gghjkqmhm";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hashable http_client network process random
+       ];
+       testHaskellDepends = [ mtl stm ];
+       homepage = "https://github.com/example/process-207";
+       description = "Package number 207";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "lens-208" = callPackage
+    ({ lib, mkDerivation, tasty, tls, zlib }:
+     mkDerivation {
+       pname = "lens-208";
+       version = "2.14.1";
+       sha256 = "kk5m3mb6362hnvn00szhmf5gy0ljdxml4hggfqi3xvb8npkz6bz4";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         tasty tls zlib
+       ];
+       homepage = "https://github.com/example/lens-208";
+       description = "Package number 208";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "text-209" = callPackage
+    ({ QuickCheck, async, containers, lib, mkDerivation, time }:
+     mkDerivation {
+       pname = "text-209";
+       version = "6.4.0";
+       sha256 = "4kyn8lfiww92i8k3f9afdfl79l0war6lvmi53rsc4qpjjhq442gr";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck time
+       ];
+       testHaskellDepends = [ async containers ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/text-209";
+       description = "Package number 209";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "random-210" = callPackage
+    ({ QuickCheck, base, criterion, filepath, hashable, lens, lib, mkDerivation, mtl, network, stm, transformers }:
+     mkDerivation {
+       pname = "random-210";
+       version = "1.2.5";
+       sha256 = "1qrwg6hzknmf7lx5h61l4jbbjjayk87qpdp1czmsrsqnns8hghlw";
+       libraryHaskellDepends = [
+         QuickCheck base hashable lens mtl network stm transformers
+       ];
+       testHaskellDepends = [ criterion filepath ];
+       postPatch = "substituteInPlace random-210.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/random-210";
+       description = "Package number 210";
+       license = lib.licenses.mit;
+     }) {};
+
+  "tls-211" = callPackage
+    ({ base, containers, lib, megaparsec, mkDerivation, tasty, vector }:
+     mkDerivation {
+       pname = "tls-211";
+       version = "3.8.8";
+       sha256 = "0xzv81cl0hq8lk7azp3zszj78pahm5rpxva9y3xnh4nahjq5hyfx";
+       libraryHaskellDepends = [
+         base megaparsec tasty vector
+       ];
+       testHaskellDepends = [ containers vector ];
+       homepage = "https://github.com/example/tls-211";
+       description = "Package number 211";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hashable-212" = callPackage
+    ({ aeson, async, bytestring, criterion, hashable, hspec, lib, mkDerivation, mtl, tasty }:
+     mkDerivation {
+       pname = "hashable-212";
+       version = "0.10.9";
+       sha256 = "a6ndzw0a8k7hy4w9va54gw8hmfzlgv9csg7cz6z18m89arzhzha6";
+       libraryHaskellDepends = [
+         aeson async bytestring criterion mtl tasty
+       ];
+       testHaskellDepends = [ hashable hspec ];
+       homepage = "https://github.com/example/hashable-212";
+       description = "Package number 212";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "network-213" = callPackage
+    ({ containers, criterion, hashable, http_client, lib, megaparsec, mkDerivation, parsec, stm, zlib }:
+     mkDerivation {
+       pname = "network-213";
+       version = "1.9.2";
+       sha256 = "vxvjpr366ny36z5mss5bj4ja99kw0q6bwx3hw9klxpj2lac1mrrc";
+       libraryHaskellDepends = [
+         containers criterion hashable http_client megaparsec parsec stm zlib
+       ];
+       postPatch = "substituteInPlace network-213.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/network-213";
+       description = "Package number 213";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "base-214" = callPackage
+    ({ QuickCheck, binary, lib, mkDerivation, random }:
+     mkDerivation {
+       pname = "base-214";
+       version = "5.13.1";
+       sha256 = "899mz6liffa6l9p3x52l9kayrayn13y0vqs0hdviip3yd4iqhxqg";
+       libraryHaskellDepends = [
+         binary random
+       ];
+       testHaskellDepends = [ QuickCheck random ];
+       homepage = "https://github.com/example/base-214";
+       description = "Package number 214";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "process-215" = callPackage
+    ({ directory, lib, mkDerivation, mtl, network, random, tasty, vector }:
+     mkDerivation {
+       pname = "process-215";
+       version = "0.0.2";
+       sha256 = "zcqrigk2wvv7by1dj5ngmx6nh1bq7hfapgg9j7kybj2ij6c4vrqc";
+       libraryHaskellDepends = [
+         directory mtl random tasty
+       ];
+       testHaskellDepends = [ network vector ];
+       homepage = "https://github.com/example/process-215";
+       description = "Package number 215";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "parsec-216" = callPackage
+    ({ aeson, binary, lib, megaparsec, mkDerivation, stm, tls, unordered_containers, vector }:
+     mkDerivation {
+       pname = "parsec-216";
+       version = "1.9.4";
+       sha256 = "xns1xnb2mc5x7v1lvj5jydadcphfnnjz56lz9li2b57brd0h5wg3";
+       libraryHaskellDepends = [
+         aeson megaparsec tls unordered_containers vector
+       ];
+       testHaskellDepends = [ binary stm ];
+       homepage = "https://github.com/example/parsec-216";
+       description = "Package number 216";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "network-217" = callPackage
+    ({ bytestring, deepseq, lens, lib, megaparsec, mkDerivation, tls, zlib }:
+     mkDerivation {
+       pname = "network-217";
+       version = "5.14.9";
+       sha256 = "0x6smmbfbyb8b07g8hxaw6fnxra3kpda9fajp1qza8xdi5d77mny";
+       libraryHaskellDepends = [
+         deepseq lens
+       ];
+       testHaskellDepends = [ bytestring megaparsec tls zlib ];
+       homepage = "https://github.com/example/network-217";
+       description = "Package number 217";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "vector-218" = callPackage
+    ({ binary, lib, mkDerivation, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "vector-218";
+       version = "9.0.3";
+       sha256 = "9kbkvj9b1hyq1ws1fp2j6jxa5zm0mf0q32igckyyiys2808d6dp5";
+       libraryHaskellDepends = [
+         binary transformers unordered_containers
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/vector-218";
+       description = "Package number 218";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tls-219" = callPackage
+    ({ base, directory, filepath, lib, mkDerivation, network, stm, tasty, zlib }:
+     mkDerivation {
+       pname = "tls-219";
+       version = "9.12.7";
+       sha256 = "6n5nlfgcvrwhvjzmfbdvix7dmd66y2j3njs222dngqm24gjwwdjy";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base directory filepath network stm tasty
+       ];
+       testHaskellDepends = [ zlib ];
+       homepage = "https://github.com/example/tls-219";
+       description = "Package number 219";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "zlib-220" = callPackage
+    ({ bytestring, directory, hspec, lib, mkDerivation, parsec, time, transformers, zlib }:
+     mkDerivation {
+       pname = "zlib-220";
+       version = "3.16.7";
+       sha256 = "a1yiwg7x0kcsys3l3fnppf09wkng30mvr8j5m0hdcvmkdz93c4j9";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring directory hspec parsec time transformers
+       ];
+       testHaskellDepends = [ hspec zlib ];
+       homepage = "https://github.com/example/zlib-220";
+       description = "Package number 220";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "zlib-221" = callPackage
+    ({ deepseq, hashable, lib, mkDerivation, network, parsec, stm }:
+     mkDerivation {
+       pname = "zlib-221";
+       version = "6.15.0";
+       sha256 = "z4ix3mmi8ssxsib0iq9lbzfhz8dyz37l4ndz0lvlsf8d6vgw75ap";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hashable network
+       ];
+       testHaskellDepends = [ deepseq network parsec stm ];
+       homepage = "https://github.com/example/zlib-221";
+       description = "Package number 221";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "mtl-222" = callPackage
+    ({ QuickCheck, aeson, async, binary, filepath, lib, megaparsec, mkDerivation, parsec, process, stm, tasty, tls }:
+     mkDerivation {
+       pname = "mtl-222";
+       version = "1.1.5";
+       sha256 = "ypv8ld0r19nzh19dx9qj3j4dihdk4rjq8fmhkgmyrlddz8g8xjld";
+       libraryHaskellDepends = [
+         QuickCheck async filepath parsec process stm tasty tls
+       ];
+       testHaskellDepends = [ aeson binary megaparsec ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/mtl-222";
+       description = "Package number 222";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-223" = callPackage
+    ({ QuickCheck, async, binary, lib, mkDerivation, mtl, parsec, process, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "filepath-223";
+       version = "8.10.3";
+       sha256 = "sgfmqn558lj36l3y57g1qs99dymhfl3avqddycnbfigvnc6adhxa";
+       libraryHaskellDepends = [
+         QuickCheck async binary mtl parsec process transformers
+       ];
+       testHaskellDepends = [ unordered_containers ];
+       homepage = "https://github.com/example/filepath-223";
+       description = "Package number 223";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "zlib-224" = callPackage
+    ({ QuickCheck, bytestring, lib, mkDerivation, process, stm }:
+     mkDerivation {
+       pname = "zlib-224";
+       version = "1.13.4";
+       sha256 = "qf21ys93afkkcp4ji8ir2l61avm01g48nqhrv7x64mwwznaipmr8";
+       libraryHaskellDepends = [
+         process stm
+       ];
+       testHaskellDepends = [ QuickCheck bytestring ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/zlib-224";
+       description = "Package number 224";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "text-225" = callPackage
+    ({ async, criterion, http_client, lib, mkDerivation, tasty }:
+     mkDerivation {
+       pname = "text-225";
+       version = "0.0.1";
+       sha256 = "9ishmj9gcvp19g5rvsc9f88srjlynrh0lf93pqshnz84ska5qb4p";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async tasty
+       ];
+       testHaskellDepends = [ criterion http_client ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/text-225";
+       description = "Package number 225";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hspec-226" = callPackage
+    ({ criterion, http_client, lens, lib, megaparsec, mkDerivation, mtl, random, time, vector, zlib }:
+     mkDerivation {
+       pname = "hspec-226";
+       version = "3.9.8";
+       sha256 = "32j2g364czssnaiils3rjwfwwkp01y3m2yldiz8xi3wjz8s3yq3n";
+       libraryHaskellDepends = [
+         http_client lens megaparsec mtl random time vector zlib
+       ];
+       testHaskellDepends = [ criterion random ];
+       postPatch = "substituteInPlace hspec-226.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/hspec-226";
+       description = "Package number 226";
+       license = lib.licenses.mit;
+     }) {};
+
+  "http-client-227" = callPackage
+    ({ lib, mkDerivation, time, tls }:
+     mkDerivation {
+       pname = "http-client-227";
+       version = "9.5.3";
+       sha256 = "n65d4310zrhb7j34y4gb0lk329hvihf5ixf4b6xyrhnjy1d31z9f";
+       libraryHaskellDepends = [
+         time tls
+       ];
+       homepage = "https://github.com/example/http-client-227";
+       description = "Package number 227";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "aeson-228" = callPackage
+    ({ aeson, base, filepath, lib, mkDerivation, tls, vector }:
+     mkDerivation {
+       pname = "aeson-228";
+       version = "6.19.1";
+       sha256 = "4a6vzwrk365m9q7wv0asjg8wl6bfr9gdnf7da9j3d3nv2djpqsqq";
+       libraryHaskellDepends = [
+         aeson base filepath tls
+       ];
+       testHaskellDepends = [ vector ];
+       homepage = "https://github.com/example/aeson-228";
+       description = "Package number 228";
+       license = lib.licenses.mit;
+     }) {};
+
+  "tls-229" = callPackage
+    ({ QuickCheck, bytestring, http_client, lens, lib, mkDerivation, network, vector }:
+     mkDerivation {
+       pname = "tls-229";
+       version = "8.9.1";
+       sha256 = "bim714d0s36ay2nqj1rs0nlpgxagy4ag8wdwfx3vj6vm42za14hm";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck lens network vector
+       ];
+       testHaskellDepends = [ bytestring http_client ];
+       homepage = "https://github.com/example/tls-229";
+       description = "Package number 229";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "filepath-230" = callPackage
+    ({ hspec, http_client, lib, megaparsec, mkDerivation }:
+     mkDerivation {
+       pname = "filepath-230";
+       version = "6.0.4";
+       sha256 = "85nxijmar48cmzjiag12b0nijmpdsd0w0l3zrr5hlbqhhcwfx572";
+       libraryHaskellDepends = [
+         hspec http_client megaparsec
+       ];
+       testHaskellDepends = [ hspec ];
+       homepage = "https://github.com/example/filepath-230";
+       description = "Package number 230";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hashable-231" = callPackage
+    ({ deepseq, hspec, lib, mkDerivation, time, vector }:
+     mkDerivation {
+       pname = "hashable-231";
+       version = "8.15.3";
+       sha256 = "bcg4yly6rxzy34bppsp92clxvg8qj6pz88m3ls97r0vhx0ppawx4";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq hspec time vector
+       ];
+       homepage = "https://github.com/example/hashable-231";
+       description = "Package number 231";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "directory-232" = callPackage
+    ({ async, deepseq, hspec, http_client, lib, megaparsec, mkDerivation, parsec, random, tls }:
+     mkDerivation {
+       pname = "directory-232";
+       version = "3.18.6";
+       sha256 = "99i4nlagq42qyvi9l0wmaphxkwdf1yf3ccrhz5vvj3237gfz14jx";
+       libraryHaskellDepends = [
+         deepseq hspec http_client megaparsec parsec random tls
+       ];
+       testHaskellDepends = [ async ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/directory-232";
+       description = "Package number 232";
+       license = lib.licenses.mit;
+     }) {};
+
+  "base-233" = callPackage
+    ({ QuickCheck, directory, hashable, lib, mkDerivation, parsec, text, tls, vector, zlib }:
+     mkDerivation {
+       pname = "base-233";
+       version = "6.13.7";
+       sha256 = "r5phv79qx3103zpbhgxgdqbsz4g772bf76lqwhn93lvyzr12khxr";
+       libraryHaskellDepends = [
+         QuickCheck directory hashable parsec text tls vector zlib
+       ];
+       testHaskellDepends = [ parsec ];
+       homepage = "https://github.com/example/base-233";
+       description = "Package number 233";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "vector-234" = callPackage
+    ({ containers, hashable, hspec, lib, mkDerivation, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "vector-234";
+       version = "1.3.8";
+       sha256 = "7j68f68r04crbrc4jaz065hajhd7j5qd2akk3qhld1wcsr34rkh7";
+       libraryHaskellDepends = [
+         containers tasty unordered_containers
+       ];
+       testHaskellDepends = [ hashable hspec ];
+       homepage = "https://github.com/example/vector-234";
+       description = "Package number 234";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "deepseq-235" = callPackage
+    ({ async, bytestring, containers, directory, lib, mkDerivation, text, vector }:
+     mkDerivation {
+       pname = "deepseq-235";
+       version = "3.12.2";
+       sha256 = "hk4fsbpd3dmdnyg5zs0qv1xm0284wy4w1qvwnv7bnrz3f25d5iac";
+       libraryHaskellDepends = [
+         async bytestring containers
+       ];
+       testHaskellDepends = [ directory text vector ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/deepseq-235";
+       description = "Package number 235";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "parsec-236" = callPackage
+    ({ async, deepseq, lib, mkDerivation, mtl, network, text, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "parsec-236";
+       version = "8.14.2";
+       sha256 = "7hdpkhcajwnsiw4d70q0l5xmyz4kszh1kdmk9rqdsrlg8f285w0y";
+       libraryHaskellDepends = [
+         mtl network text transformers unordered_containers
+       ];
+       testHaskellDepends = [ async deepseq transformers ];
+       postPatch = "substituteInPlace parsec-236.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/parsec-236";
+       description = "Package number 236";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "zlib-237" = callPackage
+    ({ binary, containers, directory, http_client, lib, mkDerivation, stm, text, vector }:
+     mkDerivation {
+       pname = "zlib-237";
+       version = "9.3.8";
+       sha256 = "9dqv1fwww8f0yqnj0hgvvhp5y8jpsmdrgval8gpqgxx2s1i635gb";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary containers directory http_client stm text
+       ];
+       testHaskellDepends = [ vector ];
+       homepage = "https://github.com/example/zlib-237";
+       description = "Package number 237";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "network-238" = callPackage
+    ({ bytestring, filepath, hashable, hspec, lens, lib, mkDerivation, text, zlib }:
+     mkDerivation {
+       pname = "network-238";
+       version = "0.19.1";
+       sha256 = "mv0c6h58xyfp4b72q607j88cw7a7v6hrx838g62jp51rizajb78s";
+       libraryHaskellDepends = [
+         bytestring hspec text
+       ];
+       testHaskellDepends = [ filepath hashable lens zlib ];
+       postPatch = "substituteInPlace network-238.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/network-238";
+       description = "Package number 238";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "criterion-239" = callPackage
+    ({ QuickCheck, aeson, base, directory, hashable, http_client, lib, mkDerivation, text }:
+     mkDerivation {
+       pname = "criterion-239";
+       version = "5.20.5";
+       sha256 = "7sn63hmf45w62s43rlpfi34ayyb595l5izrk061sw35c8jz8nac4";
+       libraryHaskellDepends = [
+         QuickCheck base directory hashable http_client
+       ];
+       testHaskellDepends = [ aeson text ];
+       homepage = "https://github.com/example/criterion-239";
+       description = "Package number 239";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "hashable-240" = callPackage
+    ({ async, containers, http_client, lib, mkDerivation, parsec, process, tls, unordered_containers }:
+     mkDerivation {
+       pname = "hashable-240";
+       version = "3.9.9";
+       sha256 = "lk88pfjgng07n2gr7ivyjx9g94zk27byql3i4y33jc5n48nx17fn";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async containers http_client parsec process tls unordered_containers
+       ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/hashable-240";
+       description = "Package number 240";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "megaparsec-241" = callPackage
+    ({ QuickCheck, directory, filepath, hspec, lens, lib, mkDerivation, random, stm }:
+     mkDerivation {
+       pname = "megaparsec-241";
+       version = "2.8.4";
+       sha256 = "vzylmjd8jnq9q28lvki5magbx1zb8dndb22d22xgqg0fv5kxya9y";
+       libraryHaskellDepends = [
+         QuickCheck directory filepath lens random stm
+       ];
+       testHaskellDepends = [ hspec ];
+       homepage = "https://github.com/example/megaparsec-241";
+       description = "Package number 241";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "parsec-242" = callPackage
+    ({ aeson, bytestring, hspec, lens, lib, mkDerivation, tls }:
+     mkDerivation {
+       pname = "parsec-242";
+       version = "6.3.8";
+       sha256 = "yinmay9cf37c4wcznz0a4z89ql2khmmb6814iwjbkq01rgd7lfir";
+       libraryHaskellDepends = [
+         aeson bytestring hspec lens tls
+       ];
+       testHaskellDepends = [ bytestring ];
+       postPatch = "substituteInPlace parsec-242.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/parsec-242";
+       description = "Package number 242";
+       license = lib.licenses.mit;
+     }) {};
+
+  "text-243" = callPackage
+    ({ async, filepath, http_client, lib, mkDerivation, parsec, time, tls, unordered_containers, vector, zlib }:
+     mkDerivation {
+       pname = "text-243";
+       version = "4.13.7";
+       sha256 = "4wn34w6jnl1s2f84nfxkybvf662gb082x0v8jw0rzhsymg67ajjd";
+       libraryHaskellDepends = [
+         async http_client parsec time tls zlib
+       ];
+       testHaskellDepends = [ filepath unordered_containers vector ];
+       homepage = "https://github.com/example/text-243";
+       description = "Package number 243";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "deepseq-244" = callPackage
+    ({ QuickCheck, aeson, containers, deepseq, http_client, lib, mkDerivation, network, text, unordered_containers }:
+     mkDerivation {
+       pname = "deepseq-244";
+       version = "5.14.5";
+       sha256 = "hvf8h3b42dz1cqh7w87y3png97qj2k9qw1sbj8m7cnigzv01ysh0";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck aeson containers deepseq http_client network text unordered_containers
+       ];
+       homepage = "https://github.com/example/deepseq-244";
+       description = "Package number 244";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "binary-245" = callPackage
+    ({ criterion, hspec, lib, mkDerivation, network, parsec }:
+     mkDerivation {
+       pname = "binary-245";
+       version = "8.1.5";
+       sha256 = "sb57abf6sbl161gpzk06bph99xi9hi7k5gpnz11f8167ap6a1pvr";
+       libraryHaskellDepends = [
+         criterion hspec network parsec
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/binary-245";
+       description = "Package number 245";
+       license = lib.licenses.mit;
+     }) {};
+
+  "deepseq-246" = callPackage
+    ({ bytestring, criterion, deepseq, hashable, http_client, lib, mkDerivation, process, random, stm, tasty, vector }:
+     mkDerivation {
+       pname = "deepseq-246";
+       version = "0.10.7";
+       sha256 = "qfkja2cl5bywn9w89g3apy2i1vr8vy6ccv9l50q7f3y8d1p7jv20";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring criterion deepseq http_client random tasty vector
+       ];
+       testHaskellDepends = [ hashable process stm ];
+       homepage = "https://github.com/example/deepseq-246";
+       description = "Package number 246";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "hashable-247" = callPackage
+    ({ containers, directory, hashable, lib, mkDerivation, parsec, stm, unordered_containers }:
+     mkDerivation {
+       pname = "hashable-247";
+       version = "8.2.1";
+       sha256 = "ighmgc15j4y0zpkgr79qmaqjh1ylhf230vlw4nfzp0vd5ydiz2yd";
+       libraryHaskellDepends = [
+         containers directory parsec
+       ];
+       testHaskellDepends = [ hashable stm unordered_containers ];
+       homepage = "https://github.com/example/hashable-247";
+       description = "Package number 247";
+       license = lib.licenses.mit;
+     }) {};
+
+  "tasty-248" = callPackage
+    ({ QuickCheck, aeson, async, http_client, lens, lib, megaparsec, mkDerivation, process, time }:
+     mkDerivation {
+       pname = "tasty-248";
+       version = "5.19.3";
+       sha256 = "5waz410kbvclcz5pgd79jpm8q3hs9wsybixccgqx8j39j9gx7gx3";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck aeson async http_client lens process
+       ];
+       testHaskellDepends = [ megaparsec time ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tasty-248";
+       description = "Package number 248";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "async-249" = callPackage
+    ({ async, base, binary, containers, criterion, filepath, lib, mkDerivation, process, random, text, time, transformers }:
+     mkDerivation {
+       pname = "async-249";
+       version = "6.17.6";
+       sha256 = "l0jvsfbc6y88g2s6jqlz8f75b2f1ryin19mq5v5dvf6bppvnc5wa";
+       libraryHaskellDepends = [
+         async base binary filepath process random text time
+       ];
+       testHaskellDepends = [ containers criterion transformers ];
+       homepage = "https://github.com/example/async-249";
+       description = "Package number 249";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "vector-250" = callPackage
+    ({ QuickCheck, base, criterion, filepath, hashable, lib, mkDerivation, mtl, network, process, tasty, time }:
+     mkDerivation {
+       pname = "vector-250";
+       version = "5.14.4";
+       sha256 = "5ckdls4rq2vvq3ga6yim9qxrrdmfsnk5vskba4p6591jr4n9n80j";
+       libraryHaskellDepends = [
+         QuickCheck base criterion filepath hashable mtl network tasty
+       ];
+       testHaskellDepends = [ hashable process time ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/vector-250";
+       description = "Package number 250";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "criterion-251" = callPackage
+    ({ aeson, deepseq, filepath, hspec, lib, mkDerivation, text, tls, zlib }:
+     mkDerivation {
+       pname = "criterion-251";
+       version = "3.2.4";
+       sha256 = "h7i2pak2ngag9cyk7mbl2g7pmafn1by8kcp6y4wwg6cp73zpbqnr";
+       libraryHaskellDepends = [
+         aeson deepseq hspec text tls zlib
+       ];
+       testHaskellDepends = [ filepath ];
+       homepage = "https://github.com/example/criterion-251";
+       description = "Package number 251";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "tasty-252" = callPackage
+    ({ lib, mkDerivation, tls, unordered_containers }:
+     mkDerivation {
+       pname = "tasty-252";
+       version = "1.19.7";
+       sha256 = "xscc89qcvp6r7qzb98mw44faydx7llsarmb5rb9gndmij34aq53b";
+       libraryHaskellDepends = [
+         tls unordered_containers
+       ];
+       postPatch = "substituteInPlace tasty-252.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/tasty-252";
+       description = "Package number 252";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "directory-253" = callPackage
+    ({ aeson, bytestring, criterion, deepseq, directory, lib, mkDerivation, time, transformers }:
+     mkDerivation {
+       pname = "directory-253";
+       version = "0.14.7";
+       sha256 = "nsq3bfrmblx9zla0lmyl253h1rprxsz4zq66xxpid98v0qiznynb";
+       libraryHaskellDepends = [
+         aeson bytestring criterion directory time transformers
+       ];
+       testHaskellDepends = [ deepseq ];
+       homepage = "https://github.com/example/directory-253";
+       description = "Package number 253";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "directory-254" = callPackage
+    ({ base, binary, directory, hspec, lens, lib, megaparsec, mkDerivation, tasty, text }:
+     mkDerivation {
+       pname = "directory-254";
+       version = "5.2.6";
+       sha256 = "vwmid5pnnji0847n1livnk2lris4drbqra8vv8g89h3mdmkp5l4s";
+       libraryHaskellDepends = [
+         directory hspec lens megaparsec tasty text
+       ];
+       testHaskellDepends = [ base binary ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/directory-254";
+       description = "Package number 254";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "text-255" = callPackage
+    ({ QuickCheck, aeson, async, base, containers, criterion, lib, mkDerivation, network, process, time, unordered_containers, vector }:
+     mkDerivation {
+       pname = "text-255";
+       version = "4.8.7";
+       sha256 = "6przyzfhz30kgdwnkimk0cxq3xx3z4y5zzcs00k4x8dh1a6a7gfl";
+       libraryHaskellDepends = [
+         QuickCheck aeson async base containers process time vector
+       ];
+       testHaskellDepends = [ QuickCheck criterion network unordered_containers ];
+       postPatch = "substituteInPlace text-255.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/text-255";
+       description = "Package number 255";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "process-256" = callPackage
+    ({ base, containers, deepseq, filepath, lib, mkDerivation, network, random, tls, transformers, unordered_containers, vector }:
+     mkDerivation {
+       pname = "process-256";
+       version = "9.2.3";
+       sha256 = "qjfk8nds2n3kchpv6c7p37i670bp5sjmi5divbsi9gm9icnycs1p";
+       libraryHaskellDepends = [
+         base deepseq filepath network tls transformers unordered_containers vector
+       ];
+       testHaskellDepends = [ containers random ];
+       homepage = "https://github.com/example/process-256";
+       description = "Package number 256";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "deepseq-257" = callPackage
+    ({ QuickCheck, base, bytestring, criterion, directory, hashable, hspec, lib, mkDerivation, network, tls, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "deepseq-257";
+       version = "8.13.0";
+       sha256 = "702vars8dxrzpqjc7zw7czvzhxq4la8r3kqqb28s5bwizwbaizrb";
+       libraryHaskellDepends = [
+         QuickCheck base criterion network tls transformers unordered_containers
+       ];
+       testHaskellDepends = [ bytestring directory hashable hspec ];
+       homepage = "https://github.com/example/deepseq-257";
+       description = "Package number 257";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "process-258" = callPackage
+    ({ binary, filepath, hspec, http_client, lens, lib, mkDerivation, mtl, network, process, tasty, zlib }:
+     mkDerivation {
+       pname = "process-258";
+       version = "1.2.6";
+       sha256 = "64j14w51nz3dqwammxamffm6mpph2s3nrpgy4d37n284z4q3msrw";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary filepath http_client lens mtl network tasty
+       ];
+       testHaskellDepends = [ hspec process zlib ];
+       homepage = "https://github.com/example/process-258";
+       description = "Package number 258";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "mtl-259" = callPackage
+    ({ QuickCheck, binary, criterion, deepseq, lib, megaparsec, mkDerivation, stm, transformers }:
+     mkDerivation {
+       pname = "mtl-259";
+       version = "4.14.0";
+       sha256 = "j2gs4f8y8sxxdnf1n16kgzs66cfx0f3x6hy39ybc3qdhmyjjqn42";
+       libraryHaskellDepends = [
+         binary criterion deepseq megaparsec stm
+       ];
+       testHaskellDepends = [ QuickCheck binary transformers ];
+       homepage = "https://github.com/example/mtl-259";
+       description = "Package number 259";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "filepath-260" = callPackage
+    ({ QuickCheck, base, criterion, lib, megaparsec, mkDerivation, network, process, tls }:
+     mkDerivation {
+       pname = "filepath-260";
+       version = "8.3.0";
+       sha256 = "rl95zp96a96pddbgdy0s1yqh7fkwhvgxp1ywjqkhs6gwgda58im2";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck base criterion megaparsec network process tls
+       ];
+       homepage = "https://github.com/example/filepath-260";
+       description = "Package number 260";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "filepath-261" = callPackage
+    ({ criterion, lib, mkDerivation, mtl, network, random, vector, zlib }:
+     mkDerivation {
+       pname = "filepath-261";
+       version = "8.1.9";
+       sha256 = "g1jg5p8wdzycgl33acyc56222gigpn2nn52xcjffv2pk6k7y0zi1";
+       libraryHaskellDepends = [
+         mtl network random vector zlib
+       ];
+       testHaskellDepends = [ criterion mtl random ];
+       homepage = "https://github.com/example/filepath-261";
+       description = "Package number 261";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "mtl-262" = callPackage
+    ({ base, criterion, deepseq, filepath, hspec, lib, mkDerivation, mtl, tasty }:
+     mkDerivation {
+       pname = "mtl-262";
+       version = "7.19.7";
+       sha256 = "6cswj32v6ijsdmii0w0fcmy8ff26ab5an3c84l3qn7q41dlidhnr";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base criterion deepseq filepath hspec mtl tasty
+       ];
+       homepage = "https://github.com/example/mtl-262";
+       description = "Package number 262";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-263" = callPackage
+    ({ aeson, base, bytestring, containers, directory, hspec, lib, mkDerivation, parsec, process, random, unordered_containers, vector }:
+     mkDerivation {
+       pname = "filepath-263";
+       version = "8.6.6";
+       sha256 = "a1n6b3zfpxhkhizjckrfnvk93vvjw9x4zaz6qxgnhik9z8mr1clp";
+       libraryHaskellDepends = [
+         aeson base bytestring containers parsec process unordered_containers
+       ];
+       testHaskellDepends = [ directory hspec random vector ];
+       homepage = "https://github.com/example/filepath-263";
+       description = "Package number 263";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-264" = callPackage
+    ({ containers, hspec, http_client, lens, lib, mkDerivation, tasty, tls, unordered_containers, vector }:
+     mkDerivation {
+       pname = "stm-264";
+       version = "9.16.2";
+       sha256 = "ixgfys4k8g7wv804bpvfsfwnp952inz0xnxnpl9cqvm5n4av0gz1";
+       libraryHaskellDepends = [
+         hspec http_client lens tasty tls unordered_containers vector
+       ];
+       testHaskellDepends = [ containers tls ];
+       homepage = "https://github.com/example/stm-264";
+       description = "Package number 264";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-265" = callPackage
+    ({ QuickCheck, base, criterion, lib, megaparsec, mkDerivation, network, random, time, tls, unordered_containers }:
+     mkDerivation {
+       pname = "stm-265";
+       version = "5.0.5";
+       sha256 = "hkjganyqabzqb7srg80n9ijzayhrhycvawsly5af8gganp1001yd";
+       libraryHaskellDepends = [
+         QuickCheck criterion megaparsec network random time unordered_containers
+       ];
+       testHaskellDepends = [ base criterion tls ];
+       homepage = "https://github.com/example/stm-265";
+       description = "Package number 265";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "stm-266" = callPackage
+    ({ aeson, filepath, hashable, lib, mkDerivation, parsec, process, unordered_containers, vector }:
+     mkDerivation {
+       pname = "stm-266";
+       version = "4.17.2";
+       sha256 = "8j95m1x79bgr75z8298q8fpmfb02xn3yx2ma5r2wzzaj5jacyqlc";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         filepath hashable process unordered_containers
+       ];
+       testHaskellDepends = [ aeson parsec vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/stm-266";
+       description = "Package number 266";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "filepath-267" = callPackage
+    ({ directory, hspec, lib, mkDerivation, network, tasty, time, vector, zlib }:
+     mkDerivation {
+       pname = "filepath-267";
+       version = "4.2.2";
+       sha256 = "6s0h8rr09zpnpx393yxrjjljk2nj4qcdcqlibzyfsar2jqfwsqwj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hspec network tasty vector zlib
+       ];
+       testHaskellDepends = [ directory time ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/filepath-267";
+       description = "Package number 267";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "filepath-268" = callPackage
+    ({ QuickCheck, aeson, base, criterion, filepath, lib, mkDerivation, stm, zlib }:
+     mkDerivation {
+       pname = "filepath-268";
+       version = "7.18.5";
+       sha256 = "88s9s29bzmy5imycl0sy404j3v7mfgnd700v6z64zsayqq7g79ph";
+       libraryHaskellDepends = [
+         QuickCheck aeson base criterion filepath stm zlib
+       ];
+       homepage = "https://github.com/example/filepath-268";
+       description = "Package number 268";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "directory-269" = callPackage
+    ({ criterion, hashable, lib, megaparsec, mkDerivation, network, parsec, tasty, tls, transformers }:
+     mkDerivation {
+       pname = "directory-269";
+       version = "9.20.9";
+       sha256 = "xv03fbp2pjpgafdq158bpsbfa3g8wb6h7mjszcw1xhcyfzd67wk0";
+       libraryHaskellDepends = [
+         criterion hashable megaparsec network parsec tasty transformers
+       ];
+       testHaskellDepends = [ tls ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/directory-269";
+       description = "Package number 269";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "hspec-270" = callPackage
+    ({ async, base, containers, hspec, http_client, lib, mkDerivation, mtl, process, random, stm }:
+     mkDerivation {
+       pname = "hspec-270";
+       version = "5.18.0";
+       sha256 = "psr46dg2cllj6p2v3a219rw5i37chrs74qxy7xzw5589bjg2ndq0";
+       libraryHaskellDepends = [
+         async containers hspec mtl process random
+       ];
+       testHaskellDepends = [ base http_client process stm ];
+       postPatch = "substituteInPlace hspec-270.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/hspec-270";
+       description = "Package number 270";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "binary-271" = callPackage
+    ({ QuickCheck, http_client, lib, mkDerivation, unordered_containers }:
+     mkDerivation {
+       pname = "binary-271";
+       version = "2.13.4";
+       sha256 = "3cfrb918k28bkdgkqfkl8c9qjzn2nnpp4n5xbk1nsh5pvxgvvjbx";
+       libraryHaskellDepends = [
+         QuickCheck unordered_containers
+       ];
+       testHaskellDepends = [ http_client unordered_containers ];
+       homepage = "https://github.com/example/binary-271";
+       description = "Package number 271";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "mtl-272" = callPackage
+    ({ bytestring, deepseq, filepath, hashable, lib, mkDerivation, unordered_containers, vector }:
+     mkDerivation {
+       pname = "mtl-272";
+       version = "5.7.7";
+       sha256 = "7wa44xymsmzgnnafwkvycgkcl6kq2jkrzmw675a8fndmfm9lal24";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring deepseq unordered_containers vector
+       ];
+       testHaskellDepends = [ deepseq filepath hashable vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace mtl-272.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/mtl-272";
+       description = "Package number 272";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "vector-273" = callPackage
+    ({ bytestring, containers, criterion, filepath, lib, mkDerivation, network, process, random }:
+     mkDerivation {
+       pname = "vector-273";
+       version = "4.15.3";
+       sha256 = "pwcnw63fazr8w9bw5mhv0nldkvl15dixl43fxrzmy03lyzcikn29";
+       libraryHaskellDepends = [
+         bytestring containers filepath process random
+       ];
+       testHaskellDepends = [ criterion network random ];
+       postPatch = "substituteInPlace vector-273.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/vector-273";
+       description = "Package number 273";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "unordered-containers-274" = callPackage
+    ({ async, binary, lens, lib, mkDerivation, process, unordered_containers, vector, zlib }:
+     mkDerivation {
+       pname = "unordered-containers-274";
+       version = "4.7.2";
+       sha256 = "99j7smlbs6r8slxbdr02rq7g0g0z1v5iy99xkwhyg4858cpacxpi";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async binary lens process unordered_containers vector zlib
+       ];
+       postPatch = "substituteInPlace unordered-containers-274.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/unordered-containers-274";
+       description = "Package number 274";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "transformers-275" = callPackage
+    ({ bytestring, lib, mkDerivation, text }:
+     mkDerivation {
+       pname = "transformers-275";
+       version = "7.3.9";
+       sha256 = "4dm8jfwvnvlhwm4dl5zshs2gfwamsc75jydpw6q3lxgv340rvan7";
+       libraryHaskellDepends = [
+         bytestring text
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/transformers-275";
+       description = "Package number 275";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "bytestring-276" = callPackage
+    ({ async, containers, deepseq, lens, lib, mkDerivation, random }:
+     mkDerivation {
+       pname = "bytestring-276";
+       version = "7.14.4";
+       sha256 = "nd3sn7wrrav458n6w96qjr1gajx9adlkc26724czgnm7d91bxxlw";
+       libraryHaskellDepends = [
+         containers deepseq
+       ];
+       testHaskellDepends = [ async deepseq lens random ];
+       homepage = "https://github.com/example/bytestring-276";
+       description = "Package number 276";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "text-277" = callPackage
+    ({ aeson, deepseq, lib, megaparsec, mkDerivation, parsec, process, unordered_containers }:
+     mkDerivation {
+       pname = "text-277";
+       version = "6.8.8";
+       sha256 = "vk6pflzfdarg73wqs01x3x3yj15h6lkcg7zh70sadhf8snyh6wbr";
+       libraryHaskellDepends = [
+         aeson megaparsec parsec unordered_containers
+       ];
+       testHaskellDepends = [ deepseq process ];
+       homepage = "https://github.com/example/text-277";
+       description = "Package number 277";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "binary-278" = callPackage
+    ({ filepath, lib, megaparsec, mkDerivation, network, random }:
+     mkDerivation {
+       pname = "binary-278";
+       version = "9.1.3";
+       sha256 = "g1rk3bj2qi1nibv0459njpkhwpmiygrn5cyn7rbc2v0lhdr3rywg";
+       libraryHaskellDepends = [
+         filepath megaparsec network random
+       ];
+       testHaskellDepends = [ megaparsec ];
+       postPatch = "substituteInPlace binary-278.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/binary-278";
+       description = "Package number 278";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "random-279" = callPackage
+    ({ directory, hashable, lib, mkDerivation, process, random, stm }:
+     mkDerivation {
+       pname = "random-279";
+       version = "6.0.3";
+       sha256 = "51b79jb5h2hm7zzfxspxi33s61mrq8gvklhd9piyy47a30yyjdb3";
+       libraryHaskellDepends = [
+         directory hashable process stm
+       ];
+       testHaskellDepends = [ random ];
+       postPatch = "substituteInPlace random-279.cabal --replace ${lib.escapeShellArg "base"} base";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/random-279";
+       description = "Package number 279";
+       license = lib.licenses.mit;
+     }) {};
+
+  "async-280" = callPackage
+    ({ criterion, hashable, hspec, http_client, lib, megaparsec, mkDerivation, tls, transformers }:
+     mkDerivation {
+       pname = "async-280";
+       version = "0.9.9";
+       sha256 = "jb40hxjp5qis0r8x9gy68dgjpxhj2xnyjrkr0l8isz4rkfvbywjz";
+       libraryHaskellDepends = [
+         criterion hashable hspec http_client transformers
+       ];
+       testHaskellDepends = [ megaparsec tls ];
+       homepage = "https://github.com/example/async-280";
+       description = "Package number 280";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "hashable-281" = callPackage
+    ({ async, base, criterion, lib, mkDerivation, network, text, zlib }:
+     mkDerivation {
+       pname = "hashable-281";
+       version = "2.16.0";
+       sha256 = "3gxdj0iyxs9kpiq8hsr75jvz370dvw66pai8rn1jcrqmrpl8fbfj";
+       libraryHaskellDepends = [
+         async base criterion network zlib
+       ];
+       testHaskellDepends = [ text ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/hashable-281";
+       description = "Package number 281";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "stm-282" = callPackage
+    ({ lens, lib, mkDerivation, network, process, tls, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "stm-282";
+       version = "8.19.2";
+       sha256 = "7xldbvayqncqn6c9xi51mh6si9128xmznr2gdg4hl2b30d10srlz";
+       libraryHaskellDepends = [
+         process unordered_containers
+       ];
+       testHaskellDepends = [ lens network tls transformers ];
+       homepage = "https://github.com/example/stm-282";
+       description = "Package number 282";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "tls-283" = callPackage
+    ({ aeson, directory, hspec, lib, mkDerivation, network, parsec, process, tasty, text, zlib }:
+     mkDerivation {
+       pname = "tls-283";
+       version = "8.10.2";
+       sha256 = "f60w5l37kgpmhp9h8nfbhw2yzvhpn9v3vw4rh2ndswg81ygq6a3k";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson directory hspec parsec process tasty text zlib
+       ];
+       testHaskellDepends = [ network process ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace tls-283.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/tls-283";
+       description = "Package number 283";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "http-client-284" = callPackage
+    ({ aeson, bytestring, deepseq, directory, lens, lib, megaparsec, mkDerivation, tasty, tls, vector }:
+     mkDerivation {
+       pname = "http-client-284";
+       version = "1.8.4";
+       sha256 = "vbbg2lm181yzl361zk1wxq5k3k5rhqk335nfz11zirqliywh7i2w";
+       libraryHaskellDepends = [
+         aeson bytestring deepseq directory lens megaparsec tls
+       ];
+       testHaskellDepends = [ tasty vector ];
+       postPatch = "substituteInPlace http-client-284.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/http-client-284";
+       description = "Package number 284";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hashable-285" = callPackage
+    ({ lib, mkDerivation, parsec, random }:
+     mkDerivation {
+       pname = "hashable-285";
+       version = "8.7.1";
+       sha256 = "jfywsk43cckxc2xnlzz84k03w759qcgrkn19snqnavy006585nfg";
+       libraryHaskellDepends = [
+         parsec random
+       ];
+       homepage = "https://github.com/example/hashable-285";
+       description = "Package number 285";
+       license = lib.licenses.mit;
+     }) {};
+
+  "directory-286" = callPackage
+    ({ base, containers, http_client, lens, lib, mkDerivation, vector }:
+     mkDerivation {
+       pname = "directory-286";
+       version = "1.10.1";
+       sha256 = "xzwl1dvfrxvjw376m4pgmnp2jsizzc3wb60n4wsy0fnkdb1bs8ii";
+       libraryHaskellDepends = [
+         base lens
+       ];
+       testHaskellDepends = [ containers http_client vector ];
+       homepage = "https://github.com/example/directory-286";
+       description = "Package number 286";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "process-287" = callPackage
+    ({ deepseq, directory, http_client, lib, mkDerivation, mtl, process }:
+     mkDerivation {
+       pname = "process-287";
+       version = "1.20.1";
+       sha256 = "md3rlrhh36npsmv08ipfwnqzgf6yx8axnpc7dnrvkkmywzzgz4j9";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq directory http_client process
+       ];
+       testHaskellDepends = [ mtl ];
+       homepage = "https://github.com/example/process-287";
+       description = "Package number 287";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "http-client-288" = callPackage
+    ({ QuickCheck, aeson, bytestring, hashable, lens, lib, mkDerivation, time, vector }:
+     mkDerivation {
+       pname = "http-client-288";
+       version = "1.13.8";
+       sha256 = "mzgs585mr4409jhxdcdcrpdvs5rgzby84mk9x1xj4gs1kii3hz9w";
+       libraryHaskellDepends = [
+         QuickCheck aeson bytestring hashable lens time vector
+       ];
+       postPatch = "substituteInPlace http-client-288.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/http-client-288";
+       description = "Package number 288";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "transformers-289" = callPackage
+    ({ aeson, async, base, bytestring, lens, lib, mkDerivation, parsec, text, unordered_containers }:
+     mkDerivation {
+       pname = "transformers-289";
+       version = "3.6.1";
+       sha256 = "w7skv0ifkaznq8wb0gk8dqshi3s4bm86rv4h1iqi7naa669yw4dj";
+       libraryHaskellDepends = [
+         aeson base lens text unordered_containers
+       ];
+       testHaskellDepends = [ async bytestring parsec ];
+       homepage = "https://github.com/example/transformers-289";
+       description = "Package number 289";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "parsec-290" = callPackage
+    ({ async, filepath, lib, mkDerivation, stm, time, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "parsec-290";
+       version = "9.1.9";
+       sha256 = "aav6h005wlind3l2m4jkmy1211dagf4m6zk79nq025lccxsjaavc";
+       libraryHaskellDepends = [
+         async filepath stm time unordered_containers zlib
+       ];
+       homepage = "https://github.com/example/parsec-290";
+       description = "Package number 290";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "hashable-291" = callPackage
+    ({ QuickCheck, hashable, http_client, lens, lib, mkDerivation, process, random, stm, tasty }:
+     mkDerivation {
+       pname = "hashable-291";
+       version = "4.6.1";
+       sha256 = "wklnb09zkh06anbs6gjqr4d99g1hz4m3m5wj3khd6ghfwkj549vs";
+       libraryHaskellDepends = [
+         hashable http_client lens process stm tasty
+       ];
+       testHaskellDepends = [ QuickCheck random ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/hashable-291";
+       description = "Package number 291";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "zlib-292" = callPackage
+    ({ aeson, async, deepseq, hashable, http_client, lib, mkDerivation, parsec, tls }:
+     mkDerivation {
+       pname = "zlib-292";
+       version = "9.1.5";
+       sha256 = "9za55p6hilgk75c989kbh3czjipd3cvsxq97d42x4y5jpvn88g40";
+       libraryHaskellDepends = [
+         aeson async hashable
+       ];
+       testHaskellDepends = [ deepseq http_client parsec tls ];
+       homepage = "https://github.com/example/zlib-292";
+       description = "Package number 292";
+       license = lib.licenses.mit;
+     }) {};
+
+  "time-293" = callPackage
+    ({ criterion, filepath, lib, mkDerivation, network, process, tasty }:
+     mkDerivation {
+       pname = "time-293";
+       version = "2.15.1";
+       sha256 = "dkvkyg49riy5339vwykcas795k33ffy2ccdk8gcsdwfqvnm3gkdl";
+       libraryHaskellDepends = [
+         criterion tasty
+       ];
+       testHaskellDepends = [ filepath network process tasty ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/time-293";
+       description = "Package number 293";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-294" = callPackage
+    ({ aeson, containers, deepseq, hashable, http_client, lens, lib, mkDerivation, network, random, stm, unordered_containers }:
+     mkDerivation {
+       pname = "filepath-294";
+       version = "8.18.6";
+       sha256 = "k03amkdfpvp19i09qpc5fn5gy2w19c9b0snpg93jvk5xz2g8s4ym";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson containers deepseq hashable lens network random stm
+       ];
+       testHaskellDepends = [ deepseq http_client unordered_containers ];
+       homepage = "https://github.com/example/filepath-294";
+       description = "Package number 294";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "process-295" = callPackage
+    ({ aeson, async, binary, hashable, hspec, http_client, lib, megaparsec, mkDerivation, random, vector }:
+     mkDerivation {
+       pname = "process-295";
+       version = "2.19.6";
+       sha256 = "569726sv72d4hw9b0rc0s15q8y7mqbgv7mzcfp45jdxsid827rk4";
+       libraryHaskellDepends = [
+         aeson async binary hashable http_client megaparsec random vector
+       ];
+       testHaskellDepends = [ async hspec megaparsec ];
+       postPatch = "substituteInPlace process-295.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/process-295";
+       description = "Package number 295";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "tasty-296" = callPackage
+    ({ deepseq, directory, hspec, http_client, lib, megaparsec, mkDerivation, network, stm, tasty, vector }:
+     mkDerivation {
+       pname = "tasty-296";
+       version = "0.13.0";
+       sha256 = "zqn8pm49azhr36c6r5l4digd9qdwih6qihx7pjs4968bc2ad3djj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         directory hspec megaparsec network stm tasty
+       ];
+       testHaskellDepends = [ deepseq directory http_client vector ];
+       homepage = "https://github.com/example/tasty-296";
+       description = "Package number 296";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "hspec-297" = callPackage
+    ({ criterion, directory, lens, lib, mkDerivation, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "hspec-297";
+       version = "5.11.4";
+       sha256 = "l5ykxa7w8c0rrmzw3bvgvzskbp1wfj01lmrkxm8wmkl3qqh1vkgv";
+       libraryHaskellDepends = [
+         criterion lens tasty
+       ];
+       testHaskellDepends = [ criterion directory unordered_containers ];
+       homepage = "https://github.com/example/hspec-297";
+       description = "Package number 297";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "process-298" = callPackage
+    ({ QuickCheck, async, criterion, filepath, hashable, lens, lib, mkDerivation, stm, zlib }:
+     mkDerivation {
+       pname = "process-298";
+       version = "9.5.7";
+       sha256 = "3rkpll89klxlrl3pkjslhxfhjy0f0wc87y3nrg80qbv18nkczgjs";
+       libraryHaskellDepends = [
+         QuickCheck async criterion filepath hashable lens stm zlib
+       ];
+       homepage = "https://github.com/example/process-298";
+       description = "Package number 298";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "zlib-299" = callPackage
+    ({ QuickCheck, binary, filepath, lib, mkDerivation, stm }:
+     mkDerivation {
+       pname = "zlib-299";
+       version = "3.11.0";
+       sha256 = "jvvkkgljhq3vqpgy1a2l5ahbv3mqgyib137bzpfcv7z5d5imbm76";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck binary filepath stm
+       ];
+       homepage = "https://github.com/example/zlib-299";
+       description = "Package number 299";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "zlib-300" = callPackage
+    ({ bytestring, lib, mkDerivation, mtl, network, random, tasty, time, vector }:
+     mkDerivation {
+       pname = "zlib-300";
+       version = "3.2.1";
+       sha256 = "bb1fd884ml883m3nzk5zcraizqddzc0b35hy86pm5wkyakpfqip4";
+       libraryHaskellDepends = [
+         bytestring mtl tasty
+       ];
+       testHaskellDepends = [ network random time vector ];
+       homepage = "https://github.com/example/zlib-300";
+       description = "Package number 300";
+       license = lib.licenses.mit;
+     }) {};
+
+  "tls-301" = callPackage
+    ({ binary, criterion, lib, mkDerivation, process, tls, transformers, zlib }:
+     mkDerivation {
+       pname = "tls-301";
+       version = "4.12.4";
+       sha256 = "3ak1wyqqmjzvpsms14nczs4qv78a4zvpx2cnad85jyzd5wph595d";
+       libraryHaskellDepends = [
+         binary criterion transformers
+       ];
+       testHaskellDepends = [ process tls zlib ];
+       homepage = "https://github.com/example/tls-301";
+       description = "Package number 301";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "aeson-302" = callPackage
+    ({ lib, mkDerivation, process, random, tls }:
+     mkDerivation {
+       pname = "aeson-302";
+       version = "2.18.8";
+       sha256 = "rajmnkyr9ai5j0jha6018hr6c6b0nms4gpsjfwqadghm7ih4lkq4";
+       libraryHaskellDepends = [
+         process random tls
+       ];
+       postPatch = "substituteInPlace aeson-302.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/aeson-302";
+       description = "Package number 302";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "directory-303" = callPackage
+    ({ criterion, filepath, lib, mkDerivation, parsec, tasty, time }:
+     mkDerivation {
+       pname = "directory-303";
+       version = "7.20.4";
+       sha256 = "z3mbwxxf1zdjg7j5p32q3vahz8cjdp8bx12bwb303yvfjwpvib4p";
+       libraryHaskellDepends = [
+         filepath tasty time
+       ];
+       testHaskellDepends = [ criterion parsec ];
+       homepage = "https://github.com/example/directory-303";
+       description = "Package number 303";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "filepath-304" = callPackage
+    ({ QuickCheck, binary, bytestring, hspec, lib, megaparsec, mkDerivation, parsec, text, transformers }:
+     mkDerivation {
+       pname = "filepath-304";
+       version = "2.3.7";
+       sha256 = "c3ik4z5571dz1j7a11k1fhzl18wa55ncaddxcymzg2z23cw5g9nr";
+       libraryHaskellDepends = [
+         QuickCheck bytestring hspec megaparsec
+       ];
+       testHaskellDepends = [ binary parsec text transformers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/filepath-304";
+       description = "Package number 304";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "unordered-containers-305" = callPackage
+    ({ QuickCheck, base, filepath, lib, mkDerivation, time }:
+     mkDerivation {
+       pname = "unordered-containers-305";
+       version = "1.16.4";
+       sha256 = "zfmk71l9a6xh0h74b2pqfwfiq2d2264r15dqrvhzxjf103avsibj";
+       libraryHaskellDepends = [
+         QuickCheck filepath time
+       ];
+       testHaskellDepends = [ base ];
+       homepage = "https://github.com/example/unordered-containers-305";
+       description = "Package number 305";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "aeson-306" = callPackage
+    ({ binary, deepseq, filepath, lens, lib, mkDerivation, stm, text, zlib }:
+     mkDerivation {
+       pname = "aeson-306";
+       version = "9.4.1";
+       sha256 = "vggqqs6b57a55i5fg324lsc3f4c6927fc2q09r7j6sw06mpvjyaw";
+       libraryHaskellDepends = [
+         deepseq lens stm text
+       ];
+       testHaskellDepends = [ binary filepath zlib ];
+       homepage = "https://github.com/example/aeson-306";
+       description = "Package number 306";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "bytestring-307" = callPackage
+    ({ binary, filepath, hspec, lib, mkDerivation, stm, tls, transformers, zlib }:
+     mkDerivation {
+       pname = "bytestring-307";
+       version = "4.0.5";
+       sha256 = "idfjvf98q83bd5nzk4yxpj306gkmkzxhvvicv2fzidw51w5ga9ck";
+       libraryHaskellDepends = [
+         binary hspec tls
+       ];
+       testHaskellDepends = [ filepath stm transformers zlib ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/bytestring-307";
+       description = "Package number 307";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "http-client-308" = callPackage
+    ({ aeson, async, base, bytestring, criterion, filepath, hspec, http_client, lens, lib, mkDerivation, random }:
+     mkDerivation {
+       pname = "http-client-308";
+       version = "1.1.4";
+       sha256 = "gng2zdnnfr7hgfhjf14adhdq1gim1pnrhj559pdi7wpdv47a3bj0";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson async base bytestring criterion filepath lens random
+       ];
+       testHaskellDepends = [ base hspec http_client ];
+       homepage = "https://github.com/example/http-client-308";
+       description = "Package number 308";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "filepath-309" = callPackage
+    ({ aeson, criterion, deepseq, http_client, lib, mkDerivation, network, parsec, time }:
+     mkDerivation {
+       pname = "filepath-309";
+       version = "8.0.4";
+       sha256 = "xpj3ca3nk8i3pd3m6v7mf7anwwh4p7j2m6jwx09adkm9vrplkr5l";
+       libraryHaskellDepends = [
+         criterion deepseq http_client network parsec
+       ];
+       testHaskellDepends = [ aeson network time ];
+       homepage = "https://github.com/example/filepath-309";
+       description = "Package number 309";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "directory-310" = callPackage
+    ({ hashable, lib, megaparsec, mkDerivation, tls }:
+     mkDerivation {
+       pname = "directory-310";
+       version = "1.14.8";
+       sha256 = "16dyn8ykbvvrw30l42ahrzaziz8n8x3gqc5h4kjxcggy8ammb3df";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hashable megaparsec tls
+       ];
+       homepage = "https://github.com/example/directory-310";
+       description = "Package number 310";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "deepseq-311" = callPackage
+    ({ QuickCheck, lib, mkDerivation, network, text, zlib }:
+     mkDerivation {
+       pname = "deepseq-311";
+       version = "3.8.7";
+       sha256 = "8wv2jm5swxx1w9szlqhr7kw5a1hcw8rk7y05rsvxsy5vw2djb4vk";
+       libraryHaskellDepends = [
+         text zlib
+       ];
+       testHaskellDepends = [ QuickCheck network text ];
+       homepage = "https://github.com/example/deepseq-311";
+       description = "Package number 311";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "transformers-312" = callPackage
+    ({ QuickCheck, async, criterion, filepath, hashable, hspec, lib, mkDerivation, mtl, network, process, tls }:
+     mkDerivation {
+       pname = "transformers-312";
+       version = "5.14.7";
+       sha256 = "ssd3rmvlhbiy2z1win8i47dkr9zvsxam1gkhwbhcjvv985v6wi39";
+       libraryHaskellDepends = [
+         QuickCheck criterion filepath mtl network process tls
+       ];
+       testHaskellDepends = [ async hashable hspec ];
+       homepage = "https://github.com/example/transformers-312";
+       description = "Package number 312";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "base-313" = callPackage
+    ({ aeson, containers, directory, filepath, hashable, hspec, lib, mkDerivation, process, stm, zlib }:
+     mkDerivation {
+       pname = "base-313";
+       version = "3.9.4";
+       sha256 = "hxzc352nqd49cxxgw21zki4nwdhfrpagmad9y8wrlqsnmh6pichb";
+       libraryHaskellDepends = [
+         directory filepath hashable hspec process stm zlib
+       ];
+       testHaskellDepends = [ aeson containers ];
+       homepage = "https://github.com/example/base-313";
+       description = "Package number 313";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "QuickCheck-314" = callPackage
+    ({ QuickCheck, bytestring, containers, deepseq, lib, mkDerivation, parsec, text, time, vector }:
+     mkDerivation {
+       pname = "QuickCheck-314";
+       version = "3.15.9";
+       sha256 = "b6vxp5j3k4ysl6ksrfj5svsin36ngmjf4n3cysbbf6mi9mkqh0q9";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck bytestring containers deepseq parsec text time vector
+       ];
+       homepage = "https://github.com/example/QuickCheck-314";
+       description = "Package number 314";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "aeson-315" = callPackage
+    ({ QuickCheck, bytestring, containers, deepseq, hspec, http_client, lib, megaparsec, mkDerivation, time, unordered_containers, vector }:
+     mkDerivation {
+       pname = "aeson-315";
+       version = "4.9.5";
+       sha256 = "sslpn40hs794267ryai1skh49a64a1hx5azdy376j7c1bykhj439";
+       libraryHaskellDepends = [
+         QuickCheck bytestring containers deepseq hspec time unordered_containers vector
+       ];
+       testHaskellDepends = [ containers hspec http_client megaparsec ];
+       homepage = "https://github.com/example/aeson-315";
+       description = "Package number 315";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "unordered-containers-316" = callPackage
+    ({ binary, criterion, directory, lens, lib, mkDerivation, network, random, text, zlib }:
+     mkDerivation {
+       pname = "unordered-containers-316";
+       version = "8.18.2";
+       sha256 = "3hiwvf00vr91w4h1d60bjqxz4qkjq5nf9kjf3a1zmcvshcwhxdcw";
+       libraryHaskellDepends = [
+         binary criterion directory lens network random text zlib
+       ];
+       postPatch = "substituteInPlace unordered-containers-316.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/unordered-containers-316";
+       description = "Package number 316";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tasty-317" = callPackage
+    ({ base, bytestring, lib, mkDerivation, mtl, network }:
+     mkDerivation {
+       pname = "tasty-317";
+       version = "0.13.4";
+       sha256 = "g4kcw1165ax58q1mdijrsbmdz766gxzdld7mz2qzgrzpiljf14ax";
+       libraryHaskellDepends = [
+         bytestring mtl network
+       ];
+       testHaskellDepends = [ base ];
+       homepage = "https://github.com/example/tasty-317";
+       description = "Package number 317";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "criterion-318" = callPackage
+    ({ deepseq, directory, hashable, hspec, lib, mkDerivation }:
+     mkDerivation {
+       pname = "criterion-318";
+       version = "2.5.0";
+       sha256 = "s0r7bhfw3hsv49imvrz3qki9d39nhli9n2fp1jz7d9y3zsp24wjq";
+       libraryHaskellDepends = [
+         deepseq directory
+       ];
+       testHaskellDepends = [ hashable hspec ];
+       homepage = "https://github.com/example/criterion-318";
+       description = "Package number 318";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "binary-319" = callPackage
+    ({ containers, directory, http_client, lib, megaparsec, mkDerivation, parsec, tasty, text, transformers, zlib }:
+     mkDerivation {
+       pname = "binary-319";
+       version = "8.4.0";
+       sha256 = "k5mzn1d0v9i0dygy3xj3zl1lvy48hpp3pk7vxbh3ma8ricbl1jk9";
+       libraryHaskellDepends = [
+         containers http_client megaparsec parsec tasty text transformers zlib
+       ];
+       testHaskellDepends = [ directory ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/binary-319";
+       description = "Package number 319";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "containers-320" = callPackage
+    ({ deepseq, hspec, lens, lib, mkDerivation, parsec, random, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "containers-320";
+       version = "3.10.8";
+       sha256 = "nqjlh1lr8r6ahxlx22frpbca6djknwv849fk7kv4777iixnifk1m";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq hspec lens parsec zlib
+       ];
+       testHaskellDepends = [ hspec parsec random unordered_containers ];
+       homepage = "https://github.com/example/containers-320";
+       description = "Package number 320";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "text-321" = callPackage
+    ({ QuickCheck, aeson, async, base, bytestring, containers, http_client, lib, megaparsec, mkDerivation, process, zlib }:
+     mkDerivation {
+       pname = "text-321";
+       version = "7.13.5";
+       sha256 = "xs5hbfk66gd4r1axd2zmn698icxy464vsa0gi2zhccwrc0gdqzld";
+       libraryHaskellDepends = [
+         QuickCheck aeson containers http_client megaparsec process zlib
+       ];
+       testHaskellDepends = [ async base bytestring containers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace text-321.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/text-321";
+       description = "Package number 321";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "tasty-322" = callPackage
+    ({ base, bytestring, containers, lens, lib, mkDerivation, process, tls, transformers, vector, zlib }:
+     mkDerivation {
+       pname = "tasty-322";
+       version = "3.19.6";
+       sha256 = "3h5ksybzaxfskpn7qhr5ynkx9kqp1nqn8cyscj3y15wgfz0476s1";
+       libraryHaskellDepends = [
+         bytestring tls transformers vector zlib
+       ];
+       testHaskellDepends = [ base containers lens process ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tasty-322";
+       description = "Package number 322";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "network-323" = callPackage
+    ({ containers, deepseq, lib, megaparsec, mkDerivation, transformers, vector }:
+     mkDerivation {
+       pname = "network-323";
+       version = "8.3.9";
+       sha256 = "k5xsji68f7011j9jmmyyy8xjfhxxqsl4zsbcacrw9yj3ah1861qz";
+       libraryHaskellDepends = [
+         containers deepseq megaparsec transformers vector
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/network-323";
+       description = "Package number 323";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "vector-324" = callPackage
+    ({ deepseq, directory, hashable, http_client, lens, lib, mkDerivation, unordered_containers }:
+     mkDerivation {
+       pname = "vector-324";
+       version = "6.14.3";
+       sha256 = "2jiify1k6n5js3hd66ks53l9ppjm9qp0nmbdy0gclj4hlhcv5h2q";
+       libraryHaskellDepends = [
+         deepseq http_client unordered_containers
+       ];
+       testHaskellDepends = [ directory hashable lens ];
+       homepage = "https://github.com/example/vector-324";
+       description = "Package number 324";
+       license = lib.licenses.mit;
+     }) {};
+
+  "bytestring-325" = callPackage
+    ({ containers, deepseq, lens, lib, mkDerivation, parsec, stm }:
+     mkDerivation {
+       pname = "bytestring-325";
+       version = "8.20.2";
+       sha256 = "0dgmzfdjry9j1ka1l6h6xgd57406n6j5rx831az3b7c7amzma0kh";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         parsec stm
+       ];
+       testHaskellDepends = [ containers deepseq lens ];
+       homepage = "https://github.com/example/bytestring-325";
+       description = "Package number 325";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "process-326" = callPackage
+    ({ base, binary, criterion, deepseq, filepath, hashable, lib, megaparsec, mkDerivation, parsec, stm, tasty }:
+     mkDerivation {
+       pname = "process-326";
+       version = "6.19.7";
+       sha256 = "161zn8mscz0aa8qpmzvjd6rvyk8ynrbylj8csrp2a2mhdzp1fw9v";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base binary criterion deepseq megaparsec parsec stm tasty
+       ];
+       testHaskellDepends = [ filepath hashable parsec ];
+       homepage = "https://github.com/example/process-326";
+       description = "Package number 326";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "vector-327" = callPackage
+    ({ aeson, binary, bytestring, hspec, http_client, lib, mkDerivation, vector }:
+     mkDerivation {
+       pname = "vector-327";
+       version = "2.12.2";
+       sha256 = "kqaxjj33cjbq0fnsma87w2xx9qrhx7hav25ghgd5mbfjz378h3bv";
+       libraryHaskellDepends = [
+         aeson binary bytestring hspec http_client vector
+       ];
+       homepage = "https://github.com/example/vector-327";
+       description = "Package number 327";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "base-328" = callPackage
+    ({ aeson, async, http_client, lib, mkDerivation, mtl, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "base-328";
+       version = "1.8.7";
+       sha256 = "briz25p0340s1bgybjr8rd8sjhmnfvp6ndfgrwh9pi3ll56wbg8i";
+       libraryHaskellDepends = [
+         aeson async http_client mtl transformers unordered_containers
+       ];
+       homepage = "https://github.com/example/base-328";
+       description = "Package number 328";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "zlib-329" = callPackage
+    ({ async, binary, criterion, deepseq, http_client, lens, lib, mkDerivation, mtl, vector, zlib }:
+     mkDerivation {
+       pname = "zlib-329";
+       version = "5.19.8";
+       sha256 = "j3zc0yrbgv1dmgcq761h7m8bz68nqndqiz3d4j5pgw1s94syq5zh";
+       libraryHaskellDepends = [
+         binary criterion deepseq http_client lens mtl zlib
+       ];
+       testHaskellDepends = [ async http_client vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/zlib-329";
+       description = "Package number 329";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "async-330" = callPackage
+    ({ bytestring, containers, criterion, deepseq, lens, lib, mkDerivation, process, text, time, tls, zlib }:
+     mkDerivation {
+       pname = "async-330";
+       version = "8.19.3";
+       sha256 = "kp2n8f1a2ckbawz5lf4518f56apgq358m8qdv05hb680q9pv4bxn";
+       libraryHaskellDepends = [
+         bytestring containers criterion process time tls
+       ];
+       testHaskellDepends = [ deepseq lens text zlib ];
+       homepage = "https://github.com/example/async-330";
+       description = "Package number 330";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "criterion-331" = callPackage
+    ({ bytestring, lib, megaparsec, mkDerivation, random }:
+     mkDerivation {
+       pname = "criterion-331";
+       version = "1.10.2";
+       sha256 = "ayj9cwsp9kbmalygipnphpa2s98fanhw4n68ykmcx1vsv8z25hwk";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring megaparsec random
+       ];
+       homepage = "https://github.com/example/criterion-331";
+       description = "Package number 331";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "bytestring-332" = callPackage
+    ({ async, criterion, lens, lib, mkDerivation, network, text, transformers }:
+     mkDerivation {
+       pname = "bytestring-332";
+       version = "1.15.5";
+       sha256 = "hf07nrr269fvyrb5mwzalvg336xfxh6a04limsrdfchll320rk03";
+       libraryHaskellDepends = [
+         async criterion lens network text transformers
+       ];
+       homepage = "https://github.com/example/bytestring-332";
+       description = "Package number 332";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "megaparsec-333" = callPackage
+    ({ QuickCheck, aeson, filepath, http_client, lib, mkDerivation, network }:
+     mkDerivation {
+       pname = "megaparsec-333";
+       version = "4.0.3";
+       sha256 = "363gjh3z0knsg5s267i1yfa9xlvvil7wv81k1a2rrwbmaq7bz8kj";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck http_client
+       ];
+       testHaskellDepends = [ aeson filepath network ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/megaparsec-333";
+       description = "Package number 333";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "aeson-334" = callPackage
+    ({ async, bytestring, http_client, lib, mkDerivation, mtl, parsec, process, random, text }:
+     mkDerivation {
+       pname = "aeson-334";
+       version = "9.14.6";
+       sha256 = "0ycsgcrc00izr7ngwipd5pz7frdlf422gpbzg6nd5lvqr6w3n6wr";
+       libraryHaskellDepends = [
+         async bytestring mtl parsec random
+       ];
+       testHaskellDepends = [ http_client parsec process text ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/aeson-334";
+       description = "Package number 334";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "hashable-335" = callPackage
+    ({ binary, filepath, lib, mkDerivation, network, zlib }:
+     mkDerivation {
+       pname = "hashable-335";
+       version = "0.2.2";
+       sha256 = "s3kgwyw72baj489z2yyvf76m4fm2jr7a13q78789cvnhplj9149y";
+       libraryHaskellDepends = [
+         filepath zlib
+       ];
+       testHaskellDepends = [ binary network zlib ];
+       homepage = "https://github.com/example/hashable-335";
+       description = "Package number 335";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "QuickCheck-336" = callPackage
+    ({ bytestring, deepseq, lib, megaparsec, mkDerivation, mtl, process, stm, text, time, transformers }:
+     mkDerivation {
+       pname = "QuickCheck-336";
+       version = "2.3.3";
+       sha256 = "fl5r3427q5w6c3171fwshzk31fyv2mj0vbyz0lbzq7z40b6wwlcn";
+       libraryHaskellDepends = [
+         bytestring deepseq mtl process stm text time transformers
+       ];
+       testHaskellDepends = [ megaparsec ];
+       postPatch = "substituteInPlace QuickCheck-336.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/QuickCheck-336";
+       description = "Package number 336";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "directory-337" = callPackage
+    ({ containers, deepseq, hspec, http_client, lens, lib, mkDerivation, process, text }:
+     mkDerivation {
+       pname = "directory-337";
+       version = "3.3.8";
+       sha256 = "jifinbyx1i1rn11x7y55anrvjz1ljqyva0hb5d1pphaq8bcxj6v1";
+       libraryHaskellDepends = [
+         deepseq http_client lens process
+       ];
+       testHaskellDepends = [ containers hspec http_client text ];
+       homepage = "https://github.com/example/directory-337";
+       description = "Package number 337";
+       license = lib.licenses.mit;
+     }) {};
+
+  "tls-338" = callPackage
+    ({ base, filepath, hashable, http_client, lib, mkDerivation, mtl, zlib }:
+     mkDerivation {
+       pname = "tls-338";
+       version = "4.18.9";
+       sha256 = "1l9337l97f7lh17sczncxa1shyx842p0qpygyvgjxyn1ph7biiyp";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base filepath hashable http_client mtl zlib
+       ];
+       homepage = "https://github.com/example/tls-338";
+       description = "Package number 338";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tls-339" = callPackage
+    ({ bytestring, criterion, hashable, lib, mkDerivation, parsec, tasty, time, transformers, zlib }:
+     mkDerivation {
+       pname = "tls-339";
+       version = "4.10.7";
+       sha256 = "lhvmapc3y82mp04h77fr5002sczn4fancclr5f5zcrdrv70k3abx";
+       libraryHaskellDepends = [
+         criterion parsec tasty time transformers zlib
+       ];
+       testHaskellDepends = [ bytestring hashable transformers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tls-339";
+       description = "Package number 339";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "process-340" = callPackage
+    ({ binary, filepath, lib, mkDerivation, parsec }:
+     mkDerivation {
+       pname = "process-340";
+       version = "3.5.9";
+       sha256 = "f4a1yfaa7lmv559xwf2nb603qa4sk7sm49yszq7wd257g00znn0d";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         filepath parsec
+       ];
+       testHaskellDepends = [ binary ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/process-340";
+       description = "Package number 340";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "transformers-341" = callPackage
+    ({ binary, lens, lib, mkDerivation, mtl, network, process, text }:
+     mkDerivation {
+       pname = "transformers-341";
+       version = "0.20.9";
+       sha256 = "mf2dsh8xkbyg5sk6fz83p0bxg1cl94nccprzydy0iyypxfif8im3";
+       libraryHaskellDepends = [
+         binary lens mtl
+       ];
+       testHaskellDepends = [ binary network process text ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/transformers-341";
+       description = "Package number 341";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "bytestring-342" = callPackage
+    ({ QuickCheck, bytestring, hashable, lens, lib, megaparsec, mkDerivation, tasty, text, tls }:
+     mkDerivation {
+       pname = "bytestring-342";
+       version = "1.6.4";
+       sha256 = "lank3w064n9nfv7zv4diqhywwhargsbpwqh266f1n373i3fs0n3v";
+       libraryHaskellDepends = [
+         lens megaparsec tasty tls
+       ];
+       testHaskellDepends = [ QuickCheck bytestring hashable text ];
+       homepage = "https://github.com/example/bytestring-342";
+       description = "Package number 342";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-343" = callPackage
+    ({ async, containers, lib, mkDerivation, process, tasty }:
+     mkDerivation {
+       pname = "filepath-343";
+       version = "7.13.7";
+       sha256 = "j21r68m0v869yl4qmzgvfpqxizpsjbxl631gkibykrpknvhd3ydb";
+       libraryHaskellDepends = [
+         containers process tasty
+       ];
+       testHaskellDepends = [ async ];
+       postPatch = "substituteInPlace filepath-343.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/filepath-343";
+       description = "Package number 343";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "stm-344" = callPackage
+    ({ async, binary, criterion, filepath, lib, mkDerivation, mtl, random, stm, text, vector }:
+     mkDerivation {
+       pname = "stm-344";
+       version = "2.5.1";
+       sha256 = "99dpdi1a3xwmmspvpgsp6x21cgw5nnnccw1dgh5da9y0sm0l3n82";
+       libraryHaskellDepends = [
+         binary criterion filepath mtl random stm text vector
+       ];
+       testHaskellDepends = [ async ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/stm-344";
+       description = "Package number 344";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "QuickCheck-345" = callPackage
+    ({ deepseq, hashable, lib, megaparsec, mkDerivation, process, tasty, text, transformers }:
+     mkDerivation {
+       pname = "QuickCheck-345";
+       version = "2.12.4";
+       sha256 = "34rbvzwng2p1ycdzzzkn2nrdlcds6kmi2w9qn1dhylcg4s2dkwzc";
+       libraryHaskellDepends = [
+         deepseq hashable megaparsec tasty transformers
+       ];
+       testHaskellDepends = [ megaparsec process text transformers ];
+       homepage = "https://github.com/example/QuickCheck-345";
+       description = "Package number 345";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "random-346" = callPackage
+    ({ QuickCheck, aeson, binary, hashable, hspec, lib, megaparsec, mkDerivation, random }:
+     mkDerivation {
+       pname = "random-346";
+       version = "1.14.6";
+       sha256 = "k4bfz042cdxybhqwj9z66cqlapn8g4nci9y76vr7m679rai0xi4f";
+       libraryHaskellDepends = [
+         QuickCheck aeson megaparsec random
+       ];
+       testHaskellDepends = [ binary hashable hspec ];
+       homepage = "https://github.com/example/random-346";
+       description = "Package number 346";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "vector-347" = callPackage
+    ({ bytestring, lib, mkDerivation, process, tasty, transformers }:
+     mkDerivation {
+       pname = "vector-347";
+       version = "2.15.2";
+       sha256 = "kg4rs3pmb66iw4v632v3x8xlg8whcxi57ycqsvbb0m4sarwfjlgg";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring process tasty transformers
+       ];
+       homepage = "https://github.com/example/vector-347";
+       description = "Package number 347";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tls-348" = callPackage
+    ({ aeson, async, binary, criterion, hashable, lens, lib, mkDerivation, time, transformers, zlib }:
+     mkDerivation {
+       pname = "tls-348";
+       version = "5.18.0";
+       sha256 = "6bbkxy83r30pxgwpb0nw1dgpl9w8k95xwiasr29pvl039ivq6hsg";
+       libraryHaskellDepends = [
+         aeson async binary criterion hashable lens transformers zlib
+       ];
+       testHaskellDepends = [ time ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tls-348";
+       description = "Package number 348";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "unordered-containers-349" = callPackage
+    ({ aeson, binary, criterion, deepseq, lib, mkDerivation, stm, zlib }:
+     mkDerivation {
+       pname = "unordered-containers-349";
+       version = "3.11.9";
+       sha256 = "9r9bjgzwn1pz7bfk40s1vbc8v1cjijf6fpbl80w6zx2p69w6q5vp";
+       libraryHaskellDepends = [
+         aeson binary criterion deepseq stm zlib
+       ];
+       homepage = "https://github.com/example/unordered-containers-349";
+       description = "Package number 349";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "base-350" = callPackage
+    ({ QuickCheck, aeson, async, base, bytestring, criterion, http_client, lib, megaparsec, mkDerivation, process, random, stm }:
+     mkDerivation {
+       pname = "base-350";
+       version = "1.4.8";
+       sha256 = "f2md3rx9wjdfkc28d0fnlcqzfgjrg96yip9q2h83ngmr98glnasq";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck aeson base bytestring criterion megaparsec stm
+       ];
+       testHaskellDepends = [ async http_client process random ];
+       postPatch = "substituteInPlace base-350.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/base-350";
+       description = "Package number 350";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "base-351" = callPackage
+    ({ aeson, async, deepseq, lens, lib, megaparsec, mkDerivation, parsec, random, text, time }:
+     mkDerivation {
+       pname = "base-351";
+       version = "8.15.9";
+       sha256 = "4kbph0pzd4639gdl7kbaw5mxybyrnspsyfyscb8xik61zrvil3iz";
+       libraryHaskellDepends = [
+         async lens megaparsec text time
+       ];
+       testHaskellDepends = [ aeson deepseq parsec random ];
+       homepage = "https://github.com/example/base-351";
+       description = "Package number 351";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-352" = callPackage
+    ({ directory, http_client, lib, mkDerivation, network, vector }:
+     mkDerivation {
+       pname = "stm-352";
+       version = "2.3.9";
+       sha256 = "6996ggjbk9s5fbqjpcv7xis18zhx609jw8x7h78cgf5q4i677fr5";
+       libraryHaskellDepends = [
+         directory http_client network vector
+       ];
+       testHaskellDepends = [ http_client ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/stm-352";
+       description = "Package number 352";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "deepseq-353" = callPackage
+    ({ QuickCheck, criterion, filepath, http_client, lens, lib, mkDerivation, random, stm, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "deepseq-353";
+       version = "8.11.3";
+       sha256 = "6gy24rv1pzdsx1xiw3sh3pjzv5safj6amiq78s5xlsdw3lxp0hix";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck criterion filepath http_client unordered_containers
+       ];
+       testHaskellDepends = [ lens random stm tasty ];
+       homepage = "https://github.com/example/deepseq-353";
+       description = "Package number 353";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "parsec-354" = callPackage
+    ({ aeson, base, criterion, deepseq, hspec, lens, lib, mkDerivation, time, tls, transformers }:
+     mkDerivation {
+       pname = "parsec-354";
+       version = "1.2.2";
+       sha256 = "0g7skqicwp9wf94r9b4skrsaqv6cjkd1zapf03da92yrc389bmq3";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson hspec time tls transformers
+       ];
+       testHaskellDepends = [ base criterion deepseq lens ];
+       homepage = "https://github.com/example/parsec-354";
+       description = "Package number 354";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "directory-355" = callPackage
+    ({ binary, bytestring, criterion, lib, megaparsec, mkDerivation, mtl, random, vector }:
+     mkDerivation {
+       pname = "directory-355";
+       version = "2.0.8";
+       sha256 = "7z8bxqhy7qnrikj10d6n5icsbn6aiqnxssrdhvn36h0d6kf9sdbv";
+       libraryHaskellDepends = [
+         binary bytestring criterion megaparsec mtl random vector
+       ];
+       homepage = "https://github.com/example/directory-355";
+       description = "Package number 355";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "hspec-356" = callPackage
+    ({ base, binary, containers, criterion, deepseq, lib, mkDerivation, network, process, random, unordered_containers }:
+     mkDerivation {
+       pname = "hspec-356";
+       version = "0.16.7";
+       sha256 = "n1922pnrr5plrnaginir10jcwv4gk51190cy04gsp3b24na0djv9";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base binary containers deepseq network process random unordered_containers
+       ];
+       testHaskellDepends = [ criterion ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/hspec-356";
+       description = "Package number 356";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tasty-357" = callPackage
+    ({ base, lens, lib, megaparsec, mkDerivation, time }:
+     mkDerivation {
+       pname = "tasty-357";
+       version = "4.4.3";
+       sha256 = "b77l468akiphmlyprlhd1dd768306r6bbcsajkvrsb09jvkhajsw";
+       libraryHaskellDepends = [
+         base megaparsec time
+       ];
+       testHaskellDepends = [ lens ];
+       homepage = "https://github.com/example/tasty-357";
+       description = "Package number 357";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "hspec-358" = callPackage
+    ({ binary, containers, deepseq, filepath, lib, mkDerivation, process, unordered_containers }:
+     mkDerivation {
+       pname = "hspec-358";
+       version = "1.3.1";
+       sha256 = "fwpiikdvyvy26gag9p2xkr7100p9ak9x4ra687nfnq11fka97nps";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary containers deepseq process unordered_containers
+       ];
+       testHaskellDepends = [ filepath ];
+       homepage = "https://github.com/example/hspec-358";
+       description = "Package number 358";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "tasty-359" = callPackage
+    ({ lens, lib, mkDerivation, network, tls, transformers }:
+     mkDerivation {
+       pname = "tasty-359";
+       version = "1.7.8";
+       sha256 = "y2qi5s3y6qsizi4mm18d2i8vvgswas642lq1s9s4nx616kwf382z";
+       libraryHaskellDepends = [
+         network tls
+       ];
+       testHaskellDepends = [ lens transformers ];
+       postPatch = "substituteInPlace tasty-359.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/tasty-359";
+       description = "Package number 359";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "async-360" = callPackage
+    ({ http_client, lib, mkDerivation, parsec, vector }:
+     mkDerivation {
+       pname = "async-360";
+       version = "8.20.9";
+       sha256 = "iidjchkcj487nvl5pdv43i4by6xx9zdml98nqk8p6v9bckrd58x4";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         http_client parsec vector
+       ];
+       homepage = "https://github.com/example/async-360";
+       description = "Package number 360";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "deepseq-361" = callPackage
+    ({ async, hashable, hspec, lib, megaparsec, mkDerivation, process }:
+     mkDerivation {
+       pname = "deepseq-361";
+       version = "5.12.6";
+       sha256 = "lzdnq2yxajjl1bl8pvc6wvl7pl1ckygs99j3n0vgkcs56q8rjcrd";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hashable hspec megaparsec
+       ];
+       testHaskellDepends = [ async process ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/deepseq-361";
+       description = "Package number 361";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "zlib-362" = callPackage
+    ({ async, deepseq, filepath, hspec, lib, mkDerivation, network, parsec, process, transformers, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "zlib-362";
+       version = "0.16.7";
+       sha256 = "2gbfbamac261qfmmxnkp6aq8aydi3cc3m5j98p1i7vjxsswsnrf8";
+       libraryHaskellDepends = [
+         async deepseq filepath network parsec process unordered_containers zlib
+       ];
+       testHaskellDepends = [ hspec network parsec transformers ];
+       homepage = "https://github.com/example/zlib-362";
+       description = "Package number 362";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "time-363" = callPackage
+    ({ deepseq, filepath, lens, lib, megaparsec, mkDerivation, time, zlib }:
+     mkDerivation {
+       pname = "time-363";
+       version = "8.5.9";
+       sha256 = "11hdjkb2dynzprvl49ajipfvmfg7wqhcn4ncicii3v7v0k1ipmk8";
+       libraryHaskellDepends = [
+         filepath lens
+       ];
+       testHaskellDepends = [ deepseq megaparsec time zlib ];
+       homepage = "https://github.com/example/time-363";
+       description = "Package number 363";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "binary-364" = callPackage
+    ({ QuickCheck, criterion, directory, filepath, hspec, lib, megaparsec, mkDerivation, parsec, process, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "binary-364";
+       version = "9.13.8";
+       sha256 = "lk0w8vzl0d1vc037hm442xia6pil679hi7bdkncl3yvsb3rxzavl";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck criterion directory filepath hspec megaparsec tasty unordered_containers
+       ];
+       testHaskellDepends = [ criterion parsec process ];
+       homepage = "https://github.com/example/binary-364";
+       description = "Package number 364";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "QuickCheck-365" = callPackage
+    ({ bytestring, lens, lib, mkDerivation, network, parsec, random, unordered_containers }:
+     mkDerivation {
+       pname = "QuickCheck-365";
+       version = "9.9.0";
+       sha256 = "w3a45f4iz5br64fabpqfkpc33l3bwpa41xw8xjmcxbyj89y8z854";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring network parsec unordered_containers
+       ];
+       testHaskellDepends = [ bytestring lens random ];
+       homepage = "https://github.com/example/QuickCheck-365";
+       description = "Package number 365";
+       license = lib.licenses.mit;
+     }) {};
+
+  "process-366" = callPackage
+    ({ QuickCheck, filepath, lib, mkDerivation, network, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "process-366";
+       version = "1.1.9";
+       sha256 = "dykhcvn8lhh85rm3yrbw7sm2zijh1khb7am4qwlyys5p1mcs0f2r";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck filepath
+       ];
+       testHaskellDepends = [ network tasty unordered_containers ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/process-366";
+       description = "Package number 366";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tasty-367" = callPackage
+    ({ base, bytestring, deepseq, http_client, lib, megaparsec, mkDerivation, mtl, network, random, tasty, text, tls }:
+     mkDerivation {
+       pname = "tasty-367";
+       version = "1.1.5";
+       sha256 = "iwvv278shm3i66pk52lm2yd39xcxp2vqaaf1ywxs1l2lm8c72qz7";
+       libraryHaskellDepends = [
+         base deepseq http_client megaparsec mtl random tasty tls
+       ];
+       testHaskellDepends = [ base bytestring network text ];
+       homepage = "https://github.com/example/tasty-367";
+       description = "Package number 367";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "filepath-368" = callPackage
+    ({ filepath, lib, mkDerivation, parsec, stm, tasty, time, transformers }:
+     mkDerivation {
+       pname = "filepath-368";
+       version = "7.6.6";
+       sha256 = "xjz6vjl3032zla0js1gj280hhm11avbxbp92iwkj6iav8bwkfk7k";
+       libraryHaskellDepends = [
+         parsec stm tasty
+       ];
+       testHaskellDepends = [ filepath time transformers ];
+       homepage = "https://github.com/example/filepath-368";
+       description = "Package number 368";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "hashable-369" = callPackage
+    ({ async, hspec, lib, mkDerivation, network, time, tls, transformers }:
+     mkDerivation {
+       pname = "hashable-369";
+       version = "2.12.7";
+       sha256 = "51qd50f1m93x7ys9klji08aw3h9m4va3clmqjn1wz2567dkvwky0";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async hspec network tls transformers
+       ];
+       testHaskellDepends = [ time ];
+       homepage = "https://github.com/example/hashable-369";
+       description = "Package number 369";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "megaparsec-370" = callPackage
+    ({ binary, hashable, lens, lib, mkDerivation, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "megaparsec-370";
+       version = "5.17.1";
+       sha256 = "64z0nrld7rhd3jcwb5w07ljh7y009flb5lpslnhffg5gp1qj642x";
+       libraryHaskellDepends = [
+         binary hashable lens unordered_containers zlib
+       ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/megaparsec-370";
+       description = "Package number 370";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "megaparsec-371" = callPackage
+    ({ filepath, http_client, lib, mkDerivation, mtl, unordered_containers }:
+     mkDerivation {
+       pname = "megaparsec-371";
+       version = "9.1.7";
+       sha256 = "p0dih4s8c7xadbxflj55s1i4drvfi8zf7zn25izzr7jsdgd05ajh";
+       libraryHaskellDepends = [
+         filepath http_client mtl
+       ];
+       testHaskellDepends = [ unordered_containers ];
+       postPatch = "substituteInPlace megaparsec-371.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/megaparsec-371";
+       description = "Package number 371";
+       license = lib.licenses.mit;
+     }) {};
+
+  "process-372" = callPackage
+    ({ filepath, hspec, lens, lib, mkDerivation, parsec }:
+     mkDerivation {
+       pname = "process-372";
+       version = "1.6.6";
+       sha256 = "ijs4piwjwyv6l8jradd6q08c9y53gny963nnkc1ykj4vpndkw66z";
+       libraryHaskellDepends = [
+         hspec lens parsec
+       ];
+       testHaskellDepends = [ filepath ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/process-372";
+       description = "Package number 372";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "time-373" = callPackage
+    ({ binary, criterion, hashable, lib, mkDerivation, network, random, text, tls }:
+     mkDerivation {
+       pname = "time-373";
+       version = "5.14.9";
+       sha256 = "14h5bq89z40zrfrb43f14a8iq49kh5whvaflj6y6imx3h6bb9z9s";
+       libraryHaskellDepends = [
+         binary criterion network random text tls
+       ];
+       testHaskellDepends = [ hashable ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/time-373";
+       description = "Package number 373";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "megaparsec-374" = callPackage
+    ({ containers, filepath, lib, mkDerivation, mtl, parsec, process, time, transformers, zlib }:
+     mkDerivation {
+       pname = "megaparsec-374";
+       version = "2.13.9";
+       sha256 = "y1qicr0dplhlfs2h21rf39ia6wlm44lkb6v3sjf1k3saam4ryh2c";
+       libraryHaskellDepends = [
+         containers filepath mtl parsec process time
+       ];
+       testHaskellDepends = [ transformers zlib ];
+       homepage = "https://github.com/example/megaparsec-374";
+       description = "Package number 374";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "containers-375" = callPackage
+    ({ async, deepseq, hspec, lib, mkDerivation, stm, tls, unordered_containers, vector }:
+     mkDerivation {
+       pname = "containers-375";
+       version = "3.17.4";
+       sha256 = "6m1dl5q4iys0621m9000jvmsx55rh38kr1lvgsk753p1g267aqx0";
+       libraryHaskellDepends = [
+         hspec stm unordered_containers
+       ];
+       testHaskellDepends = [ async deepseq tls vector ];
+       homepage = "https://github.com/example/containers-375";
+       description = "Package number 375";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "time-376" = callPackage
+    ({ bytestring, deepseq, hashable, hspec, lib, mkDerivation, time }:
+     mkDerivation {
+       pname = "time-376";
+       version = "1.19.9";
+       sha256 = "7289f7yvmjvlv5psni5mpgvy3ysr6125q4ka4a2ffp8vpd6zndw7";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq hspec time
+       ];
+       testHaskellDepends = [ bytestring hashable ];
+       homepage = "https://github.com/example/time-376";
+       description = "Package number 376";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "transformers-377" = callPackage
+    ({ QuickCheck, deepseq, hspec, http_client, lens, lib, megaparsec, mkDerivation, random, time, vector }:
+     mkDerivation {
+       pname = "transformers-377";
+       version = "7.14.3";
+       sha256 = "32db8xcj1wcgsrb8a8s7phjm0680393dcgk85rqlydjw94wif3bw";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck deepseq hspec lens time vector
+       ];
+       testHaskellDepends = [ http_client megaparsec random vector ];
+       postPatch = "substituteInPlace transformers-377.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/transformers-377";
+       description = "Package number 377";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "lens-378" = callPackage
+    ({ async, criterion, hashable, lib, mkDerivation, vector }:
+     mkDerivation {
+       pname = "lens-378";
+       version = "1.11.9";
+       sha256 = "ac3hry8jy6hgr7k6ayzgv5i6c7jshs51bv2appyzpwhhdqkbgizr";
+       libraryHaskellDepends = [
+         async criterion hashable vector
+       ];
+       testHaskellDepends = [ async vector ];
+       postPatch = "substituteInPlace lens-378.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/lens-378";
+       description = "Package number 378";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "containers-379" = callPackage
+    ({ base, binary, deepseq, filepath, http_client, lib, megaparsec, mkDerivation, tasty, text }:
+     mkDerivation {
+       pname = "containers-379";
+       version = "1.3.3";
+       sha256 = "zpqgpzmd81bb5iv66fhgfm6j0328ka2yyc1mcj30g4vsn246c0ni";
+       libraryHaskellDepends = [
+         base binary deepseq filepath http_client
+       ];
+       testHaskellDepends = [ megaparsec tasty text ];
+       homepage = "https://github.com/example/containers-379";
+       description = "Package number 379";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tasty-380" = callPackage
+    ({ directory, filepath, lib, mkDerivation, network, stm, text, tls }:
+     mkDerivation {
+       pname = "tasty-380";
+       version = "3.17.0";
+       sha256 = "7vhx216hq5nnrgqy99yw1wh3zhs259azs8cax237xpk6yzxmmi9i";
+       libraryHaskellDepends = [
+         directory filepath network stm text tls
+       ];
+       homepage = "https://github.com/example/tasty-380";
+       description = "Package number 380";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "aeson-381" = callPackage
+    ({ QuickCheck, base, criterion, hspec, http_client, lens, lib, mkDerivation, unordered_containers }:
+     mkDerivation {
+       pname = "aeson-381";
+       version = "3.17.0";
+       sha256 = "71nvi3wlq7527xxbllavx7nhyqdm06vpz71cjzawcvrq0iq2dqqh";
+       libraryHaskellDepends = [
+         QuickCheck base criterion http_client lens unordered_containers
+       ];
+       testHaskellDepends = [ QuickCheck hspec ];
+       homepage = "https://github.com/example/aeson-381";
+       description = "Package number 381";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "zlib-382" = callPackage
+    ({ async, hspec, lib, mkDerivation, mtl, parsec, time, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "zlib-382";
+       version = "3.0.3";
+       sha256 = "n595qjl9bzkhdwmdqdlx13pip75haikc898pa4zzj4sr0anqbcqx";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         mtl parsec time transformers
+       ];
+       testHaskellDepends = [ async hspec parsec unordered_containers ];
+       homepage = "https://github.com/example/zlib-382";
+       description = "Package number 382";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "criterion-383" = callPackage
+    ({ hspec, lib, mkDerivation, mtl, process, stm, time }:
+     mkDerivation {
+       pname = "criterion-383";
+       version = "0.13.3";
+       sha256 = "a6hh7i2ipn1jv5p1hwj3dyccxl5whsb0sz4cfhn10345xnxjafl6";
+       libraryHaskellDepends = [
+         hspec mtl process stm time
+       ];
+       postPatch = "substituteInPlace criterion-383.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/criterion-383";
+       description = "Package number 383";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hspec-384" = callPackage
+    ({ http_client, lib, mkDerivation, mtl, tasty, text, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "hspec-384";
+       version = "1.10.1";
+       sha256 = "45s601gj5v9g7g1s1dh3b1mqrdsx51yqjsr91izpdrniqpj4ma3s";
+       libraryHaskellDepends = [
+         http_client mtl transformers unordered_containers
+       ];
+       testHaskellDepends = [ http_client mtl tasty text ];
+       homepage = "https://github.com/example/hspec-384";
+       description = "Package number 384";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-385" = callPackage
+    ({ binary, bytestring, containers, deepseq, lib, mkDerivation, text, time }:
+     mkDerivation {
+       pname = "stm-385";
+       version = "9.18.1";
+       sha256 = "rdpp1kn3fglmjm4zrbd5a5nlbig0vhzljhligmvyg33q1bfj7zx4";
+       libraryHaskellDepends = [
+         binary bytestring containers text time
+       ];
+       testHaskellDepends = [ deepseq ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/stm-385";
+       description = "Package number 385";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "process-386" = callPackage
+    ({ binary, containers, deepseq, lib, mkDerivation, text }:
+     mkDerivation {
+       pname = "process-386";
+       version = "1.9.7";
+       sha256 = "dg3hz9dgrhcmsv0xi49rv88b805rf3nxnfvqqfp10j5acsfhgzf9";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary deepseq text
+       ];
+       testHaskellDepends = [ containers ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/process-386";
+       description = "Package number 386";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "zlib-387" = callPackage
+    ({ aeson, bytestring, containers, hashable, lib, megaparsec, mkDerivation, process, random }:
+     mkDerivation {
+       pname = "zlib-387";
+       version = "3.9.7";
+       sha256 = "g5n9q0nczrlznid72wp6b0qzhpwaqsbr57r9x127zpyrn93wqywn";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson bytestring containers process random
+       ];
+       testHaskellDepends = [ aeson hashable megaparsec ];
+       homepage = "https://github.com/example/zlib-387";
+       description = "Package number 387";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "parsec-388" = callPackage
+    ({ QuickCheck, base, criterion, lib, mkDerivation }:
+     mkDerivation {
+       pname = "parsec-388";
+       version = "6.20.7";
+       sha256 = "8jpjgxxi3nkk45g7aah8m66v7sk2yzy1rw3sw69gzj4jyggk5vp4";
+       libraryHaskellDepends = [
+         QuickCheck criterion
+       ];
+       testHaskellDepends = [ base ];
+       homepage = "https://github.com/example/parsec-388";
+       description = "Package number 388";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "directory-389" = callPackage
+    ({ aeson, binary, containers, filepath, lib, mkDerivation, text, unordered_containers }:
+     mkDerivation {
+       pname = "directory-389";
+       version = "4.5.8";
+       sha256 = "9ciz8zgy16yqxzrl1b9jnswh9brhzsq1f5qjnmxn2pf3vxq4498a";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary text
+       ];
+       testHaskellDepends = [ aeson containers filepath unordered_containers ];
+       postPatch = "substituteInPlace directory-389.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/directory-389";
+       description = "Package number 389";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "lens-390" = callPackage
+    ({ QuickCheck, bytestring, directory, lens, lib, mkDerivation, mtl, tls }:
+     mkDerivation {
+       pname = "lens-390";
+       version = "8.5.9";
+       sha256 = "kwk1jjfnybs28gf51bq2gxwxng0bahr7rfni4jj8z7hpgywb29f3";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck bytestring directory lens mtl tls
+       ];
+       homepage = "https://github.com/example/lens-390";
+       description = "Package number 390";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "unordered-containers-391" = callPackage
+    ({ QuickCheck, async, criterion, filepath, lib, megaparsec, mkDerivation, tls, transformers, zlib }:
+     mkDerivation {
+       pname = "unordered-containers-391";
+       version = "6.16.2";
+       sha256 = "yxa1g25l1g3ii23h45ns7hf0sacj28k4qyx4s0g64lrl1z879hn3";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck criterion filepath tls transformers
+       ];
+       testHaskellDepends = [ async megaparsec tls zlib ];
+       postPatch = "substituteInPlace unordered-containers-391.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/unordered-containers-391";
+       description = "Package number 391";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "filepath-392" = callPackage
+    ({ QuickCheck, base, directory, lens, lib, mkDerivation, parsec, time, vector }:
+     mkDerivation {
+       pname = "filepath-392";
+       version = "3.0.9";
+       sha256 = "abv9lbkni260f2iwmlgwwpa8q7ivzc2ga6f0f5kj8zahkrqilb99";
+       libraryHaskellDepends = [
+         QuickCheck base directory time vector
+       ];
+       testHaskellDepends = [ lens parsec ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/filepath-392";
+       description = "Package number 392";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "containers-393" = callPackage
+    ({ async, base, binary, criterion, hspec, lib, mkDerivation, network }:
+     mkDerivation {
+       pname = "containers-393";
+       version = "6.17.5";
+       sha256 = "63s1c231c81cv3563a6qqiyh4m22yzi9y1w0kvx3aaf67yaq4z3s";
+       libraryHaskellDepends = [
+         base binary network
+       ];
+       testHaskellDepends = [ async criterion hspec ];
+       homepage = "https://github.com/example/containers-393";
+       description = "Package number 393";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "binary-394" = callPackage
+    ({ async, base, hashable, hspec, lib, mkDerivation, process, random, text, tls, transformers, unordered_containers, vector }:
+     mkDerivation {
+       pname = "binary-394";
+       version = "1.14.3";
+       sha256 = "dsf4cammv47619vmpqccmlf2m8vcayqiqc33nbs2fb3nff2cp5hl";
+       libraryHaskellDepends = [
+         async base hspec random text tls transformers unordered_containers
+       ];
+       testHaskellDepends = [ hashable process vector ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/binary-394";
+       description = "Package number 394";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "binary-395" = callPackage
+    ({ QuickCheck, filepath, hashable, lib, mkDerivation, process, tls }:
+     mkDerivation {
+       pname = "binary-395";
+       version = "5.16.0";
+       sha256 = "848ywq6pljxbqr28qzlcp16wdmk78zbylxs1fydc28fsn1i9sbz0";
+       libraryHaskellDepends = [
+         QuickCheck filepath
+       ];
+       testHaskellDepends = [ QuickCheck hashable process tls ];
+       postPatch = "substituteInPlace binary-395.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/binary-395";
+       description = "Package number 395";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "stm-396" = callPackage
+    ({ binary, containers, criterion, deepseq, directory, lens, lib, mkDerivation, mtl, text, time, tls, zlib }:
+     mkDerivation {
+       pname = "stm-396";
+       version = "2.7.3";
+       sha256 = "77i6r8a6pn9ahm13wl380l3w7rx7p2bhgcjpynay1q94x3viw85a";
+       libraryHaskellDepends = [
+         binary containers criterion deepseq mtl time tls zlib
+       ];
+       testHaskellDepends = [ directory lens text ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/stm-396";
+       description = "Package number 396";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "network-397" = callPackage
+    ({ QuickCheck, filepath, hashable, hspec, lib, mkDerivation, network, parsec, tls }:
+     mkDerivation {
+       pname = "network-397";
+       version = "9.3.5";
+       sha256 = "pw5az2ypw3z1a0a67zwfh8dar6v7f59cpn4cix04qhjx60l0s1gl";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck hspec tls
+       ];
+       testHaskellDepends = [ filepath hashable network parsec ];
+       homepage = "https://github.com/example/network-397";
+       description = "Package number 397";
+       license = lib.licenses.mit;
+     }) {};
+
+  "aeson-398" = callPackage
+    ({ base, hspec, lib, mkDerivation, parsec, process, vector }:
+     mkDerivation {
+       pname = "aeson-398";
+       version = "3.10.6";
+       sha256 = "9fpgs4056n6376mqrghd0djrwny2v9dahlzxra0m3a1l2sq850lw";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base hspec parsec process vector
+       ];
+       homepage = "https://github.com/example/aeson-398";
+       description = "Package number 398";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "hspec-399" = callPackage
+    ({ base, binary, hashable, http_client, lib, megaparsec, mkDerivation, tasty, text, tls, zlib }:
+     mkDerivation {
+       pname = "hspec-399";
+       version = "4.16.1";
+       sha256 = "cvkmn8hy0cvyh709lh93w3w9hfr5ml1yqg1kl25867032wa5bpq0";
+       libraryHaskellDepends = [
+         base binary http_client tasty text tls zlib
+       ];
+       testHaskellDepends = [ hashable megaparsec ];
+       homepage = "https://github.com/example/hspec-399";
+       description = "Package number 399";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "async-400" = callPackage
+    ({ base, criterion, directory, lib, mkDerivation, mtl, process, random, text }:
+     mkDerivation {
+       pname = "async-400";
+       version = "5.15.0";
+       sha256 = "n6ak2m2pcbl1fw8hz6j8j7h5imx9nw8ccial9x5pr61s9z0g7iks";
+       libraryHaskellDepends = [
+         criterion random text
+       ];
+       testHaskellDepends = [ base directory mtl process ];
+       homepage = "https://github.com/example/async-400";
+       description = "Package number 400";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "unordered-containers-401" = callPackage
+    ({ async, criterion, deepseq, hspec, lib, mkDerivation, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "unordered-containers-401";
+       version = "1.15.6";
+       sha256 = "mlvhpjlmd3yk6zpjkgbpaw78ff3hxjcr99f9p0j6b8bgr9zz8m18";
+       libraryHaskellDepends = [
+         async criterion deepseq unordered_containers
+       ];
+       testHaskellDepends = [ async hspec transformers ];
+       homepage = "https://github.com/example/unordered-containers-401";
+       description = "Package number 401";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "parsec-402" = callPackage
+    ({ bytestring, http_client, lens, lib, megaparsec, mkDerivation, parsec, random, stm, time, tls }:
+     mkDerivation {
+       pname = "parsec-402";
+       version = "5.19.3";
+       sha256 = "mkyb6vr5ilm3mzzbsv3khhfb40blz86h9vmz6h5x4bkn9163hbi3";
+       libraryHaskellDepends = [
+         bytestring lens megaparsec random time
+       ];
+       testHaskellDepends = [ http_client parsec stm tls ];
+       homepage = "https://github.com/example/parsec-402";
+       description = "Package number 402";
+       license = lib.licenses.mit;
+     }) {};
+
+  "unordered-containers-403" = callPackage
+    ({ async, containers, directory, filepath, hspec, lib, mkDerivation, tasty, text, tls }:
+     mkDerivation {
+       pname = "unordered-containers-403";
+       version = "3.3.0";
+       sha256 = "faa0xjnal73mdn50amnv70ajai2jg335jrkn0wv0f0nvmkifb199";
+       libraryHaskellDepends = [
+         async containers directory filepath hspec tls
+       ];
+       testHaskellDepends = [ hspec tasty text ];
+       homepage = "https://github.com/example/unordered-containers-403";
+       description = "Package number 403";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "network-404" = callPackage
+    ({ directory, hashable, lens, lib, mkDerivation, tasty, text }:
+     mkDerivation {
+       pname = "network-404";
+       version = "4.3.2";
+       sha256 = "q5jhb26j358ljljwldnp33dc272xg0g9nl2yiwn9xjiwv4jcslnm";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         directory hashable lens
+       ];
+       testHaskellDepends = [ tasty text ];
+       homepage = "https://github.com/example/network-404";
+       description = "Package number 404";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hashable-405" = callPackage
+    ({ filepath, http_client, lib, megaparsec, mkDerivation, random, text }:
+     mkDerivation {
+       pname = "hashable-405";
+       version = "8.17.4";
+       sha256 = "g6303ka273rjfb5j24rhdni62icz2lrx2p5myf4adb0y7x1fa6ay";
+       libraryHaskellDepends = [
+         http_client megaparsec random
+       ];
+       testHaskellDepends = [ filepath text ];
+       homepage = "https://github.com/example/hashable-405";
+       description = "Package number 405";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "process-406" = callPackage
+    ({ QuickCheck, aeson, base, bytestring, lib, mkDerivation, mtl, tls, unordered_containers }:
+     mkDerivation {
+       pname = "process-406";
+       version = "0.8.2";
+       sha256 = "xwkcac7r5zgiixlxbjc1v4lc2nkanslsljv6kr9ny821hyzz9pmg";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck aeson base bytestring mtl tls unordered_containers
+       ];
+       homepage = "https://github.com/example/process-406";
+       description = "Package number 406";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "process-407" = callPackage
+    ({ async, base, containers, hashable, lib, mkDerivation, mtl, network, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "process-407";
+       version = "0.6.4";
+       sha256 = "6g55d1a47xkpg49vhp8qyg2njjbp51s92lwzlv5x2cyl5fpf9y3l";
+       libraryHaskellDepends = [
+         async base containers hashable mtl network unordered_containers zlib
+       ];
+       testHaskellDepends = [ base ];
+       homepage = "https://github.com/example/process-407";
+       description = "Package number 407";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "megaparsec-408" = callPackage
+    ({ async, deepseq, directory, filepath, hashable, lib, mkDerivation, mtl, parsec, process, random, unordered_containers, vector, zlib }:
+     mkDerivation {
+       pname = "megaparsec-408";
+       version = "1.17.1";
+       sha256 = "dk0isk48yv3v06n7blv1vxjphjgsi36fnvlaaq4v0ypkalm0qlng";
+       libraryHaskellDepends = [
+         async deepseq filepath hashable mtl process random zlib
+       ];
+       testHaskellDepends = [ directory parsec unordered_containers vector ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/megaparsec-408";
+       description = "Package number 408";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "filepath-409" = callPackage
+    ({ bytestring, containers, deepseq, lens, lib, mkDerivation, network, tasty, tls, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "filepath-409";
+       version = "1.5.5";
+       sha256 = "sk8iaf4gm7ix05cv2xn5cgm0z533jac2np0kmly9dadggij3chlk";
+       libraryHaskellDepends = [
+         containers deepseq lens network tasty tls transformers unordered_containers
+       ];
+       testHaskellDepends = [ bytestring ];
+       homepage = "https://github.com/example/filepath-409";
+       description = "Package number 409";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "network-410" = callPackage
+    ({ aeson, async, binary, deepseq, lib, mkDerivation, mtl, parsec, text, time }:
+     mkDerivation {
+       pname = "network-410";
+       version = "8.16.3";
+       sha256 = "q2zk06iznyhmall82zxgvnfg3as8dy89szrs9dk08qdlbpm8gg7p";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson binary deepseq mtl parsec time
+       ];
+       testHaskellDepends = [ async text ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/network-410";
+       description = "Package number 410";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tasty-411" = callPackage
+    ({ QuickCheck, binary, bytestring, directory, hashable, lib, mkDerivation, network, stm, vector }:
+     mkDerivation {
+       pname = "tasty-411";
+       version = "9.17.4";
+       sha256 = "jgicb949678ld5ywh6xvlbwwl3qri3vzkmx29kdsdhzr64lavki9";
+       libraryHaskellDepends = [
+         QuickCheck binary hashable network stm vector
+       ];
+       testHaskellDepends = [ bytestring directory network ];
+       postPatch = "substituteInPlace tasty-411.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/tasty-411";
+       description = "Package number 411";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "async-412" = callPackage
+    ({ aeson, binary, bytestring, containers, criterion, hashable, lib, megaparsec, mkDerivation, stm, text, zlib }:
+     mkDerivation {
+       pname = "async-412";
+       version = "6.1.3";
+       sha256 = "2fpmbdz5ynx7s657c6rkkla17b8pgb0bqydr7vm4q6s6sy40ir9n";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary bytestring containers criterion hashable megaparsec stm zlib
+       ];
+       testHaskellDepends = [ aeson text ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/async-412";
+       description = "Package number 412";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "filepath-413" = callPackage
+    ({ containers, criterion, hspec, lib, megaparsec, mkDerivation, parsec, stm, time, vector }:
+     mkDerivation {
+       pname = "filepath-413";
+       version = "3.9.5";
+       sha256 = "2z6s0lf087nk6ij62xzfp1c4zf3s5pk0wq0aclrkl3jzvkjax81r";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         containers criterion hspec megaparsec parsec stm time vector
+       ];
+       homepage = "https://github.com/example/filepath-413";
+       description = "Package number 413";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "vector-414" = callPackage
+    ({ filepath, lens, lib, mkDerivation, mtl, stm, text }:
+     mkDerivation {
+       pname = "vector-414";
+       version = "6.0.0";
+       sha256 = "8mjri3yk1iy263v6w6q9v40vw3mmzpskcphv6vv6rjk3j5l52z52";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         lens mtl text
+       ];
+       testHaskellDepends = [ filepath stm ];
+       postPatch = "substituteInPlace vector-414.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/vector-414";
+       description = "Package number 414";
+       license = lib.licenses.mit;
+     }) {};
+
+  "zlib-415" = callPackage
+    ({ aeson, lens, lib, mkDerivation, parsec, text, time, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "zlib-415";
+       version = "9.14.4";
+       sha256 = "6s5rsgaf0kk9myicswd3q8p7ackcq73zn8kfpisrdab41caff8gw";
+       libraryHaskellDepends = [
+         aeson lens parsec text time unordered_containers
+       ];
+       testHaskellDepends = [ zlib ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/zlib-415";
+       description = "Package number 415";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "criterion-416" = callPackage
+    ({ QuickCheck, base, criterion, filepath, hspec, http_client, lib, megaparsec, mkDerivation, network }:
+     mkDerivation {
+       pname = "criterion-416";
+       version = "3.20.4";
+       sha256 = "w47bwnxkczdxndhi7rl8srg6w1np46sbcyw5r8dprqxx42gza2f2";
+       libraryHaskellDepends = [
+         QuickCheck base criterion filepath http_client megaparsec network
+       ];
+       testHaskellDepends = [ hspec ];
+       homepage = "https://github.com/example/criterion-416";
+       description = "Package number 416";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "async-417" = callPackage
+    ({ QuickCheck, base, bytestring, deepseq, hspec, lens, lib, mkDerivation }:
+     mkDerivation {
+       pname = "async-417";
+       version = "2.5.0";
+       sha256 = "jjdjh9158mxg84jvkxrazv917qzrqvy8a7p5rz86y5396ri3gwl5";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck lens
+       ];
+       testHaskellDepends = [ base bytestring deepseq hspec ];
+       homepage = "https://github.com/example/async-417";
+       description = "Package number 417";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "mtl-418" = callPackage
+    ({ directory, http_client, lib, mkDerivation }:
+     mkDerivation {
+       pname = "mtl-418";
+       version = "2.9.5";
+       sha256 = "l3xnh9rkkfczzkkid4xllkw0pd2dcvx9f36f6xrx0m7aid87sv7g";
+       libraryHaskellDepends = [
+         directory http_client
+       ];
+       homepage = "https://github.com/example/mtl-418";
+       description = "Package number 418";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "bytestring-419" = callPackage
+    ({ binary, deepseq, lens, lib, mkDerivation, mtl, unordered_containers }:
+     mkDerivation {
+       pname = "bytestring-419";
+       version = "8.15.9";
+       sha256 = "gzp3r5d5x5g9xllmlcl90v7qn2sv96xmmlbirff03xkncj1xldk7";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         lens mtl unordered_containers
+       ];
+       testHaskellDepends = [ binary deepseq lens ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/bytestring-419";
+       description = "Package number 419";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "QuickCheck-420" = callPackage
+    ({ QuickCheck, aeson, criterion, http_client, lib, mkDerivation, mtl, process, random, text }:
+     mkDerivation {
+       pname = "QuickCheck-420";
+       version = "2.1.8";
+       sha256 = "y9pwmni687gnsg4lrgk0chkng3302234qwi3x19dvdwxw0ykz7bf";
+       libraryHaskellDepends = [
+         QuickCheck aeson criterion http_client mtl random text
+       ];
+       testHaskellDepends = [ process ];
+       postPatch = "substituteInPlace QuickCheck-420.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/QuickCheck-420";
+       description = "Package number 420";
+       license = lib.licenses.mit;
+     }) {};
+
+  "tasty-421" = callPackage
+    ({ async, directory, hspec, http_client, lens, lib, megaparsec, mkDerivation, unordered_containers }:
+     mkDerivation {
+       pname = "tasty-421";
+       version = "0.10.2";
+       sha256 = "7sv1ry897m2vzrv51ldkrgxfhy6w4bbfprywpm9arb931fqsvddf";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         directory hspec http_client lens megaparsec unordered_containers
+       ];
+       testHaskellDepends = [ async hspec ];
+       homepage = "https://github.com/example/tasty-421";
+       description = "Package number 421";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "parsec-422" = callPackage
+    ({ aeson, binary, containers, hspec, lib, megaparsec, mkDerivation, network, process }:
+     mkDerivation {
+       pname = "parsec-422";
+       version = "5.6.2";
+       sha256 = "z4x435iyr3qg7kmn9ivi2l7nh8m8di3kizgcai1wpb62w0kqswzp";
+       libraryHaskellDepends = [
+         aeson binary containers hspec
+       ];
+       testHaskellDepends = [ megaparsec network process ];
+       homepage = "https://github.com/example/parsec-422";
+       description = "Package number 422";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "parsec-423" = callPackage
+    ({ QuickCheck, async, bytestring, hashable, http_client, lib, mkDerivation, mtl, network, stm, tls }:
+     mkDerivation {
+       pname = "parsec-423";
+       version = "6.19.1";
+       sha256 = "4bnbpzirq3xwkbyc21rhsy1j9zzaywrrraqbl7hybdyd8gdc1k18";
+       libraryHaskellDepends = [
+         QuickCheck async bytestring http_client mtl network stm
+       ];
+       testHaskellDepends = [ hashable tls ];
+       homepage = "https://github.com/example/parsec-423";
+       description = "Package number 423";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "binary-424" = callPackage
+    ({ async, hashable, lib, mkDerivation, process, random, stm, tasty }:
+     mkDerivation {
+       pname = "binary-424";
+       version = "2.2.4";
+       sha256 = "s4gkl6fj2r1wgpmaq5ca8i1w1dix9s6lyq30ny4pfhki7mxbx66h";
+       libraryHaskellDepends = [
+         async process random
+       ];
+       testHaskellDepends = [ hashable stm tasty ];
+       homepage = "https://github.com/example/binary-424";
+       description = "Package number 424";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "random-425" = callPackage
+    ({ hashable, lib, mkDerivation, mtl, network, process, unordered_containers, vector, zlib }:
+     mkDerivation {
+       pname = "random-425";
+       version = "3.14.5";
+       sha256 = "z6lm864kpdwhn3ikh4i90kywnx32mm8024v3plr8zb9zmjc3mzxk";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hashable network process unordered_containers zlib
+       ];
+       testHaskellDepends = [ mtl process vector ];
+       homepage = "https://github.com/example/random-425";
+       description = "Package number 425";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "async-426" = callPackage
+    ({ deepseq, http_client, lib, mkDerivation, network, process, tasty, time }:
+     mkDerivation {
+       pname = "async-426";
+       version = "1.3.1";
+       sha256 = "kx9sv3klwn1szm5wykmn792xv055jxcxi9vmps2gh0z8cy231xvs";
+       libraryHaskellDepends = [
+         deepseq http_client process tasty time
+       ];
+       testHaskellDepends = [ network ];
+       postPatch = "substituteInPlace async-426.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/async-426";
+       description = "Package number 426";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "binary-427" = callPackage
+    ({ lib, mkDerivation, network, text, time }:
+     mkDerivation {
+       pname = "binary-427";
+       version = "0.13.4";
+       sha256 = "zkvdwm178ya7p7xmypnmfdw0g10hj495zxxg4n9rfv18gkizm9jc";
+       libraryHaskellDepends = [
+         network time
+       ];
+       testHaskellDepends = [ text ];
+       homepage = "https://github.com/example/binary-427";
+       description = "Package number 427";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "bytestring-428" = callPackage
+    ({ QuickCheck, base, bytestring, directory, lib, mkDerivation, mtl, time, vector, zlib }:
+     mkDerivation {
+       pname = "bytestring-428";
+       version = "3.10.8";
+       sha256 = "mfyldwrzry0gfjbmzznd71ks9wmgk7nbgnh0ni0iwr40naipiylm";
+       libraryHaskellDepends = [
+         QuickCheck base bytestring directory mtl time vector zlib
+       ];
+       homepage = "https://github.com/example/bytestring-428";
+       description = "Package number 428";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "hashable-429" = callPackage
+    ({ base, bytestring, directory, lens, lib, mkDerivation, parsec, random }:
+     mkDerivation {
+       pname = "hashable-429";
+       version = "7.10.8";
+       sha256 = "9pf1g70ls4kxz9kqjvy0ncnacn9qwmg4hrn41c9x74bh9p77al4f";
+       libraryHaskellDepends = [
+         base bytestring
+       ];
+       testHaskellDepends = [ directory lens parsec random ];
+       homepage = "https://github.com/example/hashable-429";
+       description = "Package number 429";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "text-430" = callPackage
+    ({ lib, megaparsec, mkDerivation, time, unordered_containers }:
+     mkDerivation {
+       pname = "text-430";
+       version = "4.7.3";
+       sha256 = "djgfl39z2khgb6qhhqnk0jawvmklsdgnq1f824rl6s7n2jijl874";
+       libraryHaskellDepends = [
+         megaparsec time unordered_containers
+       ];
+       homepage = "https://github.com/example/text-430";
+       description = "Package number 430";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "tasty-431" = callPackage
+    ({ bytestring, directory, lib, mkDerivation, parsec, stm }:
+     mkDerivation {
+       pname = "tasty-431";
+       version = "9.20.5";
+       sha256 = "4a6z86wh1vhawyski761an6jhvdgwxy4qr66qsv678slbj7b5xj4";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring parsec
+       ];
+       testHaskellDepends = [ directory stm ];
+       homepage = "https://github.com/example/tasty-431";
+       description = "Package number 431";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "stm-432" = callPackage
+    ({ hashable, hspec, lib, mkDerivation, parsec, time, unordered_containers, vector }:
+     mkDerivation {
+       pname = "stm-432";
+       version = "0.14.0";
+       sha256 = "rn47fnjyn52a0lya4am8ml4rcbaazpih7kpvzhf9cy50b23hjz5c";
+       libraryHaskellDepends = [
+         hashable hspec vector
+       ];
+       testHaskellDepends = [ parsec time unordered_containers ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/stm-432";
+       description = "Package number 432";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "text-433" = callPackage
+    ({ QuickCheck, binary, bytestring, lib, mkDerivation, random, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "text-433";
+       version = "1.0.6";
+       sha256 = "8mksi8n496qdl6ddaxgvf3c1q9gb55qyixgnj2lrvpza2cld6777";
+       libraryHaskellDepends = [
+         binary bytestring random unordered_containers
+       ];
+       testHaskellDepends = [ QuickCheck zlib ];
+       postPatch = "substituteInPlace text-433.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/text-433";
+       description = "Package number 433";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "directory-434" = callPackage
+    ({ aeson, binary, criterion, http_client, lib, mkDerivation, random, stm, tasty, vector }:
+     mkDerivation {
+       pname = "directory-434";
+       version = "2.3.0";
+       sha256 = "q4m1kvh7s4n8p2rmhcbl0kimlhdp2w496c3y4hv1x2w757fv3751";
+       libraryHaskellDepends = [
+         aeson binary criterion http_client random stm vector
+       ];
+       testHaskellDepends = [ tasty ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/directory-434";
+       description = "Package number 434";
+       license = lib.licenses.mit;
+     }) {};
+
+  "zlib-435" = callPackage
+    ({ QuickCheck, aeson, async, base, containers, deepseq, lib, mkDerivation, stm, text, transformers }:
+     mkDerivation {
+       pname = "zlib-435";
+       version = "1.20.5";
+       sha256 = "pkvdvg6p764gg55yj5j0bcqn3y6f4mfa9hklw94gr98bfcsjflb1";
+       libraryHaskellDepends = [
+         QuickCheck aeson base containers stm transformers
+       ];
+       testHaskellDepends = [ async deepseq stm text ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/zlib-435";
+       description = "Package number 435";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "base-436" = callPackage
+    ({ QuickCheck, criterion, filepath, lib, mkDerivation, parsec, process, text, unordered_containers, vector }:
+     mkDerivation {
+       pname = "base-436";
+       version = "2.10.5";
+       sha256 = "9743zms7mmb3jsh2clpb0bb8i19mdpqjn0pmlycczlm80gzzh0nl";
+       libraryHaskellDepends = [
+         QuickCheck criterion filepath parsec process text unordered_containers vector
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/base-436";
+       description = "Package number 436";
+       license = lib.licenses.mit;
+     }) {};
+
+  "containers-437" = callPackage
+    ({ aeson, async, bytestring, containers, deepseq, lib, mkDerivation, parsec, time, tls, unordered_containers }:
+     mkDerivation {
+       pname = "containers-437";
+       version = "4.2.9";
+       sha256 = "7c8n5b21q7l92yz8r0ncn0y7zhrz1b7jjk6wi88idy622x3qs7gw";
+       libraryHaskellDepends = [
+         aeson async bytestring containers deepseq time unordered_containers
+       ];
+       testHaskellDepends = [ parsec tls ];
+       homepage = "https://github.com/example/containers-437";
+       description = "Package number 437";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "hspec-438" = callPackage
+    ({ async, base, binary, containers, deepseq, hashable, hspec, lib, mkDerivation, network, transformers }:
+     mkDerivation {
+       pname = "hspec-438";
+       version = "9.13.4";
+       sha256 = "0hj4b0gnrhpbb0mi3rhnv89qmm8745n7ybnwkjcqsf12xmkifgdg";
+       libraryHaskellDepends = [
+         async binary containers deepseq hspec network transformers
+       ];
+       testHaskellDepends = [ async base hashable ];
+       homepage = "https://github.com/example/hspec-438";
+       description = "Package number 438";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "megaparsec-439" = callPackage
+    ({ binary, containers, deepseq, hspec, lib, megaparsec, mkDerivation, parsec, vector }:
+     mkDerivation {
+       pname = "megaparsec-439";
+       version = "2.17.0";
+       sha256 = "2pwfimyhmk90r177yvnf4p9mhjhwbh5zrwbshic6azhggflrypja";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary hspec megaparsec vector
+       ];
+       testHaskellDepends = [ containers deepseq parsec ];
+       homepage = "https://github.com/example/megaparsec-439";
+       description = "Package number 439";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "lens-440" = callPackage
+    ({ aeson, lib, mkDerivation, parsec, random, stm, tasty, time, tls, unordered_containers }:
+     mkDerivation {
+       pname = "lens-440";
+       version = "7.9.7";
+       sha256 = "g0abnkrsizsk96w59s2vc6m2rqf5v3kr1kvx4s67iikbimhyizqi";
+       libraryHaskellDepends = [
+         aeson parsec tasty time tls
+       ];
+       testHaskellDepends = [ random stm unordered_containers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/lens-440";
+       description = "Package number 440";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hashable-441" = callPackage
+    ({ aeson, binary, bytestring, hspec, lib, megaparsec, mkDerivation, process, random, stm, text }:
+     mkDerivation {
+       pname = "hashable-441";
+       version = "3.15.8";
+       sha256 = "lypavh41ah9xdr9x9pq67ls5p5vy0a0z8dz9i2g41b18h4xzvbbb";
+       libraryHaskellDepends = [
+         binary hspec megaparsec random text
+       ];
+       testHaskellDepends = [ aeson bytestring process stm ];
+       homepage = "https://github.com/example/hashable-441";
+       description = "Package number 441";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "async-442" = callPackage
+    ({ aeson, bytestring, containers, deepseq, lib, megaparsec, mkDerivation, mtl, stm, unordered_containers }:
+     mkDerivation {
+       pname = "async-442";
+       version = "6.15.5";
+       sha256 = "k2vgm1nlan054xcbbyj5864a88igk2lmmxfjp79l8lvnxb2phf9b";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson bytestring containers deepseq megaparsec mtl stm unordered_containers
+       ];
+       testHaskellDepends = [ containers ];
+       postPatch = "substituteInPlace async-442.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/async-442";
+       description = "Package number 442";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "binary-443" = callPackage
+    ({ aeson, deepseq, hashable, http_client, lib, mkDerivation, time, zlib }:
+     mkDerivation {
+       pname = "binary-443";
+       version = "9.4.7";
+       sha256 = "jspvaqqsx4w7iw7aanwghm3lmzh7p6ykyswymgzfs3z4rx8lbg61";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hashable http_client time
+       ];
+       testHaskellDepends = [ aeson deepseq zlib ];
+       postPatch = "substituteInPlace binary-443.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/binary-443";
+       description = "Package number 443";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "process-444" = callPackage
+    ({ QuickCheck, bytestring, containers, deepseq, hspec, lens, lib, mkDerivation, tasty, vector }:
+     mkDerivation {
+       pname = "process-444";
+       version = "3.1.9";
+       sha256 = "jharz0drsds6dqlxd9d7m6lsjvyfyydjp8klmbjqzp3q0vayblwz";
+       libraryHaskellDepends = [
+         bytestring hspec lens tasty vector
+       ];
+       testHaskellDepends = [ QuickCheck containers deepseq ];
+       postPatch = "substituteInPlace process-444.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/process-444";
+       description = "Package number 444";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "base-445" = callPackage
+    ({ aeson, base, bytestring, deepseq, lib, mkDerivation, stm, tasty, vector, zlib }:
+     mkDerivation {
+       pname = "base-445";
+       version = "8.0.1";
+       sha256 = "8ryaskimd6klj3b1yrh9s3x4k08bw4amrdd2sykxamlmj3ckk7q3";
+       libraryHaskellDepends = [
+         aeson base bytestring deepseq stm tasty vector zlib
+       ];
+       testHaskellDepends = [ bytestring stm ];
+       homepage = "https://github.com/example/base-445";
+       description = "Package number 445";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "criterion-446" = callPackage
+    ({ aeson, hashable, http_client, lib, mkDerivation, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "criterion-446";
+       version = "6.16.0";
+       sha256 = "jqavcyi6m55x0nx2ddd50qalvgrnh1vl0nvpqzccn9w7rdrrn6mh";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         http_client unordered_containers
+       ];
+       testHaskellDepends = [ aeson hashable transformers ];
+       homepage = "https://github.com/example/criterion-446";
+       description = "Package number 446";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "containers-447" = callPackage
+    ({ deepseq, lib, megaparsec, mkDerivation, transformers, zlib }:
+     mkDerivation {
+       pname = "containers-447";
+       version = "6.20.0";
+       sha256 = "8jhkv6qwss88xcr9l9qkd41c7axzr432b04lbfgll6jyxfcwrfsx";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq megaparsec zlib
+       ];
+       testHaskellDepends = [ transformers ];
+       homepage = "https://github.com/example/containers-447";
+       description = "Package number 447";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tls-448" = callPackage
+    ({ async, directory, lib, mkDerivation, mtl, tls }:
+     mkDerivation {
+       pname = "tls-448";
+       version = "6.17.4";
+       sha256 = "x5ppzskxwj07503y871k6jzqvvflppi2pwblhj2n1864ri4vykhy";
+       libraryHaskellDepends = [
+         async directory tls
+       ];
+       testHaskellDepends = [ mtl ];
+       homepage = "https://github.com/example/tls-448";
+       description = "Package number 448";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "binary-449" = callPackage
+    ({ QuickCheck, aeson, binary, criterion, lib, mkDerivation, mtl, network }:
+     mkDerivation {
+       pname = "binary-449";
+       version = "6.14.4";
+       sha256 = "l58mf5jipw3rflgfqh8fyd102xl0f578i3liwl9prv051k9imjwh";
+       libraryHaskellDepends = [
+         QuickCheck aeson criterion mtl network
+       ];
+       testHaskellDepends = [ binary ];
+       homepage = "https://github.com/example/binary-449";
+       description = "Package number 449";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "aeson-450" = callPackage
+    ({ async, binary, containers, hashable, lib, megaparsec, mkDerivation, network, random, tls }:
+     mkDerivation {
+       pname = "aeson-450";
+       version = "7.2.0";
+       sha256 = "0cz072gwx0g1brwwkbf326cn8xn40jwsr8d294xb7dmrj95hj91f";
+       libraryHaskellDepends = [
+         binary containers hashable megaparsec random
+       ];
+       testHaskellDepends = [ async network random tls ];
+       homepage = "https://github.com/example/aeson-450";
+       description = "Package number 450";
+       license = lib.licenses.mit;
+     }) {};
+
+  "base-451" = callPackage
+    ({ async, base, lens, lib, mkDerivation, mtl, parsec, stm, tasty, tls }:
+     mkDerivation {
+       pname = "base-451";
+       version = "1.13.8";
+       sha256 = "l30w8rymn45kscy4spfc68b7dq4h56jjhnv2ny8ycv6h10nl7avp";
+       libraryHaskellDepends = [
+         async base mtl stm tasty tls
+       ];
+       testHaskellDepends = [ lens parsec stm ];
+       postPatch = "substituteInPlace base-451.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/base-451";
+       description = "Package number 451";
+       license = lib.licenses.asl20;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "QuickCheck-452" = callPackage
+    ({ bytestring, criterion, lens, lib, mkDerivation, stm, transformers }:
+     mkDerivation {
+       pname = "QuickCheck-452";
+       version = "8.16.1";
+       sha256 = "1lw70s0xdd4x5yjlr4s7z1qlrbig2gb9hdpqaq8rn4dhc3i16mfm";
+       libraryHaskellDepends = [
+         bytestring criterion lens transformers
+       ];
+       testHaskellDepends = [ stm ];
+       homepage = "https://github.com/example/QuickCheck-452";
+       description = "Package number 452";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "criterion-453" = callPackage
+    ({ async, http_client, lib, mkDerivation, stm }:
+     mkDerivation {
+       pname = "criterion-453";
+       version = "0.14.0";
+       sha256 = "6dwkkwslkspp02mjw8vvlg35grvx41906kj828icwsr1xxddqapb";
+       libraryHaskellDepends = [
+         async http_client stm
+       ];
+       homepage = "https://github.com/example/criterion-453";
+       description = "Package number 453";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "QuickCheck-454" = callPackage
+    ({ aeson, async, deepseq, directory, hashable, lib, mkDerivation, mtl, random, tasty, text, transformers }:
+     mkDerivation {
+       pname = "QuickCheck-454";
+       version = "3.19.7";
+       sha256 = "agi6ymfqc0qh99qjnn8vxly9as1d83zd8r1m1pplmawvi02569my";
+       libraryHaskellDepends = [
+         aeson async deepseq directory hashable mtl random transformers
+       ];
+       testHaskellDepends = [ tasty text ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace QuickCheck-454.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/QuickCheck-454";
+       description = "Package number 454";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "unordered-containers-455" = callPackage
+    ({ binary, directory, hashable, hspec, lens, lib, megaparsec, mkDerivation, mtl, process, time }:
+     mkDerivation {
+       pname = "unordered-containers-455";
+       version = "7.2.2";
+       sha256 = "mrrj66kj69mysabzgizwn23h13p1lhkr40awj89v5m5zih70snia";
+       libraryHaskellDepends = [
+         binary directory hashable hspec lens megaparsec mtl process
+       ];
+       testHaskellDepends = [ directory time ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace unordered-containers-455.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/unordered-containers-455";
+       description = "Package number 455";
+       license = lib.licenses.mit;
+     }) {};
+
+  "filepath-456" = callPackage
+    ({ criterion, directory, filepath, http_client, lib, megaparsec, mkDerivation, mtl, process, time, transformers, vector }:
+     mkDerivation {
+       pname = "filepath-456";
+       version = "0.2.9";
+       sha256 = "cjph9jaz0w2da1bcc9s9sknmr69z4hh1xrrf5961nsrc97x67n8w";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         filepath http_client megaparsec mtl time transformers vector
+       ];
+       testHaskellDepends = [ criterion directory process ];
+       postPatch = "substituteInPlace filepath-456.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/filepath-456";
+       description = "Package number 456";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "http-client-457" = callPackage
+    ({ base, hashable, hspec, lib, megaparsec, mkDerivation, tasty, time }:
+     mkDerivation {
+       pname = "http-client-457";
+       version = "1.8.6";
+       sha256 = "9skjy4k3y265k6iaqrmf6nzjzjw2zj0yi0jbsx714k381x4wd47k";
+       libraryHaskellDepends = [
+         base hashable hspec megaparsec time
+       ];
+       testHaskellDepends = [ tasty ];
+       homepage = "https://github.com/example/http-client-457";
+       description = "Package number 457";
+       license = lib.licenses.mit;
+     }) {};
+
+  "base-458" = callPackage
+    ({ async, binary, filepath, hashable, http_client, lib, mkDerivation, text, tls, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "base-458";
+       version = "4.4.9";
+       sha256 = "dflx8p5azzb4iqs6dk0hzkaav893v5mijrl59ck506rgvn3rd2sj";
+       libraryHaskellDepends = [
+         async binary filepath hashable text tls transformers unordered_containers
+       ];
+       testHaskellDepends = [ http_client ];
+       homepage = "https://github.com/example/base-458";
+       description = "Package number 458";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "filepath-459" = callPackage
+    ({ QuickCheck, binary, criterion, hashable, http_client, lib, mkDerivation, process, time, unordered_containers }:
+     mkDerivation {
+       pname = "filepath-459";
+       version = "6.12.1";
+       sha256 = "f7hhxijzda1kvsr66bzhwniis0kj93lcr39bpbdvqi9r3lpkildj";
+       libraryHaskellDepends = [
+         QuickCheck binary criterion hashable process time unordered_containers
+       ];
+       testHaskellDepends = [ http_client ];
+       homepage = "https://github.com/example/filepath-459";
+       description = "Package number 459";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "directory-460" = callPackage
+    ({ aeson, base, criterion, filepath, hspec, lib, mkDerivation, process, tasty, time, unordered_containers }:
+     mkDerivation {
+       pname = "directory-460";
+       version = "1.17.5";
+       sha256 = "d2h1qj5jz9p5gbjyaa9cyigajdgaa5a20xbyx6l085ks3w5rq234";
+       libraryHaskellDepends = [
+         aeson base filepath process tasty time
+       ];
+       testHaskellDepends = [ base criterion hspec unordered_containers ];
+       homepage = "https://github.com/example/directory-460";
+       description = "Package number 460";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "stm-461" = callPackage
+    ({ aeson, async, containers, lib, mkDerivation, mtl, stm, text, unordered_containers }:
+     mkDerivation {
+       pname = "stm-461";
+       version = "6.17.7";
+       sha256 = "a0pngdms7ifap3yywaw19b5mpiyc9xqfmispck49231b1pdlfs7y";
+       libraryHaskellDepends = [
+         aeson async containers mtl stm
+       ];
+       testHaskellDepends = [ text unordered_containers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/stm-461";
+       description = "Package number 461";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "bytestring-462" = callPackage
+    ({ base, directory, lib, mkDerivation, tls }:
+     mkDerivation {
+       pname = "bytestring-462";
+       version = "6.18.4";
+       sha256 = "r8xg978cky1vc2vx1czvivcglwfjcaqv2kq26hshkxgwpaaawsb6";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base tls
+       ];
+       testHaskellDepends = [ directory ];
+       homepage = "https://github.com/example/bytestring-462";
+       description = "Package number 462";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "vector-463" = callPackage
+    ({ aeson, binary, deepseq, hspec, lib, mkDerivation, text }:
+     mkDerivation {
+       pname = "vector-463";
+       version = "4.17.1";
+       sha256 = "h58czwmk81gdsf8x8q4wqvyshbbnwsip62v9shfyg09icav59zqg";
+       libraryHaskellDepends = [
+         aeson binary deepseq hspec text
+       ];
+       testHaskellDepends = [ deepseq ];
+       homepage = "https://github.com/example/vector-463";
+       description = "Package number 463";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tasty-464" = callPackage
+    ({ aeson, binary, directory, lens, lib, mkDerivation, mtl, transformers, vector }:
+     mkDerivation {
+       pname = "tasty-464";
+       version = "3.16.1";
+       sha256 = "70knknxl8hd4d29kfa1wf6wc9bkqaqpk8yf6bljalhgsy9gmnpmy";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary lens mtl vector
+       ];
+       testHaskellDepends = [ aeson directory mtl transformers ];
+       homepage = "https://github.com/example/tasty-464";
+       description = "Package number 464";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "process-465" = callPackage
+    ({ base, deepseq, lib, mkDerivation, random, transformers, zlib }:
+     mkDerivation {
+       pname = "process-465";
+       version = "7.15.9";
+       sha256 = "q7cyccawwxiv3s8qff2ywxgqaynymawnp4ab31wmiv204kzyxz41";
+       libraryHaskellDepends = [
+         base deepseq random transformers zlib
+       ];
+       testHaskellDepends = [ transformers ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/process-465";
+       description = "Package number 465";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "stm-466" = callPackage
+    ({ aeson, async, lib, mkDerivation, tasty, tls, zlib }:
+     mkDerivation {
+       pname = "stm-466";
+       version = "2.7.6";
+       sha256 = "6jlimiz8dc0iqq6yy7cb0mj7wjbpz59r51crlh8brmbbhj5scb1f";
+       libraryHaskellDepends = [
+         aeson async tasty tls zlib
+       ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/stm-466";
+       description = "Package number 466";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "mtl-467" = callPackage
+    ({ base, hspec, lib, mkDerivation, random, transformers, vector, zlib }:
+     mkDerivation {
+       pname = "mtl-467";
+       version = "6.0.0";
+       sha256 = "32py58wfv3ngs03w32ilgx7a0ccqhynmw533yki9vqffc7fwl6dr";
+       libraryHaskellDepends = [
+         base hspec random transformers vector zlib
+       ];
+       homepage = "https://github.com/example/mtl-467";
+       description = "Package number 467";
+       license = lib.licenses.mit;
+     }) {};
+
+  "parsec-468" = callPackage
+    ({ binary, bytestring, containers, deepseq, filepath, hashable, hspec, lib, mkDerivation, network, random, stm, tls }:
+     mkDerivation {
+       pname = "parsec-468";
+       version = "3.18.9";
+       sha256 = "19cwj0hraaydz5il5x2nnm5nrsiflsbbri66d34pdyjyl2a1f1lq";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring containers deepseq hashable hspec stm tls
+       ];
+       testHaskellDepends = [ binary filepath network random ];
+       homepage = "https://github.com/example/parsec-468";
+       description = "Package number 468";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "transformers-469" = callPackage
+    ({ binary, containers, lib, megaparsec, mkDerivation, network, random, stm, zlib }:
+     mkDerivation {
+       pname = "transformers-469";
+       version = "3.13.2";
+       sha256 = "ppcqnvilrqqximi3smgpkinipx64jayln8sh7pysdcqhq17skhfa";
+       libraryHaskellDepends = [
+         binary containers megaparsec network random stm zlib
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace transformers-469.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/transformers-469";
+       description = "Package number 469";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "lens-470" = callPackage
+    ({ async, criterion, lib, mkDerivation, mtl, process, stm, vector }:
+     mkDerivation {
+       pname = "lens-470";
+       version = "1.7.0";
+       sha256 = "dsm9r7sy946709pm8zzvlpn9irdx98w161smc2jc6477ykdg3cab";
+       libraryHaskellDepends = [
+         criterion process stm
+       ];
+       testHaskellDepends = [ async mtl vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/lens-470";
+       description = "Package number 470";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "megaparsec-471" = callPackage
+    ({ aeson, async, containers, deepseq, filepath, hspec, lib, megaparsec, mkDerivation, network, parsec, tls }:
+     mkDerivation {
+       pname = "megaparsec-471";
+       version = "4.3.6";
+       sha256 = "hkla66ssmcd9k02sskx8x7165qd3dmm2r0ki1fp5d977cw4y6iwa";
+       libraryHaskellDepends = [
+         aeson async containers hspec network parsec tls
+       ];
+       testHaskellDepends = [ deepseq filepath megaparsec ];
+       homepage = "https://github.com/example/megaparsec-471";
+       description = "Package number 471";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "time-472" = callPackage
+    ({ QuickCheck, async, base, criterion, http_client, lib, mkDerivation, mtl, random, stm, transformers }:
+     mkDerivation {
+       pname = "time-472";
+       version = "4.4.0";
+       sha256 = "zvzjily4y1fv5k8wbjp2qs3dks6vk6rhlxm43ba50z4c095w04df";
+       libraryHaskellDepends = [
+         QuickCheck async base criterion mtl random stm transformers
+       ];
+       testHaskellDepends = [ http_client transformers ];
+       homepage = "https://github.com/example/time-472";
+       description = "Package number 472";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "network-473" = callPackage
+    ({ hashable, lib, megaparsec, mkDerivation, unordered_containers, vector }:
+     mkDerivation {
+       pname = "network-473";
+       version = "9.20.7";
+       sha256 = "xqd536jdfqrhbs3v161nxss7ki4127x95qa4790i4mzdswhhxymz";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hashable megaparsec unordered_containers
+       ];
+       testHaskellDepends = [ vector ];
+       homepage = "https://github.com/example/network-473";
+       description = "Package number 473";
+       license = lib.licenses.mit;
+     }) {};
+
+  "parsec-474" = callPackage
+    ({ binary, bytestring, criterion, directory, lib, mkDerivation, mtl, tasty }:
+     mkDerivation {
+       pname = "parsec-474";
+       version = "7.6.9";
+       sha256 = "pvv8g46nh6cz9zz5i0zzgwm0x5pfbn9mch7fxpzhdasn3r5w3lwj";
+       libraryHaskellDepends = [
+         directory mtl tasty
+       ];
+       testHaskellDepends = [ binary bytestring criterion ];
+       postPatch = "substituteInPlace parsec-474.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/parsec-474";
+       description = "Package number 474";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "filepath-475" = callPackage
+    ({ directory, filepath, http_client, lib, megaparsec, mkDerivation, mtl, process, stm, vector, zlib }:
+     mkDerivation {
+       pname = "filepath-475";
+       version = "2.5.3";
+       sha256 = "6idw9llhccs0nf4kfrdkqd8dinxgzg8wphimrxdf22fqlib28zpp";
+       libraryHaskellDepends = [
+         directory filepath http_client mtl process stm zlib
+       ];
+       testHaskellDepends = [ directory http_client megaparsec vector ];
+       homepage = "https://github.com/example/filepath-475";
+       description = "Package number 475";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "bytestring-476" = callPackage
+    ({ binary, containers, filepath, hashable, hspec, lib, megaparsec, mkDerivation, random }:
+     mkDerivation {
+       pname = "bytestring-476";
+       version = "3.15.0";
+       sha256 = "yb61a1sqgs9n28knrxq99kpc33ax1aq2d3rsbhq2k06aw46dd2sq";
+       libraryHaskellDepends = [
+         binary containers hspec megaparsec random
+       ];
+       testHaskellDepends = [ filepath hashable ];
+       homepage = "https://github.com/example/bytestring-476";
+       description = "Package number 476";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "random-477" = callPackage
+    ({ base, binary, bytestring, hashable, lib, mkDerivation, zlib }:
+     mkDerivation {
+       pname = "random-477";
+       version = "6.10.5";
+       sha256 = "j19r6r09x05sz15nbj608hllc14s23x2h9nb2idkyx0vyvmz6pi7";
+       libraryHaskellDepends = [
+         base binary bytestring hashable zlib
+       ];
+       homepage = "https://github.com/example/random-477";
+       description = "Package number 477";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "stm-478" = callPackage
+    ({ async, containers, deepseq, hspec, lib, mkDerivation, mtl, process, tasty }:
+     mkDerivation {
+       pname = "stm-478";
+       version = "5.17.0";
+       sha256 = "z1vqih3zvf2mpgzpd2rfgjbd5lqkkz03mcii7c0skpv5bm2xwzvx";
+       libraryHaskellDepends = [
+         async deepseq hspec process
+       ];
+       testHaskellDepends = [ containers mtl process tasty ];
+       postPatch = "substituteInPlace stm-478.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/stm-478";
+       description = "Package number 478";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "QuickCheck-479" = callPackage
+    ({ binary, criterion, deepseq, lib, mkDerivation, random, text, time, tls }:
+     mkDerivation {
+       pname = "QuickCheck-479";
+       version = "2.15.5";
+       sha256 = "rgx55fpy7zvf7vmqn80igipcc9m16iybwfrbr36s036xfvz99ypl";
+       libraryHaskellDepends = [
+         binary criterion deepseq random text time tls
+       ];
+       testHaskellDepends = [ text ];
+       postPatch = "substituteInPlace QuickCheck-479.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/QuickCheck-479";
+       description = "Package number 479";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "tls-480" = callPackage
+    ({ bytestring, criterion, filepath, hashable, hspec, lib, mkDerivation, network, parsec, process, text, vector }:
+     mkDerivation {
+       pname = "tls-480";
+       version = "0.5.0";
+       sha256 = "x6dvk9s0m5v71246b9yi2mlix1wlrwxhm6ds7n467gx1h0pkvp67";
+       libraryHaskellDepends = [
+         criterion filepath hashable parsec process vector
+       ];
+       testHaskellDepends = [ bytestring hspec network text ];
+       postPatch = "substituteInPlace tls-480.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/tls-480";
+       description = "Package number 480";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "directory-481" = callPackage
+    ({ base, bytestring, directory, hashable, lens, lib, mkDerivation, parsec }:
+     mkDerivation {
+       pname = "directory-481";
+       version = "7.13.3";
+       sha256 = "2vy1gnnddavv5px9hn3cz6ccah6zvj63k2mxnvr02bzvyc0wpsgr";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring directory parsec
+       ];
+       testHaskellDepends = [ base hashable lens parsec ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/directory-481";
+       description = "Package number 481";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "vector-482" = callPackage
+    ({ lib, mkDerivation, parsec, text, unordered_containers, vector }:
+     mkDerivation {
+       pname = "vector-482";
+       version = "9.11.5";
+       sha256 = "pjqyip8a518b0472akg8hv2fdzrvxng2na7s6v89v0rwki0ak7ii";
+       libraryHaskellDepends = [
+         parsec text
+       ];
+       testHaskellDepends = [ unordered_containers vector ];
+       homepage = "https://github.com/example/vector-482";
+       description = "Package number 482";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "containers-483" = callPackage
+    ({ QuickCheck, bytestring, deepseq, filepath, hashable, lib, mkDerivation, process }:
+     mkDerivation {
+       pname = "containers-483";
+       version = "7.6.8";
+       sha256 = "amjf6gfy8a0l4p3xkxxf2xcmf5s91iy9z8i32hk39af14n1pna6z";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck bytestring deepseq filepath hashable process
+       ];
+       homepage = "https://github.com/example/containers-483";
+       description = "Package number 483";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "bytestring-484" = callPackage
+    ({ bytestring, containers, filepath, lib, mkDerivation, parsec, tasty, time, tls }:
+     mkDerivation {
+       pname = "bytestring-484";
+       version = "3.7.0";
+       sha256 = "yij6k248yr295n3axv17whcd2a61x4985vpk001qwb6i3bds15x6";
+       libraryHaskellDepends = [
+         containers filepath parsec tasty tls
+       ];
+       testHaskellDepends = [ bytestring time ];
+       homepage = "https://github.com/example/bytestring-484";
+       description = "Package number 484";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "unordered-containers-485" = callPackage
+    ({ aeson, hashable, hspec, lib, mkDerivation, network, parsec, stm }:
+     mkDerivation {
+       pname = "unordered-containers-485";
+       version = "4.11.1";
+       sha256 = "lgdny9602g69mvdgw4n7s1g5qdrsz3cyz88h3fxhzpc96mbfngvw";
+       libraryHaskellDepends = [
+         hspec stm
+       ];
+       testHaskellDepends = [ aeson hashable network parsec ];
+       homepage = "https://github.com/example/unordered-containers-485";
+       description = "Package number 485";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "unordered-containers-486" = callPackage
+    ({ http_client, lib, mkDerivation, stm, time, tls }:
+     mkDerivation {
+       pname = "unordered-containers-486";
+       version = "6.16.0";
+       sha256 = "vz6l2dj1s1aakz0wzqbylph1gycpq200gm0rcpjg5c3qaf6aa828";
+       libraryHaskellDepends = [
+         http_client stm time tls
+       ];
+       homepage = "https://github.com/example/unordered-containers-486";
+       description = "Package number 486";
+       license = lib.licenses.mit;
+     }) {};
+
+  "transformers-487" = callPackage
+    ({ QuickCheck, base, bytestring, filepath, hspec, lib, megaparsec, mkDerivation, time, tls, transformers }:
+     mkDerivation {
+       pname = "transformers-487";
+       version = "3.0.5";
+       sha256 = "x2imr712accmxrl3232ysjb7m6maymnkbhz7nxgmyxmfzw1impcw";
+       libraryHaskellDepends = [
+         QuickCheck base bytestring filepath time tls transformers
+       ];
+       testHaskellDepends = [ filepath hspec megaparsec ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/transformers-487";
+       description = "Package number 487";
+       license = lib.licenses.mit;
+     }) {};
+
+  "binary-488" = callPackage
+    ({ bytestring, filepath, hspec, http_client, lib, mkDerivation, tasty, time, unordered_containers, vector }:
+     mkDerivation {
+       pname = "binary-488";
+       version = "1.7.8";
+       sha256 = "c4b5dr069r5izk8ildfpcix9225zgj3pwx7h3spja6z1g2v8wj6v";
+       libraryHaskellDepends = [
+         bytestring filepath http_client time unordered_containers vector
+       ];
+       testHaskellDepends = [ hspec tasty ];
+       homepage = "https://github.com/example/binary-488";
+       description = "Package number 488";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "aeson-489" = callPackage
+    ({ aeson, directory, filepath, hspec, lib, mkDerivation, parsec, transformers }:
+     mkDerivation {
+       pname = "aeson-489";
+       version = "0.14.5";
+       sha256 = "xkmfiwsng01wz4sgdhgfrriqcpxgwqivsw6lrsl3jh4is2d7ar40";
+       libraryHaskellDepends = [
+         aeson directory filepath hspec transformers
+       ];
+       testHaskellDepends = [ parsec ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/aeson-489";
+       description = "Package number 489";
+       license = lib.licenses.mit;
+     }) {};
+
+  "directory-490" = callPackage
+    ({ QuickCheck, aeson, async, base, directory, lib, mkDerivation, time, transformers, vector, zlib }:
+     mkDerivation {
+       pname = "directory-490";
+       version = "3.9.9";
+       sha256 = "k6g69pzq0xyk35sb5kbdv4l5y195bw1h19ay3ynf99hnwyndcx4r";
+       libraryHaskellDepends = [
+         QuickCheck async base directory time transformers vector
+       ];
+       testHaskellDepends = [ aeson zlib ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace directory-490.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/directory-490";
+       description = "Package number 490";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "tasty-491" = callPackage
+    ({ QuickCheck, binary, deepseq, http_client, lib, mkDerivation, network, tasty, time, zlib }:
+     mkDerivation {
+       pname = "tasty-491";
+       version = "7.5.5";
+       sha256 = "yqw8p3bxv950fif0krjx2a3k99fr70vh9l8hwj1qp1icjy9z7z4k";
+       libraryHaskellDepends = [
+         deepseq http_client network zlib
+       ];
+       testHaskellDepends = [ QuickCheck binary tasty time ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tasty-491";
+       description = "Package number 491";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "time-492" = callPackage
+    ({ filepath, lens, lib, megaparsec, mkDerivation, process, tasty, zlib }:
+     mkDerivation {
+       pname = "time-492";
+       version = "2.3.4";
+       sha256 = "p08mpfijrw2vwkxdzxxs6gx0cjn7c4sv65ns67vywdijs6h14waj";
+       libraryHaskellDepends = [
+         megaparsec process
+       ];
+       testHaskellDepends = [ filepath lens tasty zlib ];
+       homepage = "https://github.com/example/time-492";
+       description = "Package number 492";
+       license = lib.licenses.mit;
+     }) {};
+
+  "stm-493" = callPackage
+    ({ binary, containers, criterion, filepath, http_client, lib, mkDerivation, parsec, random, tasty }:
+     mkDerivation {
+       pname = "stm-493";
+       version = "8.14.5";
+       sha256 = "j8hz5sq0jknfsyn9b5s0c0ih6kfrmkjljlzs26rjb3cgdbs0ddah";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary containers criterion filepath http_client parsec random tasty
+       ];
+       homepage = "https://github.com/example/stm-493";
+       description = "Package number 493";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "tasty-494" = callPackage
+    ({ binary, directory, hashable, lib, megaparsec, mkDerivation, network, process, transformers, vector }:
+     mkDerivation {
+       pname = "tasty-494";
+       version = "6.8.6";
+       sha256 = "b23avnd9i9ar6xn7gc6rdxa3jqihfs3hnyprhxja7hx5x3d2ig1p";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary directory hashable megaparsec network process
+       ];
+       testHaskellDepends = [ hashable network transformers vector ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/tasty-494";
+       description = "Package number 494";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "stm-495" = callPackage
+    ({ bytestring, directory, filepath, hspec, http_client, lib, megaparsec, mkDerivation, time, transformers, zlib }:
+     mkDerivation {
+       pname = "stm-495";
+       version = "6.20.0";
+       sha256 = "b63hw9yz5awj4smqcc05fy8l8wd2rzp2w8d1q9mfb4rrabbkq8f8";
+       libraryHaskellDepends = [
+         bytestring filepath hspec megaparsec time zlib
+       ];
+       testHaskellDepends = [ directory filepath http_client transformers ];
+       homepage = "https://github.com/example/stm-495";
+       description = "Package number 495";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "hspec-496" = callPackage
+    ({ filepath, hashable, http_client, lib, megaparsec, mkDerivation, text, unordered_containers }:
+     mkDerivation {
+       pname = "hspec-496";
+       version = "9.11.8";
+       sha256 = "2ff69j201anmhh10xiy41jbpip36d71dp8laghbmai0z895085gf";
+       libraryHaskellDepends = [
+         hashable text unordered_containers
+       ];
+       testHaskellDepends = [ filepath http_client megaparsec ];
+       homepage = "https://github.com/example/hspec-496";
+       description = "Package number 496";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "mtl-497" = callPackage
+    ({ base, criterion, deepseq, lens, lib, mkDerivation, mtl, process, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "mtl-497";
+       version = "7.15.3";
+       sha256 = "l7yyx2prr4dkrywvz1kr34zcq7kjs6iwbzi3ffhvs44x53d90bsx";
+       libraryHaskellDepends = [
+         deepseq lens process unordered_containers
+       ];
+       testHaskellDepends = [ base criterion mtl zlib ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/mtl-497";
+       description = "Package number 497";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "hashable-498" = callPackage
+    ({ binary, lens, lib, mkDerivation, random, zlib }:
+     mkDerivation {
+       pname = "hashable-498";
+       version = "3.20.7";
+       sha256 = "v3mhqz9b8g6hqb1p0qjhqgc9pggbyjcbpn3qw2srw67kv50v9mrj";
+       libraryHaskellDepends = [
+         binary lens random zlib
+       ];
+       testHaskellDepends = [ zlib ];
+       homepage = "https://github.com/example/hashable-498";
+       description = "Package number 498";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "time-499" = callPackage
+    ({ directory, lib, mkDerivation, stm }:
+     mkDerivation {
+       pname = "time-499";
+       version = "8.16.9";
+       sha256 = "zsmzll087j0m3a1z9kfhwzg8znp78lfn33ikscfm4lcly88c914n";
+       libraryHaskellDepends = [
+         directory stm
+       ];
+       homepage = "https://github.com/example/time-499";
+       description = "Package number 499";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "parsec-500" = callPackage
+    ({ base, criterion, filepath, hashable, hspec, http_client, lib, mkDerivation, mtl, time }:
+     mkDerivation {
+       pname = "parsec-500";
+       version = "0.11.3";
+       sha256 = "8zd257zk75xw0apkk1s4b7vv7ppzn9jwv06szfs1ivwfjcgmr7qf";
+       libraryHaskellDepends = [
+         criterion filepath hashable hspec http_client mtl time
+       ];
+       testHaskellDepends = [ base hashable ];
+       homepage = "https://github.com/example/parsec-500";
+       description = "Package number 500";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "zlib-501" = callPackage
+    ({ aeson, bytestring, hspec, http_client, lens, lib, mkDerivation, mtl, tasty, time, unordered_containers }:
+     mkDerivation {
+       pname = "zlib-501";
+       version = "4.18.7";
+       sha256 = "l8pbhn60y7imw1638zxy4crmzk47l8sfxbv78a47w84q8w9h1g45";
+       libraryHaskellDepends = [
+         aeson bytestring hspec lens mtl tasty time unordered_containers
+       ];
+       testHaskellDepends = [ http_client ];
+       homepage = "https://github.com/example/zlib-501";
+       description = "Package number 501";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "directory-502" = callPackage
+    ({ containers, lib, mkDerivation, process, time, vector, zlib }:
+     mkDerivation {
+       pname = "directory-502";
+       version = "9.16.1";
+       sha256 = "bnvplyjjvl46xcqf61df8bj2z9szyfhy6bs82j24pkaf2bzh4bd1";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         containers process time vector zlib
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/directory-502";
+       description = "Package number 502";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "process-503" = callPackage
+    ({ async, base, filepath, http_client, lib, mkDerivation, mtl, tls, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "process-503";
+       version = "9.8.1";
+       sha256 = "ydpcwgbkvpp1fcwh2vb2vy1qv5qvan81fw58awwg7mdn6fxj27fa";
+       libraryHaskellDepends = [
+         async base filepath http_client mtl tls unordered_containers zlib
+       ];
+       homepage = "https://github.com/example/process-503";
+       description = "Package number 503";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "time-504" = callPackage
+    ({ async, containers, deepseq, hspec, http_client, lib, mkDerivation, parsec, random, stm, tls, vector }:
+     mkDerivation {
+       pname = "time-504";
+       version = "8.8.9";
+       sha256 = "nywr1jml25w8y7rlgrq1a9nr12sijnw5f4vaybwbl01snydnckrc";
+       libraryHaskellDepends = [
+         async deepseq http_client parsec random stm vector
+       ];
+       testHaskellDepends = [ containers hspec random tls ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/time-504";
+       description = "Package number 504";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "stm-505" = callPackage
+    ({ async, binary, lens, lib, megaparsec, mkDerivation, stm, tls, unordered_containers }:
+     mkDerivation {
+       pname = "stm-505";
+       version = "1.20.1";
+       sha256 = "2zh3yjrxb0rf6g4vinjaisi7k0wgnkh6ramxykg3l73nss0jdip9";
+       libraryHaskellDepends = [
+         binary lens megaparsec stm tls unordered_containers
+       ];
+       testHaskellDepends = [ async ];
+       homepage = "https://github.com/example/stm-505";
+       description = "Package number 505";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "network-506" = callPackage
+    ({ criterion, http_client, lib, mkDerivation, network, stm, text, time, unordered_containers }:
+     mkDerivation {
+       pname = "network-506";
+       version = "9.20.2";
+       sha256 = "2s6piskzdk52c53ck5s651ac3f20jq70mzy85j6x1vnjd42w7aqp";
+       libraryHaskellDepends = [
+         http_client text unordered_containers
+       ];
+       testHaskellDepends = [ criterion network stm time ];
+       homepage = "https://github.com/example/network-506";
+       description = "Package number 506";
+       license = lib.licenses.mit;
+     }) {};
+
+  "transformers-507" = callPackage
+    ({ filepath, hspec, lib, mkDerivation, random, tls, zlib }:
+     mkDerivation {
+       pname = "transformers-507";
+       version = "5.2.7";
+       sha256 = "yq8idm2x5ahdlraka0afmlwyq1583h9g8k9nn6krxnpg88rk25f2";
+       libraryHaskellDepends = [
+         filepath hspec random tls zlib
+       ];
+       testHaskellDepends = [ random ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/transformers-507";
+       description = "Package number 507";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "QuickCheck-508" = callPackage
+    ({ base, deepseq, http_client, lib, megaparsec, mkDerivation, network, stm, tasty, text }:
+     mkDerivation {
+       pname = "QuickCheck-508";
+       version = "7.19.6";
+       sha256 = "1yhzvdqpyrnr254ksl719ig8djl6xpbf75dw7q228z6f8zvmpa1s";
+       libraryHaskellDepends = [
+         base deepseq http_client megaparsec network stm tasty text
+       ];
+       testHaskellDepends = [ http_client ];
+       homepage = "https://github.com/example/QuickCheck-508";
+       description = "Package number 508";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "mtl-509" = callPackage
+    ({ bytestring, lib, mkDerivation, mtl, process, tasty }:
+     mkDerivation {
+       pname = "mtl-509";
+       version = "4.7.8";
+       sha256 = "v65k9lmf3crcs13wbmdj7rrils6a2rvzyvak703w9azggv9n5v6r";
+       libraryHaskellDepends = [
+         bytestring process tasty
+       ];
+       testHaskellDepends = [ mtl ];
+       homepage = "https://github.com/example/mtl-509";
+       description = "Package number 509";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "hspec-510" = callPackage
+    ({ async, base, criterion, lens, lib, mkDerivation, tls, vector }:
+     mkDerivation {
+       pname = "hspec-510";
+       version = "0.12.6";
+       sha256 = "kgfqign5gm0kbpazljnngvzycg30y00q7jsjdfk7zk30ak443rwf";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async base criterion lens vector
+       ];
+       testHaskellDepends = [ criterion lens tls ];
+       homepage = "https://github.com/example/hspec-510";
+       description = "Package number 510";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hspec-511" = callPackage
+    ({ criterion, filepath, lib, mkDerivation, unordered_containers, vector }:
+     mkDerivation {
+       pname = "hspec-511";
+       version = "9.20.9";
+       sha256 = "h1ynvzvxbj4ihjaaawzflvgb811vfwcmk22i19fgm5svj5b1pkps";
+       libraryHaskellDepends = [
+         unordered_containers vector
+       ];
+       testHaskellDepends = [ criterion filepath unordered_containers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/hspec-511";
+       description = "Package number 511";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "bytestring-512" = callPackage
+    ({ aeson, hspec, http_client, lib, megaparsec, mkDerivation, network, random, tasty, transformers, vector }:
+     mkDerivation {
+       pname = "bytestring-512";
+       version = "8.13.2";
+       sha256 = "al5cri1dqz7w462vim04f65d4k3d7wr5ky0m08c4b12jl7yq2z62";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         hspec http_client megaparsec network random tasty vector
+       ];
+       testHaskellDepends = [ aeson transformers ];
+       postPatch = "substituteInPlace bytestring-512.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/bytestring-512";
+       description = "Package number 512";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "lens-513" = callPackage
+    ({ hashable, lib, mkDerivation, network }:
+     mkDerivation {
+       pname = "lens-513";
+       version = "9.4.7";
+       sha256 = "fr85ln5vfq3s96pfzv5h0jfijd6p4h2c8hwq4z3qnnqd3yk7mhc9";
+       libraryHaskellDepends = [
+         hashable network
+       ];
+       homepage = "https://github.com/example/lens-513";
+       description = "Package number 513";
+       license = lib.licenses.mit;
+     }) {};
+
+  "deepseq-514" = callPackage
+    ({ aeson, containers, criterion, deepseq, hspec, lib, mkDerivation, tasty, text, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "deepseq-514";
+       version = "4.16.9";
+       sha256 = "97bmxhg9nybamiidqb2xc67c6aa3wgbm9nrfsd4and9z5xh2x5n8";
+       libraryHaskellDepends = [
+         containers deepseq hspec tasty text transformers
+       ];
+       testHaskellDepends = [ aeson criterion unordered_containers ];
+       homepage = "https://github.com/example/deepseq-514";
+       description = "Package number 514";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "transformers-515" = callPackage
+    ({ directory, lens, lib, mkDerivation, process, text, transformers, zlib }:
+     mkDerivation {
+       pname = "transformers-515";
+       version = "9.0.3";
+       sha256 = "xz7yksc12kpcwkv7bry5h291cjjlhw2bc8al7v4jn90q168jfsdi";
+       libraryHaskellDepends = [
+         directory transformers zlib
+       ];
+       testHaskellDepends = [ lens process text ];
+       homepage = "https://github.com/example/transformers-515";
+       description = "Package number 515";
+       license = lib.licenses.mit;
+     }) {};
+
+  "base-516" = callPackage
+    ({ base, criterion, http_client, lens, lib, mkDerivation, network, process, unordered_containers, vector, zlib }:
+     mkDerivation {
+       pname = "base-516";
+       version = "4.1.6";
+       sha256 = "k862n5zzr89kz7a6gk241784arnwk121znw265m3m2sdg5hp5gns";
+       libraryHaskellDepends = [
+         base criterion http_client lens network process vector zlib
+       ];
+       testHaskellDepends = [ unordered_containers ];
+       homepage = "https://github.com/example/base-516";
+       description = "Package number 516";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "text-517" = callPackage
+    ({ aeson, async, deepseq, lens, lib, mkDerivation, random, unordered_containers }:
+     mkDerivation {
+       pname = "text-517";
+       version = "4.20.4";
+       sha256 = "l1h8rbi9wa8c97ybwchx7micy7rlgv4rf8qyfjq779rmz0xclwkc";
+       libraryHaskellDepends = [
+         deepseq unordered_containers
+       ];
+       testHaskellDepends = [ aeson async lens random ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/text-517";
+       description = "Package number 517";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "binary-518" = callPackage
+    ({ QuickCheck, binary, criterion, deepseq, http_client, lib, mkDerivation, parsec, process, stm, time }:
+     mkDerivation {
+       pname = "binary-518";
+       version = "9.13.2";
+       sha256 = "7mdy1dy905hfaj2k7xqqj9hhaw428bpi7qhgm297npfrqgarb62s";
+       libraryHaskellDepends = [
+         QuickCheck binary criterion deepseq parsec stm time
+       ];
+       testHaskellDepends = [ http_client process ];
+       homepage = "https://github.com/example/binary-518";
+       description = "Package number 518";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "lens-519" = callPackage
+    ({ base, binary, criterion, hspec, http_client, lib, mkDerivation }:
+     mkDerivation {
+       pname = "lens-519";
+       version = "2.1.6";
+       sha256 = "9nf62nbq8dbn761jfzba8l1nwh78iyzdhdqwxd6zz2gs042gzrzh";
+       libraryHaskellDepends = [
+         base http_client
+       ];
+       testHaskellDepends = [ binary criterion hspec ];
+       homepage = "https://github.com/example/lens-519";
+       description = "Package number 519";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "vector-520" = callPackage
+    ({ base, binary, bytestring, deepseq, filepath, lib, mkDerivation, vector, zlib }:
+     mkDerivation {
+       pname = "vector-520";
+       version = "2.17.7";
+       sha256 = "y9mybba191s8mznm7daq7yypvbv897q9b1hlsf3v348ywapc1df7";
+       libraryHaskellDepends = [
+         base bytestring deepseq vector zlib
+       ];
+       testHaskellDepends = [ binary bytestring filepath ];
+       homepage = "https://github.com/example/vector-520";
+       description = "Package number 520";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "lens-521" = callPackage
+    ({ bytestring, lens, lib, mkDerivation, mtl, parsec, time, tls, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "lens-521";
+       version = "6.5.3";
+       sha256 = "f2rjwn60l3nrsixwx4baa4hj78abpvdisshbncc992qw363aa6ig";
+       libraryHaskellDepends = [
+         bytestring mtl time tls unordered_containers
+       ];
+       testHaskellDepends = [ bytestring lens parsec zlib ];
+       postPatch = "substituteInPlace lens-521.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/lens-521";
+       description = "Package number 521";
+       license = lib.licenses.mit;
+     }) {};
+
+  "parsec-522" = callPackage
+    ({ lib, mkDerivation, mtl, network, zlib }:
+     mkDerivation {
+       pname = "parsec-522";
+       version = "5.2.8";
+       sha256 = "205nxf2apfach1nw154k9nlqqvph721idlz6la7wpcdiamr5cwc0";
+       libraryHaskellDepends = [
+         mtl network zlib
+       ];
+       homepage = "https://github.com/example/parsec-522";
+       description = "Package number 522";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "aeson-523" = callPackage
+    ({ async, containers, lib, mkDerivation, parsec, time, transformers, zlib }:
+     mkDerivation {
+       pname = "aeson-523";
+       version = "3.6.2";
+       sha256 = "cpgg0s82am28blni1ma1a40gryx5alwri4q2ypvn7643i0jm5pjq";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async containers parsec time zlib
+       ];
+       testHaskellDepends = [ transformers ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/aeson-523";
+       description = "Package number 523";
+       license = lib.licenses.mit;
+     }) {};
+
+  "stm-524" = callPackage
+    ({ filepath, hspec, lib, mkDerivation, process, transformers, unordered_containers }:
+     mkDerivation {
+       pname = "stm-524";
+       version = "2.17.6";
+       sha256 = "v1i3bgclkfqca4c072qllxnzhyqcdr1n44s68pq8mf90z86smhnp";
+       libraryHaskellDepends = [
+         hspec process
+       ];
+       testHaskellDepends = [ filepath process transformers unordered_containers ];
+       homepage = "https://github.com/example/stm-524";
+       description = "Package number 524";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "random-525" = callPackage
+    ({ async, filepath, hashable, lib, mkDerivation, network, process, tls }:
+     mkDerivation {
+       pname = "random-525";
+       version = "5.18.2";
+       sha256 = "a62dmnj0cqz6vx0hrsvj98pwcdxmyjcn3n5vvw8j26dsr5nbnrjw";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async filepath hashable network process tls
+       ];
+       homepage = "https://github.com/example/random-525";
+       description = "Package number 525";
+       license = lib.licenses.mit;
+     }) {};
+
+  "text-526" = callPackage
+    ({ deepseq, hspec, lib, megaparsec, mkDerivation, vector, zlib }:
+     mkDerivation {
+       pname = "text-526";
+       version = "2.8.9";
+       sha256 = "c3qs84vb4yyjwh0635cw0hbjdql7gpwbq5dszfl9b79w7nkmagv8";
+       libraryHaskellDepends = [
+         deepseq hspec megaparsec zlib
+       ];
+       testHaskellDepends = [ vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/text-526";
+       description = "Package number 526";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "transformers-527" = callPackage
+    ({ async, containers, directory, lib, megaparsec, mkDerivation, mtl, random, tasty, vector }:
+     mkDerivation {
+       pname = "transformers-527";
+       version = "9.9.2";
+       sha256 = "6lxivrjza415snl9zv1lqa7z7fzidfzz6n2r8fm0l51k5hppmj9n";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async containers directory tasty vector
+       ];
+       testHaskellDepends = [ megaparsec mtl random ];
+       homepage = "https://github.com/example/transformers-527";
+       description = "Package number 527";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "parsec-528" = callPackage
+    ({ hashable, lens, lib, mkDerivation, mtl, process, time }:
+     mkDerivation {
+       pname = "parsec-528";
+       version = "6.9.1";
+       sha256 = "5ymlbs92im2n8bgjlfswrsa25r6hrnxc1jkp1sbrkrv0svglp1sm";
+       libraryHaskellDepends = [
+         hashable lens mtl process time
+       ];
+       testHaskellDepends = [ hashable ];
+       homepage = "https://github.com/example/parsec-528";
+       description = "Package number 528";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "vector-529" = callPackage
+    ({ base, bytestring, criterion, lib, megaparsec, mkDerivation, parsec, time }:
+     mkDerivation {
+       pname = "vector-529";
+       version = "2.10.4";
+       sha256 = "g76f8f6fvdnlmv0r2bqabapz8qr2glg2f0jjb2m485grcyq18zq0";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         bytestring criterion time
+       ];
+       testHaskellDepends = [ base criterion megaparsec parsec ];
+       homepage = "https://github.com/example/vector-529";
+       description = "Package number 529";
+       license = lib.licenses.mit;
+     }) {};
+
+  "directory-530" = callPackage
+    ({ QuickCheck, base, bytestring, criterion, directory, hspec, lens, lib, mkDerivation, network, text, vector, zlib }:
+     mkDerivation {
+       pname = "directory-530";
+       version = "3.10.9";
+       sha256 = "2vvzqc5xz4x6lbpi4k3m0a0gzhfzqm1q7df8wm6aw92f8zrcz8xi";
+       libraryHaskellDepends = [
+         QuickCheck bytestring criterion directory hspec vector zlib
+       ];
+       testHaskellDepends = [ base lens network text ];
+       postPatch = "substituteInPlace directory-530.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/directory-530";
+       description = "Package number 530";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "QuickCheck-531" = callPackage
+    ({ containers, deepseq, http_client, lib, mkDerivation, network, tasty, transformers, vector, zlib }:
+     mkDerivation {
+       pname = "QuickCheck-531";
+       version = "2.1.4";
+       sha256 = "hfkckl3iyclfnh2s4g000cjw2m24g2ji8sv0zlq49x9sn8kabw1d";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         containers http_client network tasty transformers vector zlib
+       ];
+       testHaskellDepends = [ deepseq ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/QuickCheck-531";
+       description = "Package number 531";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "lens-532" = callPackage
+    ({ aeson, async, binary, directory, filepath, lib, mkDerivation, mtl, parsec, text, zlib }:
+     mkDerivation {
+       pname = "lens-532";
+       version = "0.19.3";
+       sha256 = "gvbidms3wjhmnfhp69xmllh1nwiiv109s4h82x8w9gbf0jc54360";
+       libraryHaskellDepends = [
+         aeson async directory filepath mtl parsec text zlib
+       ];
+       testHaskellDepends = [ aeson binary ];
+       homepage = "https://github.com/example/lens-532";
+       description = "Package number 532";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "process-533" = callPackage
+    ({ base, lib, mkDerivation, random }:
+     mkDerivation {
+       pname = "process-533";
+       version = "7.4.4";
+       sha256 = "4y35nmvqb23fkvv37g45ncyyshkykvmmwp8ndf9hqc0gy73pi6iw";
+       libraryHaskellDepends = [
+         base random
+       ];
+       homepage = "https://github.com/example/process-533";
+       description = "Package number 533";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "hashable-534" = callPackage
+    ({ QuickCheck, aeson, binary, bytestring, containers, filepath, lib, megaparsec, mkDerivation, random, stm, tasty, text, time }:
+     mkDerivation {
+       pname = "hashable-534";
+       version = "4.9.9";
+       sha256 = "l4w7rswp7k473knwxpbjy8c57m4w8cmf51yl6f4kkkfbbs8hahnl";
+       libraryHaskellDepends = [
+         QuickCheck aeson bytestring filepath megaparsec random stm tasty
+       ];
+       testHaskellDepends = [ binary containers text time ];
+       postPatch = "substituteInPlace hashable-534.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/hashable-534";
+       description = "Package number 534";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "hashable-535" = callPackage
+    ({ base, hashable, lib, mkDerivation, mtl, tasty }:
+     mkDerivation {
+       pname = "hashable-535";
+       version = "4.1.5";
+       sha256 = "aj0w0yfran1ig0slsh2ia6cihbag0lhy1w0b6mli7wlai9q0ii60";
+       libraryHaskellDepends = [
+         base hashable mtl
+       ];
+       testHaskellDepends = [ tasty ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/hashable-535";
+       description = "Package number 535";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "random-536" = callPackage
+    ({ filepath, hspec, lib, mkDerivation, process, text, tls, unordered_containers }:
+     mkDerivation {
+       pname = "random-536";
+       version = "1.0.2";
+       sha256 = "2biz6jlp5v3d571ir0g6zypxlbl2y5cn80x7yba0kafm0z6mbapk";
+       libraryHaskellDepends = [
+         filepath hspec text tls unordered_containers
+       ];
+       testHaskellDepends = [ process ];
+       homepage = "https://github.com/example/random-536";
+       description = "Package number 536";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "base-537" = callPackage
+    ({ aeson, async, base, bytestring, containers, deepseq, directory, hspec, http_client, lib, mkDerivation, time }:
+     mkDerivation {
+       pname = "base-537";
+       version = "8.6.8";
+       sha256 = "1295zf5qfcfdqjc35rjlmbfr6s7n5rmz7vwp840mjhiwi25zcrqc";
+       libraryHaskellDepends = [
+         aeson async containers directory hspec http_client time
+       ];
+       testHaskellDepends = [ base bytestring deepseq ];
+       postPatch = "substituteInPlace base-537.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/base-537";
+       description = "Package number 537";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "vector-538" = callPackage
+    ({ aeson, criterion, directory, lib, mkDerivation, parsec, process, random, text, time, tls, transformers }:
+     mkDerivation {
+       pname = "vector-538";
+       version = "3.18.1";
+       sha256 = "j1mfhgjjs72syxwwr61h39x742scar3x2gpglxz1mxybpjkamd33";
+       libraryHaskellDepends = [
+         aeson criterion process random text time tls transformers
+       ];
+       testHaskellDepends = [ criterion directory parsec transformers ];
+       postPatch = "substituteInPlace vector-538.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/vector-538";
+       description = "Package number 538";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "containers-539" = callPackage
+    ({ containers, deepseq, directory, filepath, lib, mkDerivation, network, stm, tasty, text, tls }:
+     mkDerivation {
+       pname = "containers-539";
+       version = "0.1.4";
+       sha256 = "8ww6s68l0bdibs27hlnv37rvyfm10ljdz6wwiqd0dwbw0aqcqxxy";
+       libraryHaskellDepends = [
+         deepseq filepath stm tasty text
+       ];
+       testHaskellDepends = [ containers directory network tls ];
+       homepage = "https://github.com/example/containers-539";
+       description = "Package number 539";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "transformers-540" = callPackage
+    ({ QuickCheck, hashable, hspec, lib, mkDerivation, mtl, process, zlib }:
+     mkDerivation {
+       pname = "transformers-540";
+       version = "8.1.0";
+       sha256 = "sql1x8r9650npm1j1npm5gjjzbim5zal6vqm6vnf19ird6cjmdpz";
+       libraryHaskellDepends = [
+         QuickCheck hashable mtl process
+       ];
+       testHaskellDepends = [ hspec zlib ];
+       homepage = "https://github.com/example/transformers-540";
+       description = "Package number 540";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "stm-541" = callPackage
+    ({ QuickCheck, binary, bytestring, filepath, lib, mkDerivation, network, transformers }:
+     mkDerivation {
+       pname = "stm-541";
+       version = "1.3.2";
+       sha256 = "dajy5yc8adf48yzzssz6id2l93xk8ai0as19i96fd04jj9224g0l";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck binary bytestring filepath network transformers
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace stm-541.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/stm-541";
+       description = "Package number 541";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "bytestring-542" = callPackage
+    ({ containers, criterion, deepseq, lib, mkDerivation, mtl, network, process, tls, vector }:
+     mkDerivation {
+       pname = "bytestring-542";
+       version = "2.1.0";
+       sha256 = "4r9ppa9iyx5h4azwmzvqm8hz26yp3rbqk393dd62gv4kq5gaaf6n";
+       libraryHaskellDepends = [
+         containers criterion mtl network process tls vector
+       ];
+       testHaskellDepends = [ deepseq ];
+       postPatch = "substituteInPlace bytestring-542.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/bytestring-542";
+       description = "Package number 542";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "lens-543" = callPackage
+    ({ bytestring, containers, criterion, lens, lib, mkDerivation, mtl, random, stm, time, zlib }:
+     mkDerivation {
+       pname = "lens-543";
+       version = "5.2.5";
+       sha256 = "7x9n9ll2610nlmg22xajyzcw8i9axmshbr3ymnnlyi7dc381cla1";
+       libraryHaskellDepends = [
+         bytestring containers criterion mtl random stm time zlib
+       ];
+       testHaskellDepends = [ lens stm ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/lens-543";
+       description = "Package number 543";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "async-544" = callPackage
+    ({ aeson, deepseq, filepath, hashable, lib, megaparsec, mkDerivation, random, stm, transformers, vector }:
+     mkDerivation {
+       pname = "async-544";
+       version = "9.8.6";
+       sha256 = "mnchvqfr5nyjmiirnb9lsgna0c3n0m4nfn9xiqx55vva0f3qwlg0";
+       libraryHaskellDepends = [
+         aeson deepseq megaparsec transformers vector
+       ];
+       testHaskellDepends = [ filepath hashable random stm ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/async-544";
+       description = "Package number 544";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "lens-545" = callPackage
+    ({ QuickCheck, async, filepath, lib, mkDerivation, parsec, tasty, time }:
+     mkDerivation {
+       pname = "lens-545";
+       version = "6.12.6";
+       sha256 = "rnlk29vixkjmf3z0cb7cf7rgvd1zis2625j91cyn251pyp3qxrm2";
+       libraryHaskellDepends = [
+         QuickCheck async filepath tasty time
+       ];
+       testHaskellDepends = [ parsec ];
+       homepage = "https://github.com/example/lens-545";
+       description = "Package number 545";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "async-546" = callPackage
+    ({ bytestring, filepath, hashable, http_client, lib, mkDerivation, network, random, tasty }:
+     mkDerivation {
+       pname = "async-546";
+       version = "6.2.7";
+       sha256 = "wmjmffcvhsb4sxhrdw8g2h5zjx39ab1n9vrgxy5bcwkr6dij9h1g";
+       libraryHaskellDepends = [
+         filepath hashable http_client network tasty
+       ];
+       testHaskellDepends = [ bytestring random tasty ];
+       homepage = "https://github.com/example/async-546";
+       description = "Package number 546";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "lens-547" = callPackage
+    ({ binary, criterion, http_client, lib, mkDerivation, text, tls }:
+     mkDerivation {
+       pname = "lens-547";
+       version = "2.0.8";
+       sha256 = "8sxw7zi253rvqw0f6yfgadg9n020l1pmnxrn2ln17vyqzk7i2xf6";
+       libraryHaskellDepends = [
+         criterion http_client text
+       ];
+       testHaskellDepends = [ binary tls ];
+       homepage = "https://github.com/example/lens-547";
+       description = "Package number 547";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "tasty-548" = callPackage
+    ({ containers, deepseq, hspec, lens, lib, mkDerivation, network, stm, unordered_containers }:
+     mkDerivation {
+       pname = "tasty-548";
+       version = "3.17.0";
+       sha256 = "6ggcxlx6wsqajna1158sr4g7szx1ynqj48ckxbdqwdrrx68m6qgs";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         deepseq lens network stm unordered_containers
+       ];
+       testHaskellDepends = [ containers hspec ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tasty-548";
+       description = "Package number 548";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "lens-549" = callPackage
+    ({ aeson, directory, hspec, lib, mkDerivation, mtl, network, parsec, random, stm, tasty }:
+     mkDerivation {
+       pname = "lens-549";
+       version = "8.4.5";
+       sha256 = "1hxzcg7khgxx29fwhq9qf0iqwqs2a5sf3rbisfv2hl4abnwijidp";
+       libraryHaskellDepends = [
+         directory hspec mtl random tasty
+       ];
+       testHaskellDepends = [ aeson network parsec stm ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/lens-549";
+       description = "Package number 549";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "containers-550" = callPackage
+    ({ async, containers, lens, lib, mkDerivation, parsec, vector, zlib }:
+     mkDerivation {
+       pname = "containers-550";
+       version = "4.13.1";
+       sha256 = "b789j6w4ag41rahrhkvjnvcidjyq4dp91bcgk3kdg5nq58p257fg";
+       libraryHaskellDepends = [
+         async lens parsec vector
+       ];
+       testHaskellDepends = [ containers vector zlib ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/containers-550";
+       description = "Package number 550";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "criterion-551" = callPackage
+    ({ QuickCheck, aeson, binary, filepath, lib, megaparsec, mkDerivation, mtl, parsec, time, unordered_containers }:
+     mkDerivation {
+       pname = "criterion-551";
+       version = "2.12.6";
+       sha256 = "jnpsgii1kiz41li73rqbh9s53dk4s2cjidlrpzvrz29xc24p4l5y";
+       libraryHaskellDepends = [
+         aeson binary mtl parsec unordered_containers
+       ];
+       testHaskellDepends = [ QuickCheck filepath megaparsec time ];
+       homepage = "https://github.com/example/criterion-551";
+       description = "Package number 551";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "network-552" = callPackage
+    ({ base, deepseq, hspec, lens, lib, mkDerivation, stm, vector }:
+     mkDerivation {
+       pname = "network-552";
+       version = "7.7.4";
+       sha256 = "9rdry20l3c3g586wpqvc7w5kpgf75jwq4zyfz2x7kqrnsn2rzbcv";
+       libraryHaskellDepends = [
+         base deepseq hspec lens stm vector
+       ];
+       homepage = "https://github.com/example/network-552";
+       description = "Package number 552";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "process-553" = callPackage
+    ({ QuickCheck, async, hspec, http_client, lib, megaparsec, mkDerivation, parsec, random, vector }:
+     mkDerivation {
+       pname = "process-553";
+       version = "4.11.8";
+       sha256 = "lhrsbfvjaxrpsjkwyja8p57g90h35kdv0xzbya1sas04hqy7qjfd";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck async hspec vector
+       ];
+       testHaskellDepends = [ http_client megaparsec parsec random ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/process-553";
+       description = "Package number 553";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "mtl-554" = callPackage
+    ({ binary, lib, mkDerivation, tasty, time, tls, zlib }:
+     mkDerivation {
+       pname = "mtl-554";
+       version = "5.12.3";
+       sha256 = "yghgmdb7jilqflfl5zracnf9xa2lvphyzsylr413j2gagjw3xnky";
+       libraryHaskellDepends = [
+         binary tls
+       ];
+       testHaskellDepends = [ tasty time zlib ];
+       homepage = "https://github.com/example/mtl-554";
+       description = "Package number 554";
+       license = lib.licenses.mit;
+     }) {};
+
+  "parsec-555" = callPackage
+    ({ QuickCheck, aeson, base, binary, bytestring, filepath, lib, megaparsec, mkDerivation, mtl, stm, text, time }:
+     mkDerivation {
+       pname = "parsec-555";
+       version = "9.1.8";
+       sha256 = "6i5m6p649fimzw4nv5l290kh85np2cja5dnpmbvhc6ia5c2b0zzi";
+       libraryHaskellDepends = [
+         QuickCheck base binary filepath megaparsec mtl text time
+       ];
+       testHaskellDepends = [ aeson bytestring mtl stm ];
+       homepage = "https://github.com/example/parsec-555";
+       description = "Package number 555";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "async-556" = callPackage
+    ({ aeson, bytestring, containers, criterion, deepseq, hspec, http_client, lib, megaparsec, mkDerivation, parsec, random, stm, vector }:
+     mkDerivation {
+       pname = "async-556";
+       version = "6.14.9";
+       sha256 = "d5a9m3ziilj4aznv807lis62970ndx1rwl7xmyz356blrk8gj6r7";
+       libraryHaskellDepends = [
+         aeson bytestring containers http_client megaparsec parsec random vector
+       ];
+       testHaskellDepends = [ criterion deepseq hspec stm ];
+       homepage = "https://github.com/example/async-556";
+       description = "Package number 556";
+       license = lib.licenses.bsd3;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "base-557" = callPackage
+    ({ deepseq, lens, lib, mkDerivation, network, stm }:
+     mkDerivation {
+       pname = "base-557";
+       version = "6.7.5";
+       sha256 = "qlpghn2y4ki6m9fsm2r209krjgswk88sxrsncl5v7n9m04hi6940";
+       libraryHaskellDepends = [
+         deepseq lens network stm
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/base-557";
+       description = "Package number 557";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "mtl-558" = callPackage
+    ({ base, binary, bytestring, deepseq, lib, mkDerivation, mtl, random, time, zlib }:
+     mkDerivation {
+       pname = "mtl-558";
+       version = "4.9.8";
+       sha256 = "xxmz989mij7ync09q4hbrqm1rc7z4db3g6mg640z4f8wfl0dms9c";
+       libraryHaskellDepends = [
+         base binary mtl random time
+       ];
+       testHaskellDepends = [ bytestring deepseq zlib ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace mtl-558.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/mtl-558";
+       description = "Package number 558";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "mtl-559" = callPackage
+    ({ QuickCheck, aeson, async, http_client, lib, mkDerivation, network, parsec, tls }:
+     mkDerivation {
+       pname = "mtl-559";
+       version = "0.0.5";
+       sha256 = "89890j004xmmb8nvbzn37sdjbx2wadq4d1lfjh0wsvf3agbdgiak";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck aeson async http_client network parsec tls
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace mtl-559.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/mtl-559";
+       description = "Package number 559";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "transformers-560" = callPackage
+    ({ QuickCheck, aeson, async, binary, bytestring, criterion, lib, mkDerivation, random, stm, tls, zlib }:
+     mkDerivation {
+       pname = "transformers-560";
+       version = "2.11.5";
+       sha256 = "173sg72b6ssxc5qqd4s77rcrbpcabmggsj589pzknpcc0pfd3py6";
+       libraryHaskellDepends = [
+         QuickCheck async binary random stm tls zlib
+       ];
+       testHaskellDepends = [ aeson bytestring criterion random ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace transformers-560.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/transformers-560";
+       description = "Package number 560";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "time-561" = callPackage
+    ({ async, criterion, deepseq, hspec, lens, lib, mkDerivation, network, time }:
+     mkDerivation {
+       pname = "time-561";
+       version = "5.5.4";
+       sha256 = "54wnpycrdgiyyy4n40h1q0h446vs1kaf61hsghdy2v0wyypmdi0m";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async criterion hspec
+       ];
+       testHaskellDepends = [ deepseq lens network time ];
+       homepage = "https://github.com/example/time-561";
+       description = "Package number 561";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "directory-562" = callPackage
+    ({ criterion, deepseq, lib, mkDerivation, vector }:
+     mkDerivation {
+       pname = "directory-562";
+       version = "6.12.6";
+       sha256 = "hqqq5x19ajl569kvnng3zwa7qym38vxpa5svrqq65rifqdb47bfq";
+       libraryHaskellDepends = [
+         criterion deepseq vector
+       ];
+       homepage = "https://github.com/example/directory-562";
+       description = "Package number 562";
+       license = lib.licenses.gpl2Only;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "QuickCheck-563" = callPackage
+    ({ base, containers, criterion, lib, megaparsec, mkDerivation, parsec, text }:
+     mkDerivation {
+       pname = "QuickCheck-563";
+       version = "6.2.4";
+       sha256 = "gk8x1q8048n8fn4s1hx66nwi4a2wpczrb7k6z8m01qnks6qkd25x";
+       libraryHaskellDepends = [
+         base containers criterion megaparsec parsec text
+       ];
+       homepage = "https://github.com/example/QuickCheck-563";
+       description = "Package number 563";
+       license = lib.licenses.mit;
+     }) {};
+
+  "stm-564" = callPackage
+    ({ containers, criterion, filepath, hashable, hspec, http_client, lib, mkDerivation, network, tasty, text, unordered_containers, vector, zlib }:
+     mkDerivation {
+       pname = "stm-564";
+       version = "5.14.5";
+       sha256 = "zbfwc5jxi0d2jd5kwyq64jscirfygcxd1jyc7zbaxg95rqnnsz20";
+       libraryHaskellDepends = [
+         containers criterion http_client network tasty text unordered_containers vector
+       ];
+       testHaskellDepends = [ filepath hashable hspec zlib ];
+       homepage = "https://github.com/example/stm-564";
+       description = "Package number 564";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "aeson-565" = callPackage
+    ({ base, filepath, lens, lib, megaparsec, mkDerivation, parsec, process, random, stm, tls }:
+     mkDerivation {
+       pname = "aeson-565";
+       version = "1.3.9";
+       sha256 = "fqh9dinbrf2hzl1z05xbgrxk63j7n7m5n5bxbplqzn6y2229yw0i";
+       libraryHaskellDepends = [
+         filepath lens megaparsec process random stm tls
+       ];
+       testHaskellDepends = [ base parsec ];
+       postPatch = "substituteInPlace aeson-565.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/aeson-565";
+       description = "Package number 565";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "mtl-566" = callPackage
+    ({ QuickCheck, async, binary, lib, mkDerivation, process, text }:
+     mkDerivation {
+       pname = "mtl-566";
+       version = "2.15.6";
+       sha256 = "65aly072rlfpa8q9snxcmisjj7hcbbw2qhmspys9phfd562aagw9";
+       libraryHaskellDepends = [
+         QuickCheck async binary text
+       ];
+       testHaskellDepends = [ QuickCheck process ];
+       postPatch = "substituteInPlace mtl-566.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/mtl-566";
+       description = "Package number 566";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "http-client-567" = callPackage
+    ({ aeson, filepath, hashable, http_client, lib, megaparsec, mkDerivation, parsec, time }:
+     mkDerivation {
+       pname = "http-client-567";
+       version = "3.17.2";
+       sha256 = "0hhy4nrb4v2xfz3qxd89l77rvjmfx1inp2h0cmz26ahnfdh3p43m";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson filepath hashable http_client megaparsec parsec time
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace http-client-567.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/http-client-567";
+       description = "Package number 567";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "megaparsec-568" = callPackage
+    ({ bytestring, criterion, directory, filepath, lib, mkDerivation, random, time, tls, zlib }:
+     mkDerivation {
+       pname = "megaparsec-568";
+       version = "1.7.1";
+       sha256 = "7lr747n4apy6yg5amww5zz3g0wl46kcsa3c4hkg96011cy2sb747";
+       libraryHaskellDepends = [
+         bytestring criterion directory filepath random time tls zlib
+       ];
+       homepage = "https://github.com/example/megaparsec-568";
+       description = "Package number 568";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "criterion-569" = callPackage
+    ({ deepseq, filepath, lib, megaparsec, mkDerivation, parsec, unordered_containers, vector }:
+     mkDerivation {
+       pname = "criterion-569";
+       version = "6.9.2";
+       sha256 = "pbr6dpn6bkzjcqiwgxwxdny7mdm3xkbiyg3vkxzzsand8y60ixrk";
+       libraryHaskellDepends = [
+         filepath megaparsec unordered_containers vector
+       ];
+       testHaskellDepends = [ deepseq megaparsec parsec ];
+       homepage = "https://github.com/example/criterion-569";
+       description = "Package number 569";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "containers-570" = callPackage
+    ({ bytestring, criterion, directory, lib, mkDerivation, process, time, vector }:
+     mkDerivation {
+       pname = "containers-570";
+       version = "1.17.9";
+       sha256 = "304yy6bl39w072rh8gf7jzhdjp3pgkns27fpva102prxmd883rp9";
+       libraryHaskellDepends = [
+         time vector
+       ];
+       testHaskellDepends = [ bytestring criterion directory process ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/containers-570";
+       description = "Package number 570";
+       license = lib.licenses.mit;
+     }) {};
+
+  "mtl-571" = callPackage
+    ({ base, containers, filepath, hashable, lib, mkDerivation, parsec }:
+     mkDerivation {
+       pname = "mtl-571";
+       version = "5.6.4";
+       sha256 = "8mazxgnhiwxwn08zq2plgb3w5lpmkrhbl96jrpi4f8clycxc8wvj";
+       libraryHaskellDepends = [
+         base containers filepath hashable parsec
+       ];
+       homepage = "https://github.com/example/mtl-571";
+       description = "Package number 571";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "time-572" = callPackage
+    ({ aeson, containers, deepseq, hashable, http_client, lens, lib, mkDerivation, process, text, transformers }:
+     mkDerivation {
+       pname = "time-572";
+       version = "7.2.1";
+       sha256 = "smfghjw036vc95nap5df7ck412z3d3d6wcjps9anrzg96x44gzkx";
+       libraryHaskellDepends = [
+         deepseq hashable http_client lens process
+       ];
+       testHaskellDepends = [ aeson containers text transformers ];
+       homepage = "https://github.com/example/time-572";
+       description = "Package number 572";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "containers-573" = callPackage
+    ({ base, binary, criterion, hspec, lib, mkDerivation, stm, tasty, text, tls }:
+     mkDerivation {
+       pname = "containers-573";
+       version = "0.6.3";
+       sha256 = "pn55d7xbdg057y0r5p4x3ajcr541p6jpji730zqilbf6sx943qj3";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base binary hspec stm text tls
+       ];
+       testHaskellDepends = [ criterion tasty ];
+       postPatch = "substituteInPlace containers-573.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/containers-573";
+       description = "Package number 573";
+       license = lib.licenses.mit;
+     }) {};
+
+  "parsec-574" = callPackage
+    ({ QuickCheck, aeson, async, binary, containers, directory, hashable, lib, mkDerivation, text, transformers, vector }:
+     mkDerivation {
+       pname = "parsec-574";
+       version = "6.5.8";
+       sha256 = "hd272w0wqm1qla1y2lznffziwbi3v775fncg31g4bxp5q16hhg3j";
+       libraryHaskellDepends = [
+         QuickCheck aeson async binary directory hashable transformers vector
+       ];
+       testHaskellDepends = [ binary containers text ];
+       homepage = "https://github.com/example/parsec-574";
+       description = "Package number 574";
+       license = lib.licenses.bsd3;
+     }) {};
+
+  "time-575" = callPackage
+    ({ binary, http_client, lib, mkDerivation, random, text, transformers }:
+     mkDerivation {
+       pname = "time-575";
+       version = "5.18.2";
+       sha256 = "35mds5ih03fdc1jabfd0904iisafpiky16pzf71vsfa50rqan0p3";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         binary http_client random text transformers
+       ];
+       homepage = "https://github.com/example/time-575";
+       description = "Package number 575";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "tasty-576" = callPackage
+    ({ directory, hspec, lib, megaparsec, mkDerivation, time }:
+     mkDerivation {
+       pname = "tasty-576";
+       version = "5.1.1";
+       sha256 = "dz9r25ga1ds7p3wv9dig9bqk1mi10drmyk29kgl53d5dr3m9983x";
+       libraryHaskellDepends = [
+         directory hspec time
+       ];
+       testHaskellDepends = [ megaparsec ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/tasty-576";
+       description = "Package number 576";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "http-client-577" = callPackage
+    ({ containers, hashable, lib, mkDerivation, mtl, random, transformers }:
+     mkDerivation {
+       pname = "http-client-577";
+       version = "6.13.1";
+       sha256 = "ad40bwcd5wgn94wdprz9r7aw57d48m429z60xmwy18fhlz9xp630";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         containers hashable transformers
+       ];
+       testHaskellDepends = [ mtl random ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/http-client-577";
+       description = "Package number 577";
+       license = lib.licenses.mit;
+     }) {};
+
+  "process-578" = callPackage
+    ({ QuickCheck, base, binary, lib, mkDerivation, mtl, random, tasty, unordered_containers, vector }:
+     mkDerivation {
+       pname = "process-578";
+       version = "9.10.7";
+       sha256 = "5q0q3b3szbj3w630vg8lvxbii2s190r12y2hk7mplcwlcp9ipd4b";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck base binary mtl random unordered_containers vector
+       ];
+       testHaskellDepends = [ tasty ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/process-578";
+       description = "Package number 578";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "async-579" = callPackage
+    ({ binary, criterion, hspec, lib, mkDerivation, tls, vector }:
+     mkDerivation {
+       pname = "async-579";
+       version = "7.7.9";
+       sha256 = "gqis8y3bv0nzs08rqr7snhf5awpz119xf59bycyd7kadf5gkq3z7";
+       libraryHaskellDepends = [
+         binary vector
+       ];
+       testHaskellDepends = [ criterion hspec tls ];
+       homepage = "https://github.com/example/async-579";
+       description = "Package number 579";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "binary-580" = callPackage
+    ({ QuickCheck, aeson, async, hashable, hspec, lib, megaparsec, mkDerivation, network, parsec, text }:
+     mkDerivation {
+       pname = "binary-580";
+       version = "9.4.8";
+       sha256 = "cfikjcp5ws6dpipj7qc4b240b8bd5wkwddfvlag5s1hsmzpxcl74";
+       libraryHaskellDepends = [
+         aeson async hspec megaparsec network parsec text
+       ];
+       testHaskellDepends = [ QuickCheck async hashable hspec ];
+       homepage = "https://github.com/example/binary-580";
+       description = "Package number 580";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "stm-581" = callPackage
+    ({ async, bytestring, containers, directory, lib, mkDerivation, network, tasty, time, zlib }:
+     mkDerivation {
+       pname = "stm-581";
+       version = "9.15.2";
+       sha256 = "8090vd9ycvwk5hr6qannvsb47j376xi6whlq39i9digcfaja235r";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async bytestring containers directory tasty time zlib
+       ];
+       testHaskellDepends = [ network ];
+       homepage = "https://github.com/example/stm-581";
+       description = "Package number 581";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "deepseq-582" = callPackage
+    ({ deepseq, directory, lib, mkDerivation, parsec, random, stm, text, time, vector }:
+     mkDerivation {
+       pname = "deepseq-582";
+       version = "4.8.2";
+       sha256 = "6br8zk4s445qlb1gy3dk3a7xlssy5qzvmjnwq43c68dbcfmdsw99";
+       libraryHaskellDepends = [
+         deepseq directory random stm text time vector
+       ];
+       testHaskellDepends = [ parsec ];
+       homepage = "https://github.com/example/deepseq-582";
+       description = "Package number 582";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "lens-583" = callPackage
+    ({ async, containers, lib, mkDerivation, process, time }:
+     mkDerivation {
+       pname = "lens-583";
+       version = "8.18.7";
+       sha256 = "aj4xhv377f9s4lgwdqa05ki7acg80g4dl27rnyfavajyjkq8fbc2";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async containers process time
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/lens-583";
+       description = "Package number 583";
+       license = lib.licenses.mit;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "text-584" = callPackage
+    ({ QuickCheck, base, containers, hashable, lens, lib, megaparsec, mkDerivation, random, transformers }:
+     mkDerivation {
+       pname = "text-584";
+       version = "2.11.9";
+       sha256 = "2zbcwpjipwdis5wcps4dygbqhzxiv1jwmpjja16lzlgr8c2sayna";
+       libraryHaskellDepends = [
+         base containers hashable lens megaparsec random transformers
+       ];
+       testHaskellDepends = [ QuickCheck base hashable ];
+       homepage = "https://github.com/example/text-584";
+       description = "Package number 584";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "time-585" = callPackage
+    ({ filepath, http_client, lib, mkDerivation, network, parsec, process, transformers }:
+     mkDerivation {
+       pname = "time-585";
+       version = "2.4.0";
+       sha256 = "fdbvcl46aif93b867ljc287ja1hgnx91dwvf3358897air5xjmch";
+       libraryHaskellDepends = [
+         http_client network
+       ];
+       testHaskellDepends = [ filepath parsec process transformers ];
+       configureFlags = lib.optionals (pkgs.stdenv.hostPlatform.isDarwin or false) [ "-fdarwin" ] ++ [ "-O2" ];
+       homepage = "https://github.com/example/time-585";
+       description = "Package number 585";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "random-586" = callPackage
+    ({ QuickCheck, http_client, lib, mkDerivation, network, random, transformers }:
+     mkDerivation {
+       pname = "random-586";
+       version = "5.14.2";
+       sha256 = "n2mhk1x5d0b3zph13jsl3nhva0nfqqb9brjinbyzdprsmkk3nhsq";
+       libraryHaskellDepends = [
+         network random transformers
+       ];
+       testHaskellDepends = [ QuickCheck http_client ];
+       postPatch = "substituteInPlace random-586.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/random-586";
+       description = "Package number 586";
+       license = lib.licenses.mit;
+     }) {};
+
+  "transformers-587" = callPackage
+    ({ binary, directory, hashable, lens, lib, mkDerivation, mtl, network, process, text, transformers }:
+     mkDerivation {
+       pname = "transformers-587";
+       version = "2.2.6";
+       sha256 = "4z025ibdpwxdxc73pjayvlhkax18lzfdjfv8026czsx00rx263ik";
+       libraryHaskellDepends = [
+         directory hashable lens mtl network process text transformers
+       ];
+       testHaskellDepends = [ binary lens transformers ];
+       homepage = "https://github.com/example/transformers-587";
+       description = "Package number 587";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "aeson-588" = callPackage
+    ({ async, binary, containers, criterion, http_client, lib, megaparsec, mkDerivation }:
+     mkDerivation {
+       pname = "aeson-588";
+       version = "1.14.1";
+       sha256 = "gwxiiyclm6zj8m0mx643zydjagf9fhmib0d5s9d2cas5snlfwc2s";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async binary criterion
+       ];
+       testHaskellDepends = [ containers http_client megaparsec ];
+       postPatch = "substituteInPlace aeson-588.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/aeson-588";
+       description = "Package number 588";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "text-589" = callPackage
+    ({ async, binary, lib, mkDerivation, tasty }:
+     mkDerivation {
+       pname = "text-589";
+       version = "0.9.9";
+       sha256 = "mn9jlha2nrfg2qqi1h9r835cndbl1lbws3wps2c74cnbrm86mzss";
+       libraryHaskellDepends = [
+         async binary
+       ];
+       testHaskellDepends = [ tasty ];
+       homepage = "https://github.com/example/text-589";
+       description = "Package number 589";
+       license = lib.licenses.mit;
+     }) {};
+
+  "vector-590" = callPackage
+    ({ async, criterion, http_client, lib, mkDerivation, tls, transformers }:
+     mkDerivation {
+       pname = "vector-590";
+       version = "8.13.0";
+       sha256 = "0sz5qxpjyqacqg9n4z8ji6849wybk431dabbza6wp373118y958x";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         async transformers
+       ];
+       testHaskellDepends = [ criterion http_client tls ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace vector-590.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/vector-590";
+       description = "Package number 590";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "criterion-591" = callPackage
+    ({ aeson, base, filepath, hashable, lib, mkDerivation, stm, text, time, vector, zlib }:
+     mkDerivation {
+       pname = "criterion-591";
+       version = "4.8.3";
+       sha256 = "9jqk828n9i042xmcpyrahm2z32dapw6ky1jk7050f8dg3kgl6436";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         aeson base stm text time zlib
+       ];
+       testHaskellDepends = [ filepath hashable vector ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/criterion-591";
+       description = "Package number 591";
+       license = lib.licenses.gpl2Only;
+     }) {};
+
+  "random-592" = callPackage
+    ({ QuickCheck, async, criterion, http_client, lib, mkDerivation, transformers }:
+     mkDerivation {
+       pname = "random-592";
+       version = "9.14.2";
+       sha256 = "lqp3vm0pfdsrll95z73qf7d64a4c0362xk1wflhd4dj69dc4y9mx";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck async criterion http_client transformers
+       ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/random-592";
+       description = "Package number 592";
+       license = lib.licenses.mit;
+     }) {};
+
+  "hspec-593" = callPackage
+    ({ QuickCheck, async, deepseq, http_client, lib, mkDerivation, tasty }:
+     mkDerivation {
+       pname = "hspec-593";
+       version = "3.14.0";
+       sha256 = "fiiryj3syavpnanigk9p65cmvgm52r26kll1xcppd8vrxhc9lq5j";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         QuickCheck async tasty
+       ];
+       testHaskellDepends = [ deepseq http_client ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       postPatch = "substituteInPlace hspec-593.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/hspec-593";
+       description = "Package number 593";
+       license = lib.licenses.mit;
+     }) {};
+
+  "text-594" = callPackage
+    ({ async, bytestring, containers, hspec, lens, lib, mkDerivation, network, text, tls, vector }:
+     mkDerivation {
+       pname = "text-594";
+       version = "3.15.8";
+       sha256 = "ax7svd235g3pam8gd8zd1k9jch3sgxv0vq1001nvgdr59zxyakj6";
+       libraryHaskellDepends = [
+         async bytestring lens network text tls
+       ];
+       testHaskellDepends = [ containers hspec tls vector ];
+       homepage = "https://github.com/example/text-594";
+       description = "Package number 594";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "QuickCheck-595" = callPackage
+    ({ async, binary, hspec, lens, lib, mkDerivation, parsec, random, tasty, unordered_containers }:
+     mkDerivation {
+       pname = "QuickCheck-595";
+       version = "0.9.3";
+       sha256 = "8p0k6544w3792gibr1scj2cx9vw4hqfhcgvlvr1swjvfpi5fary3";
+       libraryHaskellDepends = [
+         async binary lens parsec
+       ];
+       testHaskellDepends = [ hspec random tasty unordered_containers ];
+       postPatch = "substituteInPlace QuickCheck-595.cabal --replace ${lib.escapeShellArg "base"} base";
+       homepage = "https://github.com/example/QuickCheck-595";
+       description = "Package number 595";
+       license = lib.licenses.lgpl21Plus;
+     }) {};
+
+  "http-client-596" = callPackage
+    ({ aeson, binary, lib, mkDerivation, tls, transformers }:
+     mkDerivation {
+       pname = "http-client-596";
+       version = "9.18.4";
+       sha256 = "snx7i9byrdyh2lnw2j9kfyhgzrrwq7kccpgz9ps65wnk5cfj1j66";
+       libraryHaskellDepends = [
+         aeson binary
+       ];
+       testHaskellDepends = [ tls transformers ];
+       homepage = "https://github.com/example/http-client-596";
+       description = "Package number 596";
+       license = lib.licenses.mit;
+     }) {};
+
+  "transformers-597" = callPackage
+    ({ binary, containers, deepseq, filepath, hashable, lib, mkDerivation, stm, time, unordered_containers, zlib }:
+     mkDerivation {
+       pname = "transformers-597";
+       version = "8.4.1";
+       sha256 = "alk1abvikf568cb05q92z0x4gsrfm3winy42g6av5y4z1aj1xg4s";
+       libraryHaskellDepends = [
+         containers deepseq filepath stm unordered_containers
+       ];
+       testHaskellDepends = [ binary hashable time zlib ];
+       homepage = "https://github.com/example/transformers-597";
+       description = "Package number 597";
+       license = lib.licenses.asl20;
+     }) {};
+
+  "random-598" = callPackage
+    ({ base, containers, http_client, lens, lib, mkDerivation, parsec, random, vector, zlib }:
+     mkDerivation {
+       pname = "random-598";
+       version = "8.6.2";
+       sha256 = "5aqpvly05yg1cj8r0y7amba7q4n40srdzkxrc2wjqxg1j0sykimm";
+       isLibrary = true;
+       isExecutable = false;
+       libraryHaskellDepends = [
+         base containers http_client lens parsec random vector
+       ];
+       testHaskellDepends = [ lens zlib ];
+       doCheck = !(lib.versionOlder version "1.0") && self.ghc.version >= "9";
+       homepage = "https://github.com/example/random-598";
+       description = "Package number 598";
+       license = lib.licenses.lgpl21Plus;
+       hydraPlatforms = lib.platforms.none;
+       broken = true;
+     }) {};
+
+  "deepseq-599" = callPackage
+    ({ async, base, bytestring, deepseq, directory, http_client, lib, mkDerivation }:
+     mkDerivation {
+       pname = "deepseq-599";
+       version = "4.3.3";
+       sha256 = "9bzzphqzmfqj9smxfrbbs7qr3gjs2cnaqq1ln1fmiv0zpwdp9711";
+       libraryHaskellDepends = [
+         async base bytestring deepseq directory http_client
+       ];
+       homepage = "https://github.com/example/deepseq-599";
+       description = "Package number 599";
+       license = lib.licenses.mit;
+     }) {};
+
+}
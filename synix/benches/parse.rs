@@ -0,0 +1,31 @@
+//! Parsing a large package set, `large.nix`, which is generated in
+//! the style of nixpkgs' `hackage-packages.nix`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use synix::{ParseOptions, parse_with_options};
+use synix_lexer::TokenStream;
+
+const LARGE: &str = include_str!("large.nix");
+
+fn lex(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lex");
+    group.throughput(Throughput::Bytes(LARGE.len() as u64));
+    group.bench_function("large.nix", |b| {
+        b.iter(|| black_box(LARGE).parse::<TokenStream>().unwrap())
+    });
+    group.finish();
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.throughput(Throughput::Bytes(LARGE.len() as u64));
+    group.bench_function("large.nix", |b| {
+        b.iter(|| parse_with_options(black_box(LARGE), ParseOptions::default()).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lex, parse);
+criterion_main!(benches);
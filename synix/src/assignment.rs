@@ -1,6 +1,9 @@
 use crate::{ident::LiteralOrInterpolatedIdent, *};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum Assignment {
     Inherit(AssignmentInherit),
     Named(AssignmentNamed),
@@ -17,12 +20,16 @@ impl Assignment {
 
 impl Parse for Assignment {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
-        let result = if AssignmentInherit::peek(buffer) {
+        let lookahead = buffer.lookahead1();
+
+        let result = if lookahead.peek::<AssignmentInherit>() {
             let inherit = buffer.parse()?;
             Self::Inherit(inherit)
-        } else {
+        } else if lookahead.peek::<LiteralOrInterpolatedIdent>() {
             let assignment = buffer.parse()?;
             Self::Named(assignment)
+        } else {
+            return Err(lookahead.error());
         };
 
         Ok(result)
@@ -30,10 +37,13 @@ impl Parse for Assignment {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssignmentInherit {
     pub inherit: Token![inherit],
     pub base: Option<Expr>,
-    pub names: Vec<Ident>,
+    /// The inherited names. These never contain interpolations.
+    pub names: Vec<LiteralOrInterpolatedIdent>,
     pub semicolon: Token![;],
     span: Span,
 }
@@ -48,39 +58,42 @@ impl Peek for AssignmentInherit {
     fn peek(input: &ParseBuffer) -> bool {
         <Token![inherit]>::peek(input)
     }
+
+    fn display() -> &'static str {
+        "`inherit`"
+    }
 }
 
 impl Parse for AssignmentInherit {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
-        use LiteralOrInterpolatedIdent::*;
-
         let start = buffer.span();
         let inherit = buffer.parse()?;
 
         let base = if buffer.peek(Paren) {
             let mut expr;
             parenthesized!(buffer as expr else "Expected parenthesized expression");
-            Some(expr.parse()?)
+            let base = expr.parse_expr_or_recover(|_| false)?;
+            expr.expect_empty()?;
+            Some(base)
         } else {
             None
         };
 
         let mut names = Vec::new();
         while !<Token![;]>::peek(buffer) {
-            let ident = match LiteralOrInterpolatedIdent::parse(buffer)? {
-                Literal(ident) => ident,
-                Interpolated(_) => {
-                    let msg = "Interpolated identifiers not allowed in inherit context";
-                    return Err(Error::new(buffer.span(), msg));
-                }
-            };
-
-            names.push(ident);
+            let name = LiteralOrInterpolatedIdent::parse(buffer)?;
+
+            if name.static_name().is_none() {
+                let msg = "Interpolated identifiers not allowed in inherit context";
+                return Err(Error::new(name.span(), msg));
+            }
+
+            names.push(name);
         }
 
         let semicolon = buffer.parse()?;
 
-        let span = start.join(&buffer.span());
+        let span = buffer.span_from(&start);
 
         Ok(Self {
             inherit,
@@ -93,6 +106,8 @@ impl Parse for AssignmentInherit {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssignmentNamed {
     pub head: LiteralOrInterpolatedIdent,
     pub tail: Vec<LiteralOrInterpolatedIdent>,
@@ -114,18 +129,36 @@ impl Parse for AssignmentNamed {
         let head = buffer.parse()?;
 
         let mut tail = Vec::new();
-        while <Token![.]>::peek(buffer) {
-            let _ = <Token![.]>::parse(buffer)?;
-            tail.push(buffer.parse()?);
-        }
-
-        let eq = buffer.parse()?;
-
-        let value = buffer.parse()?;
+        let eq = loop {
+            let lookahead = buffer.lookahead1();
+
+            if lookahead.peek::<Token![.]>() {
+                let _ = <Token![.]>::parse(buffer)?;
+                tail.push(buffer.parse()?);
+            } else if lookahead.peek::<Token![=]>() {
+                break buffer.parse()?;
+            } else {
+                return Err(lookahead.error());
+            }
+        };
 
-        let semicolon = buffer.parse()?;
+        // `in` can not appear in a value, so it is most likely the end
+        // of the surrounding `let` after a missing value.
+        let value =
+            buffer.parse_expr_or_recover(|b| <Token![;]>::peek(b) || <Token![in]>::peek(b))?;
+
+        let semicolon = if let Expr::Error(span) = &value
+            && !<Token![;]>::peek(buffer)
+        {
+            // The missing value has been reported already.
+            let mut semicolon = <Token![;]>::new();
+            semicolon.span = Span::new(span.end(), span.end());
+            semicolon
+        } else {
+            buffer.parse()?
+        };
 
-        let span = start.join(&buffer.span());
+        let span = buffer.span_from(&start);
 
         Ok(Self {
             head,
@@ -3,6 +3,8 @@ use crate::*;
 use crate::{Brace, Expr, Parse, Peek, braced};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprAttrSet {
     pub rec: Option<Token![rec]>,
     pub assignments: Vec<Assignment>,
@@ -19,10 +21,15 @@ impl Peek for ExprAttrSet {
     fn peek(input: &crate::ParseBuffer) -> bool {
         input.peek(Brace) || <Token![rec]>::peek(input)
     }
+
+    fn display() -> &'static str {
+        "attribute set"
+    }
 }
 
 impl Parse for ExprAttrSet {
     fn parse(buffer: &mut crate::ParseBuffer) -> crate::Result<Self> {
+        let start = buffer.span();
         let rec = if <Token![rec]>::peek(buffer) {
             Some(buffer.parse()?)
         } else {
@@ -32,10 +39,14 @@ impl Parse for ExprAttrSet {
         let mut braced;
         braced!(buffer as braced else "Expected attribute set.");
 
-        let span = braced.span();
+        let span = buffer.span_from(&start);
         let mut assignments = Vec::new();
         while !braced.is_empty() {
-            assignments.push(braced.parse()?);
+            if let Some(assignment) = braced.parse_or_recover(<Token![;]>::peek)? {
+                assignments.push(assignment);
+            } else if <Token![;]>::peek(&braced) {
+                let _: Token![;] = braced.parse()?;
+            }
         }
 
         Ok(Self {
@@ -47,20 +58,19 @@ impl Parse for ExprAttrSet {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttributeAccess {
     pub set: Expr,
     pub accessors: Vec<LiteralOrInterpolatedIdent>,
+    /// The value to use if the attribute does not exist.
+    pub default: Option<(Token![or], Expr)>,
+    span: Span,
 }
 
 impl AttributeAccess {
     pub fn span(&self) -> Span {
-        let mut span = self.set.span();
-
-        for v in &self.accessors {
-            span = span.join(&v.span());
-        }
-
-        span
+        self.span.clone()
     }
 
     pub fn peek(buffer: &ParseBuffer) -> bool {
@@ -68,7 +78,7 @@ impl AttributeAccess {
     }
 
     pub fn parse_rest(set: Expr, parser: &mut ParseBuffer) -> Result<Self> {
-        if !Self::peek(&parser) {
+        if !Self::peek(parser) {
             return Err(Error::new(
                 parser.span(),
                 "Expected `.` while parsing attribute access",
@@ -79,11 +89,80 @@ impl AttributeAccess {
 
         while <Token![.]>::peek(parser) {
             let _dot: Token![.] = parser.parse()?;
-            let accessor = parser.parse()?;
+            let accessor: LiteralOrInterpolatedIdent = parser.parse()?;
 
             accessors.push(accessor);
         }
 
-        Ok(Self { set, accessors })
+        let default = if <Token![or]>::peek(parser) {
+            let or = parser.parse()?;
+            let default = Expr::parse_select(parser)?;
+            Some((or, default))
+        } else {
+            None
+        };
+
+        let mut span = set.span();
+
+        for v in &accessors {
+            span = span.join(&v.span());
+        }
+
+        if let Some((_, default)) = &default {
+            span = span.join(&default.span());
+        }
+
+        Ok(Self {
+            set,
+            accessors,
+            default,
+            span,
+        })
+    }
+}
+
+/// `set ? attr.path`
+#[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExprHasAttr {
+    pub set: Expr,
+    pub question: Token![?],
+    pub head: LiteralOrInterpolatedIdent,
+    pub tail: Vec<LiteralOrInterpolatedIdent>,
+}
+
+impl ExprHasAttr {
+    /// The precedence of `?`, comparable to [`Operator::precedence`](crate::binary::Operator::precedence).
+    pub const PRECEDENCE: u8 = 12;
+
+    pub fn span(&self) -> Span {
+        self.tail
+            .iter()
+            .fold(self.set.span().join(&self.head.span()), |span, v| {
+                span.join(&v.span())
+            })
+    }
+
+    pub fn peek(buffer: &ParseBuffer) -> bool {
+        <Token![?]>::peek(buffer)
+    }
+
+    pub fn parse_rest(set: Expr, parser: &mut ParseBuffer) -> Result<Self> {
+        let question = parser.parse()?;
+        let head = parser.parse()?;
+
+        let mut tail = Vec::new();
+        while <Token![.]>::peek(parser) {
+            let _dot: Token![.] = parser.parse()?;
+            tail.push(parser.parse()?);
+        }
+
+        Ok(Self {
+            set,
+            question,
+            head,
+            tail,
+        })
     }
 }
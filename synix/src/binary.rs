@@ -1,8 +1,8 @@
-use std::cmp::Ordering;
-
-use crate::{Expr, Parse, ParseBuffer, Result, *};
+use crate::{Expr, Parse, ParseBuffer, Result, token::custom::parse_punct, *};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprBinary {
     pub lhs: Expr,
     pub operator: Operator,
@@ -11,7 +11,9 @@ pub struct ExprBinary {
 }
 
 impl ExprBinary {
-    pub(crate) fn new(lhs: Expr, operator: Operator, rhs: Expr, span: Span) -> Self {
+    pub(crate) fn new(lhs: Expr, operator: Operator, rhs: Expr) -> Self {
+        let span = lhs.span().join(&rhs.span());
+
         Self {
             lhs,
             operator,
@@ -23,33 +25,143 @@ impl ExprBinary {
     pub fn span(&self) -> Span {
         self.span.clone()
     }
+}
 
-    pub fn parse_rest(lhs: Expr, operator: Operator, rest: &mut ParseBuffer) -> Result<Self> {
-        let rhs: Expr = rest.parse()?;
+/// Parse an operator expression using precedence climbing.
+///
+/// Only operators that bind at least as tightly as `min_precedence` are
+/// consumed, the rest is left for the caller.
+pub(crate) fn parse_operation(input: &mut ParseBuffer, min_precedence: u8) -> Result<Expr> {
+    let mut lhs = if UnaryOperator::peek(input) {
+        Expr::Unary(Box::new(input.parse()?))
+    } else {
+        Expr::parse_application(input)?
+    };
 
-        let out = if let Expr::Binary(rhs) = rhs {
-            if operator.presedence(&rhs.operator) == Ordering::Greater {
-                // TODO: compute span
-                let lhs = ExprBinary::new(lhs, operator, rhs.lhs, Default::default());
-                let lhs = Expr::Binary(Box::new(lhs));
+    // The precedence of the last non-associative operator that was
+    // applied at this level, if any.
+    let mut non_associative = None;
+    // How many operators were applied at this level, each of which
+    // nests `lhs` one level deeper.
+    let mut applied = 0;
 
-                // TODO: compute span.
-                ExprBinary::new(lhs, rhs.operator, rhs.rhs, Default::default())
-            } else {
-                let rhs = Expr::Binary(rhs);
-                // TODO: compute span.
-                ExprBinary::new(lhs, operator, rhs, Default::default())
-            }
+    loop {
+        let (precedence, associativity, repr) = if ExprHasAttr::peek(input) {
+            (ExprHasAttr::PRECEDENCE, Associativity::None, "?")
+        } else if let Some(operator) = Operator::lookup(input) {
+            (
+                operator.precedence(),
+                operator.associativity(),
+                operator.as_str(),
+            )
         } else {
-            // TODO: compute span.
-            ExprBinary::new(lhs, operator, rhs, Default::default())
+            break;
         };
 
-        Ok(out)
+        if precedence < min_precedence {
+            break;
+        }
+
+        if non_associative == Some(precedence) {
+            let msg = format!("Operator `{repr}` is not associative.");
+            let error =
+                Error::new(input.span(), msg).with_help("Add parentheses to group the operations.");
+            return Err(error);
+        }
+
+        applied += 1;
+        lhs = input.deeper(applied, |input| {
+            if ExprHasAttr::peek(input) {
+                let has_attr = ExprHasAttr::parse_rest(lhs, input)?;
+                Ok(Expr::HasAttr(Box::new(has_attr)))
+            } else if associativity == Associativity::Right {
+                parse_right_associative(lhs, input, precedence)
+            } else {
+                let operator: Operator = input.parse()?;
+                let rhs = parse_operation(input, precedence + 1)?;
+
+                binary(lhs, operator, rhs)
+            }
+        })?;
+
+        non_associative = (associativity == Associativity::None).then_some(precedence);
     }
+
+    Ok(lhs)
+}
+
+/// Parse the rest of a chain of right-associative operators with
+/// `precedence`, such as `++` in `a ++ b ++ c`, after `lhs`.
+///
+/// The operands are parsed in a loop and only grouped to the right
+/// at the end, so that long chains do not need a deep stack.
+fn parse_right_associative(lhs: Expr, input: &mut ParseBuffer, precedence: u8) -> Result<Expr> {
+    let mut operands = vec![lhs];
+    let mut operators = Vec::new();
+
+    while let Some(operator) = Operator::lookup(input)
+        && operator.precedence() == precedence
+        && operator.associativity() == Associativity::Right
+    {
+        operators.push(input.parse::<Operator>()?);
+
+        // The chain is grouped to the right, so every operand is
+        // nested one level deeper than the one before it.
+        let operand = input.deeper(operands.len(), |input| {
+            parse_operation(input, precedence + 1)
+        })?;
+        operands.push(operand);
+    }
+
+    let mut rhs = operands
+        .pop()
+        .expect("There is an operand after every operator");
+    while let Some(operator) = operators.pop() {
+        let lhs = operands
+            .pop()
+            .expect("There is an operand before every operator");
+        rhs = binary(lhs, operator, rhs)?;
+    }
+
+    Ok(rhs)
+}
+
+/// Apply `operator` to `lhs` and `rhs`.
+fn binary(lhs: Expr, operator: Operator, rhs: Expr) -> Result<Expr> {
+    // Operators with the same precedence but a different associativity
+    // (`|>` and `<|`) can not be mixed without parentheses.
+    for operand in [&lhs, &rhs] {
+        if let Expr::Binary(operand) = operand
+            && operand.operator.precedence() == operator.precedence()
+            && operand.operator.associativity() != operator.associativity()
+        {
+            let msg = format!(
+                "Operators `{}` and `{}` can not be mixed without parentheses.",
+                operand.operator, operator
+            );
+            let error = Error::new(operand.span(), msg)
+                .with_help("Add parentheses to group the operations.");
+            return Err(error);
+        }
+    }
+
+    Ok(Expr::Binary(Box::new(ExprBinary::new(lhs, operator, rhs))))
+}
+
+/// The associativity of an operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    /// `a op b op c` is `(a op b) op c`.
+    Left,
+    /// `a op b op c` is `a op (b op c)`.
+    Right,
+    /// `a op b op c` is not allowed.
+    None,
 }
 
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, Copy, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operator {
     Add,
     Subtract,
@@ -65,45 +177,106 @@ pub enum Operator {
     Lt,
     Le,
     Concat,
+    Implies,
+    /// `|>`, only available with [`ParseOptions::pipe_operators`].
+    PipeInto,
+    /// `<|`, only available with [`ParseOptions::pipe_operators`].
+    PipeFrom,
 }
 
 impl Operator {
-    fn presedence_val(&self) -> usize {
+    /// The precedence of this operator. Operators with a higher
+    /// precedence bind more tightly.
+    ///
+    /// This follows the operator table in the Nix manual, where
+    /// function application binds tighter than any operator.
+    pub fn precedence(&self) -> u8 {
         match self {
-            // Mathematical operators
-            Operator::Multiply => 4,
-            Operator::Divide => 4,
-            Operator::Add => 3,
-            Operator::Subtract => 3,
-            // Equality operators
-            Operator::Equals => 2,
-            Operator::NotEquals => 2,
-            Operator::Gt => 2,
-            Operator::Ge => 2,
-            Operator::Lt => 2,
-            Operator::Le => 2,
-            // Binary operators
-            Operator::And => 1,
-            Operator::Or => 1,
             // List & attribute set operators
-            Operator::Update => 0,
-            Operator::Concat => 0,
+            Operator::Concat => 11,
+            // Mathematical operators
+            Operator::Multiply => 10,
+            Operator::Divide => 10,
+            Operator::Add => 9,
+            Operator::Subtract => 9,
+            // Logical negation (`!`) is 8.
+            Operator::Update => 7,
+            // Comparison operators
+            Operator::Gt => 6,
+            Operator::Ge => 6,
+            Operator::Lt => 6,
+            Operator::Le => 6,
+            Operator::Equals => 5,
+            Operator::NotEquals => 5,
+            // Logical operators
+            Operator::And => 4,
+            Operator::Or => 3,
+            Operator::Implies => 2,
+            // Pipe operators
+            Operator::PipeInto => 1,
+            Operator::PipeFrom => 1,
         }
     }
 
-    pub fn presedence(&self, other: &Self) -> Ordering {
-        self.presedence_val().cmp(&other.presedence_val())
+    pub fn associativity(&self) -> Associativity {
+        match self {
+            Operator::Concat | Operator::Update | Operator::Implies | Operator::PipeFrom => {
+                Associativity::Right
+            }
+            Operator::Gt
+            | Operator::Ge
+            | Operator::Lt
+            | Operator::Le
+            | Operator::Equals
+            | Operator::NotEquals => Associativity::None,
+            Operator::Add
+            | Operator::Subtract
+            | Operator::Divide
+            | Operator::Multiply
+            | Operator::And
+            | Operator::Or
+            | Operator::PipeInto => Associativity::Left,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Divide => "/",
+            Operator::Multiply => "*",
+            Operator::Update => "//",
+            Operator::And => "&&",
+            Operator::Or => "||",
+            Operator::Equals => "==",
+            Operator::NotEquals => "!=",
+            Operator::Gt => ">",
+            Operator::Ge => ">=",
+            Operator::Lt => "<",
+            Operator::Le => "<=",
+            Operator::Concat => "++",
+            Operator::Implies => "->",
+            Operator::PipeInto => "|>",
+            Operator::PipeFrom => "<|",
+        }
     }
 }
 
-impl Parse for Operator {
-    fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
+impl core::fmt::Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Operator {
+    /// The operator at the start of `input`, including pipe operators
+    /// even if they are not enabled.
+    pub(crate) fn lookup(input: &ParseBuffer) -> Option<Self> {
         macro_rules! do_map {
             ($($token:ty => $value:ident),*$(,)?) => {
                 $(
-                    if <$token>::peek(&buffer) {
-                        let _: $token = buffer.parse()?;
-                        return Ok(Self::$value);
+                    if <$token>::peek(input) {
+                        return Some(Self::$value);
                     }
                 )*
             };
@@ -124,14 +297,145 @@ impl Parse for Operator {
             Token![<=] => Le,
             crate::token::Concat => Concat,
             Token![!=] => NotEquals,
+            Token![->] => Implies,
+            Token![|>] => PipeInto,
+            Token![<|] => PipeFrom,
         }
 
-        Err(Error::new(buffer.span(), "Expected binary operator."))
+        None
+    }
+}
+
+impl Parse for Operator {
+    fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
+        let Some(operator) = Self::lookup(buffer) else {
+            return Err(Error::new(buffer.span(), "Expected binary operator."));
+        };
+
+        if matches!(operator, Operator::PipeInto | Operator::PipeFrom) {
+            if !buffer.options().has_pipe_operators() {
+                let msg =
+                    "Pipe operators are experimental and must be enabled in the parse options.";
+                return Err(Error::new(buffer.span(), msg));
+            }
+
+            if let Some(target) = buffer.options().target()
+                && !target.has_pipe_operators()
+            {
+                let msg = format!("Pipe operators are not supported by {target}.");
+                return Err(Error::new(buffer.span(), msg));
+            }
+        }
+
+        parse_punct(buffer, operator.as_str())?;
+
+        Ok(operator)
     }
 }
 
 impl Peek for Operator {
     fn peek(input: &ParseBuffer) -> bool {
-        Self::parse(&mut input.fork()).is_ok()
+        // Pipe operators are always peeked, so that using them without
+        // enabling them produces an error instead of being ignored.
+        Self::lookup(input).is_some()
+    }
+
+    fn display() -> &'static str {
+        "binary operator"
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExprUnary {
+    pub operator: UnaryOperator,
+    pub expr: Expr,
+    span: Span,
+}
+
+impl ExprUnary {
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl Parse for ExprUnary {
+    fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
+        buffer.deeper(1, |buffer| {
+            let start = buffer.span();
+            let operator: UnaryOperator = buffer.parse()?;
+            let expr = parse_operation(buffer, operator.precedence())?;
+            let span = start.join(&expr.span());
+
+            Ok(Self {
+                operator,
+                expr,
+                span,
+            })
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, Copy, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnaryOperator {
+    /// `!`
+    Not,
+    /// `-`
+    Negate,
+}
+
+impl UnaryOperator {
+    /// The precedence of this operator, comparable to [`Operator::precedence`].
+    pub fn precedence(&self) -> u8 {
+        match self {
+            UnaryOperator::Negate => 13,
+            UnaryOperator::Not => 8,
+        }
+    }
+
+    /// Prefix operators apply to everything after them, so they
+    /// group to the right, as in `- -a`.
+    pub fn associativity(&self) -> Associativity {
+        Associativity::Right
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnaryOperator::Not => "!",
+            UnaryOperator::Negate => "-",
+        }
+    }
+}
+
+impl core::fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Parse for UnaryOperator {
+    fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
+        if <Token![!]>::peek(buffer) {
+            let _: Token![!] = buffer.parse()?;
+            Ok(Self::Not)
+        } else if <Token![-]>::peek(buffer) {
+            let _: Token![-] = buffer.parse()?;
+            Ok(Self::Negate)
+        } else {
+            Err(Error::new(buffer.span(), "Expected unary operator."))
+        }
+    }
+}
+
+impl Peek for UnaryOperator {
+    fn peek(input: &ParseBuffer) -> bool {
+        <Token![!]>::peek(input) || <Token![-]>::peek(input)
+    }
+
+    fn display() -> &'static str {
+        "unary operator"
     }
 }
@@ -0,0 +1,162 @@
+//! A flat representation of token trees, which is traversed
+//! with [`Cursor`]s.
+//!
+//! The trees inside a group are stored right after the group,
+//! followed by an end marker, so that a cursor is just a position
+//! in a single list. Moving to the next tree, into a group or past
+//! it are all constant time.
+//!
+//! ```
+//! use synix::buffer::TokenBuffer;
+//! use synix_lexer::{TokenStream, group::Delimiter};
+//!
+//! let tokens: TokenStream = "{ a = 1; } b".parse().unwrap();
+//! let buffer = TokenBuffer::new(tokens.as_ref());
+//!
+//! let (inside, _, after) = buffer.begin().group(Delimiter::Brace).unwrap();
+//! assert_eq!(inside.span().unwrap().start().column, 2);
+//!
+//! let (b, rest) = after.tree().unwrap();
+//! assert_eq!(b.span().start().column, 11);
+//! assert!(rest.eof());
+//! ```
+
+use synix_lexer::{LineColumn, Span, TokenTree, group::Delimiter};
+
+/// Token trees, flattened into a single list.
+#[derive(Debug)]
+pub struct TokenBuffer<'a> {
+    entries: Box<[Entry<'a>]>,
+}
+
+#[derive(Debug)]
+enum Entry<'a> {
+    /// A group, and the offset from here to the entry after
+    /// the end of the group.
+    Group(&'a TokenTree, usize),
+    /// An identifier, punct or literal.
+    Tree(&'a TokenTree),
+    /// The end of a group, with the span of its closing delimiter,
+    /// or the end of the input.
+    End(Option<Span>),
+}
+
+impl<'a> TokenBuffer<'a> {
+    pub fn new(trees: &'a [TokenTree]) -> Self {
+        let mut entries = Vec::new();
+
+        // The groups that are being flattened, with the index of their
+        // entry, the span of their closing delimiter and the trees after
+        // them. Groups are nested arbitrarily deep, so this is not
+        // done recursively.
+        let mut stack = Vec::new();
+        let mut trees = trees.iter();
+
+        loop {
+            let Some(tree) = trees.next() else {
+                let Some((idx, close, rest)) = stack.pop() else {
+                    entries.push(Entry::End(None));
+                    break;
+                };
+
+                entries.push(Entry::End(Some(close)));
+                let offset = entries.len() - idx;
+                if let Entry::Group(_, end) = &mut entries[idx] {
+                    *end = offset;
+                }
+
+                trees = rest;
+                continue;
+            };
+
+            let TokenTree::Group(group) = tree else {
+                entries.push(Entry::Tree(tree));
+                continue;
+            };
+
+            // The closing delimiter is the last character of the group.
+            let end = group.span().end();
+            let close = LineColumn {
+                line: end.line,
+                column: end.column.saturating_sub(1),
+            };
+
+            stack.push((entries.len(), Span::new(close, end), trees));
+            entries.push(Entry::Group(tree, 0));
+            trees = group.inner.as_ref().iter();
+        }
+
+        Self {
+            entries: entries.into_boxed_slice(),
+        }
+    }
+
+    /// A cursor at the first tree.
+    pub fn begin(&self) -> Cursor<'_> {
+        Cursor {
+            entries: &self.entries,
+            idx: 0,
+        }
+    }
+}
+
+/// A position in a [`TokenBuffer`], scoped to the group it is in.
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+    entries: &'a [Entry<'a>],
+    idx: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn entry(self) -> &'a Entry<'a> {
+        &self.entries[self.idx]
+    }
+
+    fn at(self, idx: usize) -> Self {
+        Self { idx, ..self }
+    }
+
+    /// Whether the cursor is at the end of its group, or of the input.
+    pub fn eof(self) -> bool {
+        matches!(self.entry(), Entry::End(_))
+    }
+
+    /// The next tree, and a cursor past it.
+    pub fn tree(self) -> Option<(&'a TokenTree, Cursor<'a>)> {
+        match self.entry() {
+            Entry::Group(tree, offset) => Some((tree, self.at(self.idx + offset))),
+            Entry::Tree(tree) => Some((tree, self.at(self.idx + 1))),
+            Entry::End(_) => None,
+        }
+    }
+
+    /// If the next tree is a group with `delimiter`, a cursor at its
+    /// first tree, the span of the group and a cursor past it.
+    pub fn group(self, delimiter: Delimiter) -> Option<(Cursor<'a>, Span, Cursor<'a>)> {
+        match self.entry() {
+            Entry::Group(TokenTree::Group(group), offset) if group.delimiter == delimiter => {
+                Some((
+                    self.at(self.idx + 1),
+                    group.span(),
+                    self.at(self.idx + offset),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// A cursor past the next tree.
+    pub fn skip(self) -> Option<Cursor<'a>> {
+        self.tree().map(|(_, next)| next)
+    }
+
+    /// The span of the next tree. At the end of a group, this is the
+    /// span of its closing delimiter, and at the end of the input
+    /// there is none.
+    pub fn span(self) -> Option<Span> {
+        match self.entry() {
+            Entry::Group(tree, _) | Entry::Tree(tree) => Some(tree.span()),
+            Entry::End(span) => span.clone(),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Typed views over [`SyntaxNode`]s.
+
+use super::{SyntaxKind, SyntaxNode};
+use crate::{Error, Expr, Parse, ParseBuffer, Result, buffer::TokenBuffer};
+
+/// A typed view over a [`SyntaxNode`] of a specific kind.
+pub trait AstNode: Sized {
+    /// The typed AST node this is a view of.
+    type Ast;
+
+    fn can_cast(kind: SyntaxKind) -> bool;
+
+    fn cast(node: SyntaxNode) -> Option<Self>;
+
+    fn syntax(&self) -> &SyntaxNode;
+
+    /// Lex and parse the text of this node again into its typed
+    /// AST node.
+    ///
+    /// Spans in the result are relative to the start of this node,
+    /// not to the start of the file.
+    fn ast_relative(&self) -> Result<Self::Ast>;
+}
+
+/// Parse the text of `node` with the options of its tree. The
+/// whole text must be a `T`.
+fn parse_text<T: Parse>(node: &SyntaxNode) -> Result<T> {
+    let text = node.text();
+    let options = node.options();
+    let lexed = synix_lexer::TokenStream::lex_with_options(&text, options.lex_options())?;
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let mut buffer = ParseBuffer::new_with_options(&tokens, options);
+
+    let value = buffer.parse()?;
+    buffer.expect_empty()?;
+    Ok(value)
+}
+
+/// Parse the text of `node` as an expression, which `variant` must
+/// unwrap. Used for the nodes that only [`Expr`] knows how to parse.
+fn parse_variant<T>(
+    node: &SyntaxNode,
+    variant: fn(Expr) -> Option<T>,
+    expected: &str,
+) -> Result<T> {
+    let expr: Expr = parse_text(node)?;
+    let span = expr.span();
+    variant(expr).ok_or_else(|| Error::new(span, format!("Expected {expected}.")))
+}
+
+macro_rules! ast_nodes {
+    ($($name:ident => $ast:ty $(as $variant:ident $expected:literal)?),*$(,)?) => {
+        $(
+            #[doc = concat!("A view over a [`SyntaxKind::", stringify!($name), "`] node.")]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            pub struct $name(SyntaxNode);
+
+            impl AstNode for $name {
+                type Ast = $ast;
+
+                fn can_cast(kind: SyntaxKind) -> bool {
+                    kind == SyntaxKind::$name
+                }
+
+                fn cast(node: SyntaxNode) -> Option<Self> {
+                    Self::can_cast(node.kind()).then_some(Self(node))
+                }
+
+                fn syntax(&self) -> &SyntaxNode {
+                    &self.0
+                }
+
+                fn ast_relative(&self) -> Result<Self::Ast> {
+                    ast_nodes!(@parse &self.0 $(, $variant $expected)?)
+                }
+            }
+        )*
+    };
+    (@parse $node:expr) => {
+        parse_text($node)
+    };
+    (@parse $node:expr, $variant:ident $expected:literal) => {
+        parse_variant(
+            $node,
+            |expr| match expr {
+                Expr::$variant(inner) => Some(*inner),
+                _ => None,
+            },
+            $expected,
+        )
+    };
+}
+
+ast_nodes! {
+    ExprLet => crate::ExprLet,
+    ExprLegacyLet => crate::ExprLegacyLet,
+    ExprLit => crate::lit::ExprLit,
+    ExprInterpolatedStr => crate::lit::ExprInterpolatedStr,
+    ExprLambda => crate::lambda::ExprLambda,
+    ExprIdent => crate::Ident,
+    ExprAttrSet => crate::attrset::ExprAttrSet,
+    ExprParenthesized => crate::ExprParenthesized,
+    ExprList => crate::list::ExprList,
+    ExprWith => crate::ExprWith,
+    ExprIf => crate::ExprIf,
+    ExprFunctionCall => crate::ExprFunctionCall as FunctionCall "a function call",
+    ExprBinary => crate::binary::ExprBinary as Binary "a binary expression",
+    ExprUnary => crate::binary::ExprUnary,
+    ExprHasAttr => crate::attrset::ExprHasAttr as HasAttr "an attribute check",
+    AttributeAccess => crate::attrset::AttributeAccess as AttributeAccess "an attribute access",
+    Path => crate::path::Path,
+    AssignmentNamed => crate::AssignmentNamed,
+    AssignmentInherit => crate::AssignmentInherit,
+    ArgAttrSet => crate::lambda::ArgAttrSet,
+}
+
+/// Whether `kind` is the kind of an expression node.
+fn is_expr(kind: SyntaxKind) -> bool {
+    !matches!(
+        kind,
+        SyntaxKind::AssignmentNamed
+            | SyntaxKind::AssignmentInherit
+            | SyntaxKind::AttrName
+            | SyntaxKind::ArgAttrSet
+            | SyntaxKind::ArgAttrSetValue
+    )
+}
+
+fn assignments(node: &SyntaxNode) -> impl Iterator<Item = SyntaxNode> + '_ {
+    node.children().filter(|child| {
+        matches!(
+            child.kind(),
+            SyntaxKind::AssignmentNamed | SyntaxKind::AssignmentInherit
+        )
+    })
+}
+
+fn last_expr(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.children().filter(|child| is_expr(child.kind())).last()
+}
+
+impl ExprLet {
+    pub fn assignments(&self) -> impl Iterator<Item = SyntaxNode> + '_ {
+        assignments(&self.0)
+    }
+
+    pub fn body(&self) -> Option<SyntaxNode> {
+        last_expr(&self.0)
+    }
+}
+
+impl ExprAttrSet {
+    pub fn assignments(&self) -> impl Iterator<Item = SyntaxNode> + '_ {
+        assignments(&self.0)
+    }
+}
+
+impl ExprLambda {
+    pub fn body(&self) -> Option<SyntaxNode> {
+        last_expr(&self.0)
+    }
+}
+
+impl ExprWith {
+    pub fn body(&self) -> Option<SyntaxNode> {
+        last_expr(&self.0)
+    }
+}
+
+impl AssignmentNamed {
+    /// The attribute names, such as `a` and `b` in `a.b = c;`.
+    pub fn names(&self) -> impl Iterator<Item = SyntaxNode> + '_ {
+        self.0
+            .children()
+            .filter(|child| child.kind() == SyntaxKind::AttrName)
+    }
+
+    pub fn value(&self) -> Option<SyntaxNode> {
+        last_expr(&self.0)
+    }
+}
@@ -0,0 +1,442 @@
+//! A lossless concrete syntax tree.
+//!
+//! Unlike the typed AST, the syntax tree keeps every token,
+//! including whitespace and comments, so printing it gives back
+//! the exact input. Edits made through
+//! [`SyntaxNode::replace_with`] leave the formatting of everything
+//! else untouched.
+//!
+//! The tree is split in two layers: the immutable [`GreenNode`]s,
+//! which only know their kind and children, and [`SyntaxNode`]s,
+//! which add positions and parents on top. Typed views, such as
+//! [`ExprLet`], wrap a [`SyntaxNode`] of the matching kind.
+
+mod ast;
+mod green;
+mod red;
+
+use std::ops::Range;
+
+use synix_lexer::{LineColumn, Span, TokenStream, TokenTree, group::Delimiter, literal::Literal};
+
+pub use ast::*;
+pub use green::{GreenElement, GreenNode, GreenToken};
+pub use red::{SyntaxElement, SyntaxNode, SyntaxToken};
+
+use crate::{
+    Assignment, Expr, LiteralOrInterpolatedIdent, ParseBuffer, ParseOptions, Result,
+    buffer::TokenBuffer,
+    lambda::LambdaArg,
+    path::{self, PathPart, PathSubPart},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyntaxKind {
+    // Tokens
+    Whitespace,
+    /// `# comment`
+    Comment,
+    Ident,
+    Int,
+    Float,
+    Str,
+    /// A string containing interpolations. Its contents are
+    /// not split into tokens.
+    InterpolatedStr,
+    Url,
+    Punct,
+    LBrace,
+    RBrace,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+
+    // Nodes
+    Root,
+    ExprLet,
+    ExprLegacyLet,
+    ExprLit,
+    ExprInterpolatedStr,
+    ExprLambda,
+    ExprIdent,
+    ExprAttrSet,
+    ExprParenthesized,
+    ExprList,
+    ExprWith,
+    ExprIf,
+    ExprFunctionCall,
+    ExprBinary,
+    ExprUnary,
+    ExprHasAttr,
+    AttributeAccess,
+    Path,
+    AssignmentNamed,
+    AssignmentInherit,
+    /// A single attribute name in an attribute path.
+    AttrName,
+    InterpolatedIdent,
+    ArgAttrSet,
+    ArgAttrSetValue,
+
+    /// Input that could not be lexed or parsed.
+    Error,
+}
+
+impl SyntaxKind {
+    pub fn is_trivia(&self) -> bool {
+        matches!(self, SyntaxKind::Whitespace | SyntaxKind::Comment)
+    }
+}
+
+/// Parse `input` into a syntax tree of kind [`SyntaxKind::Root`].
+pub fn parse(input: &str) -> Result<SyntaxNode> {
+    parse_with_options(input, ParseOptions::default())
+}
+
+pub fn parse_with_options(input: &str, options: ParseOptions) -> Result<SyntaxNode> {
+    let lexed = TokenStream::lex_with_options(input, options.lex_options())?;
+
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let mut buffer = ParseBuffer::new_with_options(&tokens, options);
+    let expr = crate::parse_to_end(&mut buffer)?;
+
+    let index = LineIndex::new(input);
+
+    let mut tokens = Vec::new();
+    flatten(lexed.as_ref(), &index, &mut tokens);
+    let tokens = with_trivia(input, tokens);
+
+    let mut nodes = Vec::new();
+    Nodes {
+        index: &index,
+        output: &mut nodes,
+    }
+    .expr(&expr);
+
+    let mut builder = Builder {
+        input,
+        tokens: tokens.into_iter().peekable(),
+        nodes: nodes.into_iter().peekable(),
+    };
+
+    let green = builder.node(SyntaxKind::Root, 0..input.len());
+    Ok(SyntaxNode::new_root_with_options(green, options))
+}
+
+/// Maps line/column positions to byte offsets.
+struct LineIndex<'a> {
+    input: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    fn new(input: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(input.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        Self { input, line_starts }
+    }
+
+    fn offset(&self, position: LineColumn) -> usize {
+        let start = self
+            .line_starts
+            .get(position.line)
+            .copied()
+            .unwrap_or(self.input.len());
+
+        let column: usize = self.input[start..]
+            .chars()
+            .take(position.column)
+            .map(char::len_utf8)
+            .sum();
+
+        start + column
+    }
+
+    fn range(&self, span: &Span) -> Range<usize> {
+        self.offset(span.start())..self.offset(span.end())
+    }
+}
+
+/// Flatten token trees into tokens, without trivia.
+fn flatten(trees: &[TokenTree], index: &LineIndex, output: &mut Vec<(SyntaxKind, Range<usize>)>) {
+    for tree in trees {
+        let range = index.range(&tree.span());
+
+        let kind = match tree {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter {
+                    Delimiter::Brace => (SyntaxKind::LBrace, SyntaxKind::RBrace),
+                    Delimiter::Paren => (SyntaxKind::LParen, SyntaxKind::RParen),
+                    Delimiter::Bracket => (SyntaxKind::LBracket, SyntaxKind::RBracket),
+                };
+
+                output.push((open, range.start..range.start + 1));
+                crate::ensure_stack(|| flatten(group.inner.as_ref(), index, output));
+                output.push((close, range.end - 1..range.end));
+                continue;
+            }
+            TokenTree::Ident(_) => SyntaxKind::Ident,
+            TokenTree::Punct(_) => SyntaxKind::Punct,
+            TokenTree::Literal(Literal::Int(_)) => SyntaxKind::Int,
+            TokenTree::Literal(Literal::Float(_)) => SyntaxKind::Float,
+            TokenTree::Literal(Literal::Str(_)) => SyntaxKind::Str,
+            TokenTree::Literal(Literal::InterpolatedStr(_)) => SyntaxKind::InterpolatedStr,
+            TokenTree::Literal(Literal::Url(_)) => SyntaxKind::Url,
+        };
+
+        output.push((kind, range));
+    }
+}
+
+/// Fill the gaps between `tokens` with whitespace and
+/// comment tokens.
+fn with_trivia(
+    input: &str,
+    tokens: Vec<(SyntaxKind, Range<usize>)>,
+) -> Vec<(SyntaxKind, Range<usize>)> {
+    let mut output = Vec::new();
+    let mut offset = 0;
+
+    for (kind, range) in tokens {
+        trivia(input, offset..range.start, &mut output);
+        offset = range.end;
+        output.push((kind, range));
+    }
+
+    trivia(input, offset..input.len(), &mut output);
+
+    output
+}
+
+fn trivia(input: &str, range: Range<usize>, output: &mut Vec<(SyntaxKind, Range<usize>)>) {
+    let mut offset = range.start;
+
+    while offset < range.end {
+        let rest = &input[offset..range.end];
+
+        let (kind, len) = if rest.starts_with('#') {
+            (SyntaxKind::Comment, rest.find('\n').unwrap_or(rest.len()))
+        } else {
+            match rest.find(|c: char| !c.is_whitespace()) {
+                Some(0) => {
+                    // Anything that is neither whitespace nor a comment
+                    // was skipped by the lexer, which does not happen
+                    // for input that parses.
+                    let len = rest.chars().next().map_or(1, char::len_utf8);
+                    (SyntaxKind::Error, len)
+                }
+                Some(len) => (SyntaxKind::Whitespace, len),
+                None => (SyntaxKind::Whitespace, rest.len()),
+            }
+        };
+
+        output.push((kind, offset..offset + len));
+        offset += len;
+    }
+}
+
+/// Collects the kind and range of every node, in pre-order.
+struct Nodes<'a, 'b> {
+    index: &'a LineIndex<'b>,
+    output: &'a mut Vec<(SyntaxKind, Range<usize>)>,
+}
+
+impl Nodes<'_, '_> {
+    fn push(&mut self, kind: SyntaxKind, span: Span) {
+        let range = self.index.range(&span);
+
+        // Nodes that do not cover any input, such as a missing
+        // path prefix, have no tokens to hold.
+        if !range.is_empty() {
+            self.output.push((kind, range));
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        crate::ensure_stack(|| match expr {
+            Expr::Let(let_) => {
+                self.push(SyntaxKind::ExprLet, let_.span());
+                let_.assignments.iter().for_each(|a| self.assignment(a));
+                self.expr(&let_.body);
+            }
+            Expr::LegacyLet(let_) => {
+                self.push(SyntaxKind::ExprLegacyLet, let_.span());
+                let_.assignments.iter().for_each(|a| self.assignment(a));
+            }
+            Expr::Lit(lit) => self.push(SyntaxKind::ExprLit, lit.span()),
+            Expr::InterpolatedStr(str) => self.push(SyntaxKind::ExprInterpolatedStr, str.span()),
+            Expr::Lambda(lambda) => {
+                self.push(SyntaxKind::ExprLambda, lambda.span());
+
+                if let LambdaArg::AttrSet(set) = &lambda.arg {
+                    self.push(SyntaxKind::ArgAttrSet, set.span());
+
+                    for arg in &set.args {
+                        self.push(SyntaxKind::ArgAttrSetValue, arg.span());
+
+                        if let Some(default) = &arg.default {
+                            self.expr(default);
+                        }
+                    }
+                }
+
+                self.expr(&lambda.body);
+            }
+            Expr::Ident(ident) => self.push(SyntaxKind::ExprIdent, ident.span()),
+            Expr::AttrSet(set) => {
+                self.push(SyntaxKind::ExprAttrSet, set.span());
+                set.assignments.iter().for_each(|a| self.assignment(a));
+            }
+            Expr::Parenthesized(paren) => {
+                self.push(SyntaxKind::ExprParenthesized, paren.span());
+                self.expr(&paren.inner);
+            }
+            Expr::List(list) => {
+                self.push(SyntaxKind::ExprList, list.span());
+                list.entries.iter().for_each(|e| self.expr(e));
+            }
+            Expr::With(with) => {
+                self.push(SyntaxKind::ExprWith, with.span());
+                self.expr(&with.expr);
+                self.expr(&with.body);
+            }
+            Expr::If(if_) => {
+                self.push(SyntaxKind::ExprIf, if_.span());
+                self.expr(&if_.condition);
+                self.expr(&if_.then_branch);
+                self.expr(&if_.else_branch);
+            }
+            Expr::FunctionCall(call) => {
+                self.push(SyntaxKind::ExprFunctionCall, call.span());
+                self.expr(&call.head);
+                self.expr(&call.tail);
+            }
+            Expr::Binary(binary) => {
+                self.push(SyntaxKind::ExprBinary, binary.span());
+                self.expr(&binary.lhs);
+                self.expr(&binary.rhs);
+            }
+            Expr::Unary(unary) => {
+                self.push(SyntaxKind::ExprUnary, unary.span());
+                self.expr(&unary.expr);
+            }
+            Expr::HasAttr(has_attr) => {
+                self.push(SyntaxKind::ExprHasAttr, has_attr.span());
+                self.expr(&has_attr.set);
+                self.attr_name(&has_attr.head);
+                has_attr.tail.iter().for_each(|n| self.attr_name(n));
+            }
+            Expr::AttributeAccess(access) => {
+                self.push(SyntaxKind::AttributeAccess, access.span());
+                self.expr(&access.set);
+                access.accessors.iter().for_each(|n| self.attr_name(n));
+
+                if let Some((_, default)) = &access.default {
+                    self.expr(default);
+                }
+            }
+            Expr::Path(path) => {
+                self.push(SyntaxKind::Path, path.span());
+
+                if let path::Path::Normal(path) = path.as_ref() {
+                    std::iter::once(&path.head)
+                        .chain(&path.tail)
+                        .for_each(|part| self.path_part(part));
+                }
+            }
+            Expr::Error(span) => self.push(SyntaxKind::Error, span.clone()),
+        })
+    }
+
+    fn assignment(&mut self, assignment: &Assignment) {
+        match assignment {
+            Assignment::Named(named) => {
+                self.push(SyntaxKind::AssignmentNamed, named.span());
+                self.attr_name(&named.head);
+                named.tail.iter().for_each(|n| self.attr_name(n));
+                self.expr(&named.value);
+            }
+            Assignment::Inherit(inherit) => {
+                self.push(SyntaxKind::AssignmentInherit, inherit.span());
+
+                if let Some(base) = &inherit.base {
+                    self.expr(base);
+                }
+
+                inherit.names.iter().for_each(|n| self.attr_name(n));
+            }
+        }
+    }
+
+    fn attr_name(&mut self, name: &LiteralOrInterpolatedIdent) {
+        self.push(SyntaxKind::AttrName, name.span());
+
+        if let LiteralOrInterpolatedIdent::Interpolated(interpolated) = name {
+            self.push(SyntaxKind::InterpolatedIdent, interpolated.span());
+            self.expr(&interpolated.value);
+        }
+    }
+
+    fn path_part(&mut self, part: &PathPart) {
+        for sub_part in std::iter::once(&part.head).chain(&part.tail) {
+            if let PathSubPart::Ident(LiteralOrInterpolatedIdent::Interpolated(interpolated)) =
+                sub_part
+            {
+                self.push(SyntaxKind::InterpolatedIdent, interpolated.span());
+                self.expr(&interpolated.value);
+            }
+        }
+    }
+}
+
+/// Builds green nodes from the tokens and the nodes
+/// that cover them.
+struct Builder<'a, T: Iterator, N: Iterator> {
+    input: &'a str,
+    tokens: std::iter::Peekable<T>,
+    nodes: std::iter::Peekable<N>,
+}
+
+impl<T, N> Builder<'_, T, N>
+where
+    T: Iterator<Item = (SyntaxKind, Range<usize>)>,
+    N: Iterator<Item = (SyntaxKind, Range<usize>)>,
+{
+    fn node(&mut self, kind: SyntaxKind, range: Range<usize>) -> GreenNode {
+        let mut children = Vec::new();
+
+        while let Some((_, token)) = self.tokens.peek() {
+            if token.start >= range.end {
+                break;
+            }
+
+            let token_start = token.start;
+
+            // Drop nodes that can not be nested here, which only
+            // happens if their span is wrong.
+            while self
+                .nodes
+                .next_if(|(_, child)| {
+                    child.start < token_start
+                        || (child.start == token_start && child.end > range.end)
+                })
+                .is_some()
+            {}
+
+            let child = self.nodes.next_if(|(_, child)| child.start == token_start);
+
+            if let Some((child_kind, child_range)) = child {
+                let node = crate::ensure_stack(|| self.node(child_kind, child_range));
+                children.push(node.into());
+            } else {
+                let (kind, token) = self.tokens.next().expect("A token was peeked");
+                children.push(GreenToken::new(kind, &self.input[token]).into());
+            }
+        }
+
+        GreenNode::new(kind, children)
+    }
+}
@@ -1,16 +1,107 @@
 use synix_lexer::Span;
 
-#[derive(Debug)]
+/// An error produced while parsing.
+///
+/// An error holds one or more [`Diagnostic`]s, as several errors
+/// may be [combined](Self::combine) into one.
+#[derive(Debug, Clone)]
 pub struct Error {
-    span: Span,
-    message: String,
+    /// Never empty.
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl Error {
     pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            diagnostics: vec![Diagnostic::new(span, message)],
+        }
+    }
+
+    /// The span of the first diagnostic.
+    pub fn span(&self) -> Span {
+        self.diagnostics[0].span()
+    }
+
+    /// The message of the first diagnostic.
+    pub fn message(&self) -> &str {
+        self.diagnostics[0].message()
+    }
+
+    /// Add a secondary span with a message, as "first defined
+    /// here", to the last diagnostic.
+    pub fn with_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.last().labels.push(Label {
+            span,
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Add a note to the last diagnostic.
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.last().notes.push(note.into());
+        self
+    }
+
+    /// Add a help message to the last diagnostic.
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.last().help.push(help.into());
+        self
+    }
+
+    /// Add the diagnostics of `other` after those of this error.
+    pub fn combine(&mut self, other: Error) {
+        self.diagnostics.extend(other.diagnostics);
+    }
+
+    /// The diagnostics of this error, in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> {
+        self.diagnostics.iter()
+    }
+
+    fn last(&mut self) -> &mut Diagnostic {
+        self.diagnostics
+            .last_mut()
+            .expect("An error has at least one diagnostic")
+    }
+}
+
+impl<'a> IntoIterator for &'a Error {
+    type Item = &'a Diagnostic;
+    type IntoIter = std::slice::Iter<'a, Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Error {
+    type Item = Diagnostic;
+    type IntoIter = std::vec::IntoIter<Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.diagnostics.into_iter()
+    }
+}
+
+/// A single message of an [`Error`], with the span it applies to.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    span: Span,
+    message: String,
+    labels: Vec<Label>,
+    notes: Vec<String>,
+    help: Vec<String>,
+}
+
+impl Diagnostic {
+    fn new(span: Span, message: impl Into<String>) -> Self {
         Self {
             span,
             message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: Vec::new(),
         }
     }
 
@@ -21,6 +112,36 @@ impl Error {
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// Secondary spans, each with a message.
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    pub fn help(&self) -> &[String] {
+        &self.help
+    }
+}
+
+/// A secondary span of a [`Diagnostic`].
+#[derive(Debug, Clone)]
+pub struct Label {
+    span: Span,
+    message: String,
+}
+
+impl Label {
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
 }
 
 impl From<synix_lexer::Error> for Error {
@@ -28,3 +149,28 @@ impl From<synix_lexer::Error> for Error {
         Self::new(value.span(), format!("Lexer error: {}", value.message()))
     }
 }
+
+/// A non-fatal diagnostic produced while parsing, such as the
+/// use of deprecated syntax.
+#[derive(Debug, Clone)]
+pub struct Warning {
+    span: Span,
+    message: String,
+}
+
+impl Warning {
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
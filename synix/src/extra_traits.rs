@@ -0,0 +1,100 @@
+//! `PartialEq`, `Eq` and `Hash` for nodes that store a span, which is
+//! ignored: nodes are equal if they have the same syntax tree. Nodes
+//! without a span derive these traits.
+
+use std::{
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use crate::{
+    AssignmentInherit, AssignmentNamed, Expr, ExprFunctionCall, ExprIf, ExprLegacyLet, ExprLet,
+    ExprParenthesized, ExprWith, InterpolatedIdent,
+    attrset::{AttributeAccess, ExprAttrSet},
+    binary::{ExprBinary, ExprUnary},
+    lambda::{ArgAttrSet, ExprLambda},
+    list::ExprList,
+    lit::{ExprInterpolatedStr, LitBool},
+    path::{LookupPath, PathPrefix},
+};
+
+/// Implement the traits by comparing and hashing the key that
+/// `$key` returns for a node.
+macro_rules! by_key {
+    ($($ty:ty => fn($node:ident) $key:block)*) => {
+        $(
+            impl PartialEq for $ty {
+                fn eq(&self, other: &Self) -> bool {
+                    fn key($node: &$ty) -> impl PartialEq + '_ $key
+                    key(self) == key(other)
+                }
+            }
+
+            impl Eq for $ty {}
+
+            impl Hash for $ty {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    let $node = self;
+                    $key.hash(state);
+                }
+            }
+        )*
+    };
+}
+
+by_key! {
+    ArgAttrSet => fn(node) { (&node.binds_to, &node.args, &node.ellipsis) }
+    InterpolatedIdent => fn(node) { &node.value }
+    ExprAttrSet => fn(node) { (&node.rec, &node.assignments) }
+    PathPrefix => fn(node) { node.kind }
+    LookupPath => fn(node) { (&node.head, &node.tail) }
+    ExprFunctionCall => fn(node) { (&node.head, &node.tail) }
+    ExprList => fn(node) { &node.entries }
+    LitBool => fn(node) { node.value }
+    ExprInterpolatedStr => fn(node) { &node.parts }
+    AssignmentInherit => fn(node) { (&node.base, &node.names) }
+    AssignmentNamed => fn(node) { (&node.head, &node.tail, &node.value) }
+    ExprParenthesized => fn(node) { &node.inner }
+    ExprLegacyLet => fn(node) { &node.assignments }
+    ExprBinary => fn(node) { (&node.lhs, &node.operator, &node.rhs) }
+    ExprUnary => fn(node) { (&node.operator, &node.expr) }
+    ExprLet => fn(node) { (&node.assignments, &node.body) }
+    ExprLambda => fn(node) { (&node.arg, &node.body) }
+    ExprWith => fn(node) { (&node.expr, &node.body) }
+    ExprIf => fn(node) { (&node.condition, &node.then_branch, &node.else_branch) }
+    AttributeAccess => fn(node) { (&node.set, &node.accessors, &node.default) }
+}
+
+/// Implement the traits for [`Expr`], where all [`Expr::Error`]s
+/// are equal.
+macro_rules! expr {
+    ($($variant:ident)*) => {
+        impl PartialEq for Expr {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    $((Expr::$variant(lhs), Expr::$variant(rhs)) => lhs == rhs,)*
+                    (Expr::Error(_), Expr::Error(_)) => true,
+                    _ => false,
+                }
+            }
+        }
+
+        impl Eq for Expr {}
+
+        impl Hash for Expr {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                mem::discriminant(self).hash(state);
+
+                match self {
+                    $(Expr::$variant(node) => node.hash(state),)*
+                    Expr::Error(_) => {}
+                }
+            }
+        }
+    };
+}
+
+expr! {
+    Let LegacyLet Lit InterpolatedStr Lambda Ident AttrSet Parenthesized List
+    With If FunctionCall Binary Unary HasAttr AttributeAccess Path
+}
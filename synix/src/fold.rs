@@ -0,0 +1,382 @@
+//! Transformation of the AST by value.
+//!
+//! Every method of [`Fold`] defaults to the free function of the same
+//! name, which folds all children of the node and returns it. Spans are
+//! kept as they were, also when a node is replaced.
+//!
+//! ```
+//! # use synix::{Expr, fold::{self, Fold}};
+//! /// Replace `(x)` by `x`.
+//! struct Unparenthesize;
+//!
+//! impl Fold for Unparenthesize {
+//!     fn fold_expr(&mut self, node: Expr) -> Expr {
+//!         match fold::fold_expr(self, node) {
+//!             Expr::Parenthesized(paren) => Expr::from(*paren),
+//!             expr => expr,
+//!         }
+//!     }
+//! }
+//!
+//! let expr = synix::parse("[ (a) ((b)) ]").unwrap();
+//! let expr = Unparenthesize.fold_expr(expr);
+//!
+//! assert_eq!(synix::print(&expr), "[ a b ]");
+//! ```
+
+use synix_lexer::Span;
+
+use crate::{
+    Assignment, AssignmentInherit, AssignmentNamed, Expr, ExprFunctionCall, ExprIf, ExprLegacyLet,
+    ExprLet, ExprParenthesized, ExprWith, Ident, InterpolatedIdent, LiteralOrInterpolatedIdent,
+    attrset::{AttributeAccess, ExprAttrSet, ExprHasAttr},
+    binary::{ExprBinary, ExprUnary, Operator, UnaryOperator},
+    lambda::{ArgAttrSet, ArgAttrSetValue, ExprLambda, LambdaArg},
+    list::ExprList,
+    lit::{ExprInterpolatedStr, ExprLit, InterpolatedStrPart},
+    path::{DirPath, LookupPath, Path, PathPart, PathSubPart},
+    punctuated::{Pair, Punctuated},
+};
+
+macro_rules! fold_methods {
+    ($($method:ident($ty:ty);)*) => {
+        /// Transforms the nodes of the AST by value.
+        ///
+        /// See the [module documentation](self) for details.
+        pub trait Fold {
+            $(
+                fn $method(&mut self, node: $ty) -> $ty {
+                    $method(self, node)
+                }
+            )*
+        }
+    };
+}
+
+fold_methods! {
+    fold_expr(Expr);
+    fold_expr_let(ExprLet);
+    fold_expr_legacy_let(ExprLegacyLet);
+    fold_expr_lit(ExprLit);
+    fold_expr_interpolated_str(ExprInterpolatedStr);
+    fold_interpolated_str_part(InterpolatedStrPart);
+    fold_expr_lambda(ExprLambda);
+    fold_lambda_arg(LambdaArg);
+    fold_arg_attr_set(ArgAttrSet);
+    fold_arg_attr_set_value(ArgAttrSetValue);
+    fold_ident(Ident);
+    fold_expr_attr_set(ExprAttrSet);
+    fold_expr_parenthesized(ExprParenthesized);
+    fold_expr_list(ExprList);
+    fold_expr_with(ExprWith);
+    fold_expr_if(ExprIf);
+    fold_expr_function_call(ExprFunctionCall);
+    fold_expr_binary(ExprBinary);
+    fold_operator(Operator);
+    fold_expr_unary(ExprUnary);
+    fold_unary_operator(UnaryOperator);
+    fold_expr_has_attr(ExprHasAttr);
+    fold_attribute_access(AttributeAccess);
+    fold_path(Path);
+    fold_lookup_path(LookupPath);
+    fold_dir_path(DirPath);
+    fold_path_part(PathPart);
+    fold_path_sub_part(PathSubPart);
+    fold_assignment(Assignment);
+    fold_assignment_inherit(AssignmentInherit);
+    fold_assignment_named(AssignmentNamed);
+    fold_literal_or_interpolated_ident(LiteralOrInterpolatedIdent);
+    fold_interpolated_ident(InterpolatedIdent);
+    fold_span(Span);
+}
+
+/// Fold every element of `nodes` with `fold`.
+fn fold_vec<T>(nodes: Vec<T>, fold: impl FnMut(T) -> T) -> Vec<T> {
+    nodes.into_iter().map(fold).collect()
+}
+
+/// Fold every value of `nodes` with `fold`, keeping the punctuation.
+fn fold_punctuated<T, P>(
+    nodes: Punctuated<T, P>,
+    mut fold: impl FnMut(T) -> T,
+) -> Punctuated<T, P> {
+    let mut folded = Punctuated::new();
+
+    for pair in nodes.into_pairs() {
+        match pair {
+            Pair::Punctuated(value, punct) => {
+                folded.push_value(fold(value));
+                folded.push_punct(punct);
+            }
+            Pair::End(value) => folded.push_value(fold(value)),
+        }
+    }
+
+    folded
+}
+
+pub fn fold_expr<F: Fold + ?Sized>(f: &mut F, node: Expr) -> Expr {
+    match node {
+        Expr::Let(let_) => Expr::Let(Box::new(f.fold_expr_let(*let_))),
+        Expr::LegacyLet(let_) => Expr::LegacyLet(f.fold_expr_legacy_let(let_)),
+        Expr::Lit(lit) => Expr::Lit(f.fold_expr_lit(lit)),
+        Expr::InterpolatedStr(str) => Expr::InterpolatedStr(f.fold_expr_interpolated_str(str)),
+        Expr::Lambda(lambda) => Expr::Lambda(Box::new(f.fold_expr_lambda(*lambda))),
+        Expr::Ident(ident) => Expr::Ident(f.fold_ident(ident)),
+        Expr::AttrSet(set) => Expr::AttrSet(f.fold_expr_attr_set(set)),
+        Expr::Parenthesized(paren) => {
+            Expr::Parenthesized(Box::new(f.fold_expr_parenthesized(*paren)))
+        }
+        Expr::List(list) => Expr::List(f.fold_expr_list(list)),
+        Expr::With(with) => Expr::With(Box::new(f.fold_expr_with(*with))),
+        Expr::If(if_) => Expr::If(Box::new(f.fold_expr_if(*if_))),
+        Expr::FunctionCall(call) => Expr::FunctionCall(Box::new(f.fold_expr_function_call(*call))),
+        Expr::Binary(binary) => Expr::Binary(Box::new(f.fold_expr_binary(*binary))),
+        Expr::Unary(unary) => Expr::Unary(Box::new(f.fold_expr_unary(*unary))),
+        Expr::HasAttr(has_attr) => Expr::HasAttr(Box::new(f.fold_expr_has_attr(*has_attr))),
+        Expr::AttributeAccess(access) => {
+            Expr::AttributeAccess(Box::new(f.fold_attribute_access(*access)))
+        }
+        Expr::Path(path) => Expr::Path(Box::new(f.fold_path(*path))),
+        Expr::Error(span) => Expr::Error(f.fold_span(span)),
+    }
+}
+
+pub fn fold_expr_let<F: Fold + ?Sized>(f: &mut F, mut node: ExprLet) -> ExprLet {
+    node.assignments = fold_vec(node.assignments, |a| f.fold_assignment(a));
+    node.body = f.fold_expr(node.body);
+    node
+}
+
+pub fn fold_expr_legacy_let<F: Fold + ?Sized>(f: &mut F, mut node: ExprLegacyLet) -> ExprLegacyLet {
+    node.assignments = fold_vec(node.assignments, |a| f.fold_assignment(a));
+    node
+}
+
+pub fn fold_expr_lit<F: Fold + ?Sized>(_f: &mut F, node: ExprLit) -> ExprLit {
+    node
+}
+
+pub fn fold_expr_interpolated_str<F: Fold + ?Sized>(
+    f: &mut F,
+    mut node: ExprInterpolatedStr,
+) -> ExprInterpolatedStr {
+    node.parts = fold_vec(node.parts, |part| f.fold_interpolated_str_part(part));
+    node
+}
+
+pub fn fold_interpolated_str_part<F: Fold + ?Sized>(
+    f: &mut F,
+    node: InterpolatedStrPart,
+) -> InterpolatedStrPart {
+    match node {
+        InterpolatedStrPart::Str(str) => InterpolatedStrPart::Str(str),
+        InterpolatedStrPart::Interpolation(expr) => {
+            InterpolatedStrPart::Interpolation(f.fold_expr(expr))
+        }
+    }
+}
+
+pub fn fold_expr_lambda<F: Fold + ?Sized>(f: &mut F, mut node: ExprLambda) -> ExprLambda {
+    node.arg = f.fold_lambda_arg(node.arg);
+    node.body = f.fold_expr(node.body);
+    node
+}
+
+pub fn fold_lambda_arg<F: Fold + ?Sized>(f: &mut F, node: LambdaArg) -> LambdaArg {
+    match node {
+        LambdaArg::Ident(ident) => LambdaArg::Ident(f.fold_ident(ident)),
+        LambdaArg::AttrSet(set) => LambdaArg::AttrSet(f.fold_arg_attr_set(set)),
+    }
+}
+
+pub fn fold_arg_attr_set<F: Fold + ?Sized>(f: &mut F, mut node: ArgAttrSet) -> ArgAttrSet {
+    node.binds_to = node.binds_to.map(|ident| f.fold_ident(ident));
+    node.args = fold_punctuated(node.args, |arg| f.fold_arg_attr_set_value(arg));
+    node
+}
+
+pub fn fold_arg_attr_set_value<F: Fold + ?Sized>(
+    f: &mut F,
+    mut node: ArgAttrSetValue,
+) -> ArgAttrSetValue {
+    node.ident = f.fold_ident(node.ident);
+    node.default = node.default.map(|default| f.fold_expr(default));
+    node
+}
+
+pub fn fold_ident<F: Fold + ?Sized>(_f: &mut F, node: Ident) -> Ident {
+    node
+}
+
+pub fn fold_expr_attr_set<F: Fold + ?Sized>(f: &mut F, mut node: ExprAttrSet) -> ExprAttrSet {
+    node.assignments = fold_vec(node.assignments, |a| f.fold_assignment(a));
+    node
+}
+
+pub fn fold_expr_parenthesized<F: Fold + ?Sized>(
+    f: &mut F,
+    mut node: ExprParenthesized,
+) -> ExprParenthesized {
+    node.inner = f.fold_expr(node.inner);
+    node
+}
+
+pub fn fold_expr_list<F: Fold + ?Sized>(f: &mut F, mut node: ExprList) -> ExprList {
+    node.entries = fold_vec(node.entries, |entry| f.fold_expr(entry));
+    node
+}
+
+pub fn fold_expr_with<F: Fold + ?Sized>(f: &mut F, mut node: ExprWith) -> ExprWith {
+    node.expr = f.fold_expr(node.expr);
+    node.body = f.fold_expr(node.body);
+    node
+}
+
+pub fn fold_expr_if<F: Fold + ?Sized>(f: &mut F, mut node: ExprIf) -> ExprIf {
+    node.condition = f.fold_expr(node.condition);
+    node.then_branch = f.fold_expr(node.then_branch);
+    node.else_branch = f.fold_expr(node.else_branch);
+    node
+}
+
+pub fn fold_expr_function_call<F: Fold + ?Sized>(
+    f: &mut F,
+    mut node: ExprFunctionCall,
+) -> ExprFunctionCall {
+    node.head = f.fold_expr(node.head);
+    node.tail = f.fold_expr(node.tail);
+    node
+}
+
+pub fn fold_expr_binary<F: Fold + ?Sized>(f: &mut F, mut node: ExprBinary) -> ExprBinary {
+    node.lhs = f.fold_expr(node.lhs);
+    node.operator = f.fold_operator(node.operator);
+    node.rhs = f.fold_expr(node.rhs);
+    node
+}
+
+pub fn fold_operator<F: Fold + ?Sized>(_f: &mut F, node: Operator) -> Operator {
+    node
+}
+
+pub fn fold_expr_unary<F: Fold + ?Sized>(f: &mut F, mut node: ExprUnary) -> ExprUnary {
+    node.operator = f.fold_unary_operator(node.operator);
+    node.expr = f.fold_expr(node.expr);
+    node
+}
+
+pub fn fold_unary_operator<F: Fold + ?Sized>(_f: &mut F, node: UnaryOperator) -> UnaryOperator {
+    node
+}
+
+pub fn fold_expr_has_attr<F: Fold + ?Sized>(f: &mut F, mut node: ExprHasAttr) -> ExprHasAttr {
+    node.set = f.fold_expr(node.set);
+    node.head = f.fold_literal_or_interpolated_ident(node.head);
+    node.tail = fold_vec(node.tail, |name| f.fold_literal_or_interpolated_ident(name));
+    node
+}
+
+pub fn fold_attribute_access<F: Fold + ?Sized>(
+    f: &mut F,
+    mut node: AttributeAccess,
+) -> AttributeAccess {
+    node.set = f.fold_expr(node.set);
+    node.accessors = fold_vec(node.accessors, |accessor| {
+        f.fold_literal_or_interpolated_ident(accessor)
+    });
+    node.default = node.default.map(|(or, default)| (or, f.fold_expr(default)));
+    node
+}
+
+pub fn fold_path<F: Fold + ?Sized>(f: &mut F, node: Path) -> Path {
+    match node {
+        Path::Lookup(lookup) => Path::Lookup(f.fold_lookup_path(lookup)),
+        Path::Normal(dir) => Path::Normal(f.fold_dir_path(dir)),
+    }
+}
+
+pub fn fold_lookup_path<F: Fold + ?Sized>(f: &mut F, mut node: LookupPath) -> LookupPath {
+    node.head = f.fold_ident(node.head);
+    node.tail = fold_vec(node.tail, |part| f.fold_ident(part));
+    node
+}
+
+pub fn fold_dir_path<F: Fold + ?Sized>(f: &mut F, mut node: DirPath) -> DirPath {
+    node.head = f.fold_path_part(node.head);
+    node.tail = fold_vec(node.tail, |part| f.fold_path_part(part));
+    node
+}
+
+pub fn fold_path_part<F: Fold + ?Sized>(f: &mut F, mut node: PathPart) -> PathPart {
+    node.head = f.fold_path_sub_part(node.head);
+    node.tail = fold_vec(node.tail, |sub_part| f.fold_path_sub_part(sub_part));
+    node
+}
+
+pub fn fold_path_sub_part<F: Fold + ?Sized>(f: &mut F, node: PathSubPart) -> PathSubPart {
+    match node {
+        PathSubPart::Ident(ident) => {
+            PathSubPart::Ident(f.fold_literal_or_interpolated_ident(ident))
+        }
+        node @ (PathSubPart::LitInt(_) | PathSubPart::LitFloat(_) | PathSubPart::Dot(_)) => node,
+    }
+}
+
+pub fn fold_assignment<F: Fold + ?Sized>(f: &mut F, node: Assignment) -> Assignment {
+    match node {
+        Assignment::Inherit(inherit) => Assignment::Inherit(f.fold_assignment_inherit(inherit)),
+        Assignment::Named(named) => Assignment::Named(f.fold_assignment_named(named)),
+    }
+}
+
+pub fn fold_assignment_inherit<F: Fold + ?Sized>(
+    f: &mut F,
+    mut node: AssignmentInherit,
+) -> AssignmentInherit {
+    node.base = node.base.map(|base| f.fold_expr(base));
+    node.names = fold_vec(node.names, |name| {
+        f.fold_literal_or_interpolated_ident(name)
+    });
+    node
+}
+
+pub fn fold_assignment_named<F: Fold + ?Sized>(
+    f: &mut F,
+    mut node: AssignmentNamed,
+) -> AssignmentNamed {
+    node.head = f.fold_literal_or_interpolated_ident(node.head);
+    node.tail = fold_vec(node.tail, |name| f.fold_literal_or_interpolated_ident(name));
+    node.value = f.fold_expr(node.value);
+    node
+}
+
+pub fn fold_literal_or_interpolated_ident<F: Fold + ?Sized>(
+    f: &mut F,
+    node: LiteralOrInterpolatedIdent,
+) -> LiteralOrInterpolatedIdent {
+    match node {
+        LiteralOrInterpolatedIdent::Literal(ident) => {
+            LiteralOrInterpolatedIdent::Literal(f.fold_ident(ident))
+        }
+        LiteralOrInterpolatedIdent::Interpolated(interpolated) => {
+            LiteralOrInterpolatedIdent::Interpolated(f.fold_interpolated_ident(interpolated))
+        }
+        LiteralOrInterpolatedIdent::Str(str) => LiteralOrInterpolatedIdent::Str(str),
+        LiteralOrInterpolatedIdent::InterpolatedStr(str) => {
+            LiteralOrInterpolatedIdent::InterpolatedStr(f.fold_expr_interpolated_str(str))
+        }
+    }
+}
+
+pub fn fold_interpolated_ident<F: Fold + ?Sized>(
+    f: &mut F,
+    mut node: InterpolatedIdent,
+) -> InterpolatedIdent {
+    node.value = f.fold_expr(node.value);
+    node
+}
+
+pub fn fold_span<F: Fold + ?Sized>(_f: &mut F, node: Span) -> Span {
+    node
+}
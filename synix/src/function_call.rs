@@ -3,6 +3,8 @@ use synix_lexer::Span;
 use crate::Expr;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprFunctionCall {
     pub head: Expr,
     pub tail: Expr,
@@ -1,12 +1,23 @@
-use synix_lexer::{Span, TokenTree};
+use synix_lexer::{Span, TokenTree, literal::Literal};
+
+use crate::lit::{ExprInterpolatedStr, LitStr};
 
 use crate::*;
 
-// TODO: do all usages of this also support strings?
+/// An attribute name.
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum LiteralOrInterpolatedIdent {
+    /// `name`
     Literal(Ident),
+    /// `${name}`
     Interpolated(InterpolatedIdent),
+    /// `"name"`
+    Str(LitStr),
+    /// `"name ${value}"`
+    InterpolatedStr(ExprInterpolatedStr),
 }
 
 impl LiteralOrInterpolatedIdent {
@@ -16,13 +27,33 @@ impl LiteralOrInterpolatedIdent {
             LiteralOrInterpolatedIdent::Interpolated(interpolated_ident) => {
                 interpolated_ident.span()
             }
+            LiteralOrInterpolatedIdent::Str(str) => str.span(),
+            LiteralOrInterpolatedIdent::InterpolatedStr(str) => str.span(),
+        }
+    }
+
+    /// The name of the attribute, if it does not contain
+    /// any interpolations.
+    pub fn static_name(&self) -> Option<&str> {
+        match self {
+            LiteralOrInterpolatedIdent::Literal(ident) => Some(ident.ident()),
+            LiteralOrInterpolatedIdent::Str(str) => Some(&str.value),
+            LiteralOrInterpolatedIdent::Interpolated(_)
+            | LiteralOrInterpolatedIdent::InterpolatedStr(_) => None,
         }
     }
 }
 
 impl Peek for LiteralOrInterpolatedIdent {
     fn peek(input: &ParseBuffer) -> bool {
-        Ident::peek(input) || InterpolatedIdent::peek(input)
+        Ident::peek(input)
+            || InterpolatedIdent::peek(input)
+            || input.peek(LitStr)
+            || ExprInterpolatedStr::peek(input)
+    }
+
+    fn display() -> &'static str {
+        "attribute name"
     }
 }
 
@@ -32,34 +63,64 @@ impl Parse for LiteralOrInterpolatedIdent {
             Ok(Self::Literal(buffer.parse()?))
         } else if InterpolatedIdent::peek(buffer) {
             Ok(Self::Interpolated(buffer.parse()?))
+        } else if let Some(TokenTree::Literal(Literal::Str(str))) = buffer.peek_tree() {
+            let _ = buffer.next();
+            Ok(Self::Str(str.clone()))
+        } else if ExprInterpolatedStr::peek(buffer) {
+            Ok(Self::InterpolatedStr(buffer.parse()?))
         } else {
-            Err(Error::new(
-                buffer.span(),
-                "Expected literal or interpolated ident",
-            ))
+            Err(Error::new(buffer.span(), "Expected attribute name"))
         }
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Ident {
     inner: synix_lexer::Ident,
 }
 
 impl Ident {
+    /// Keywords that can not be used as identifiers.
+    ///
+    /// `or` is a keyword too, but may still be used as an identifier
+    /// unless [`ParseOptions::or_identifier`] is disabled.
+    pub const KEYWORDS: &[&str] = &[
+        "let", "in", "rec", "inherit", "with", "if", "then", "else", "assert",
+    ];
+
     pub fn ident(&self) -> &str {
         self.inner.ident()
     }
 
+    pub fn is_keyword(ident: &str) -> bool {
+        Self::KEYWORDS.contains(&ident)
+    }
+
     pub fn span(&self) -> Span {
         self.inner.span()
     }
 }
 
+impl Ident {
+    /// Whether `ident` is a keyword in the context of `buffer`.
+    pub(crate) fn is_reserved(buffer: &ParseBuffer, ident: &synix_lexer::Ident) -> bool {
+        Self::is_keyword(ident.ident())
+            || (ident.ident() == "or" && !buffer.options().has_or_identifier())
+    }
+}
+
 impl Parse for Ident {
     fn parse(buffer: &mut crate::ParseBuffer) -> crate::Result<Self> {
         // TODO: warn on un-handy idents?
         if let Some(TokenTree::Ident(ident)) = buffer.peek_tree() {
+            if Self::is_reserved(buffer, ident) {
+                let msg = format!("Expected ident, got keyword `{}`", ident.ident());
+                return Err(Error::new(ident.span(), msg));
+            }
+
             let _ = buffer.next();
             Ok(Self {
                 inner: ident.clone(),
@@ -72,11 +133,17 @@ impl Parse for Ident {
 
 impl Peek for Ident {
     fn peek(input: &crate::ParseBuffer) -> bool {
-        Ident::parse(&mut input.fork()).is_ok()
+        matches!(input.peek_tree(), Some(TokenTree::Ident(ident)) if !Self::is_reserved(input, ident))
+    }
+
+    fn display() -> &'static str {
+        "identifier"
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterpolatedIdent {
     pub dollar: Token![$],
     pub value: Expr,
@@ -93,6 +160,10 @@ impl Peek for InterpolatedIdent {
     fn peek(input: &crate::ParseBuffer) -> bool {
         <Token![$]>::peek(input)
     }
+
+    fn display() -> &'static str {
+        "`${`"
+    }
 }
 
 impl Parse for InterpolatedIdent {
@@ -103,13 +174,9 @@ impl Parse for InterpolatedIdent {
         let mut inner;
         braced!(buffer as inner else "Expected braced expression.");
 
-        let value = inner.parse()?;
-
-        if !inner.is_empty() {
-            return Err(Error::new(inner.span(), "Leftover tokens."));
-        }
-
-        let span = inner.span().join(&span);
+        let span = buffer.span_from(&span);
+        let value = inner.parse_expr_or_recover(|_| false)?;
+        inner.expect_empty()?;
 
         Ok(Self {
             dollar,
@@ -0,0 +1,54 @@
+use synix_lexer::Span;
+
+use crate::*;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExprIf {
+    pub if_: Token![if],
+    pub condition: Expr,
+    pub then: Token![then],
+    pub then_branch: Expr,
+    pub else_: Token![else],
+    pub else_branch: Expr,
+    span: Span,
+}
+
+impl ExprIf {
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl Peek for ExprIf {
+    fn peek(input: &ParseBuffer) -> bool {
+        <Token![if]>::peek(input)
+    }
+
+    fn display() -> &'static str {
+        "`if`"
+    }
+}
+
+impl Parse for ExprIf {
+    fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
+        let if_: Token![if] = buffer.parse()?;
+        let condition = buffer.parse()?;
+        let then = buffer.parse()?;
+        let then_branch = buffer.parse()?;
+        let else_ = buffer.parse()?;
+        let else_branch: Expr = buffer.parse()?;
+        let span = if_.span.join(&else_branch.span());
+
+        Ok(Self {
+            if_,
+            condition,
+            then,
+            then_branch,
+            else_,
+            else_branch,
+            span,
+        })
+    }
+}
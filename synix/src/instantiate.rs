@@ -0,0 +1,981 @@
+//! Printing in the normal form of `nix-instantiate --parse`.
+//!
+//! Nix desugars expressions while parsing them, and `nix-instantiate
+//! --parse` prints the result with every compound expression
+//! parenthesized. This module reproduces that output, so that the
+//! parser can be tested against Nix by comparing the two:
+//!
+//! - Operators without syntax of their own become calls to builtins,
+//!   as `a - b` to `(__sub a b)` and `a > b` to `(__lessThan b a)`.
+//! - Nested attribute paths are merged into attribute sets, which are
+//!   printed with `inherit`s first and sorted names.
+//! - Paths are resolved to absolute paths, and lookup paths become
+//!   calls to `__findFile`.
+//! - Calls with several arguments are printed as a single call.
+//!
+//! Like Nix, defining an attribute twice is an error, unless both
+//! definitions are attribute sets, which are then merged.
+//!
+//! ```
+//! use synix::instantiate::{self, Options};
+//!
+//! let expr = synix::parse("{ a.b = 1; a.c = x: -x; }").unwrap();
+//! let options = Options::new("/base", "/home");
+//!
+//! assert_eq!(
+//!     instantiate::print(&expr, &options).unwrap(),
+//!     "{ a = { b = 1; c = (x: (__sub 0 x)); }; }"
+//! );
+//!
+//! let expr = synix::parse("{ a = 1; a.b = 2; }").unwrap();
+//! assert!(instantiate::print(&expr, &options).is_err());
+//! ```
+
+use std::collections::BTreeMap;
+
+use synix_lexer::Span;
+
+use crate::{
+    Assignment, Error, Expr, ExprFunctionCall, ExprLegacyLet, ExprLet, LiteralOrInterpolatedIdent,
+    Result,
+    attrset::{AttributeAccess, ExprAttrSet, ExprHasAttr},
+    binary::{ExprBinary, ExprUnary, Operator, UnaryOperator},
+    lambda::{ExprLambda, LambdaArg},
+    lit::{ExprInterpolatedStr, ExprLit, InterpolatedStrPart},
+    path::{DirPath, LookupPath, Path, PathPrefixKind, PathSubPart},
+    print::ToTokens,
+};
+
+/// The directories that relative paths are resolved against.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// The directory of the file, which `./a` is relative to.
+    pub base_dir: String,
+    /// The home directory, which `~/a` is relative to.
+    pub home_dir: String,
+}
+
+impl Options {
+    pub fn new(base_dir: impl Into<String>, home_dir: impl Into<String>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            home_dir: home_dir.into(),
+        }
+    }
+}
+
+/// Print `expr` as `nix-instantiate --parse` does, without the
+/// final newline.
+///
+/// Fails on the errors that Nix reports while parsing but this
+/// parser does not, which are attributes that are defined twice.
+pub fn print(expr: &Expr, options: &Options) -> Result<String> {
+    let node = Lower { options }.expr(expr)?;
+
+    let mut output = String::new();
+    node.show(&mut output);
+    Ok(output)
+}
+
+/// An expression as Nix represents it after parsing.
+#[derive(Debug)]
+enum Node {
+    Int(String),
+    Float(String),
+    Str(String),
+    Path(String),
+    Var(String),
+    Select {
+        set: Box<Node>,
+        path: Vec<AttrName>,
+        default: Option<Box<Node>>,
+    },
+    HasAttr {
+        set: Box<Node>,
+        path: Vec<AttrName>,
+    },
+    Attrs(Attrs),
+    List(Vec<Node>),
+    Lambda {
+        arg: Option<String>,
+        formals: Option<Formals>,
+        body: Box<Node>,
+    },
+    Call {
+        fun: Box<Node>,
+        args: Vec<Node>,
+    },
+    Let {
+        attrs: Attrs,
+        body: Box<Node>,
+    },
+    With {
+        env: Box<Node>,
+        body: Box<Node>,
+    },
+    If {
+        condition: Box<Node>,
+        then_branch: Box<Node>,
+        else_branch: Box<Node>,
+    },
+    Not(Box<Node>),
+    Binary(&'static str, Box<Node>, Box<Node>),
+    /// `+` and interpolated strings.
+    Concat(Vec<Node>),
+    Error,
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        // Nodes are nested as deeply as the expression, so rather than
+        // recursing, their children are moved out and dropped in a loop.
+        let mut nodes = Vec::new();
+        self.take_children(&mut nodes);
+
+        while let Some(mut node) = nodes.pop() {
+            node.take_children(&mut nodes);
+        }
+    }
+}
+
+impl Node {
+    /// Move the children of this node to `nodes`.
+    fn take_children(&mut self, nodes: &mut Vec<Node>) {
+        fn take(node: &mut Node) -> Node {
+            std::mem::replace(node, Node::Error)
+        }
+
+        fn take_names(path: &mut Vec<AttrName>, nodes: &mut Vec<Node>) {
+            nodes.extend(path.drain(..).filter_map(|name| match name {
+                AttrName::Static(_) => None,
+                AttrName::Dynamic(node) => Some(node),
+            }));
+        }
+
+        match self {
+            Node::Int(_)
+            | Node::Float(_)
+            | Node::Str(_)
+            | Node::Path(_)
+            | Node::Var(_)
+            | Node::Error => {}
+            Node::Select { set, path, default } => {
+                nodes.push(take(set));
+                take_names(path, nodes);
+                nodes.extend(default.take().map(|default| *default));
+            }
+            Node::HasAttr { set, path } => {
+                nodes.push(take(set));
+                take_names(path, nodes);
+            }
+            Node::Attrs(attrs) => attrs.take_nodes(nodes),
+            Node::List(items) | Node::Concat(items) => nodes.append(items),
+            Node::Lambda { formals, body, .. } => {
+                if let Some(formals) = formals {
+                    nodes.extend(formals.args.iter_mut().filter_map(|(_, d)| d.take()));
+                }
+                nodes.push(take(body));
+            }
+            Node::Call { fun, args } => {
+                nodes.push(take(fun));
+                nodes.append(args);
+            }
+            Node::Let { attrs, body } => {
+                attrs.take_nodes(nodes);
+                nodes.push(take(body));
+            }
+            Node::With { env, body } => {
+                nodes.push(take(env));
+                nodes.push(take(body));
+            }
+            Node::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                nodes.push(take(condition));
+                nodes.push(take(then_branch));
+                nodes.push(take(else_branch));
+            }
+            Node::Not(node) => nodes.push(take(node)),
+            Node::Binary(_, lhs, rhs) => {
+                nodes.push(take(lhs));
+                nodes.push(take(rhs));
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+enum AttrName {
+    Static(String),
+    Dynamic(Node),
+}
+
+#[derive(Debug, Default)]
+struct Attrs {
+    rec: bool,
+    /// Static attributes, sorted by name, with the span of the
+    /// attribute path that defined them.
+    attrs: BTreeMap<String, (Attr, Span)>,
+    dynamic: Vec<(Node, Node)>,
+    /// The sets of `inherit (set) ...;`, in order.
+    inherit_from: Vec<Node>,
+}
+
+#[derive(Debug)]
+enum Attr {
+    Plain(Node),
+    Inherited,
+    /// Inherited from the set with this index in
+    /// [`Attrs::inherit_from`].
+    InheritedFrom(usize),
+}
+
+#[derive(Debug)]
+struct Formals {
+    /// The arguments with their defaults, sorted by name.
+    args: Vec<(String, Option<Node>)>,
+    ellipsis: bool,
+}
+
+impl Attrs {
+    /// Define `path` to be `value`, where `span` is the span of the
+    /// whole attribute path and `full_path` its text.
+    ///
+    /// Attribute sets are merged with the attribute sets that are
+    /// already defined, and any other attribute that is already
+    /// defined is an error.
+    fn insert(
+        &mut self,
+        path: Vec<AttrName>,
+        mut value: Node,
+        span: Span,
+        full_path: &str,
+    ) -> Result<()> {
+        let mut path = path.into_iter();
+        let Some(first) = path.next() else {
+            return Ok(());
+        };
+        let rest: Vec<_> = path.collect();
+
+        let name = match first {
+            AttrName::Static(name) => name,
+            AttrName::Dynamic(name) => {
+                let value = Self::nested(rest, value, span, full_path)?;
+                self.dynamic.push((name, value));
+                return Ok(());
+            }
+        };
+
+        let Some((existing, first_span)) = self.attrs.get_mut(&name) else {
+            let value = Self::nested(rest, value, span.clone(), full_path)?;
+            self.attrs.insert(name, (Attr::Plain(value), span));
+            return Ok(());
+        };
+
+        match (existing, &mut value) {
+            // Nested attributes extend an existing set.
+            (Attr::Plain(Node::Attrs(existing)), _) if !rest.is_empty() => {
+                existing.insert(rest, value, span, full_path)
+            }
+            (Attr::Plain(Node::Attrs(existing)), Node::Attrs(attrs)) => {
+                existing.merge(std::mem::take(attrs))
+            }
+            _ => Err(already_defined(full_path, span, first_span.clone())),
+        }
+    }
+
+    /// Move the values of these attributes to `nodes`, see
+    /// [`Node::take_children`].
+    fn take_nodes(&mut self, nodes: &mut Vec<Node>) {
+        for (attr, _) in self.attrs.values_mut() {
+            if let Attr::Plain(node) = attr {
+                nodes.push(std::mem::replace(node, Node::Error));
+            }
+        }
+
+        for (name, value) in self.dynamic.drain(..) {
+            nodes.push(name);
+            nodes.push(value);
+        }
+
+        nodes.append(&mut self.inherit_from);
+    }
+
+    /// `value` nested in new sets for each name in `path`.
+    fn nested(path: Vec<AttrName>, value: Node, span: Span, full_path: &str) -> Result<Node> {
+        if path.is_empty() {
+            return Ok(value);
+        }
+
+        let mut attrs = Attrs::default();
+        attrs.insert(path, value, span, full_path)?;
+        Ok(Node::Attrs(attrs))
+    }
+
+    /// Define `name` to be inherited.
+    fn inherit(&mut self, name: &str, attr: Attr, span: Span) -> Result<()> {
+        if let Some((_, first)) = self.attrs.get(name) {
+            return Err(already_defined(name, span, first.clone()));
+        }
+
+        self.attrs.insert(name.to_string(), (attr, span));
+        Ok(())
+    }
+
+    /// Add the attributes of `other`, none of which may be defined
+    /// already. Unlike [`Self::insert`], sets are not merged again.
+    fn merge(&mut self, other: Attrs) -> Result<()> {
+        let offset = self.inherit_from.len();
+        self.inherit_from.extend(other.inherit_from);
+
+        for (name, (attr, span)) in other.attrs {
+            if let Some((_, first)) = self.attrs.get(&name) {
+                return Err(already_defined(&name, span, first.clone()));
+            }
+
+            let attr = match attr {
+                Attr::InheritedFrom(index) => Attr::InheritedFrom(index + offset),
+                attr => attr,
+            };
+
+            self.attrs.insert(name, (attr, span));
+        }
+
+        self.dynamic.extend(other.dynamic);
+        Ok(())
+    }
+}
+
+/// The error for an attribute that is defined twice.
+fn already_defined(path: &str, span: Span, first: Span) -> Error {
+    Error::new(span, format!("Attribute `{path}` is already defined."))
+        .with_label(first, "first defined here")
+}
+
+struct Lower<'a> {
+    options: &'a Options,
+}
+
+impl Lower<'_> {
+    fn expr(&self, expr: &Expr) -> Result<Node> {
+        crate::ensure_stack(|| {
+            let node = match expr {
+                Expr::Let(expr_let) => self.expr_let(expr_let)?,
+                Expr::LegacyLet(legacy_let) => self.legacy_let(legacy_let)?,
+                Expr::Lit(lit) => self.lit(lit),
+                Expr::InterpolatedStr(str) => self.interpolated_str(str)?,
+                Expr::Lambda(lambda) => self.lambda(lambda)?,
+                Expr::Ident(ident) => Node::Var(ident.ident().to_string()),
+                Expr::AttrSet(attr_set) => Node::Attrs(self.attr_set(attr_set)?),
+                Expr::Parenthesized(paren) => self.expr(&paren.inner)?,
+                Expr::List(list) => Node::List(
+                    list.entries
+                        .iter()
+                        .map(|e| self.expr(e))
+                        .collect::<Result<_>>()?,
+                ),
+                Expr::With(with) => Node::With {
+                    env: Box::new(self.expr(&with.expr)?),
+                    body: Box::new(self.expr(&with.body)?),
+                },
+                Expr::If(expr_if) => Node::If {
+                    condition: Box::new(self.expr(&expr_if.condition)?),
+                    then_branch: Box::new(self.expr(&expr_if.then_branch)?),
+                    else_branch: Box::new(self.expr(&expr_if.else_branch)?),
+                },
+                Expr::FunctionCall(call) => self.function_call(call)?,
+                Expr::Binary(binary) => self.binary(binary)?,
+                Expr::Unary(unary) => self.unary(unary)?,
+                Expr::HasAttr(has_attr) => self.has_attr(has_attr)?,
+                Expr::AttributeAccess(access) => self.attribute_access(access)?,
+                Expr::Path(path) => match &**path {
+                    Path::Lookup(lookup) => self.lookup_path(lookup),
+                    Path::Normal(dir) => self.dir_path(dir)?,
+                },
+                Expr::Error(_) => Node::Error,
+            };
+
+            Ok(node)
+        })
+    }
+
+    fn bindings(&self, assignments: &[Assignment], rec: bool) -> Result<Attrs> {
+        let mut attrs = Attrs {
+            rec,
+            ..Attrs::default()
+        };
+
+        for assignment in assignments {
+            match assignment {
+                Assignment::Named(named) => {
+                    let path = std::iter::once(&named.head)
+                        .chain(&named.tail)
+                        .map(|name| self.attr_name(name))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    let mut full_path = String::new();
+                    show_attr_path(&path, &mut full_path);
+
+                    let last = named.tail.last().unwrap_or(&named.head);
+                    let span = named.head.span().join(&last.span());
+
+                    attrs.insert(path, self.expr(&named.value)?, span, &full_path)?;
+                }
+                Assignment::Inherit(inherit) => {
+                    let from = match &inherit.base {
+                        Some(base) => {
+                            attrs.inherit_from.push(self.expr(base)?);
+                            Some(attrs.inherit_from.len() - 1)
+                        }
+                        None => None,
+                    };
+
+                    // Without interpolations, as the parser checks.
+                    for name in &inherit.names {
+                        let Some(static_name) = name.static_name() else {
+                            continue;
+                        };
+
+                        let attr = match from {
+                            Some(index) => Attr::InheritedFrom(index),
+                            None => Attr::Inherited,
+                        };
+
+                        attrs.inherit(static_name, attr, name.span())?;
+                    }
+                }
+            }
+        }
+
+        Ok(attrs)
+    }
+
+    fn attr_set(&self, attr_set: &ExprAttrSet) -> Result<Attrs> {
+        self.bindings(&attr_set.assignments, attr_set.rec.is_some())
+    }
+
+    fn expr_let(&self, expr_let: &ExprLet) -> Result<Node> {
+        Ok(Node::Let {
+            attrs: self.bindings(&expr_let.assignments, false)?,
+            body: Box::new(self.expr(&expr_let.body)?),
+        })
+    }
+
+    /// `let { body = ...; }` is `rec { body = ...; }.body`.
+    fn legacy_let(&self, legacy_let: &ExprLegacyLet) -> Result<Node> {
+        Ok(Node::Select {
+            set: Box::new(Node::Attrs(self.bindings(&legacy_let.assignments, true)?)),
+            path: vec![AttrName::Static("body".to_string())],
+            default: None,
+        })
+    }
+
+    fn attr_name(&self, name: &LiteralOrInterpolatedIdent) -> Result<AttrName> {
+        let mut node = match name {
+            LiteralOrInterpolatedIdent::Literal(ident) => {
+                return Ok(AttrName::Static(ident.ident().to_string()));
+            }
+            LiteralOrInterpolatedIdent::Str(str) => return Ok(AttrName::Static(str.value.clone())),
+            LiteralOrInterpolatedIdent::Interpolated(interpolated) => {
+                self.expr(&interpolated.value)?
+            }
+            LiteralOrInterpolatedIdent::InterpolatedStr(str) => self.interpolated_str(str)?,
+        };
+
+        // `${"a"}` is the same as `a`.
+        if let Node::Str(str) = &mut node {
+            return Ok(AttrName::Static(std::mem::take(str)));
+        }
+
+        Ok(AttrName::Dynamic(node))
+    }
+
+    fn lit(&self, lit: &ExprLit) -> Node {
+        match lit {
+            ExprLit::Int(int) => Node::Int(match int.digits.parse::<u64>() {
+                Ok(int) => int.to_string(),
+                Err(_) => int.digits.clone(),
+            }),
+            ExprLit::Float(float) => Node::Float(float.digits.clone()),
+            ExprLit::Str(str) => Node::Str(str.value.clone()),
+            // `true` and `false` are variables in Nix.
+            ExprLit::Bool(bool) => Node::Var(bool.value.to_string()),
+            ExprLit::Url(url) => Node::Str(url.url.clone()),
+        }
+    }
+
+    fn interpolated_str(&self, str: &ExprInterpolatedStr) -> Result<Node> {
+        let parts = str
+            .parts
+            .iter()
+            .filter_map(|part| match part {
+                InterpolatedStrPart::Str(str) if str.is_empty() => None,
+                InterpolatedStrPart::Str(str) => Some(Ok(Node::Str(str.clone()))),
+                InterpolatedStrPart::Interpolation(expr) => Some(self.expr(expr)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(match parts.as_slice() {
+            [] => Node::Str(String::new()),
+            _ => Node::Concat(parts),
+        })
+    }
+
+    fn lambda(&self, lambda: &ExprLambda) -> Result<Node> {
+        let (arg, formals) = match &lambda.arg {
+            LambdaArg::Ident(ident) => (Some(ident.ident().to_string()), None),
+            LambdaArg::AttrSet(set) => {
+                let mut args = set
+                    .args
+                    .iter()
+                    .map(|arg| {
+                        let default = arg.default.as_ref().map(|d| self.expr(d)).transpose()?;
+                        Ok((arg.ident.ident().to_string(), default))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                args.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+                let formals = Formals {
+                    args,
+                    ellipsis: set.ellipsis.is_some(),
+                };
+                let arg = set.binds_to.as_ref().map(|i| i.ident().to_string());
+
+                (arg, Some(formals))
+            }
+        };
+
+        Ok(Node::Lambda {
+            arg,
+            formals,
+            body: Box::new(self.expr(&lambda.body)?),
+        })
+    }
+
+    /// Calls that have a call as their function take its arguments,
+    /// even through parentheses.
+    fn function_call(&self, call: &ExprFunctionCall) -> Result<Node> {
+        let arg = self.expr(&call.tail)?;
+
+        let mut fun = self.expr(&call.head)?;
+        if let Node::Call { args, .. } = &mut fun {
+            args.push(arg);
+            return Ok(fun);
+        }
+
+        Ok(call_node(fun, vec![arg]))
+    }
+
+    fn binary(&self, binary: &ExprBinary) -> Result<Node> {
+        let lhs = self.expr(&binary.lhs)?;
+        let rhs = self.expr(&binary.rhs)?;
+
+        let builtin = |name: &str, lhs, rhs| call_node(Node::Var(name.to_string()), vec![lhs, rhs]);
+        let op = |op, lhs, rhs| Node::Binary(op, Box::new(lhs), Box::new(rhs));
+
+        Ok(match binary.operator {
+            Operator::Add => Node::Concat(vec![lhs, rhs]),
+            Operator::Subtract => builtin("__sub", lhs, rhs),
+            Operator::Multiply => builtin("__mul", lhs, rhs),
+            Operator::Divide => builtin("__div", lhs, rhs),
+            Operator::Lt => builtin("__lessThan", lhs, rhs),
+            Operator::Gt => builtin("__lessThan", rhs, lhs),
+            Operator::Le => Node::Not(Box::new(builtin("__lessThan", rhs, lhs))),
+            Operator::Ge => Node::Not(Box::new(builtin("__lessThan", lhs, rhs))),
+            Operator::Update => op("//", lhs, rhs),
+            Operator::Concat => op("++", lhs, rhs),
+            Operator::And => op("&&", lhs, rhs),
+            Operator::Or => op("||", lhs, rhs),
+            Operator::Equals => op("==", lhs, rhs),
+            Operator::NotEquals => op("!=", lhs, rhs),
+            Operator::Implies => op("->", lhs, rhs),
+            // Pipes always make a new call, unlike applications.
+            Operator::PipeInto => call_node(rhs, vec![lhs]),
+            Operator::PipeFrom => call_node(lhs, vec![rhs]),
+        })
+    }
+
+    fn unary(&self, unary: &ExprUnary) -> Result<Node> {
+        let expr = self.expr(&unary.expr)?;
+
+        Ok(match unary.operator {
+            UnaryOperator::Not => Node::Not(Box::new(expr)),
+            UnaryOperator::Negate => call_node(
+                Node::Var("__sub".to_string()),
+                vec![Node::Int("0".to_string()), expr],
+            ),
+        })
+    }
+
+    fn has_attr(&self, has_attr: &ExprHasAttr) -> Result<Node> {
+        Ok(Node::HasAttr {
+            set: Box::new(self.expr(&has_attr.set)?),
+            path: std::iter::once(&has_attr.head)
+                .chain(&has_attr.tail)
+                .map(|name| self.attr_name(name))
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    fn attribute_access(&self, access: &AttributeAccess) -> Result<Node> {
+        Ok(Node::Select {
+            set: Box::new(self.expr(&access.set)?),
+            path: access
+                .accessors
+                .iter()
+                .map(|a| self.attr_name(a))
+                .collect::<Result<_>>()?,
+            default: match &access.default {
+                Some((_, default)) => Some(Box::new(self.expr(default)?)),
+                None => None,
+            },
+        })
+    }
+
+    /// `<a/b>` is `__findFile __nixPath "a/b"`.
+    fn lookup_path(&self, lookup: &LookupPath) -> Node {
+        let mut name = lookup.head.ident().to_string();
+        for part in &lookup.tail {
+            name.push('/');
+            name.push_str(part.ident());
+        }
+
+        call_node(
+            Node::Var("__findFile".to_string()),
+            vec![Node::Var("__nixPath".to_string()), Node::Str(name)],
+        )
+    }
+
+    /// A path without interpolations is resolved to an absolute path.
+    /// Otherwise, its text up to the first interpolation is, and the
+    /// rest is concatenated to it as strings.
+    fn dir_path(&self, dir: &DirPath) -> Result<Node> {
+        let mut parts = Vec::new();
+        let mut text = String::from(match dir.prefix.kind {
+            PathPrefixKind::None => "",
+            PathPrefixKind::Absolute => "/",
+            PathPrefixKind::CurrentDir => "./",
+            PathPrefixKind::HomeDir => "~/",
+        });
+
+        let flush = |text: &mut String, parts: &mut Vec<Node>| {
+            if parts.is_empty() {
+                parts.push(Node::Path(self.resolve(text)));
+            } else if !text.is_empty() {
+                parts.push(Node::Str(text.clone()));
+            }
+
+            text.clear();
+        };
+
+        for (idx, part) in std::iter::once(&dir.head).chain(&dir.tail).enumerate() {
+            if idx > 0 {
+                text.push('/');
+            }
+
+            for sub_part in std::iter::once(&part.head).chain(&part.tail) {
+                match sub_part {
+                    PathSubPart::Ident(LiteralOrInterpolatedIdent::Interpolated(interpolated)) => {
+                        flush(&mut text, &mut parts);
+                        parts.push(self.expr(&interpolated.value)?);
+                    }
+                    PathSubPart::Ident(ident) => match ident.static_name() {
+                        Some(name) => text.push_str(name),
+                        None => text.push_str(&ident.to_token_string()),
+                    },
+                    PathSubPart::LitInt(int) => text.push_str(&int.digits),
+                    PathSubPart::LitFloat(float) => text.push_str(&float.digits),
+                    PathSubPart::Dot(_) => text.push('.'),
+                }
+            }
+        }
+
+        flush(&mut text, &mut parts);
+
+        Ok(match parts.len() {
+            1 => parts.remove(0),
+            _ => Node::Concat(parts),
+        })
+    }
+
+    /// Make `path` absolute and remove `.` and `..` from it, keeping
+    /// a trailing slash.
+    fn resolve(&self, path: &str) -> String {
+        let (base, rest) = if let Some(rest) = path.strip_prefix("~/") {
+            (self.options.home_dir.as_str(), rest)
+        } else if path.starts_with('/') {
+            ("", path)
+        } else {
+            (self.options.base_dir.as_str(), path)
+        };
+
+        let mut segments = Vec::new();
+        for segment in base.split('/').chain(rest.split('/')) {
+            match segment {
+                "" | "." => {}
+                ".." => {
+                    segments.pop();
+                }
+                segment => segments.push(segment),
+            }
+        }
+
+        let mut resolved = format!("/{}", segments.join("/"));
+        if path.len() > 1 && path.ends_with('/') && !segments.is_empty() {
+            resolved.push('/');
+        }
+
+        resolved
+    }
+}
+
+fn call_node(fun: Node, args: Vec<Node>) -> Node {
+    Node::Call {
+        fun: Box::new(fun),
+        args,
+    }
+}
+
+impl Node {
+    fn show(&self, out: &mut String) {
+        crate::ensure_stack(|| match self {
+            Node::Int(digits) | Node::Float(digits) => out.push_str(digits),
+            Node::Str(str) => show_str(str, out),
+            Node::Path(path) | Node::Var(path) => out.push_str(path),
+            Node::Select { set, path, default } => {
+                out.push('(');
+                set.show(out);
+                out.push_str(").");
+                show_attr_path(path, out);
+
+                if let Some(default) = default {
+                    out.push_str(" or (");
+                    default.show(out);
+                    out.push(')');
+                }
+            }
+            Node::HasAttr { set, path } => {
+                out.push_str("((");
+                set.show(out);
+                out.push_str(") ? ");
+                show_attr_path(path, out);
+                out.push(')');
+            }
+            Node::Attrs(attrs) => {
+                if attrs.rec {
+                    out.push_str("rec ");
+                }
+
+                out.push_str("{ ");
+                attrs.show_bindings(out);
+                out.push('}');
+            }
+            Node::List(entries) => {
+                out.push_str("[ ");
+                for entry in entries {
+                    entry.show(out);
+                    out.push(' ');
+                }
+                out.push(']');
+            }
+            Node::Lambda { arg, formals, body } => {
+                out.push('(');
+
+                if let Some(formals) = formals {
+                    out.push_str("{ ");
+
+                    for (idx, (name, default)) in formals.args.iter().enumerate() {
+                        if idx > 0 {
+                            out.push_str(", ");
+                        }
+
+                        out.push_str(name);
+
+                        if let Some(default) = default {
+                            out.push_str(" ? ");
+                            default.show(out);
+                        }
+                    }
+
+                    if formals.ellipsis {
+                        if !formals.args.is_empty() {
+                            out.push_str(", ");
+                        }
+
+                        out.push_str("...");
+                    }
+
+                    out.push_str(" }");
+
+                    if arg.is_some() {
+                        out.push_str(" @ ");
+                    }
+                }
+
+                if let Some(arg) = arg {
+                    out.push_str(arg);
+                }
+
+                out.push_str(": ");
+                body.show(out);
+                out.push(')');
+            }
+            Node::Call { fun, args } => {
+                out.push('(');
+                fun.show(out);
+                for arg in args {
+                    out.push(' ');
+                    arg.show(out);
+                }
+                out.push(')');
+            }
+            Node::Let { attrs, body } => {
+                out.push_str("(let ");
+                attrs.show_bindings(out);
+                out.push_str("in ");
+                body.show(out);
+                out.push(')');
+            }
+            Node::With { env, body } => {
+                out.push_str("(with ");
+                env.show(out);
+                out.push_str("; ");
+                body.show(out);
+                out.push(')');
+            }
+            Node::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                out.push_str("(if ");
+                condition.show(out);
+                out.push_str(" then ");
+                then_branch.show(out);
+                out.push_str(" else ");
+                else_branch.show(out);
+                out.push(')');
+            }
+            Node::Not(expr) => {
+                out.push_str("(! ");
+                expr.show(out);
+                out.push(')');
+            }
+            Node::Binary(op, lhs, rhs) => {
+                out.push('(');
+                lhs.show(out);
+                out.push(' ');
+                out.push_str(op);
+                out.push(' ');
+                rhs.show(out);
+                out.push(')');
+            }
+            Node::Concat(parts) => {
+                out.push('(');
+                for (idx, part) in parts.iter().enumerate() {
+                    if idx > 0 {
+                        out.push_str(" + ");
+                    }
+
+                    part.show(out);
+                }
+                out.push(')');
+            }
+            Node::Error => out.push_str("<error>"),
+        })
+    }
+}
+
+impl Attrs {
+    fn show_bindings(&self, out: &mut String) {
+        let inherited: Vec<_> = self
+            .attrs
+            .iter()
+            .filter(|(_, (attr, _))| matches!(attr, Attr::Inherited))
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        if !inherited.is_empty() {
+            out.push_str("inherit");
+            for name in inherited {
+                out.push(' ');
+                out.push_str(name);
+            }
+            out.push_str("; ");
+        }
+
+        for (index, from) in self.inherit_from.iter().enumerate() {
+            let names = self
+                .attrs
+                .iter()
+                .filter(|(_, (attr, _))| matches!(attr, Attr::InheritedFrom(i) if *i == index));
+
+            out.push_str("inherit (");
+            from.show(out);
+            out.push(')');
+            for (name, _) in names {
+                out.push(' ');
+                out.push_str(name);
+            }
+            out.push_str("; ");
+        }
+
+        for (name, (attr, _)) in &self.attrs {
+            if let Attr::Plain(value) = attr {
+                out.push_str(name);
+                out.push_str(" = ");
+                value.show(out);
+                out.push_str("; ");
+            }
+        }
+
+        for (name, value) in &self.dynamic {
+            out.push_str("\"${");
+            name.show(out);
+            out.push_str("}\" = ");
+            value.show(out);
+            out.push_str("; ");
+        }
+    }
+}
+
+fn show_attr_path(path: &[AttrName], out: &mut String) {
+    for (idx, name) in path.iter().enumerate() {
+        if idx > 0 {
+            out.push('.');
+        }
+
+        match name {
+            AttrName::Static(name) => out.push_str(name),
+            AttrName::Dynamic(expr) => {
+                out.push_str("\"${");
+                expr.show(out);
+                out.push_str("}\"");
+            }
+        }
+    }
+}
+
+/// Print a string literal, escaping as Nix does.
+fn show_str(str: &str, out: &mut String) {
+    out.push('"');
+
+    let mut chars = str.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '"' | '\\' => {
+                out.push('\\');
+                out.push(char);
+            }
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '$' if chars.peek() == Some(&'{') => out.push_str("\\$"),
+            char => out.push(char),
+        }
+    }
+
+    out.push('"');
+}
@@ -1,20 +1,21 @@
 use crate::Peek;
+use crate::punctuated::Punctuated;
 use crate::token::At;
 use crate::*;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprLambda {
     pub arg: LambdaArg,
     pub colon: Token![:],
     pub body: Expr,
+    span: Span,
 }
 
 impl ExprLambda {
     pub fn span(&self) -> Span {
-        self.arg
-            .span()
-            .join(&self.colon.span)
-            .join(&self.body.span())
+        self.span.clone()
     }
 }
 
@@ -22,24 +23,37 @@ impl Parse for ExprLambda {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
         let arg: LambdaArg = buffer.parse()?;
         let colon: Token![:] = buffer.parse()?;
-        let body = buffer.parse()?;
+        let body: Expr = buffer.parse()?;
+        let span = arg.span().join(&body.span());
 
-        Ok(ExprLambda { arg, colon, body })
+        Ok(ExprLambda {
+            arg,
+            colon,
+            body,
+            span,
+        })
     }
 }
 
 impl Peek for ExprLambda {
     fn peek(input: &ParseBuffer) -> bool {
-        let input = &mut input.fork();
-        let has_lambda_arg = LambdaArg::parse(input).is_ok();
+        // `name:`, `name@`, `{ ... }:` and `{ ... }@` can only start a
+        // lambda, so the arguments themselves do not have to parse.
+        let starts_arg = Ident::peek(input) || input.peek(Brace);
+        let ends_arg = input.peek_n(1, Colon) || input.peek_n(1, At);
 
-        let followed_by_colon = <Token![:]>::parse(input).is_ok();
+        starts_arg && ends_arg
+    }
 
-        has_lambda_arg && followed_by_colon
+    fn display() -> &'static str {
+        "lambda"
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum LambdaArg {
     Ident(Ident),
     AttrSet(ArgAttrSet),
@@ -56,12 +70,16 @@ impl LambdaArg {
 
 impl Parse for LambdaArg {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
-        let output = if buffer.peek(Ident) && !buffer.peek_n(1, At) {
+        let lookahead = buffer.lookahead1();
+
+        let output = if lookahead.peek::<Ident>() && !buffer.peek_n(1, At) {
             let ident: Ident = buffer.parse()?;
             Self::Ident(ident)
-        } else {
+        } else if lookahead.peek::<Ident>() || lookahead.peek::<token::Brace>() {
             let set = buffer.parse()?;
             Self::AttrSet(set)
+        } else {
+            return Err(lookahead.error());
         };
 
         Ok(output)
@@ -69,9 +87,11 @@ impl Parse for LambdaArg {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArgAttrSet {
     pub binds_to: Option<Ident>,
-    pub args: Vec<ArgAttrSetValue>,
+    pub args: Punctuated<ArgAttrSetValue, Token![,]>,
     pub ellipsis: Option<Token![...]>,
     span: Span,
 }
@@ -84,7 +104,7 @@ impl ArgAttrSet {
 
 impl Parse for ArgAttrSet {
     fn parse(input: &mut ParseBuffer) -> Result<Self> {
-        let mut args = Vec::new();
+        let mut args: Punctuated<ArgAttrSetValue, Token![,]> = Punctuated::new();
 
         let start = input.span();
 
@@ -122,30 +142,41 @@ impl Parse for ArgAttrSet {
                 break;
             }
 
-            let ident = group.parse()?;
+            let Some(ident) = group.parse_or_recover::<Ident>(<Token![,]>::peek)? else {
+                let _comma: Option<Token![,]> = group.parse_or_recover(|_| false)?;
+                continue;
+            };
+
+            if let Some(first) = args.iter().find(|arg| arg.ident.ident() == ident.ident()) {
+                let msg = format!("Duplicate argument `{}`.", ident.ident());
+                let error = Error::new(ident.span(), msg)
+                    .with_label(first.ident.span(), "first defined here");
+                group.recover(error, |_| true)?;
+            }
 
             let default = if <Token![?]>::peek(&group) {
                 let _question: Token![?] = group.parse()?;
-                let value = group.parse()?;
+                let value = group.parse_expr_or_recover(<Token![,]>::peek)?;
                 Some(value)
             } else {
                 None
             };
 
-            let _comma: Option<Token![,]> = if !group.is_empty() {
-                Some(group.parse()?)
-            } else if group.peek(Comma) {
-                Some(group.parse()?)
-            } else {
-                None
-            };
+            args.push_value(ArgAttrSetValue { ident, default });
 
-            let arg = ArgAttrSetValue { ident, default };
+            if group.is_empty() {
+                break;
+            }
 
-            args.push(arg);
+            // On anything but a comma, skip to the next one.
+            if let Some(comma) = group.parse_or_recover(<Token![,]>::peek)? {
+                args.push_punct(comma);
+            } else if <Token![,]>::peek(&group) {
+                args.push_punct(group.parse()?);
+            }
         }
 
-        let span = start.join(&group.span());
+        let span = input.span_from(&start);
 
         Ok(Self {
             binds_to,
@@ -157,6 +188,8 @@ impl Parse for ArgAttrSet {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArgAttrSetValue {
     pub ident: Ident,
     pub default: Option<Expr>,
@@ -1,51 +1,147 @@
 use crate::*;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprLet {
     pub let_: Token![let],
     pub assignments: Vec<Assignment>,
     pub in_: Token![in],
     pub body: Expr,
+    span: Span,
 }
 
 impl ExprLet {
     pub fn span(&self) -> Span {
-        let assignments = self
-            .assignments
-            .iter()
-            .fold(self.let_.span.clone(), |let_, assignment| {
-                let_.join(&assignment.span())
-            });
-
-        assignments.join(&self.in_.span).join(&self.body.span())
+        self.span.clone()
     }
 }
 
 impl Parse for ExprLet {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
-        let let_ = buffer.parse()?;
+        let let_: Token![let] = buffer.parse()?;
 
         let mut assignments = Vec::new();
-        while !<Token![in]>::peek(buffer) {
-            let assignment = buffer.parse()?;
-            assignments.push(assignment);
+        // When recovering, a missing `in` is reported below.
+        let at_end = |b: &ParseBuffer| b.is_recovering() && b.peek_tree().is_none();
+
+        while !<Token![in]>::peek(buffer) && !at_end(buffer) {
+            let stop = |b: &ParseBuffer| <Token![;]>::peek(b) || <Token![in]>::peek(b);
+
+            if let Some(assignment) = buffer.parse_or_recover(stop)? {
+                assignments.push(assignment);
+            } else if <Token![;]>::peek(buffer) {
+                let _: Token![;] = buffer.parse()?;
+            }
         }
 
-        let in_ = buffer.parse()?;
-        let body = buffer.parse()?;
+        let (in_, body) = match buffer.parse() {
+            Ok(in_) => (in_, buffer.parse_expr_or_recover(|_| false)?),
+            Err(error) => {
+                // Only reached when recovering, once the input has run out.
+                let span = buffer.recover(error, |_| true)?;
+                let mut in_ = <Token![in]>::new();
+                in_.span = span.clone();
+                (in_, Expr::Error(span))
+            }
+        };
+
+        let span = let_.span.join(&body.span());
 
         Ok(Self {
             let_,
             assignments,
             in_,
             body,
+            span,
         })
     }
 }
 
 impl Peek for ExprLet {
     fn peek(input: &ParseBuffer) -> bool {
-        let mut input = input.fork();
-        <Token![let]>::parse(&mut input).is_ok()
+        <Token![let]>::peek(input) && !input.peek_n(1, Brace)
+    }
+
+    fn display() -> &'static str {
+        "`let`"
+    }
+}
+
+/// The deprecated `let { ...; body = ...; }` syntax, which
+/// evaluates to the `body` attribute of the set.
+#[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExprLegacyLet {
+    pub let_: Token![let],
+    pub assignments: Vec<Assignment>,
+    span: Span,
+}
+
+impl ExprLegacyLet {
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+
+    /// The value of the `body` attribute, if it is assigned directly.
+    pub fn body(&self) -> Option<&Expr> {
+        self.assignments
+            .iter()
+            .find_map(|assignment| match assignment {
+                Assignment::Named(named)
+                    if named.tail.is_empty() && named.head.static_name() == Some("body") =>
+                {
+                    Some(&named.value)
+                }
+                _ => None,
+            })
+    }
+}
+
+impl Parse for ExprLegacyLet {
+    fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
+        let start = buffer.span();
+
+        if !buffer.options().has_legacy_let() {
+            let msg = "`let { ... }` is not enabled in the parse options, use `let ... in body`.";
+            return Err(Error::new(start, msg));
+        }
+
+        let let_: Token![let] = buffer.parse()?;
+
+        let mut braced;
+        braced!(buffer as braced else "Expected `{` after `let`.");
+
+        let span = buffer.span_from(&start);
+        let mut assignments = Vec::new();
+        while !braced.is_empty() {
+            if let Some(assignment) = braced.parse_or_recover(<Token![;]>::peek)? {
+                assignments.push(assignment);
+            } else if <Token![;]>::peek(&braced) {
+                let _: Token![;] = braced.parse()?;
+            }
+        }
+
+        buffer.warn(Warning::new(
+            span.clone(),
+            "`let { ... }` is deprecated, use `let ... in body` instead.",
+        ));
+
+        Ok(Self {
+            let_,
+            assignments,
+            span,
+        })
+    }
+}
+
+impl Peek for ExprLegacyLet {
+    fn peek(input: &ParseBuffer) -> bool {
+        <Token![let]>::peek(input) && input.peek_n(1, Brace)
+    }
+
+    fn display() -> &'static str {
+        "`let`"
     }
 }
@@ -1,28 +1,52 @@
-use std::str::FromStr;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 pub mod attrset;
 pub mod binary;
+pub mod buffer;
+pub mod cst;
+#[cfg(feature = "fold")]
+pub mod fold;
+pub mod instantiate;
 pub mod lambda;
 pub mod list;
 pub mod lit;
 pub mod path;
+pub mod print;
+pub mod punctuated;
+pub mod render;
 pub mod token;
+#[cfg(feature = "visit")]
+pub mod visit;
+#[cfg(feature = "visit-mut")]
+pub mod visit_mut;
 
 mod assignment;
 mod error;
+#[cfg(feature = "extra-traits")]
+mod extra_traits;
 mod function_call;
 mod ident;
+mod r#if;
 mod r#let;
+mod lookahead;
+mod options;
 mod parenthesized;
 mod with;
 
 pub use assignment::{Assignment, AssignmentInherit, AssignmentNamed};
-pub use error::Error;
+pub use error::{Diagnostic, Error, Label, Warning};
 pub use function_call::ExprFunctionCall;
-pub use ident::{Ident, InterpolatedIdent};
+pub use ident::{Ident, InterpolatedIdent, LiteralOrInterpolatedIdent};
+pub use r#if::ExprIf;
 use lambda::ExprLambda;
-pub use r#let::ExprLet;
+pub use r#let::{ExprLegacyLet, ExprLet};
+pub use lookahead::Lookahead1;
+pub use options::{ParseOptions, Target};
 pub use parenthesized::ExprParenthesized;
+pub use print::{Printer, ToTokens, print};
 use synix_lexer::{
     Span, TokenStream, TokenTree,
     group::Delimiter,
@@ -32,24 +56,64 @@ use synix_lexer::{
 pub use with::ExprWith;
 
 use crate::{
-    attrset::{AttributeAccess, ExprAttrSet},
-    binary::{ExprBinary, Operator},
+    attrset::{AttributeAccess, ExprAttrSet, ExprHasAttr},
+    binary::{ExprBinary, ExprUnary, Operator, UnaryOperator},
+    buffer::{Cursor, TokenBuffer},
     list::ExprList,
-    lit::ExprLit,
+    lit::{ExprInterpolatedStr, ExprLit},
     path::Path,
 };
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Run `f`, on a new stack if less than `RED_ZONE` bytes of the
+/// current one are left. Called before every level of recursion that
+/// depends on the input, so that deeply nested input does not
+/// overflow the stack.
+pub(crate) fn ensure_stack<R>(f: impl FnOnce() -> R) -> R {
+    const RED_ZONE: usize = 128 * 1024;
+    const STACK_SIZE: usize = 2 * 1024 * 1024;
+
+    stacker::maybe_grow(RED_ZONE, STACK_SIZE, f)
+}
+
+/// How many levels deep expressions may be nested, counting every
+/// operand of a chain of operators or arguments as one level. Trees
+/// are dropped and compared recursively, so deeper trees would
+/// overflow the stack of a thread.
+const MAX_DEPTH: usize = 10_000;
+
+/// Used by the exported macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+    pub use synix_lexer::Span;
+
+    #[cfg(feature = "serde")]
+    pub use crate::token::custom::Token;
+    pub use crate::token::custom::{parse_keyword, parse_punct, peek_keyword, peek_punct};
+}
+
 #[expect(non_snake_case)]
 pub fn At(tree: &TokenTree) -> bool {
     punct_peek_helper(tree, Char::At)
 }
 
+#[expect(non_snake_case)]
+pub fn Colon(tree: &TokenTree) -> bool {
+    punct_peek_helper(tree, Char::Colon)
+}
+
 #[expect(non_snake_case)]
 pub fn Dot(tree: &TokenTree) -> bool {
     punct_peek_helper(tree, Char::Dot)
 }
 
+#[expect(non_snake_case)]
+pub fn Dollar(tree: &TokenTree) -> bool {
+    punct_peek_helper(tree, Char::Dollar)
+}
+
 #[expect(non_snake_case)]
 pub fn LitInt(tree: &TokenTree) -> bool {
     matches!(tree, TokenTree::Literal(Literal::Int(_)))
@@ -107,9 +171,63 @@ const fn punct_peek_helper(tree: &TokenTree, char: Char) -> bool {
 }
 
 pub fn parse(input: &str) -> Result<Expr> {
-    let lexed = TokenStream::from_str(input)?;
-    let mut buffer = ParseBuffer::new(lexed.as_ref());
-    buffer.parse()
+    parse_with_options(input, ParseOptions::default())
+}
+
+pub fn parse_with_options(input: &str, options: ParseOptions) -> Result<Expr> {
+    parse_with_warnings(input, options).map(|(expr, _)| expr)
+}
+
+/// Parse `input`, also returning the warnings that were
+/// emitted while parsing.
+pub fn parse_with_warnings(input: &str, options: ParseOptions) -> Result<(Expr, Vec<Warning>)> {
+    let lexed = TokenStream::lex_with_options(input, options.lex_options())?;
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let mut buffer = ParseBuffer::new_with_options(&tokens, options);
+    let expr = parse_to_end(&mut buffer)?;
+
+    Ok((expr, buffer.take_warnings()))
+}
+
+/// Parse an expression that makes up all of `buffer`.
+pub(crate) fn parse_to_end(buffer: &mut ParseBuffer) -> Result<Expr> {
+    let expr = buffer.parse()?;
+
+    if !buffer.is_empty() {
+        return Err(Error::new(
+            buffer.span(),
+            "Unexpected input after expression.",
+        ));
+    }
+
+    Ok(expr)
+}
+
+/// Parse `input`, recovering from errors.
+///
+/// Input that can not be parsed is skipped until the next `;`, `in`,
+/// `,` or closing delimiter and replaced by an [`Expr::Error`], and
+/// every error is returned alongside the partial expression.
+pub fn parse_with_diagnostics(input: &str, options: ParseOptions) -> (Expr, Vec<Error>) {
+    let lexed = match TokenStream::lex_with_options(input, options.lex_options()) {
+        Ok(lexed) => lexed,
+        Err(error) => {
+            let error = Error::from(error);
+            return (Expr::Error(error.span()), vec![error]);
+        }
+    };
+
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let mut buffer = ParseBuffer::new_with_options(&tokens, options);
+    buffer.diagnostics = Some(Rc::default());
+
+    let expr = buffer
+        .parse_expr_or_recover(|_| false)
+        .and_then(|expr| buffer.expect_empty().map(|_| expr))
+        .expect("The buffer is recovering");
+
+    let diagnostics = buffer.diagnostics.take().unwrap_or_default().take();
+    (expr, diagnostics)
 }
 
 #[expect(non_snake_case)]
@@ -118,166 +236,393 @@ pub const fn Ident(tree: &TokenTree) -> bool {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum Expr {
     Let(Box<ExprLet>),
+    LegacyLet(ExprLegacyLet),
     Lit(ExprLit),
+    InterpolatedStr(ExprInterpolatedStr),
     Lambda(Box<ExprLambda>),
     Ident(Ident),
     AttrSet(ExprAttrSet),
     Parenthesized(Box<ExprParenthesized>),
     List(ExprList),
     With(Box<ExprWith>),
+    If(Box<ExprIf>),
     FunctionCall(Box<ExprFunctionCall>),
     Binary(Box<ExprBinary>),
+    Unary(Box<ExprUnary>),
+    HasAttr(Box<ExprHasAttr>),
     AttributeAccess(Box<AttributeAccess>),
     Path(Box<Path>),
+    /// Input that could not be parsed, only produced by
+    /// [`parse_with_diagnostics`].
+    Error(Span),
 }
 
 impl Expr {
     pub fn span(&self) -> Span {
         match self {
             Expr::Let(expr_let) => expr_let.span(),
+            Expr::LegacyLet(legacy_let) => legacy_let.span(),
             Expr::Lit(expr_lit) => expr_lit.span(),
+            Expr::InterpolatedStr(expr_str) => expr_str.span(),
             Expr::Lambda(expr_lambda) => expr_lambda.span(),
             Expr::Ident(ident) => ident.span(),
             Expr::AttrSet(attr_set) => attr_set.span(),
             Expr::Parenthesized(paren) => paren.span(),
             Expr::List(expr_list) => expr_list.span(),
             Expr::With(expr_with) => expr_with.span(),
+            Expr::If(expr_if) => expr_if.span(),
             Expr::FunctionCall(expr_function_call) => expr_function_call.span(),
             Expr::Binary(expr_binary) => expr_binary.span(),
+            Expr::Unary(expr_unary) => expr_unary.span(),
+            Expr::HasAttr(expr_has_attr) => expr_has_attr.span(),
             Expr::AttributeAccess(attribute_access) => attribute_access.span(),
             Expr::Path(path) => path.span(),
+            Expr::Error(span) => span.clone(),
         }
     }
 }
 
 impl Parse for Expr {
     fn parse(input: &mut ParseBuffer) -> Result<Self> {
-        let start = input.span();
-
-        let mut output = if ExprLit::peek(input) {
-            let lit = input.parse()?;
-            Self::Lit(lit)
-        } else if ExprLet::peek(input) {
-            let let_ = input.parse()?;
-            Self::Let(Box::new(let_))
-        } else if ExprLambda::peek(input) {
-            let lambda = input.parse()?;
-            Self::Lambda(Box::new(lambda))
-        } else if ExprAttrSet::peek(input) {
-            let attrset = input.parse()?;
-            Self::AttrSet(attrset)
-        } else if ExprParenthesized::peek(input) {
-            let parenthesized = input.parse()?;
-            Self::Parenthesized(Box::new(parenthesized))
-        } else if ExprList::peek(input) {
-            let list = input.parse()?;
-            Self::List(list)
-        } else if ExprWith::peek(input) {
-            let with = input.parse()?;
-            Self::With(Box::new(with))
-        } else if Path::peek(input) {
-            let path = input.parse()?;
-            Self::Path(Box::new(path))
-        } else if input.peek(Ident) {
-            let ident = input.parse()?;
-            Self::Ident(ident)
-        } else {
-            return Err(Error::new(input.span(), "Expected expr."));
-        };
+        input.deeper(1, |input| {
+            let lookahead = input.lookahead1();
+
+            let output = if lookahead.peek::<ExprLet>() {
+                let let_ = input.parse()?;
+                Self::Let(Box::new(let_))
+            } else if lookahead.peek::<ExprLambda>() {
+                let lambda = input.parse()?;
+                Self::Lambda(Box::new(lambda))
+            } else if lookahead.peek::<ExprWith>() {
+                let with = input.parse()?;
+                Self::With(Box::new(with))
+            } else if lookahead.peek::<ExprIf>() {
+                let if_ = input.parse()?;
+                Self::If(Box::new(if_))
+            } else if lookahead.peek::<UnaryOperator>() || Self::peek_select_in(&lookahead) {
+                binary::parse_operation(input, 0)?
+            } else {
+                return Err(lookahead.error());
+            };
 
-        output = if AttributeAccess::peek(input) {
-            Self::AttributeAccess(Box::new(AttributeAccess::parse_rest(output, input)?))
-        } else {
-            output
-        };
+            Ok(output)
+        })
+    }
+}
 
-        let result = if ParseBuffer::is_empty(input) {
-            output
-        } else {
-            if Operator::peek(input) {
-                let operator = input.parse()?;
-                let binary = ExprBinary::parse_rest(output, operator, input)?;
-                Self::Binary(Box::new(binary))
-            }
-            // TODO: remove this hack
-            else if !(<Token![;]>::peek(input) || <Token![,]>::peek(input)) {
-                let body = input.parse()?;
-                let span = start.join(&input.span());
-
-                // Reorder because function calls have higher
-                // precedence than binary expressions.
-                if let Expr::Binary(binary) = body {
-                    let ExprBinary {
-                        lhs,
-                        operator,
-                        rhs,
-                        span: _,
-                    } = *binary;
-
-                    let function_call = ExprFunctionCall {
-                        head: output,
-                        tail: lhs,
-                        // TODO: compute this
-                        span: Default::default(),
-                    };
-
-                    // TODO: compute this
-                    let span = Default::default();
-                    let lhs = Self::FunctionCall(Box::new(function_call));
-
-                    Self::Binary(Box::new(ExprBinary::new(lhs, operator, rhs, span)))
-                } else {
-                    let function_call = ExprFunctionCall {
-                        head: output,
-                        tail: body,
-                        span,
-                    };
-
-                    Self::FunctionCall(Box::new(function_call))
-                }
+impl Expr {
+    /// Parse a function application, or anything that binds
+    /// more tightly.
+    ///
+    /// Function application is left-associative, so `f a b`
+    /// is parsed as `(f a) b`.
+    pub(crate) fn parse_application(input: &mut ParseBuffer) -> Result<Self> {
+        let mut output = Self::parse_select(input)?;
+        let mut arguments = 0;
+
+        while Self::peek_argument(input) {
+            // Every argument nests the call one level deeper.
+            arguments += 1;
+            let tail = input.deeper(arguments, Self::parse_select)?;
+            let span = output.span().join(&tail.span());
+
+            let function_call = ExprFunctionCall {
+                head: output,
+                tail,
+                span,
+            };
+
+            output = Self::FunctionCall(Box::new(function_call));
+        }
+
+        Ok(output)
+    }
+
+    /// Whether the input starts with an argument to a function
+    /// application.
+    fn peek_argument(input: &ParseBuffer) -> bool {
+        // Paths may start with an operator (`/`, `<`), so
+        // they must be checked first.
+        Path::peek(input)
+            || (!Operator::peek(input) && !<Token![or]>::peek(input) && Self::peek_select(input))
+    }
+
+    /// Whether the input starts with an expression that
+    /// [`Expr::parse_select`] accepts.
+    pub(crate) fn peek_select(input: &ParseBuffer) -> bool {
+        Self::peek_select_in(&input.lookahead1())
+    }
+
+    fn peek_select_in(lookahead: &Lookahead1) -> bool {
+        lookahead.peek::<ExprLit>()
+            || lookahead.peek::<ExprInterpolatedStr>()
+            || lookahead.peek::<ExprAttrSet>()
+            || lookahead.peek::<ExprLegacyLet>()
+            || lookahead.peek::<ExprParenthesized>()
+            || lookahead.peek::<ExprList>()
+            || lookahead.peek::<Path>()
+            || lookahead.peek::<Ident>()
+    }
+
+    /// Parse a simple expression, optionally followed by
+    /// an attribute access.
+    pub(crate) fn parse_select(input: &mut ParseBuffer) -> Result<Self> {
+        input.deeper(1, |input| {
+            let lookahead = input.lookahead1();
+
+            let output = if lookahead.peek::<ExprLit>() {
+                let lit = input.parse()?;
+                Self::Lit(lit)
+            } else if lookahead.peek::<ExprInterpolatedStr>() {
+                let str = input.parse()?;
+                Self::InterpolatedStr(str)
+            } else if lookahead.peek::<ExprAttrSet>() {
+                let attrset = input.parse()?;
+                Self::AttrSet(attrset)
+            } else if lookahead.peek::<ExprLegacyLet>() {
+                let legacy_let = input.parse()?;
+                Self::LegacyLet(legacy_let)
+            } else if lookahead.peek::<ExprParenthesized>() {
+                let parenthesized = input.parse()?;
+                Self::Parenthesized(Box::new(parenthesized))
+            } else if lookahead.peek::<ExprList>() {
+                let list = input.parse()?;
+                Self::List(list)
+            } else if lookahead.peek::<Path>() {
+                let path = input.parse()?;
+                Self::Path(Box::new(path))
+            } else if lookahead.peek::<Ident>() {
+                let ident = input.parse()?;
+                Self::Ident(ident)
             } else {
-                output
-            }
-        };
+                return Err(lookahead.error());
+            };
 
-        Ok(result)
+            if AttributeAccess::peek(input) {
+                let access = AttributeAccess::parse_rest(output, input)?;
+                Ok(Self::AttributeAccess(Box::new(access)))
+            } else {
+                Ok(output)
+            }
+        })
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct ParseBuffer<'a> {
-    trees: &'a [TokenTree],
+    cursor: Cursor<'a>,
     last_span: Option<Span>,
+    options: ParseOptions,
+    warnings: Rc<RefCell<Vec<Warning>>>,
+    /// Where recovered errors are recorded, if this buffer
+    /// recovers from errors.
+    diagnostics: Option<Rc<RefCell<Vec<Error>>>>,
+    /// How deeply the expression being parsed is nested, see
+    /// [`Self::deeper`].
+    depth: Rc<Cell<usize>>,
 }
 
 impl<'a> ParseBuffer<'a> {
-    pub fn new(trees: &'a [TokenTree]) -> Self {
+    pub fn new(tokens: &'a TokenBuffer<'a>) -> Self {
+        Self::new_with_options(tokens, ParseOptions::default())
+    }
+
+    pub fn new_with_options(tokens: &'a TokenBuffer<'a>, options: ParseOptions) -> Self {
         Self {
-            trees,
+            cursor: tokens.begin(),
             last_span: None,
+            options,
+            warnings: Rc::default(),
+            diagnostics: None,
+            depth: Rc::default(),
         }
     }
 
+    /// Create a buffer for `tokens` which are not part of this
+    /// buffer, as the interpolations of a string, using the same
+    /// options and warnings as this buffer.
+    pub fn nested<'b>(&self, tokens: &'b TokenBuffer<'b>) -> ParseBuffer<'b> {
+        self.at(tokens.begin())
+    }
+
+    /// A buffer at `cursor`, using the same options and warnings
+    /// as this buffer.
+    fn at<'b>(&self, cursor: Cursor<'b>) -> ParseBuffer<'b> {
+        ParseBuffer {
+            cursor,
+            last_span: None,
+            options: self.options,
+            warnings: self.warnings.clone(),
+            diagnostics: self.diagnostics.clone(),
+            depth: self.depth.clone(),
+        }
+    }
+
+    /// If the next tree is a group with `delimiter`, consume it and
+    /// return a buffer for its contents.
+    pub fn group(&mut self, delimiter: Delimiter) -> Option<Self> {
+        let (inside, span, after) = self.cursor.group(delimiter)?;
+
+        self.cursor = after;
+        self.last_span = Some(span);
+
+        Some(self.at(inside))
+    }
+
+    /// The position of this buffer.
+    pub fn cursor(&self) -> Cursor<'a> {
+        self.cursor
+    }
+
+    /// Emit a warning. Warnings emitted by a [fork](Self::fork)
+    /// are discarded.
+    pub fn warn(&self, warning: Warning) {
+        self.warnings.borrow_mut().push(warning);
+    }
+
+    /// Take all warnings emitted so far.
+    pub fn take_warnings(&self) -> Vec<Warning> {
+        self.warnings.take()
+    }
+
+    /// Whether this buffer recovers from errors, see
+    /// [`parse_with_diagnostics`].
+    pub fn is_recovering(&self) -> bool {
+        self.diagnostics.is_some()
+    }
+
+    /// Run `f` with the expression nested `levels` deeper, on a new
+    /// stack if needed. Fails once the expression is nested more
+    /// than [`MAX_DEPTH`] levels deep.
+    pub(crate) fn deeper<T>(
+        &mut self,
+        levels: usize,
+        f: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        let depth = self.depth.get();
+        if depth + levels > MAX_DEPTH {
+            return Err(Error::new(self.span(), "Expression is nested too deeply."));
+        }
+
+        self.depth.set(depth + levels);
+        let result = ensure_stack(|| f(self));
+        self.depth.set(depth);
+        result
+    }
+
+    /// Recover from `error` by recording it and skipping trees
+    /// until `stop` matches or the buffer is empty. Returns the
+    /// span of the error and the skipped trees.
+    ///
+    /// If this buffer does not recover from errors, `error` is
+    /// returned instead.
+    pub(crate) fn recover(&mut self, error: Error, stop: impl Fn(&Self) -> bool) -> Result<Span> {
+        let Some(diagnostics) = self.diagnostics.clone() else {
+            return Err(error);
+        };
+
+        let mut span = error.span();
+        diagnostics.borrow_mut().push(error);
+
+        while self.peek_tree().is_some() && !stop(self) {
+            let tree = self.next().expect("The buffer is not empty");
+            span = span.join(&tree.span());
+        }
+
+        Ok(span)
+    }
+
+    /// Parse a `T`, or recover from the error using [`Self::recover`],
+    /// in which case `None` is returned.
+    pub(crate) fn parse_or_recover<T: Parse>(
+        &mut self,
+        stop: impl Fn(&Self) -> bool,
+    ) -> Result<Option<T>> {
+        match self.parse() {
+            Ok(value) => Ok(Some(value)),
+            Err(error) => self.recover(error, stop).map(|_| None),
+        }
+    }
+
+    /// Parse an expression, or recover from the error using
+    /// [`Self::recover`] and return an [`Expr::Error`].
+    pub(crate) fn parse_expr_or_recover(&mut self, stop: impl Fn(&Self) -> bool) -> Result<Expr> {
+        match self.parse() {
+            Ok(expr) => Ok(expr),
+            Err(error) => self.recover(error, stop).map(Expr::Error),
+        }
+    }
+
+    /// Check that all trees have been parsed, recovering by
+    /// skipping the remaining trees.
+    pub(crate) fn expect_empty(&mut self) -> Result<()> {
+        if let Some(tree) = self.peek_tree() {
+            let error = Error::new(tree.span(), "Leftover tokens.");
+            self.recover(error, |_| false)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// The span of the next tree. At the end of a group, this is
+    /// its closing delimiter, and at the end of the input the last
+    /// tree.
     pub fn span(&self) -> Span {
-        self.trees
-            .get(0)
-            .map(|t| t.span())
+        self.cursor
+            .span()
             .or(self.last_span.clone())
             .unwrap_or(Span::default())
     }
 
+    /// Returns a span that spans from `start` until the end of
+    /// the previously-parsed tree (inclusive).
+    pub fn span_from(&self, start: &Span) -> Span {
+        self.last_span
+            .as_ref()
+            .map(|last| start.join(last))
+            .unwrap_or(start.clone())
+    }
+
     pub fn parse<T: Parse>(&mut self) -> Result<T> {
         T::parse(self)
     }
 
+    /// Create a copy of this buffer for speculative parsing.
+    ///
+    /// Warnings emitted by the fork do not reach this buffer, so
+    /// that they are not reported twice once the input is parsed
+    /// for real. Forks never recover from errors, so that a failed
+    /// speculative parse is not mistaken for a successful one.
     pub fn fork(&self) -> Self {
-        self.clone()
+        Self {
+            warnings: Rc::default(),
+            diagnostics: None,
+            ..self.clone()
+        }
+    }
+
+    /// Start peeking at the next token, collecting what was
+    /// expected for the error message.
+    pub fn lookahead1(&self) -> Lookahead1<'_> {
+        Lookahead1::new(self)
     }
 
     pub(crate) fn peek_tree_n(&self, n: usize) -> Option<&'a TokenTree> {
-        self.trees.get(n)
+        let mut cursor = self.cursor;
+        for _ in 0..n {
+            cursor = cursor.skip()?;
+        }
+
+        cursor.tree().map(|(tree, _)| tree)
     }
 
     pub fn peek_n(&self, n: usize, f: fn(&'a TokenTree) -> bool) -> bool {
@@ -292,12 +637,30 @@ impl<'a> ParseBuffer<'a> {
         self.peek_n(0, f)
     }
 
+    /// Whether the `n`th tree is directly followed by the
+    /// `n + 1`th tree, without any whitespace in between.
+    pub(crate) fn is_joint(&self, n: usize) -> bool {
+        match (self.peek_tree_n(n), self.peek_tree_n(n + 1)) {
+            (Some(first), Some(second)) => first.span().end() == second.span().start(),
+            _ => false,
+        }
+    }
+
+    /// Whether the next tree directly follows the previously
+    /// consumed tree, without any whitespace in between.
+    pub(crate) fn is_joint_to_previous(&self) -> bool {
+        match (&self.last_span, self.peek_tree()) {
+            (Some(last), Some(next)) => last.end() == next.span().start(),
+            _ => false,
+        }
+    }
+
     pub(crate) fn peek_tree(&self) -> Option<&'a TokenTree> {
         self.peek_tree_n(0)
     }
 
     pub fn is_empty(&self) -> bool {
-        self.len() == 0
+        self.cursor.eof()
     }
 }
 
@@ -305,24 +668,12 @@ impl<'a> Iterator for ParseBuffer<'a> {
     type Item = &'a TokenTree;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(current) = self.trees.get(0) {
-            self.trees = &self.trees[1..];
-            self.last_span = Some(current.span());
-            Some(current)
-        } else {
-            self.last_span = self.last_span.clone().map(|s| Span::new(s.end(), s.end()));
-            None
-        }
-    }
+        let (tree, next) = self.cursor.tree()?;
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.trees.len(), Some(self.trees.len()))
-    }
-}
+        self.cursor = next;
+        self.last_span = Some(tree.span());
 
-impl ExactSizeIterator for ParseBuffer<'_> {
-    fn len(&self) -> usize {
-        self.trees.len()
+        Some(tree)
     }
 }
 
@@ -330,22 +681,27 @@ pub trait Parse: Sized {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self>;
 }
 
+/// Checks whether the input starts with a node, without parsing it.
+///
+/// Every alternative is peeked at before one is parsed, so peeking
+/// should only inspect the next few trees, and never build nodes or
+/// errors.
 pub trait Peek {
     fn peek(input: &ParseBuffer) -> bool;
+
+    /// How the token is described in errors, as in
+    /// ``Expected one of `;`, `.` ``.
+    fn display() -> &'static str;
 }
 
 #[macro_export]
 macro_rules! delimited {
     ($buffer:ident, $value:ident, $err:expr, $delim:ident) => {
-        match $buffer.next() {
-            Some(::synix_lexer::TokenTree::Group(group))
-                if group.delimiter == synix_lexer::group::Delimiter::$delim =>
-            {
-                $value = crate::ParseBuffer::new(group.inner.as_ref());
-            }
-            v => {
-                let span = v.map(|v| v.span()).unwrap_or($buffer.span());
-                return Err(crate::Error::new(span, $err));
+        match $buffer.group(::synix_lexer::group::Delimiter::$delim) {
+            Some(group) => $value = group,
+            None => {
+                let span = $buffer.next().map(|v| v.span()).unwrap_or($buffer.span());
+                return Err($crate::Error::new(span, $err));
             }
         }
     };
@@ -3,8 +3,14 @@ use synix_lexer::Span;
 use crate::*;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprList {
-    pub entries: Vec<ListEntry>,
+    /// The elements of the list.
+    ///
+    /// Elements are select expressions: function applications and
+    /// operators must be parenthesized.
+    pub entries: Vec<Expr>,
     span: Span,
 }
 
@@ -18,39 +24,25 @@ impl Peek for ExprList {
     fn peek(input: &ParseBuffer) -> bool {
         input.peek(Bracket)
     }
+
+    fn display() -> &'static str {
+        "`[`"
+    }
 }
 
 impl Parse for ExprList {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
+        let start = buffer.span();
         let mut bracketed;
         bracketed!(buffer as bracketed else "Expected list");
 
-        let span = bracketed.span();
+        let span = buffer.span_from(&start);
         let mut entries = Vec::new();
 
         while !bracketed.is_empty() {
-            let entry = if bracketed.peek(Ident) {
-                let base: Ident = bracketed.parse()?;
-
-                if <Token![.]>::peek(&bracketed) {
-                    let access = AttributeAccess::parse_rest(Expr::Ident(base), &mut bracketed)?;
-                    ListEntry::AttributeAccess(access)
-                } else {
-                    ListEntry::Ident(base)
-                }
-            } else if bracketed.peek(Paren) {
-                ListEntry::Parenthesized(bracketed.parse()?)
-            } else if bracketed.peek(Bracket) {
-                ListEntry::List(bracketed.parse()?)
-            } else if bracketed.peek(Brace) {
-                ListEntry::AttrSet(bracketed.parse()?)
-            } else if ExprLit::peek(&bracketed) {
-                ListEntry::Lit(bracketed.parse()?)
-            } else if Path::peek(&bracketed) {
-                ListEntry::Path(bracketed.parse()?)
-            } else {
-                let msg = "Expected list entry.";
-                return Err(Error::new(bracketed.span(), msg));
+            let entry = match Expr::parse_select(&mut bracketed) {
+                Ok(entry) => entry,
+                Err(error) => Expr::Error(bracketed.recover(error, |_| false)?),
             };
 
             entries.push(entry);
@@ -59,14 +51,3 @@ impl Parse for ExprList {
         Ok(Self { entries, span })
     }
 }
-
-#[derive(Debug)]
-pub enum ListEntry {
-    Ident(Ident),
-    Parenthesized(ExprParenthesized),
-    List(ExprList),
-    AttrSet(ExprAttrSet),
-    Lit(ExprLit),
-    Path(Path),
-    AttributeAccess(AttributeAccess),
-}
@@ -1,18 +1,25 @@
 use synix_lexer::Span;
 use synix_lexer::TokenTree;
-use synix_lexer::literal::Literal;
-pub use synix_lexer::literal::{LitFloat, LitInt, LitStr};
+pub use synix_lexer::literal::{LitFloat, LitInt, LitStr, LitUrl};
+use synix_lexer::literal::{Literal, StrPart};
 
 use crate::Error;
+use crate::Expr;
 use crate::Parse;
 use crate::Peek;
+use crate::buffer::TokenBuffer;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum ExprLit {
     Int(LitInt),
     Float(LitFloat),
     Str(LitStr),
     Bool(LitBool),
+    /// Only parsed with [`ParseOptions::url_literals`](crate::ParseOptions::url_literals).
+    Url(LitUrl),
 }
 
 impl Parse for ExprLit {
@@ -30,6 +37,7 @@ impl Parse for ExprLit {
             TokenTree::Literal(Literal::Int(int)) => Self::Int(int.clone()),
             TokenTree::Literal(Literal::Float(float)) => Self::Float(float.clone()),
             TokenTree::Literal(Literal::Str(str)) => Self::Str(str.clone()),
+            TokenTree::Literal(Literal::Url(url)) => Self::Url(url.clone()),
             TokenTree::Ident(ident) if ident.ident() == "true" || ident.ident() == "false" => {
                 Self::Bool(LitBool {
                     span: ident.span(),
@@ -50,17 +58,28 @@ impl ExprLit {
             ExprLit::Float(lit_float) => lit_float.span(),
             ExprLit::Str(lit_str) => lit_str.span(),
             ExprLit::Bool(lit_bool) => lit_bool.span(),
+            ExprLit::Url(lit_url) => lit_url.span(),
         }
     }
 }
 
 impl Peek for ExprLit {
     fn peek(input: &crate::ParseBuffer) -> bool {
-        Self::parse(&mut input.fork()).is_ok()
+        match input.peek_tree() {
+            Some(TokenTree::Literal(literal)) => !matches!(literal, Literal::InterpolatedStr(_)),
+            Some(TokenTree::Ident(ident)) => ident.ident() == "true" || ident.ident() == "false",
+            _ => false,
+        }
+    }
+
+    fn display() -> &'static str {
+        "literal"
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LitBool {
     span: Span,
     pub value: bool,
@@ -71,3 +90,74 @@ impl LitBool {
         self.span.clone()
     }
 }
+
+/// A string containing interpolations, such as `"a ${b} c"`.
+#[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExprInterpolatedStr {
+    pub parts: Vec<InterpolatedStrPart>,
+    span: Span,
+}
+
+impl ExprInterpolatedStr {
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
+pub enum InterpolatedStrPart {
+    Str(String),
+    Interpolation(Expr),
+}
+
+impl Parse for ExprInterpolatedStr {
+    fn parse(buffer: &mut crate::ParseBuffer) -> crate::Result<Self> {
+        let str = match buffer.next() {
+            Some(TokenTree::Literal(Literal::InterpolatedStr(str))) => str,
+            v => {
+                let span = v.map(|v| v.span()).unwrap_or(buffer.span());
+                return Err(Error::new(span, "Expected interpolated string."));
+            }
+        };
+
+        let mut parts = Vec::new();
+        for part in &str.parts {
+            let part = match part {
+                StrPart::Str(str) => InterpolatedStrPart::Str(str.clone()),
+                StrPart::Interpolation(group) => {
+                    let tokens = TokenBuffer::new(group.inner.as_ref());
+                    let mut inner = buffer.nested(&tokens);
+                    let value = inner.parse_expr_or_recover(|_| false)?;
+                    inner.expect_empty()?;
+
+                    InterpolatedStrPart::Interpolation(value)
+                }
+            };
+
+            parts.push(part);
+        }
+
+        Ok(Self {
+            parts,
+            span: str.span(),
+        })
+    }
+}
+
+impl Peek for ExprInterpolatedStr {
+    fn peek(input: &crate::ParseBuffer) -> bool {
+        matches!(
+            input.peek_tree(),
+            Some(TokenTree::Literal(Literal::InterpolatedStr(_)))
+        )
+    }
+
+    fn display() -> &'static str {
+        "string"
+    }
+}
@@ -0,0 +1,86 @@
+use std::cell::RefCell;
+
+use synix_lexer::{TokenTree, group::Delimiter, literal::Literal};
+
+use crate::{Error, ParseBuffer, Peek};
+
+/// Peeks at the next token while collecting everything that was
+/// peeked, to report what was expected if nothing matches.
+///
+/// ```
+/// # use synix::{ParseBuffer, Result, Token};
+/// # use synix::token::Brace;
+/// fn parse_start(input: &ParseBuffer) -> Result<()> {
+///     let lookahead = input.lookahead1();
+///
+///     if lookahead.peek::<Token![let]>() || lookahead.peek::<Brace>() {
+///         Ok(())
+///     } else {
+///         Err(lookahead.error())
+///     }
+/// }
+/// ```
+///
+/// On `in`, this fails with ``Expected one of `let`, `{`, found `in` ``.
+pub struct Lookahead1<'a> {
+    buffer: &'a ParseBuffer<'a>,
+    expected: RefCell<Vec<&'static str>>,
+}
+
+impl<'a> Lookahead1<'a> {
+    pub(crate) fn new(buffer: &'a ParseBuffer<'a>) -> Self {
+        Self {
+            buffer,
+            expected: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Whether the input starts with a `T`. If not, `T` is added
+    /// to the expected tokens.
+    pub fn peek<T: Peek>(&self) -> bool {
+        if T::peek(self.buffer) {
+            return true;
+        }
+
+        let mut expected = self.expected.borrow_mut();
+        if !expected.contains(&T::display()) {
+            expected.push(T::display());
+        }
+
+        false
+    }
+
+    /// An error at the next token, listing everything that
+    /// was peeked.
+    pub fn error(self) -> Error {
+        let (span, found) = match self.buffer.peek_tree() {
+            Some(tree) => (tree.span(), describe(tree)),
+            None => (self.buffer.span(), "end of input".to_string()),
+        };
+
+        let message = match self.expected.into_inner().as_slice() {
+            [] => format!("Unexpected {found}"),
+            [expected] => format!("Expected {expected}, found {found}"),
+            expected => format!("Expected one of {}, found {found}", expected.join(", ")),
+        };
+
+        Error::new(span, message)
+    }
+}
+
+/// Describe a token for an error message.
+fn describe(tree: &TokenTree) -> String {
+    match tree {
+        TokenTree::Ident(ident) => format!("`{}`", ident.ident()),
+        TokenTree::Punct(punct) => format!("`{}`", punct.ch),
+        TokenTree::Group(group) => match group.delimiter {
+            Delimiter::Brace => "`{`".to_string(),
+            Delimiter::Paren => "`(`".to_string(),
+            Delimiter::Bracket => "`[`".to_string(),
+        },
+        TokenTree::Literal(Literal::Int(int)) => format!("`{}`", int.digits),
+        TokenTree::Literal(Literal::Float(float)) => format!("`{}`", float.digits),
+        TokenTree::Literal(Literal::Str(_) | Literal::InterpolatedStr(_)) => "string".to_string(),
+        TokenTree::Literal(Literal::Url(_)) => "URL".to_string(),
+    }
+}
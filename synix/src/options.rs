@@ -0,0 +1,143 @@
+use synix_lexer::LexOptions;
+
+/// Options that control which language features the parser accepts.
+///
+/// The default options accept the stable Nix language, except for
+/// URL literals, and are permissive about input that Nix accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    pipe_operators: bool,
+    url_literals: bool,
+    legacy_let: bool,
+    or_identifier: bool,
+    strict: bool,
+    target: Option<Target>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    pub const fn new() -> Self {
+        Self {
+            pipe_operators: false,
+            url_literals: false,
+            legacy_let: true,
+            or_identifier: true,
+            strict: false,
+            target: None,
+        }
+    }
+
+    /// The options that match the default behaviour of `target`.
+    ///
+    /// URL literals, the legacy `let` syntax and `or` as an
+    /// identifier are accepted, like every Nix and Lix release does
+    /// by default, whatever the release. Only the pipe operators
+    /// depend on `target`: they are not enabled, but enabling them
+    /// is an error if `target` does not support them.
+    pub const fn for_target(target: Target) -> Self {
+        Self {
+            url_literals: true,
+            target: Some(target),
+            ..Self::new()
+        }
+    }
+
+    /// Accept the experimental pipe operators `|>` and `<|`.
+    pub const fn pipe_operators(mut self, enabled: bool) -> Self {
+        self.pipe_operators = enabled;
+        self
+    }
+
+    /// Accept unquoted URLs such as `https://nixos.org`.
+    ///
+    /// Note that this makes `x:x` a URL instead of a lambda.
+    pub const fn url_literals(mut self, enabled: bool) -> Self {
+        self.url_literals = enabled;
+        self
+    }
+
+    /// Accept the deprecated `let { ...; body = ...; }` syntax.
+    pub const fn legacy_let(mut self, enabled: bool) -> Self {
+        self.legacy_let = enabled;
+        self
+    }
+
+    /// Accept `or` as an identifier, such as in `{ or = 1; }`.
+    pub const fn or_identifier(mut self, enabled: bool) -> Self {
+        self.or_identifier = enabled;
+        self
+    }
+
+    /// Reject input that Nix accepts but that is most likely a
+    /// mistake, such as unknown escape sequences in strings.
+    pub const fn strict(mut self, enabled: bool) -> Self {
+        self.strict = enabled;
+        self
+    }
+
+    pub const fn has_pipe_operators(&self) -> bool {
+        self.pipe_operators
+    }
+
+    pub const fn has_url_literals(&self) -> bool {
+        self.url_literals
+    }
+
+    pub const fn has_legacy_let(&self) -> bool {
+        self.legacy_let
+    }
+
+    pub const fn has_or_identifier(&self) -> bool {
+        self.or_identifier
+    }
+
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    pub const fn target(&self) -> Option<Target> {
+        self.target
+    }
+
+    /// The options to lex the input with.
+    pub const fn lex_options(&self) -> LexOptions {
+        LexOptions::new()
+            .url_literals(self.url_literals)
+            .strict(self.strict)
+    }
+}
+
+/// A release of a Nix implementation, as `major.minor`.
+///
+/// The only feature that depends on the release is the pipe
+/// operators, see [`Target::has_pipe_operators`]. The other
+/// options are not checked against the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Nix(u32, u32),
+    Lix(u32, u32),
+}
+
+impl Target {
+    /// Whether this release has the (experimental) pipe operators.
+    pub const fn has_pipe_operators(&self) -> bool {
+        match *self {
+            Target::Nix(major, minor) => major > 2 || (major == 2 && minor >= 24),
+            Target::Lix(major, minor) => major > 2 || (major == 2 && minor >= 91),
+        }
+    }
+}
+
+impl core::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Target::Nix(major, minor) => write!(f, "Nix {major}.{minor}"),
+            Target::Lix(major, minor) => write!(f, "Lix {major}.{minor}"),
+        }
+    }
+}
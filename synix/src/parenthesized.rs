@@ -3,6 +3,8 @@ use synix_lexer::Span;
 use crate::{Expr, Paren, Parse, ParseBuffer, Peek, Result, parenthesized};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprParenthesized {
     pub inner: Expr,
     span: Span,
@@ -24,15 +26,21 @@ impl Peek for ExprParenthesized {
     fn peek(input: &ParseBuffer) -> bool {
         input.peek(Paren)
     }
+
+    fn display() -> &'static str {
+        "`(`"
+    }
 }
 
 impl Parse for ExprParenthesized {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
+        let start = buffer.span();
         let mut paren;
         parenthesized!(buffer as paren else "Expected parenthesized expression.");
 
-        let span = paren.span();
-        let inner = paren.parse()?;
+        let span = buffer.span_from(&start);
+        let inner = paren.parse_expr_or_recover(|_| false)?;
+        paren.expect_empty()?;
 
         Ok(Self { inner, span })
     }
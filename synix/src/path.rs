@@ -1,8 +1,11 @@
 use synix_lexer::literal::{LitFloat, LitInt};
 
-use crate::{ident::LiteralOrInterpolatedIdent, *};
+use crate::{ident::LiteralOrInterpolatedIdent, token::custom::peek_punct_n, *};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum Path {
     Lookup(LookupPath),
     Normal(DirPath),
@@ -21,6 +24,10 @@ impl Peek for Path {
     fn peek(input: &ParseBuffer) -> bool {
         LookupPath::peek(input) || DirPath::peek(input)
     }
+
+    fn display() -> &'static str {
+        "path"
+    }
 }
 
 impl Parse for Path {
@@ -36,6 +43,8 @@ impl Parse for Path {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PathPart {
     pub head: PathSubPart,
     pub tail: Vec<PathSubPart>,
@@ -54,14 +63,8 @@ impl Parse for PathPart {
         let head = buffer.parse()?;
 
         let mut tail = Vec::new();
-        while let Ok(part) = buffer.parse() {
-            let is_dot_and_alone =
-                matches!(&part, PathSubPart::Dot(dot) if !dot.spacing.is_joint());
-            tail.push(part);
-
-            if is_dot_and_alone {
-                break;
-            }
+        while buffer.is_joint_to_previous() && PathSubPart::peek(buffer) {
+            tail.push(buffer.parse()?);
         }
 
         Ok(Self { head, tail })
@@ -69,6 +72,9 @@ impl Parse for PathPart {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value"))]
 pub enum PathSubPart {
     Ident(LiteralOrInterpolatedIdent),
     LitInt(LitInt),
@@ -87,6 +93,20 @@ impl PathSubPart {
     }
 }
 
+impl Peek for PathSubPart {
+    fn peek(input: &ParseBuffer) -> bool {
+        input.peek(LitInt)
+            || input.peek(LitFloat)
+            || Ident::peek(input)
+            || InterpolatedIdent::peek(input)
+            || input.peek(Dot)
+    }
+
+    fn display() -> &'static str {
+        "path segment"
+    }
+}
+
 impl Parse for PathSubPart {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
         let result = if buffer.peek(LitInt) {
@@ -115,7 +135,7 @@ impl Parse for PathSubPart {
                 .expect("A literal float");
 
             Self::LitFloat(float)
-        } else if LiteralOrInterpolatedIdent::peek(buffer) {
+        } else if Ident::peek(buffer) || InterpolatedIdent::peek(buffer) {
             let ident = buffer.parse()?;
             Self::Ident(ident)
         } else if buffer.peek(Dot) {
@@ -133,6 +153,8 @@ impl Parse for PathSubPart {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PathPrefix {
     pub kind: PathPrefixKind,
     span: Span,
@@ -144,12 +166,16 @@ impl PathPrefix {
     }
 
     fn peek(buffer: &ParseBuffer) -> bool {
-        let normal_path =
-            buffer.peek(Slash) || <Token![./]>::peek(buffer) || <Token![~/]>::peek(buffer);
-
-        let no_prefix_path = buffer.peek(Ident) && buffer.peek_n(1, Slash);
-
-        normal_path || no_prefix_path
+        // A path may not contain whitespace, which is what
+        // distinguishes `a/b` from `a / b`.
+        let absolute = buffer.peek(Slash)
+            && buffer.is_joint(0)
+            && (buffer.peek_n(1, Ident) || buffer.peek_n(1, LitInt) || buffer.peek_n(1, Dollar));
+        let prefixed =
+            (<Token![./]>::peek(buffer) || <Token![~/]>::peek(buffer)) && buffer.is_joint(1);
+        let no_prefix_path = buffer.peek(Ident) && buffer.is_joint(0) && is_separator(buffer, 1);
+
+        absolute || prefixed || no_prefix_path
     }
 
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
@@ -170,13 +196,20 @@ impl PathPrefix {
             return Err(Error::new(buffer.span(), "Expected path"));
         };
 
-        let span = start.join(&buffer.span());
+        // A path without prefix starts at its first part.
+        let span = if kind == PathPrefixKind::None {
+            Span::new(start.start(), start.start())
+        } else {
+            buffer.span_from(&start)
+        };
 
         Ok(Self { kind, span })
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "extra-traits", derive(Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PathPrefixKind {
     /// No prefix (evaluation should be the
     /// same as [`PathPrefixKind::CurrentDir`]).
@@ -189,7 +222,16 @@ pub enum PathPrefixKind {
     HomeDir,
 }
 
+/// Whether the `n`th tree is a `/` between two parts of a path. It
+/// must be joint to the next part, which may not be another `/`, as
+/// `a//b` is `a // b`.
+fn is_separator(buffer: &ParseBuffer, n: usize) -> bool {
+    buffer.peek_n(n, Slash) && buffer.is_joint(n) && !buffer.peek_n(n + 1, Slash)
+}
+
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirPath {
     pub prefix: PathPrefix,
     pub head: PathPart,
@@ -200,7 +242,9 @@ impl DirPath {
     pub fn span(&self) -> Span {
         self.tail
             .iter()
-            .fold(self.prefix.span(), |all, ident| all.join(&ident.span()))
+            .fold(self.prefix.span().join(&self.head.span()), |all, ident| {
+                all.join(&ident.span())
+            })
     }
 }
 
@@ -208,6 +252,10 @@ impl Peek for DirPath {
     fn peek(input: &ParseBuffer) -> bool {
         PathPrefix::peek(input)
     }
+
+    fn display() -> &'static str {
+        "path"
+    }
 }
 
 impl Parse for DirPath {
@@ -218,7 +266,7 @@ impl Parse for DirPath {
 
         let head = buffer.parse()?;
 
-        while buffer.peek(Slash) {
+        while buffer.is_joint_to_previous() && is_separator(buffer, 0) {
             let _ = <Token![/]>::parse(buffer)?;
             let part = buffer.parse()?;
             tail.push(part);
@@ -229,6 +277,8 @@ impl Parse for DirPath {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LookupPath {
     pub head: Ident,
     pub tail: Vec<Ident>,
@@ -243,7 +293,35 @@ impl LookupPath {
 
 impl Peek for LookupPath {
     fn peek(input: &ParseBuffer) -> bool {
-        <Token![<]>::peek(input)
+        // Distinguish `<nixpkgs>` from `a < b`.
+        if !<Token![<]>::peek(input) || !input.is_joint(0) {
+            return false;
+        }
+
+        // The identifiers are separated by slashes.
+        let mut idx = 1;
+        loop {
+            let is_ident = matches!(
+                input.peek_tree_n(idx),
+                Some(TokenTree::Ident(ident)) if !Ident::is_reserved(input, ident)
+            );
+
+            if !is_ident {
+                return false;
+            }
+
+            if input.peek_n(idx + 1, Slash) {
+                idx += 2;
+            } else {
+                break;
+            }
+        }
+
+        peek_punct_n(input, idx + 1, ">")
+    }
+
+    fn display() -> &'static str {
+        "lookup path"
     }
 }
 
@@ -264,7 +342,7 @@ impl Parse for LookupPath {
         }
 
         let _ = <Token![>]>::parse(buffer)?;
-        let span = start.join(&buffer.span());
+        let span = buffer.span_from(&start);
 
         Ok(Self { head, tail, span })
     }
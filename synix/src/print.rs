@@ -0,0 +1,608 @@
+//! Printing the AST back to Nix source.
+//!
+//! Every node implements [`ToTokens`]. Expressions are printed with
+//! the minimal parentheses that are needed for the output to parse
+//! back into the same tree: parentheses that are part of the tree, as
+//! [`ExprParenthesized`], are always printed, and more are only added
+//! where operator precedence requires them.
+
+use crate::{
+    Assignment, AssignmentInherit, AssignmentNamed, Expr, ExprFunctionCall, ExprIf, ExprLegacyLet,
+    ExprLet, ExprParenthesized, ExprWith, Ident, InterpolatedIdent, LiteralOrInterpolatedIdent,
+    attrset::{AttributeAccess, ExprAttrSet, ExprHasAttr},
+    binary::{Associativity, ExprBinary, ExprUnary, Operator, UnaryOperator},
+    lambda::{ArgAttrSet, ArgAttrSetValue, ExprLambda, LambdaArg},
+    list::ExprList,
+    lit::{ExprInterpolatedStr, ExprLit, InterpolatedStrPart},
+    path::{DirPath, LookupPath, Path, PathPart, PathPrefixKind, PathSubPart},
+};
+
+/// Print `expr` as Nix source.
+pub fn print(expr: &Expr) -> String {
+    expr.to_token_string()
+}
+
+/// Collects printed tokens, separating them by a single space
+/// unless they are [joint](Printer::joint).
+#[derive(Debug, Default)]
+pub struct Printer {
+    output: String,
+    joint: bool,
+}
+
+impl Printer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Print a token.
+    pub fn token(&mut self, text: &str) {
+        if !self.output.is_empty() && !self.joint {
+            self.output.push(' ');
+        }
+
+        self.output.push_str(text);
+        self.joint = false;
+    }
+
+    /// Print the next token without a space before it.
+    pub fn joint(&mut self) {
+        self.joint = true;
+    }
+
+    pub fn into_string(self) -> String {
+        self.output
+    }
+}
+
+/// Types that can be printed as Nix source.
+pub trait ToTokens {
+    fn to_tokens(&self, printer: &mut Printer);
+
+    fn to_token_string(&self) -> String {
+        let mut printer = Printer::new();
+        self.to_tokens(&mut printer);
+        printer.into_string()
+    }
+}
+
+impl<T: ToTokens> ToTokens for Box<T> {
+    fn to_tokens(&self, printer: &mut Printer) {
+        (**self).to_tokens(printer);
+    }
+}
+
+/// Binding power of expressions that are not operators.
+mod level {
+    /// `let`, `with`, `if` and lambdas, which extend as far
+    /// to the right as possible.
+    pub const OPEN: u8 = 0;
+    pub const APPLICATION: u8 = 14;
+    pub const SELECT: u8 = 15;
+    pub const SIMPLE: u8 = 16;
+}
+
+/// How tightly `expr` binds, comparable to [`Operator::precedence`].
+fn level(expr: &Expr) -> u8 {
+    match expr {
+        Expr::Let(_) | Expr::Lambda(_) | Expr::With(_) | Expr::If(_) => level::OPEN,
+        Expr::Binary(binary) => binary.operator.precedence(),
+        Expr::Unary(unary) => unary.operator.precedence(),
+        Expr::HasAttr(_) => ExprHasAttr::PRECEDENCE,
+        Expr::FunctionCall(_) => level::APPLICATION,
+        Expr::AttributeAccess(_) => level::SELECT,
+        Expr::LegacyLet(_)
+        | Expr::Lit(_)
+        | Expr::InterpolatedStr(_)
+        | Expr::Ident(_)
+        | Expr::AttrSet(_)
+        | Expr::Parenthesized(_)
+        | Expr::List(_)
+        | Expr::Path(_)
+        | Expr::Error(_) => level::SIMPLE,
+    }
+}
+
+/// Print `expr`, adding parentheses if it binds less
+/// tightly than `min`.
+fn expr_at(expr: &Expr, min: u8, printer: &mut Printer) {
+    if level(expr) < min {
+        parenthesized(expr, printer);
+    } else {
+        expr.to_tokens(printer);
+    }
+}
+
+fn parenthesized(expr: &dyn ToTokens, printer: &mut Printer) {
+    printer.token("(");
+    printer.joint();
+    expr.to_tokens(printer);
+    printer.joint();
+    printer.token(")");
+}
+
+impl ToTokens for Expr {
+    fn to_tokens(&self, printer: &mut Printer) {
+        crate::ensure_stack(|| match self {
+            Expr::Let(let_) => let_.to_tokens(printer),
+            Expr::LegacyLet(let_) => let_.to_tokens(printer),
+            Expr::Lit(lit) => lit.to_tokens(printer),
+            Expr::InterpolatedStr(str) => str.to_tokens(printer),
+            Expr::Lambda(lambda) => lambda.to_tokens(printer),
+            Expr::Ident(ident) => ident.to_tokens(printer),
+            Expr::AttrSet(set) => set.to_tokens(printer),
+            Expr::Parenthesized(paren) => paren.to_tokens(printer),
+            Expr::List(list) => list.to_tokens(printer),
+            Expr::With(with) => with.to_tokens(printer),
+            Expr::If(if_) => if_.to_tokens(printer),
+            Expr::FunctionCall(call) => call.to_tokens(printer),
+            Expr::Binary(binary) => binary.to_tokens(printer),
+            Expr::Unary(unary) => unary.to_tokens(printer),
+            Expr::HasAttr(has_attr) => has_attr.to_tokens(printer),
+            Expr::AttributeAccess(access) => access.to_tokens(printer),
+            Expr::Path(path) => path.to_tokens(printer),
+            // There is no source to print for input that
+            // could not be parsed.
+            Expr::Error(_) => {}
+        })
+    }
+}
+
+impl core::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_token_string())
+    }
+}
+
+impl ToTokens for ExprLet {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token("let");
+        self.assignments.iter().for_each(|a| a.to_tokens(printer));
+        printer.token("in");
+        self.body.to_tokens(printer);
+    }
+}
+
+impl ToTokens for ExprLegacyLet {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token("let");
+        braced(&self.assignments, printer);
+    }
+}
+
+fn braced(assignments: &[Assignment], printer: &mut Printer) {
+    printer.token("{");
+    assignments.iter().for_each(|a| a.to_tokens(printer));
+    printer.token("}");
+}
+
+impl ToTokens for ExprAttrSet {
+    fn to_tokens(&self, printer: &mut Printer) {
+        if self.rec.is_some() {
+            printer.token("rec");
+        }
+
+        braced(&self.assignments, printer);
+    }
+}
+
+impl ToTokens for Assignment {
+    fn to_tokens(&self, printer: &mut Printer) {
+        match self {
+            Assignment::Inherit(inherit) => inherit.to_tokens(printer),
+            Assignment::Named(named) => named.to_tokens(printer),
+        }
+    }
+}
+
+impl ToTokens for AssignmentNamed {
+    fn to_tokens(&self, printer: &mut Printer) {
+        attr_path(&self.head, &self.tail, printer);
+        printer.token("=");
+        self.value.to_tokens(printer);
+        printer.joint();
+        printer.token(";");
+    }
+}
+
+impl ToTokens for AssignmentInherit {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token("inherit");
+
+        if let Some(base) = &self.base {
+            parenthesized(base, printer);
+        }
+
+        self.names.iter().for_each(|name| name.to_tokens(printer));
+        printer.joint();
+        printer.token(";");
+    }
+}
+
+fn attr_path(
+    head: &LiteralOrInterpolatedIdent,
+    tail: &[LiteralOrInterpolatedIdent],
+    printer: &mut Printer,
+) {
+    head.to_tokens(printer);
+
+    for name in tail {
+        printer.joint();
+        printer.token(".");
+        printer.joint();
+        name.to_tokens(printer);
+    }
+}
+
+impl ToTokens for LiteralOrInterpolatedIdent {
+    fn to_tokens(&self, printer: &mut Printer) {
+        match self {
+            LiteralOrInterpolatedIdent::Literal(ident) => ident.to_tokens(printer),
+            LiteralOrInterpolatedIdent::Interpolated(interpolated) => {
+                interpolated.to_tokens(printer)
+            }
+            LiteralOrInterpolatedIdent::Str(str) => printer.token(&quote(&str.value)),
+            LiteralOrInterpolatedIdent::InterpolatedStr(str) => str.to_tokens(printer),
+        }
+    }
+}
+
+impl ToTokens for InterpolatedIdent {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token(&format!("${{{}}}", self.value.to_token_string()));
+    }
+}
+
+impl ToTokens for Ident {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token(self.ident());
+    }
+}
+
+impl ToTokens for ExprLit {
+    fn to_tokens(&self, printer: &mut Printer) {
+        match self {
+            ExprLit::Int(int) => printer.token(&int.digits),
+            ExprLit::Float(float) => printer.token(&float.digits),
+            ExprLit::Str(str) => printer.token(&quote(&str.value)),
+            ExprLit::Bool(bool) => printer.token(if bool.value { "true" } else { "false" }),
+            ExprLit::Url(url) => printer.token(&url.url),
+        }
+    }
+}
+
+/// Escape `value` for use in a string literal. `before_interpolation`
+/// is set if the string continues with an interpolation.
+fn escape(value: &str, before_interpolation: bool, output: &mut String) {
+    let mut chars = value.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            // `$$` is never an interpolation, and is kept as-is
+            // by the lexer.
+            '$' if chars.peek() == Some(&'$') => {
+                chars.next();
+                output.push_str("$$");
+            }
+            '$' if chars.peek() == Some(&'{')
+                || (chars.peek().is_none() && before_interpolation) =>
+            {
+                output.push_str("\\$")
+            }
+            char => output.push(char),
+        }
+    }
+}
+
+fn quote(value: &str) -> String {
+    let mut output = String::from("\"");
+    escape(value, false, &mut output);
+    output.push('"');
+    output
+}
+
+impl ToTokens for ExprInterpolatedStr {
+    fn to_tokens(&self, printer: &mut Printer) {
+        let mut output = String::from("\"");
+
+        let mut parts = self.parts.iter().peekable();
+        while let Some(part) = parts.next() {
+            match part {
+                InterpolatedStrPart::Str(str) => {
+                    let before_interpolation =
+                        matches!(parts.peek(), Some(InterpolatedStrPart::Interpolation(_)));
+                    escape(str, before_interpolation, &mut output);
+                }
+                InterpolatedStrPart::Interpolation(expr) => {
+                    output.push_str("${");
+                    output.push_str(&expr.to_token_string());
+                    output.push('}');
+                }
+            }
+        }
+
+        output.push('"');
+        printer.token(&output);
+    }
+}
+
+impl ToTokens for ExprLambda {
+    fn to_tokens(&self, printer: &mut Printer) {
+        self.arg.to_tokens(printer);
+        printer.joint();
+        printer.token(":");
+        self.body.to_tokens(printer);
+    }
+}
+
+impl ToTokens for LambdaArg {
+    fn to_tokens(&self, printer: &mut Printer) {
+        match self {
+            LambdaArg::Ident(ident) => ident.to_tokens(printer),
+            LambdaArg::AttrSet(set) => set.to_tokens(printer),
+        }
+    }
+}
+
+impl ToTokens for ArgAttrSet {
+    fn to_tokens(&self, printer: &mut Printer) {
+        if let Some(binds_to) = &self.binds_to {
+            binds_to.to_tokens(printer);
+            printer.joint();
+            printer.token("@");
+            printer.joint();
+        }
+
+        printer.token("{");
+
+        let mut first = true;
+        for arg in &self.args {
+            if !first {
+                printer.joint();
+                printer.token(",");
+            }
+
+            arg.to_tokens(printer);
+            first = false;
+        }
+
+        if self.ellipsis.is_some() {
+            if !first {
+                printer.joint();
+                printer.token(",");
+            }
+
+            printer.token("...");
+        }
+
+        printer.token("}");
+    }
+}
+
+impl ToTokens for ArgAttrSetValue {
+    fn to_tokens(&self, printer: &mut Printer) {
+        self.ident.to_tokens(printer);
+
+        if let Some(default) = &self.default {
+            printer.token("?");
+            default.to_tokens(printer);
+        }
+    }
+}
+
+impl ToTokens for ExprParenthesized {
+    fn to_tokens(&self, printer: &mut Printer) {
+        parenthesized(&self.inner, printer);
+    }
+}
+
+impl ToTokens for ExprList {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token("[");
+
+        for entry in &self.entries {
+            expr_at(entry, level::SELECT, printer);
+        }
+
+        printer.token("]");
+    }
+}
+
+impl ToTokens for ExprWith {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token("with");
+        self.expr.to_tokens(printer);
+        printer.joint();
+        printer.token(";");
+        self.body.to_tokens(printer);
+    }
+}
+
+impl ToTokens for ExprIf {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token("if");
+        self.condition.to_tokens(printer);
+        printer.token("then");
+        self.then_branch.to_tokens(printer);
+        printer.token("else");
+        self.else_branch.to_tokens(printer);
+    }
+}
+
+impl ToTokens for ExprFunctionCall {
+    fn to_tokens(&self, printer: &mut Printer) {
+        expr_at(&self.head, level::APPLICATION, printer);
+        expr_at(&self.tail, level::SELECT, printer);
+    }
+}
+
+impl ToTokens for ExprBinary {
+    fn to_tokens(&self, printer: &mut Printer) {
+        binary(self, 0, printer);
+    }
+}
+
+/// Print `expr`, which is followed by an operator with precedence
+/// `follow`, or `0` if no operator follows.
+fn binary(expr: &ExprBinary, follow: u8, printer: &mut Printer) {
+    let precedence = expr.operator.precedence();
+
+    let (lhs_min, rhs_min) = match expr.operator.associativity() {
+        Associativity::Left => (precedence, precedence + 1),
+        Associativity::Right => (precedence + 1, precedence),
+        Associativity::None => (precedence + 1, precedence + 1),
+    };
+
+    let operator = (precedence, expr.operator.associativity());
+
+    operand(&expr.lhs, lhs_min, precedence, operator, printer);
+    printer.token(expr.operator.as_str());
+    operand(&expr.rhs, rhs_min, follow, operator, printer);
+}
+
+/// Print an operand of an operator with the given precedence and
+/// associativity. The operand must bind at least as tightly as `min`
+/// and is followed by an operator with precedence `follow`.
+fn operand(
+    expr: &Expr,
+    min: u8,
+    follow: u8,
+    (precedence, associativity): (u8, Associativity),
+    printer: &mut Printer,
+) {
+    // Chains of operators are printed through this function rather
+    // than `Expr::to_tokens`, so it needs its own check.
+    crate::ensure_stack(|| match expr {
+        // Operators with the same precedence but a different associativity
+        // (`|>` and `<|`) can not be mixed without parentheses.
+        Expr::Binary(binary)
+            if binary.operator.precedence() == precedence
+                && binary.operator.associativity() != associativity =>
+        {
+            parenthesized(expr, printer)
+        }
+        Expr::Binary(expr) if expr.operator.precedence() >= min => binary(expr, follow, printer),
+        // The operand of a prefix operator extends as far as possible, so it
+        // only needs parentheses if it would take the operator after it.
+        Expr::Unary(expr) if follow < expr.operator.precedence() => unary(expr, follow, printer),
+        expr => expr_at(expr, min, printer),
+    })
+}
+
+impl ToTokens for ExprUnary {
+    fn to_tokens(&self, printer: &mut Printer) {
+        unary(self, 0, printer);
+    }
+}
+
+fn unary(expr: &ExprUnary, follow: u8, printer: &mut Printer) {
+    printer.token(expr.operator.as_str());
+
+    // Nested prefix operators are kept apart, as in `- -a` and `! !a`.
+    if !matches!(expr.expr, Expr::Unary(_)) {
+        printer.joint();
+    }
+
+    let precedence = expr.operator.precedence();
+    let operator = (precedence, expr.operator.associativity());
+
+    operand(&expr.expr, precedence, follow, operator, printer);
+}
+
+impl ToTokens for UnaryOperator {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token(self.as_str());
+    }
+}
+
+impl ToTokens for Operator {
+    fn to_tokens(&self, printer: &mut Printer) {
+        printer.token(self.as_str());
+    }
+}
+
+impl ToTokens for ExprHasAttr {
+    fn to_tokens(&self, printer: &mut Printer) {
+        expr_at(&self.set, ExprHasAttr::PRECEDENCE + 1, printer);
+        printer.token("?");
+        attr_path(&self.head, &self.tail, printer);
+    }
+}
+
+impl ToTokens for AttributeAccess {
+    fn to_tokens(&self, printer: &mut Printer) {
+        expr_at(&self.set, level::SIMPLE, printer);
+
+        for accessor in &self.accessors {
+            printer.joint();
+            printer.token(".");
+            printer.joint();
+            accessor.to_tokens(printer);
+        }
+
+        if let Some((_, default)) = &self.default {
+            printer.token("or");
+            expr_at(default, level::SELECT, printer);
+        }
+    }
+}
+
+impl ToTokens for Path {
+    fn to_tokens(&self, printer: &mut Printer) {
+        match self {
+            Path::Lookup(lookup) => lookup.to_tokens(printer),
+            Path::Normal(dir) => dir.to_tokens(printer),
+        }
+    }
+}
+
+impl ToTokens for LookupPath {
+    fn to_tokens(&self, printer: &mut Printer) {
+        let mut output = format!("<{}", self.head.ident());
+
+        for part in &self.tail {
+            output.push('/');
+            output.push_str(part.ident());
+        }
+
+        output.push('>');
+        printer.token(&output);
+    }
+}
+
+impl ToTokens for DirPath {
+    fn to_tokens(&self, printer: &mut Printer) {
+        let prefix = match self.prefix.kind {
+            PathPrefixKind::None => "",
+            PathPrefixKind::Absolute => "/",
+            PathPrefixKind::CurrentDir => "./",
+            PathPrefixKind::HomeDir => "~/",
+        };
+
+        let mut output = String::from(prefix);
+        path_part(&self.head, &mut output);
+
+        for part in &self.tail {
+            output.push('/');
+            path_part(part, &mut output);
+        }
+
+        printer.token(&output);
+    }
+}
+
+fn path_part(part: &PathPart, output: &mut String) {
+    for sub_part in std::iter::once(&part.head).chain(&part.tail) {
+        match sub_part {
+            PathSubPart::Ident(ident) => output.push_str(&ident.to_token_string()),
+            PathSubPart::LitInt(int) => output.push_str(&int.digits),
+            PathSubPart::LitFloat(float) => output.push_str(&float.digits),
+            PathSubPart::Dot(_) => output.push('.'),
+        }
+    }
+}
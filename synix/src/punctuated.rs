@@ -0,0 +1,407 @@
+//! A sequence of values separated by punctuation, such as the
+//! arguments of `{ a, b ? 1, c }: a`.
+//!
+//! ```
+//! use synix::{ParseBuffer, Token, punctuated::Punctuated};
+//! use synix::buffer::TokenBuffer;
+//! use synix_lexer::TokenStream;
+//!
+//! let lexed: TokenStream = "a, b, c,".parse().unwrap();
+//! let tokens = TokenBuffer::new(lexed.as_ref());
+//! let mut input = ParseBuffer::new(&tokens);
+//!
+//! let idents: Punctuated<synix::Ident, Token![,]> =
+//!     Punctuated::parse_terminated(&mut input).unwrap();
+//!
+//! assert_eq!(idents.len(), 3);
+//! assert!(idents.trailing_punct());
+//! ```
+
+use std::ops::{Index, IndexMut};
+
+use crate::{Parse, ParseBuffer, Peek, Result};
+
+/// Values of type `T`, separated by punctuation of type `P`. The
+/// last value may or may not be followed by punctuation.
+#[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone, PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Punctuated<T, P> {
+    inner: Vec<(T, P)>,
+    last: Option<Box<T>>,
+}
+
+impl<T, P> Punctuated<T, P> {
+    pub fn new() -> Self {
+        Self {
+            inner: Vec::new(),
+            last: None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty() && self.last.is_none()
+    }
+
+    /// The number of values, not counting the punctuation.
+    pub fn len(&self) -> usize {
+        self.inner.len() + usize::from(self.last.is_some())
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.iter().next_back()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let inner = self.inner.iter().map(|(value, _)| value);
+        Iter {
+            inner: Box::new(inner.chain(self.last.as_deref())),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let inner = self.inner.iter_mut().map(|(value, _)| value);
+        IterMut {
+            inner: Box::new(inner.chain(self.last.as_deref_mut())),
+        }
+    }
+
+    /// The values together with the punctuation that follows them.
+    pub fn pairs(&self) -> Pairs<'_, T, P> {
+        Pairs {
+            inner: self.inner.iter(),
+            last: self.last.as_deref(),
+        }
+    }
+
+    pub fn into_pairs(self) -> IntoPairs<T, P> {
+        IntoPairs {
+            inner: self.inner.into_iter(),
+            last: self.last.map(|last| *last),
+        }
+    }
+
+    /// Append a value. Panics if the last value is not followed
+    /// by punctuation.
+    pub fn push_value(&mut self, value: T) {
+        assert!(
+            self.empty_or_trailing(),
+            "Punctuated::push_value: the last value is not followed by punctuation"
+        );
+
+        self.last = Some(Box::new(value));
+    }
+
+    /// Append punctuation after the last value. Panics if there
+    /// is no value to punctuate.
+    pub fn push_punct(&mut self, punct: P) {
+        let last = self
+            .last
+            .take()
+            .expect("Punctuated::push_punct: there is no value to punctuate");
+
+        self.inner.push((*last, punct));
+    }
+
+    /// Append a value, separating it from the last value with
+    /// `P::default()` if needed.
+    pub fn push(&mut self, value: T)
+    where
+        P: Default,
+    {
+        if !self.empty_or_trailing() {
+            self.push_punct(P::default());
+        }
+
+        self.push_value(value);
+    }
+
+    /// Remove the last value and the punctuation that follows it.
+    pub fn pop(&mut self) -> Option<Pair<T, P>> {
+        match self.last.take() {
+            Some(last) => Some(Pair::End(*last)),
+            None => self
+                .inner
+                .pop()
+                .map(|(value, punct)| Pair::Punctuated(value, punct)),
+        }
+    }
+
+    /// Whether the last value is followed by punctuation.
+    pub fn trailing_punct(&self) -> bool {
+        self.last.is_none() && !self.is_empty()
+    }
+
+    /// Whether a value can be pushed without punctuation first.
+    pub fn empty_or_trailing(&self) -> bool {
+        self.last.is_none()
+    }
+
+    /// Parse values separated by `P` until the input is empty,
+    /// allowing trailing punctuation.
+    pub fn parse_terminated(input: &mut ParseBuffer) -> Result<Self>
+    where
+        T: Parse,
+        P: Parse,
+    {
+        Self::parse_terminated_with(input, T::parse)
+    }
+
+    /// Like [`Self::parse_terminated`], with `parser` to parse
+    /// the values.
+    pub fn parse_terminated_with(
+        input: &mut ParseBuffer,
+        parser: fn(&mut ParseBuffer) -> Result<T>,
+    ) -> Result<Self>
+    where
+        P: Parse,
+    {
+        let mut punctuated = Self::new();
+
+        while !input.is_empty() {
+            punctuated.push_value(parser(input)?);
+
+            if input.is_empty() {
+                break;
+            }
+
+            punctuated.push_punct(input.parse()?);
+        }
+
+        Ok(punctuated)
+    }
+
+    /// Parse at least one value, followed by more values as long
+    /// as a `P` follows. Trailing punctuation is not allowed.
+    pub fn parse_separated_nonempty(input: &mut ParseBuffer) -> Result<Self>
+    where
+        T: Parse,
+        P: Parse + Peek,
+    {
+        Self::parse_separated_nonempty_with(input, T::parse)
+    }
+
+    /// Like [`Self::parse_separated_nonempty`], with `parser` to
+    /// parse the values.
+    pub fn parse_separated_nonempty_with(
+        input: &mut ParseBuffer,
+        parser: fn(&mut ParseBuffer) -> Result<T>,
+    ) -> Result<Self>
+    where
+        P: Parse + Peek,
+    {
+        let mut punctuated = Self::new();
+
+        loop {
+            punctuated.push_value(parser(input)?);
+
+            if !P::peek(input) {
+                break;
+            }
+
+            punctuated.push_punct(input.parse()?);
+        }
+
+        Ok(punctuated)
+    }
+}
+
+impl<T, P> Default for Punctuated<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, P> Index<usize> for Punctuated<T, P> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("Punctuated index out of bounds")
+    }
+}
+
+impl<T, P> IndexMut<usize> for Punctuated<T, P> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("Punctuated index out of bounds")
+    }
+}
+
+impl<T, P: Default> FromIterator<T> for Punctuated<T, P> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut punctuated = Self::new();
+        punctuated.extend(iter);
+        punctuated
+    }
+}
+
+impl<T, P: Default> Extend<T> for Punctuated<T, P> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, P> IntoIterator for Punctuated<T, P> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        let mut values: Vec<_> = self.inner.into_iter().map(|(value, _)| value).collect();
+        values.extend(self.last.map(|last| *last));
+
+        IntoIter {
+            inner: values.into_iter(),
+        }
+    }
+}
+
+impl<'a, T, P> IntoIterator for &'a Punctuated<T, P> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, P> IntoIterator for &'a mut Punctuated<T, P> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// A value, and the punctuation that follows it unless
+/// it is the last value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pair<T, P> {
+    Punctuated(T, P),
+    End(T),
+}
+
+impl<T, P> Pair<T, P> {
+    pub fn value(&self) -> &T {
+        match self {
+            Pair::Punctuated(value, _) | Pair::End(value) => value,
+        }
+    }
+
+    pub fn into_value(self) -> T {
+        match self {
+            Pair::Punctuated(value, _) | Pair::End(value) => value,
+        }
+    }
+
+    pub fn punct(&self) -> Option<&P> {
+        match self {
+            Pair::Punctuated(_, punct) => Some(punct),
+            Pair::End(_) => None,
+        }
+    }
+}
+
+/// An iterator over the values of a [`Punctuated`].
+pub struct Iter<'a, T> {
+    inner: Box<dyn DoubleEndedIterator<Item = &'a T> + 'a>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.inner.next_back()
+    }
+}
+
+/// An iterator over the mutable values of a [`Punctuated`].
+pub struct IterMut<'a, T> {
+    inner: Box<dyn DoubleEndedIterator<Item = &'a mut T> + 'a>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.inner.next_back()
+    }
+}
+
+/// An iterator over the [pairs](Pair) of a [`Punctuated`].
+pub struct Pairs<'a, T, P> {
+    inner: std::slice::Iter<'a, (T, P)>,
+    last: Option<&'a T>,
+}
+
+impl<'a, T, P> Iterator for Pairs<'a, T, P> {
+    type Item = Pair<&'a T, &'a P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next() {
+            Some((value, punct)) => Some(Pair::Punctuated(value, punct)),
+            None => self.last.take().map(Pair::End),
+        }
+    }
+}
+
+/// An iterator over the owned values of a [`Punctuated`].
+pub struct IntoIter<T> {
+    inner: std::vec::IntoIter<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back()
+    }
+}
+
+/// An iterator over the owned [pairs](Pair) of a [`Punctuated`].
+pub struct IntoPairs<T, P> {
+    inner: std::vec::IntoIter<(T, P)>,
+    last: Option<T>,
+}
+
+impl<T, P> Iterator for IntoPairs<T, P> {
+    type Item = Pair<T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next() {
+            Some((value, punct)) => Some(Pair::Punctuated(value, punct)),
+            None => self.last.take().map(Pair::End),
+        }
+    }
+}
@@ -0,0 +1,434 @@
+//! Rendering errors as snippets of the source, in the style of rustc.
+//!
+//! ```
+//! use synix::render::{Renderer, SourceMap};
+//!
+//! let source = "{ a, b, a }: a";
+//! let error = synix::parse(source).unwrap_err();
+//! let output = Renderer::new().render(&SourceMap::new("example.nix", source), &error);
+//!
+//! assert_eq!(
+//!     output,
+//!     "\
+//! error: Duplicate argument `a`.
+//!  --> example.nix:1:9
+//!   |
+//! 1 | { a, b, a }: a
+//!   |   -     ^
+//!   |   |
+//!   |   first defined here
+//! "
+//! );
+//! ```
+
+use synix_lexer::{LineColumn, Span};
+
+use crate::{Error, Warning};
+
+/// The text of a file, split into lines.
+#[derive(Debug, Clone)]
+pub struct SourceMap<'a> {
+    name: String,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> SourceMap<'a> {
+    /// `name` is shown in the location of diagnostics.
+    pub fn new(name: impl Into<String>, source: &'a str) -> Self {
+        Self {
+            name: name.into(),
+            lines: source.lines().collect(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The line with the (zero-based) index `line`, without its
+    /// line break. Lines past the end of the source are empty.
+    pub fn line(&self, line: usize) -> &'a str {
+        self.lines.get(line).copied().unwrap_or_default()
+    }
+}
+
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Renders diagnostics, optionally with ANSI colours.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Renderer {
+    color: bool,
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to colour the output with ANSI escape codes.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Render all diagnostics of `error`, separated by empty lines.
+    pub fn render(&self, source: &SourceMap, error: &Error) -> String {
+        let diagnostics: Vec<_> = error
+            .iter()
+            .map(|diagnostic| {
+                let labels = diagnostic
+                    .labels()
+                    .iter()
+                    .map(|label| (label.span(), label.message()));
+
+                self.snippet(
+                    source,
+                    Level::Error,
+                    diagnostic.message(),
+                    diagnostic.span(),
+                    labels.collect(),
+                    diagnostic.notes(),
+                    diagnostic.help(),
+                )
+            })
+            .collect();
+
+        diagnostics.join("\n")
+    }
+
+    pub fn render_warning(&self, source: &SourceMap, warning: &Warning) -> String {
+        let span = warning.span();
+        self.snippet(
+            source,
+            Level::Warning,
+            warning.message(),
+            span,
+            Vec::new(),
+            &[],
+            &[],
+        )
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    fn snippet(
+        &self,
+        source: &SourceMap,
+        level: Level,
+        message: &str,
+        span: Span,
+        labels: Vec<(Span, &str)>,
+        notes: &[String],
+        help: &[String],
+    ) -> String {
+        let location = span.start();
+        let primary = Annotation::new(span, None, true);
+        let secondary = labels
+            .into_iter()
+            .map(|(span, message)| Annotation::new(span, Some(message), false));
+
+        let mut annotations: Vec<_> = std::iter::once(primary).chain(secondary).collect();
+
+        // Only one multi-line span is drawn in the margin, the
+        // others are underlined up to the end of their first line.
+        let mut multi_line = None;
+        for (idx, annotation) in annotations.iter_mut().enumerate() {
+            if annotation.start.line < annotation.end.line {
+                if multi_line.is_none() {
+                    multi_line = Some(idx);
+                } else {
+                    let length = source.line(annotation.start.line).chars().count();
+                    annotation.end = LineColumn {
+                        line: annotation.start.line,
+                        column: length.max(annotation.start.column + 1),
+                    };
+                }
+            }
+        }
+        let multi_line = multi_line.map(|idx| annotations.remove(idx));
+
+        let mut lines: Vec<usize> = annotations.iter().map(|a| a.start.line).collect();
+        if let Some(multi_line) = &multi_line {
+            let (start, end) = (multi_line.start.line, multi_line.end.line);
+
+            if end - start <= 4 {
+                lines.extend(start..=end);
+            } else {
+                lines.extend([start, start + 1, end - 1, end]);
+            }
+        }
+        lines.sort_unstable();
+        lines.dedup();
+
+        let width = (lines.last().copied().unwrap_or_default() + 1)
+            .to_string()
+            .len();
+
+        let mut snippet = Snippet {
+            renderer: *self,
+            output: String::new(),
+            width,
+            margin: multi_line.as_ref().map(Annotation::color),
+        };
+
+        let (color, name) = match level {
+            Level::Error => (RED, "error"),
+            Level::Warning => (YELLOW, "warning"),
+        };
+        let header = format!("{}{}", self.paint(color, name), self.paint(BOLD, ":"));
+        snippet.push(&format!("{header} {}", self.paint(BOLD, message)));
+
+        let location = format!(
+            "{}:{}:{}",
+            source.name(),
+            location.line + 1,
+            location.column + 1
+        );
+        snippet.push(&format!(
+            "{}{} {location}",
+            " ".repeat(width),
+            self.paint(BLUE, "-->")
+        ));
+        snippet.empty();
+
+        let mut previous = None;
+        for line in lines {
+            if previous.is_some_and(|previous| previous + 1 < line) {
+                snippet.push(&self.paint(BLUE, "..."));
+            }
+            previous = Some(line);
+
+            let text = source.line(line);
+            let on_line = annotations.iter().filter(|a| a.start.line == line);
+
+            let (margin, start_marker, end_marker) = match &multi_line {
+                Some(multi) if multi.start.line == line => {
+                    let indent = text.chars().take_while(|c| c.is_whitespace()).count();
+
+                    if indent == multi.start.column {
+                        ("/", None, None)
+                    } else {
+                        (" ", Some(multi), None)
+                    }
+                }
+                Some(multi) if multi.start.line < line && line < multi.end.line => {
+                    ("|", None, None)
+                }
+                Some(multi) if multi.end.line == line => ("|", None, Some(multi)),
+                _ => (" ", None, None),
+            };
+
+            snippet.source_line(line, margin, text);
+
+            if let Some(multi) = end_marker {
+                let marker = format!("{}{}", "_".repeat(multi.end.column), multi.marker());
+                let mut row = self.paint(multi.color(), &marker);
+                if let Some(message) = multi.message {
+                    row.push(' ');
+                    row.push_str(&self.paint(multi.color(), message));
+                }
+                snippet.row("|", &row);
+            }
+
+            // The margin of the rows below the line.
+            let margin = match margin {
+                "/" => "|",
+                _ if end_marker.is_some() => " ",
+                margin => margin,
+            };
+            snippet.annotations(margin, on_line.collect());
+
+            if let Some(multi) = start_marker {
+                let marker = format!("{}{}", "_".repeat(multi.start.column + 1), multi.marker());
+                snippet.row(" ", &self.paint(multi.color(), &marker));
+            }
+        }
+
+        if !notes.is_empty() || !help.is_empty() {
+            snippet.empty();
+        }
+
+        for (kind, messages) in [("note", notes), ("help", help)] {
+            for message in messages {
+                let kind = self.paint(BOLD, &format!("{kind}:"));
+                snippet.push(&format!(
+                    "{}{} {kind} {message}",
+                    " ".repeat(width + 1),
+                    self.paint(BLUE, "=")
+                ));
+            }
+        }
+
+        snippet.output
+    }
+
+    fn paint(&self, color: &str, text: &str) -> String {
+        if self.color {
+            format!("{color}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Level {
+    Error,
+    Warning,
+}
+
+/// A span to underline in a snippet.
+#[derive(Debug)]
+struct Annotation<'a> {
+    start: LineColumn,
+    end: LineColumn,
+    message: Option<&'a str>,
+    primary: bool,
+}
+
+impl<'a> Annotation<'a> {
+    fn new(span: Span, message: Option<&'a str>, primary: bool) -> Self {
+        let (start, mut end) = (span.start(), span.end());
+
+        // Empty spans, as the end of the input, still get a marker.
+        if start.line == end.line && end.column <= start.column {
+            end.column = start.column + 1;
+        }
+
+        Self {
+            start,
+            end,
+            message,
+            primary,
+        }
+    }
+
+    fn marker(&self) -> &'static str {
+        if self.primary { "^" } else { "-" }
+    }
+
+    fn color(&self) -> &'static str {
+        if self.primary { RED } else { BLUE }
+    }
+}
+
+/// The output of a single diagnostic.
+struct Snippet {
+    renderer: Renderer,
+    output: String,
+    /// The width of the line numbers.
+    width: usize,
+    /// The colour of the multi-line span drawn left of the
+    /// source, if there is one.
+    margin: Option<&'static str>,
+}
+
+impl Snippet {
+    fn push(&mut self, line: &str) {
+        self.output.push_str(line.trim_end());
+        self.output.push('\n');
+    }
+
+    /// An empty line with only the gutter.
+    fn empty(&mut self) {
+        let gutter = self.renderer.paint(BLUE, "|");
+        self.push(&format!("{} {gutter}", " ".repeat(self.width)));
+    }
+
+    fn source_line(&mut self, line: usize, margin: &str, text: &str) {
+        let number = format!("{:>width$} |", line + 1, width = self.width);
+        let mut output = format!("{} ", self.renderer.paint(BLUE, &number));
+
+        if let Some(color) = self.margin {
+            output.push_str(&self.renderer.paint(color, margin));
+            output.push(' ');
+        }
+
+        output.push_str(text);
+        self.push(&output);
+    }
+
+    /// A line below the source, with `margin` drawn left of
+    /// `content` if there is a multi-line span.
+    fn row(&mut self, margin: &str, content: &str) {
+        let gutter = self.renderer.paint(BLUE, "|");
+        let mut output = format!("{} {gutter} ", " ".repeat(self.width));
+
+        if let Some(color) = self.margin {
+            output.push_str(&self.renderer.paint(color, margin));
+        }
+
+        output.push_str(content);
+        self.push(&output);
+    }
+
+    /// Underline the single-line `annotations` of a line. The
+    /// message of the rightmost one follows its marker, the others
+    /// are printed below, connected to their markers.
+    fn annotations(&mut self, margin: &str, mut annotations: Vec<&Annotation>) {
+        if annotations.is_empty() {
+            return;
+        }
+
+        annotations.sort_by_key(|a| (a.start.column, !a.primary));
+        let indent = if self.margin.is_some() { " " } else { "" };
+
+        // The markers of every annotation, on a single row.
+        let mut row: Vec<(char, &str)> = Vec::new();
+        for annotation in &annotations {
+            if row.len() < annotation.end.column {
+                row.resize(annotation.end.column, (' ', ""));
+            }
+
+            let marker = annotation.marker().chars().next().unwrap_or('^');
+            for cell in &mut row[annotation.start.column..annotation.end.column] {
+                *cell = (marker, annotation.color());
+            }
+        }
+
+        let mut content = format!("{indent}{}", self.cells(&row));
+        let mut below: Vec<_> = annotations.iter().filter(|a| a.message.is_some()).collect();
+
+        if let Some(last) = annotations.last()
+            && let Some(message) = last.message
+        {
+            content.push(' ');
+            content.push_str(&self.renderer.paint(last.color(), message));
+            below.pop();
+        }
+        self.row(margin, &content);
+
+        while let Some(last) = below.pop() {
+            let mut connectors = Vec::new();
+            for annotation in below.iter().chain([&last]) {
+                if connectors.len() <= annotation.start.column {
+                    connectors.resize(annotation.start.column + 1, (' ', ""));
+                }
+                connectors[annotation.start.column] = ('|', annotation.color());
+            }
+            self.row(margin, &format!("{indent}{}", self.cells(&connectors)));
+
+            connectors.truncate(last.start.column);
+            let message = self
+                .renderer
+                .paint(last.color(), last.message.unwrap_or_default());
+            self.row(
+                margin,
+                &format!("{indent}{}{message}", self.cells(&connectors)),
+            );
+        }
+    }
+
+    fn cells(&self, cells: &[(char, &str)]) -> String {
+        cells
+            .iter()
+            .map(|(char, color)| match char {
+                ' ' => " ".to_string(),
+                char => self.renderer.paint(color, &char.to_string()),
+            })
+            .collect()
+    }
+}
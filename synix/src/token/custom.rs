@@ -0,0 +1,341 @@
+use synix_lexer::{Span, TokenTree, punct::Char};
+
+use crate::{Error, ParseBuffer, Result};
+
+/// Whether the next tree is the keyword `name`.
+pub fn peek_keyword(buffer: &ParseBuffer, name: &str) -> bool {
+    matches!(buffer.peek_tree(), Some(TokenTree::Ident(ident)) if ident.ident() == name)
+}
+
+/// Parse the keyword `name`, returning its span.
+pub fn parse_keyword(buffer: &mut ParseBuffer, name: &str) -> Result<Span> {
+    let next = buffer.next();
+    let ident = if let Some(TokenTree::Ident(ident)) = next {
+        ident
+    } else {
+        let msg = format!("Expected `{name}`");
+        let span = next.map(|v| v.span()).unwrap_or(buffer.span());
+        return Err(Error::new(span, msg));
+    };
+
+    if ident.ident() == name {
+        Ok(ident.span())
+    } else {
+        let msg = format!("Expected `{name}`, got {}", ident.ident());
+        Err(Error::new(ident.span(), msg))
+    }
+}
+
+/// Why the next trees are not a punctuation.
+enum Mismatch {
+    /// The tree is not a punct, or there is none.
+    NotPunct,
+    Char(Char),
+    /// The punct is (not) joint to the next one.
+    Spacing,
+}
+
+/// The punctuation of Nix that is longer than one character,
+/// including the prefixes of paths.
+const LONGER_PUNCT: &[&str] = &[
+    "==", "!=", "<=", ">=", "&&", "||", "->", "//", "++", "|>", "<|", "...", "./", "~/",
+];
+
+/// Whether `first` directly followed by `second` starts one of
+/// [`LONGER_PUNCT`], such as `-` and `>` in `->`.
+fn starts_longer_punct(first: Char, second: Char) -> bool {
+    LONGER_PUNCT.iter().any(|punct| {
+        let mut chars = punct.chars();
+        chars.next() == Some(first.as_char()) && chars.next() == Some(second.as_char())
+    })
+}
+
+/// Check that the trees from the `offset`th on are the punctuation
+/// `repr`, one character per punct. On a mismatch, the index of the
+/// offending tree is returned.
+fn match_punct(
+    buffer: &ParseBuffer,
+    offset: usize,
+    repr: &str,
+) -> core::result::Result<(), (usize, Mismatch)> {
+    let len = repr.chars().count();
+
+    for (idx, expected) in (offset..).zip(repr.chars()) {
+        let Some(TokenTree::Punct(punct)) = buffer.peek_tree_n(idx) else {
+            return Err((idx, Mismatch::NotPunct));
+        };
+
+        if expected != punct.ch.as_char() {
+            return Err((idx, Mismatch::Char(punct.ch)));
+        }
+
+        let next = match buffer.peek_tree_n(idx + 1) {
+            Some(TokenTree::Punct(next)) => Some(next.ch),
+            _ => None,
+        };
+
+        // All but the last character must be joined to the next punct.
+        // The last one may only be joined to a punct it does not form a
+        // longer punctuation with, so that `a->b` is not `a - >b`, but
+        // `a*-b` and `!!a` are two operators.
+        let is_last = idx + 1 == offset + len;
+        let mismatch = match next {
+            Some(next) if is_last => {
+                punct.spacing.is_joint() && starts_longer_punct(punct.ch, next)
+            }
+            Some(_) => !punct.spacing.is_joint(),
+            None => false,
+        };
+
+        if mismatch {
+            return Err((idx, Mismatch::Spacing));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the next trees are the punctuation `repr`.
+pub fn peek_punct(buffer: &ParseBuffer, repr: &str) -> bool {
+    peek_punct_n(buffer, 0, repr)
+}
+
+/// Whether the trees from the `n`th on are the punctuation `repr`.
+pub(crate) fn peek_punct_n(buffer: &ParseBuffer, n: usize, repr: &str) -> bool {
+    match_punct(buffer, n, repr).is_ok()
+}
+
+/// Parse the punctuation `repr`, one character per punct, returning
+/// the span of all of them.
+pub fn parse_punct(buffer: &mut ParseBuffer, repr: &str) -> Result<Span> {
+    let (idx, mismatch) = match match_punct(buffer, 0, repr) {
+        Ok(()) => {
+            let first = buffer.span();
+            buffer.nth(repr.chars().count() - 1);
+            return Ok(buffer.span_from(&first));
+        }
+        Err(mismatch) => mismatch,
+    };
+
+    // Consume the trees up to the offending one, as if they had
+    // been parsed one by one.
+    let offending = buffer.nth(idx);
+    let span = offending.map(|v| v.span()).unwrap_or(buffer.span());
+
+    let msg = match mismatch {
+        Mismatch::Char(ch) => format!("Expected `{repr}`, got `{ch}`"),
+        Mismatch::NotPunct | Mismatch::Spacing => format!("Expected `{repr}`"),
+    };
+
+    Err(Error::new(span, msg))
+}
+
+/// Define a keyword token that implements [`Parse`](crate::Parse) and
+/// [`Peek`](crate::Peek) like the built-in keywords.
+///
+/// ```
+/// use synix::{ParseBuffer, Peek};
+/// use synix::buffer::TokenBuffer;
+/// use synix_lexer::TokenStream;
+///
+/// synix::custom_keyword!(Import = "import");
+///
+/// let lexed: TokenStream = "import ./a.nix".parse().unwrap();
+/// let tokens = TokenBuffer::new(lexed.as_ref());
+/// let mut input = ParseBuffer::new(&tokens);
+///
+/// assert!(Import::peek(&input));
+/// let import: Import = input.parse().unwrap();
+/// assert_eq!(import.span.end().column, 6);
+/// ```
+///
+/// The keyword is still a valid identifier, so it can be parsed as an
+/// [`Ident`](crate::Ident) as well.
+#[macro_export]
+macro_rules! custom_keyword {
+    ($(#[$attr:meta])* $ty:ident = $keyword:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Default)]
+        pub struct $ty {
+            pub span: $crate::__private::Span,
+        }
+
+        impl $ty {
+            pub const fn new() -> Self {
+                Self {
+                    span: $crate::__private::Span::default(),
+                }
+            }
+        }
+
+        impl $crate::Parse for $ty {
+            fn parse(buffer: &mut $crate::ParseBuffer) -> $crate::Result<Self> {
+                let span = $crate::__private::parse_keyword(buffer, $keyword)?;
+                Ok(Self { span })
+            }
+        }
+
+        impl $crate::Peek for $ty {
+            fn peek(buffer: &$crate::ParseBuffer) -> bool {
+                $crate::__private::peek_keyword(buffer, $keyword)
+            }
+
+            fn display() -> &'static str {
+                concat!("`", $keyword, "`")
+            }
+        }
+
+        $crate::__impl_token_traits!($ty);
+    };
+}
+
+/// Define a punctuation token of one or more characters that
+/// implements [`Parse`](crate::Parse) and [`Peek`](crate::Peek) like the
+/// built-in punctuation.
+///
+/// ```
+/// use synix::{ParseBuffer, Peek};
+/// use synix::buffer::TokenBuffer;
+/// use synix_lexer::TokenStream;
+///
+/// synix::custom_punctuation!(Spaceship = <=>);
+///
+/// let lexed: TokenStream = "a <=> b".parse().unwrap();
+/// let tokens = TokenBuffer::new(lexed.as_ref());
+/// let mut input = ParseBuffer::new(&tokens);
+///
+/// let _a: synix::Ident = input.parse().unwrap();
+/// assert!(Spaceship::peek(&input));
+///
+/// let spaceship: Spaceship = input.parse().unwrap();
+/// assert_eq!(spaceship.span.start().column, 2);
+/// assert_eq!(spaceship.span.end().column, 5);
+/// ```
+///
+/// Like the built-in punctuation, the characters must be joint. The
+/// last one may only be joint to more punctuation if the two do not
+/// start a longer punctuation of Nix, so `<=` also matches the start
+/// of `<=>`: peek for the longer custom punctuation first.
+#[macro_export]
+macro_rules! custom_punctuation {
+    ($(#[$attr:meta])* $ty:ident = $($punct:tt)+) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Default)]
+        pub struct $ty {
+            pub span: $crate::__private::Span,
+        }
+
+        impl $ty {
+            pub const fn new() -> Self {
+                Self {
+                    span: $crate::__private::Span::default(),
+                }
+            }
+        }
+
+        impl $crate::Parse for $ty {
+            fn parse(buffer: &mut $crate::ParseBuffer) -> $crate::Result<Self> {
+                let repr = concat!($(stringify!($punct)),+);
+                let span = $crate::__private::parse_punct(buffer, repr)?;
+                Ok(Self { span })
+            }
+        }
+
+        impl $crate::Peek for $ty {
+            fn peek(buffer: &$crate::ParseBuffer) -> bool {
+                $crate::__private::peek_punct(buffer, concat!($(stringify!($punct)),+))
+            }
+
+            fn display() -> &'static str {
+                concat!("`", $(stringify!($punct),)+ "`")
+            }
+        }
+
+        $crate::__impl_token_traits!($ty);
+    };
+}
+
+/// The traits of the `extra-traits` and `serde` features. These are
+/// chosen by the features of this crate, not of the crate defining
+/// the token.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_token_traits {
+    ($ty:ident) => {
+        $crate::__impl_extra_traits!($ty);
+        $crate::__impl_serde!($ty);
+    };
+}
+
+#[cfg(feature = "extra-traits")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_extra_traits {
+    ($ty:ident) => {
+        /// Tokens carry nothing but their span, so all tokens of
+        /// the same type are equal.
+        impl ::core::cmp::PartialEq for $ty {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        impl ::core::cmp::Eq for $ty {}
+
+        impl ::core::hash::Hash for $ty {
+            fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
+        }
+    };
+}
+
+#[cfg(not(feature = "extra-traits"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_extra_traits {
+    ($ty:ident) => {};
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_serde {
+    ($ty:ident) => {
+        impl $crate::__private::serde::Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                let token = $crate::__private::Token {
+                    span: self.span.clone(),
+                };
+                $crate::__private::serde::Serialize::serialize(&token, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let token: $crate::__private::Token =
+                    $crate::__private::serde::Deserialize::deserialize(deserializer)?;
+                Ok(Self { span: token.span })
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_serde {
+    ($ty:ident) => {};
+}
+
+/// How tokens are serialized: an object with only their span.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Token")]
+pub struct Token {
+    pub span: Span,
+}
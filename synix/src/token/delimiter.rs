@@ -6,9 +6,10 @@ use synix_lexer::{
 };
 
 macro_rules ! delimiter {
-    ($($name:ident),*$(,)?) => {
+    ($($name:ident = $display:literal),*$(,)?) => {
         $(
             #[derive(Debug, Default, Clone, Copy, PartialEq)]
+            #[cfg_attr(feature = "extra-traits", derive(Eq, Hash))]
             pub struct $name;
 
             impl Peek for $name {
@@ -21,9 +22,13 @@ macro_rules ! delimiter {
                         }))
                     )
                 }
+
+                fn display() -> &'static str {
+                    $display
+                }
             }
         )*
     }
 }
 
-delimiter!(Brace, Paren, Bracket);
+delimiter!(Brace = "`{`", Paren = "`(`", Bracket = "`[`");
@@ -1,3 +1,4 @@
+pub(crate) mod custom;
 mod delimiter;
 mod ident;
 mod punct;
@@ -25,6 +26,7 @@ macro_rules ! Token {
     (|) => { $crate::token::Pipe };
     (/) => { $crate::token::Slash };
     ($) => { $crate::token::Dollar };
+    (!) => { $crate::token::Exclamation };
     (...) => { $crate::token::Ellipsis };
     (>=) => { $crate::token::Ge };
     (<=) => { $crate::token::Le };
@@ -32,6 +34,9 @@ macro_rules ! Token {
     (!=) => { $crate::token::NotEquals };
     (&&) => { $crate::token::And };
     (||) => { $crate::token::Or };
+    (->) => { $crate::token::Implies };
+    (|>) => { $crate::token::PipeInto };
+    (<|) => { $crate::token::PipeFrom };
     (++) => { $crate::token::Concat };
     (./) => { $crate::token::DotSlash };
     (~/) => { $crate::token::TildeSlash };
@@ -40,6 +45,10 @@ macro_rules ! Token {
     (with) => { $crate::token::With };
     (inherit) => { $crate::token::Inherit };
     (rec) => { $crate::token::Rec };
+    (if) => { $crate::token::If };
+    (then) => { $crate::token::Then };
+    (else) => { $crate::token::Else };
+    (or) => { $crate::token::OrKeyword };
 }
 
 #[cfg(test)]
@@ -68,9 +77,13 @@ mod test {
     const EQ_EQ: Token![==] = <Token![==]>::new();
     const AND: Token![&&] = <Token![&&]>::new();
     const OR: Token![||] = <Token![||]>::new();
+    const IMPLIES: Token![->] = <Token![->]>::new();
+    const PIPE_INTO: Token![|>] = <Token![|>]>::new();
+    const PIPE_FROM: Token![<|] = <Token![<|]>::new();
     const CONCAT: Token![++] = <Token![++]>::new();
     const NOT_EQUALS: Token![!=] = <Token![!=]>::new();
     const DOLLAR: Token![$] = <Token![$]>::new();
+    const EXCLAMATION: Token![!] = <Token![!]>::new();
     const DOTSLASH: Token![./] = <Token![./]>::new();
     const TILDESLASH: Token![~/] = <Token![~/]>::new();
     const UPDATE: Update = Update::new();
@@ -0,0 +1,353 @@
+//! Traversal of the AST by shared reference.
+//!
+//! Every method of [`Visit`] defaults to the free function of the same
+//! name, which visits all children of the node. Implementations only
+//! override the methods for the nodes they are interested in, and call
+//! the free function to keep walking below them.
+//!
+//! ```
+//! # use synix::{Ident, visit::{self, Visit}};
+//! struct Idents<'ast>(Vec<&'ast str>);
+//!
+//! impl<'ast> Visit<'ast> for Idents<'ast> {
+//!     fn visit_ident(&mut self, node: &'ast Ident) {
+//!         self.0.push(node.ident());
+//!     }
+//! }
+//!
+//! let expr = synix::parse("let a = b; in a c").unwrap();
+//! let mut idents = Idents(Vec::new());
+//! idents.visit_expr(&expr);
+//!
+//! assert_eq!(idents.0, ["a", "b", "a", "c"]);
+//! ```
+
+use synix_lexer::Span;
+
+use crate::{
+    Assignment, AssignmentInherit, AssignmentNamed, Expr, ExprFunctionCall, ExprIf, ExprLegacyLet,
+    ExprLet, ExprParenthesized, ExprWith, Ident, InterpolatedIdent, LiteralOrInterpolatedIdent,
+    attrset::{AttributeAccess, ExprAttrSet, ExprHasAttr},
+    binary::{ExprBinary, ExprUnary, Operator, UnaryOperator},
+    lambda::{ArgAttrSet, ArgAttrSetValue, ExprLambda, LambdaArg},
+    list::ExprList,
+    lit::{ExprInterpolatedStr, ExprLit, InterpolatedStrPart},
+    path::{DirPath, LookupPath, Path, PathPart, PathSubPart},
+};
+
+macro_rules! visit_methods {
+    ($($method:ident($ty:ty);)*) => {
+        /// Visits the nodes of the AST by shared reference.
+        ///
+        /// See the [module documentation](self) for details.
+        pub trait Visit<'ast> {
+            $(
+                fn $method(&mut self, node: &'ast $ty) {
+                    $method(self, node);
+                }
+            )*
+        }
+    };
+}
+
+visit_methods! {
+    visit_expr(Expr);
+    visit_expr_let(ExprLet);
+    visit_expr_legacy_let(ExprLegacyLet);
+    visit_expr_lit(ExprLit);
+    visit_expr_interpolated_str(ExprInterpolatedStr);
+    visit_interpolated_str_part(InterpolatedStrPart);
+    visit_expr_lambda(ExprLambda);
+    visit_lambda_arg(LambdaArg);
+    visit_arg_attr_set(ArgAttrSet);
+    visit_arg_attr_set_value(ArgAttrSetValue);
+    visit_ident(Ident);
+    visit_expr_attr_set(ExprAttrSet);
+    visit_expr_parenthesized(ExprParenthesized);
+    visit_expr_list(ExprList);
+    visit_expr_with(ExprWith);
+    visit_expr_if(ExprIf);
+    visit_expr_function_call(ExprFunctionCall);
+    visit_expr_binary(ExprBinary);
+    visit_operator(Operator);
+    visit_expr_unary(ExprUnary);
+    visit_unary_operator(UnaryOperator);
+    visit_expr_has_attr(ExprHasAttr);
+    visit_attribute_access(AttributeAccess);
+    visit_path(Path);
+    visit_lookup_path(LookupPath);
+    visit_dir_path(DirPath);
+    visit_path_part(PathPart);
+    visit_path_sub_part(PathSubPart);
+    visit_assignment(Assignment);
+    visit_assignment_inherit(AssignmentInherit);
+    visit_assignment_named(AssignmentNamed);
+    visit_literal_or_interpolated_ident(LiteralOrInterpolatedIdent);
+    visit_interpolated_ident(InterpolatedIdent);
+    visit_span(Span);
+}
+
+pub fn visit_expr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Expr) {
+    match node {
+        Expr::Let(let_) => v.visit_expr_let(let_),
+        Expr::LegacyLet(let_) => v.visit_expr_legacy_let(let_),
+        Expr::Lit(lit) => v.visit_expr_lit(lit),
+        Expr::InterpolatedStr(str) => v.visit_expr_interpolated_str(str),
+        Expr::Lambda(lambda) => v.visit_expr_lambda(lambda),
+        Expr::Ident(ident) => v.visit_ident(ident),
+        Expr::AttrSet(set) => v.visit_expr_attr_set(set),
+        Expr::Parenthesized(paren) => v.visit_expr_parenthesized(paren),
+        Expr::List(list) => v.visit_expr_list(list),
+        Expr::With(with) => v.visit_expr_with(with),
+        Expr::If(if_) => v.visit_expr_if(if_),
+        Expr::FunctionCall(call) => v.visit_expr_function_call(call),
+        Expr::Binary(binary) => v.visit_expr_binary(binary),
+        Expr::Unary(unary) => v.visit_expr_unary(unary),
+        Expr::HasAttr(has_attr) => v.visit_expr_has_attr(has_attr),
+        Expr::AttributeAccess(access) => v.visit_attribute_access(access),
+        Expr::Path(path) => v.visit_path(path),
+        Expr::Error(span) => v.visit_span(span),
+    }
+}
+
+pub fn visit_expr_let<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprLet) {
+    for assignment in &node.assignments {
+        v.visit_assignment(assignment);
+    }
+
+    v.visit_expr(&node.body);
+}
+
+pub fn visit_expr_legacy_let<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprLegacyLet) {
+    for assignment in &node.assignments {
+        v.visit_assignment(assignment);
+    }
+}
+
+pub fn visit_expr_lit<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast ExprLit) {}
+
+pub fn visit_expr_interpolated_str<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ExprInterpolatedStr,
+) {
+    for part in &node.parts {
+        v.visit_interpolated_str_part(part);
+    }
+}
+
+pub fn visit_interpolated_str_part<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast InterpolatedStrPart,
+) {
+    match node {
+        InterpolatedStrPart::Str(_) => {}
+        InterpolatedStrPart::Interpolation(expr) => v.visit_expr(expr),
+    }
+}
+
+pub fn visit_expr_lambda<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprLambda) {
+    v.visit_lambda_arg(&node.arg);
+    v.visit_expr(&node.body);
+}
+
+pub fn visit_lambda_arg<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast LambdaArg) {
+    match node {
+        LambdaArg::Ident(ident) => v.visit_ident(ident),
+        LambdaArg::AttrSet(set) => v.visit_arg_attr_set(set),
+    }
+}
+
+pub fn visit_arg_attr_set<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ArgAttrSet) {
+    if let Some(binds_to) = &node.binds_to {
+        v.visit_ident(binds_to);
+    }
+
+    for arg in &node.args {
+        v.visit_arg_attr_set_value(arg);
+    }
+}
+
+pub fn visit_arg_attr_set_value<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ArgAttrSetValue,
+) {
+    v.visit_ident(&node.ident);
+
+    if let Some(default) = &node.default {
+        v.visit_expr(default);
+    }
+}
+
+pub fn visit_ident<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Ident) {}
+
+pub fn visit_expr_attr_set<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprAttrSet) {
+    for assignment in &node.assignments {
+        v.visit_assignment(assignment);
+    }
+}
+
+pub fn visit_expr_parenthesized<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ExprParenthesized,
+) {
+    v.visit_expr(&node.inner);
+}
+
+pub fn visit_expr_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprList) {
+    for entry in &node.entries {
+        v.visit_expr(entry);
+    }
+}
+
+pub fn visit_expr_with<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprWith) {
+    v.visit_expr(&node.expr);
+    v.visit_expr(&node.body);
+}
+
+pub fn visit_expr_if<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprIf) {
+    v.visit_expr(&node.condition);
+    v.visit_expr(&node.then_branch);
+    v.visit_expr(&node.else_branch);
+}
+
+pub fn visit_expr_function_call<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ExprFunctionCall,
+) {
+    v.visit_expr(&node.head);
+    v.visit_expr(&node.tail);
+}
+
+pub fn visit_expr_binary<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprBinary) {
+    v.visit_expr(&node.lhs);
+    v.visit_operator(&node.operator);
+    v.visit_expr(&node.rhs);
+}
+
+pub fn visit_operator<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Operator) {}
+
+pub fn visit_expr_unary<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprUnary) {
+    v.visit_unary_operator(&node.operator);
+    v.visit_expr(&node.expr);
+}
+
+pub fn visit_unary_operator<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast UnaryOperator) {
+}
+
+pub fn visit_expr_has_attr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExprHasAttr) {
+    v.visit_expr(&node.set);
+    v.visit_literal_or_interpolated_ident(&node.head);
+
+    for name in &node.tail {
+        v.visit_literal_or_interpolated_ident(name);
+    }
+}
+
+pub fn visit_attribute_access<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast AttributeAccess,
+) {
+    v.visit_expr(&node.set);
+
+    for accessor in &node.accessors {
+        v.visit_literal_or_interpolated_ident(accessor);
+    }
+
+    if let Some((_, default)) = &node.default {
+        v.visit_expr(default);
+    }
+}
+
+pub fn visit_path<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Path) {
+    match node {
+        Path::Lookup(lookup) => v.visit_lookup_path(lookup),
+        Path::Normal(dir) => v.visit_dir_path(dir),
+    }
+}
+
+pub fn visit_lookup_path<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast LookupPath) {
+    v.visit_ident(&node.head);
+
+    for part in &node.tail {
+        v.visit_ident(part);
+    }
+}
+
+pub fn visit_dir_path<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DirPath) {
+    v.visit_path_part(&node.head);
+
+    for part in &node.tail {
+        v.visit_path_part(part);
+    }
+}
+
+pub fn visit_path_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PathPart) {
+    v.visit_path_sub_part(&node.head);
+
+    for sub_part in &node.tail {
+        v.visit_path_sub_part(sub_part);
+    }
+}
+
+pub fn visit_path_sub_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PathSubPart) {
+    match node {
+        PathSubPart::Ident(ident) => v.visit_literal_or_interpolated_ident(ident),
+        PathSubPart::LitInt(_) | PathSubPart::LitFloat(_) | PathSubPart::Dot(_) => {}
+    }
+}
+
+pub fn visit_assignment<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Assignment) {
+    match node {
+        Assignment::Inherit(inherit) => v.visit_assignment_inherit(inherit),
+        Assignment::Named(named) => v.visit_assignment_named(named),
+    }
+}
+
+pub fn visit_assignment_inherit<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast AssignmentInherit,
+) {
+    if let Some(base) = &node.base {
+        v.visit_expr(base);
+    }
+
+    for name in &node.names {
+        v.visit_literal_or_interpolated_ident(name);
+    }
+}
+
+pub fn visit_assignment_named<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast AssignmentNamed,
+) {
+    v.visit_literal_or_interpolated_ident(&node.head);
+
+    for name in &node.tail {
+        v.visit_literal_or_interpolated_ident(name);
+    }
+
+    v.visit_expr(&node.value);
+}
+
+pub fn visit_literal_or_interpolated_ident<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast LiteralOrInterpolatedIdent,
+) {
+    match node {
+        LiteralOrInterpolatedIdent::Literal(ident) => v.visit_ident(ident),
+        LiteralOrInterpolatedIdent::Interpolated(interpolated) => {
+            v.visit_interpolated_ident(interpolated)
+        }
+        LiteralOrInterpolatedIdent::Str(_) => {}
+        LiteralOrInterpolatedIdent::InterpolatedStr(str) => v.visit_expr_interpolated_str(str),
+    }
+}
+
+pub fn visit_interpolated_ident<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast InterpolatedIdent,
+) {
+    v.visit_expr(&node.value);
+}
+
+pub fn visit_span<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Span) {}
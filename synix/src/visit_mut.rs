@@ -0,0 +1,335 @@
+//! Traversal of the AST by mutable reference.
+//!
+//! Like [`Visit`](crate::visit::Visit), but able to change nodes in
+//! place. Every method defaults to the free function of the same name,
+//! which visits all children of the node.
+//!
+//! ```
+//! # use synix::{binary::{ExprBinary, Operator}, visit_mut::{self, VisitMut}};
+//! /// Write comparisons as `a < b` instead of `b > a`.
+//! struct FlipGreater;
+//!
+//! impl VisitMut for FlipGreater {
+//!     fn visit_expr_binary_mut(&mut self, node: &mut ExprBinary) {
+//!         if node.operator == Operator::Gt {
+//!             std::mem::swap(&mut node.lhs, &mut node.rhs);
+//!             node.operator = Operator::Lt;
+//!         }
+//!
+//!         visit_mut::visit_expr_binary_mut(self, node);
+//!     }
+//! }
+//!
+//! let mut expr = synix::parse("f (b > a)").unwrap();
+//! FlipGreater.visit_expr_mut(&mut expr);
+//!
+//! assert_eq!(synix::print(&expr), "f (a < b)");
+//! ```
+
+use synix_lexer::Span;
+
+use crate::{
+    Assignment, AssignmentInherit, AssignmentNamed, Expr, ExprFunctionCall, ExprIf, ExprLegacyLet,
+    ExprLet, ExprParenthesized, ExprWith, Ident, InterpolatedIdent, LiteralOrInterpolatedIdent,
+    attrset::{AttributeAccess, ExprAttrSet, ExprHasAttr},
+    binary::{ExprBinary, ExprUnary, Operator, UnaryOperator},
+    lambda::{ArgAttrSet, ArgAttrSetValue, ExprLambda, LambdaArg},
+    list::ExprList,
+    lit::{ExprInterpolatedStr, ExprLit, InterpolatedStrPart},
+    path::{DirPath, LookupPath, Path, PathPart, PathSubPart},
+};
+
+macro_rules! visit_mut_methods {
+    ($($method:ident($ty:ty);)*) => {
+        /// Visits the nodes of the AST by mutable reference.
+        ///
+        /// See the [module documentation](self) for details.
+        pub trait VisitMut {
+            $(
+                fn $method(&mut self, node: &mut $ty) {
+                    $method(self, node);
+                }
+            )*
+        }
+    };
+}
+
+visit_mut_methods! {
+    visit_expr_mut(Expr);
+    visit_expr_let_mut(ExprLet);
+    visit_expr_legacy_let_mut(ExprLegacyLet);
+    visit_expr_lit_mut(ExprLit);
+    visit_expr_interpolated_str_mut(ExprInterpolatedStr);
+    visit_interpolated_str_part_mut(InterpolatedStrPart);
+    visit_expr_lambda_mut(ExprLambda);
+    visit_lambda_arg_mut(LambdaArg);
+    visit_arg_attr_set_mut(ArgAttrSet);
+    visit_arg_attr_set_value_mut(ArgAttrSetValue);
+    visit_ident_mut(Ident);
+    visit_expr_attr_set_mut(ExprAttrSet);
+    visit_expr_parenthesized_mut(ExprParenthesized);
+    visit_expr_list_mut(ExprList);
+    visit_expr_with_mut(ExprWith);
+    visit_expr_if_mut(ExprIf);
+    visit_expr_function_call_mut(ExprFunctionCall);
+    visit_expr_binary_mut(ExprBinary);
+    visit_operator_mut(Operator);
+    visit_expr_unary_mut(ExprUnary);
+    visit_unary_operator_mut(UnaryOperator);
+    visit_expr_has_attr_mut(ExprHasAttr);
+    visit_attribute_access_mut(AttributeAccess);
+    visit_path_mut(Path);
+    visit_lookup_path_mut(LookupPath);
+    visit_dir_path_mut(DirPath);
+    visit_path_part_mut(PathPart);
+    visit_path_sub_part_mut(PathSubPart);
+    visit_assignment_mut(Assignment);
+    visit_assignment_inherit_mut(AssignmentInherit);
+    visit_assignment_named_mut(AssignmentNamed);
+    visit_literal_or_interpolated_ident_mut(LiteralOrInterpolatedIdent);
+    visit_interpolated_ident_mut(InterpolatedIdent);
+    visit_span_mut(Span);
+}
+
+pub fn visit_expr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Expr) {
+    match node {
+        Expr::Let(let_) => v.visit_expr_let_mut(let_),
+        Expr::LegacyLet(let_) => v.visit_expr_legacy_let_mut(let_),
+        Expr::Lit(lit) => v.visit_expr_lit_mut(lit),
+        Expr::InterpolatedStr(str) => v.visit_expr_interpolated_str_mut(str),
+        Expr::Lambda(lambda) => v.visit_expr_lambda_mut(lambda),
+        Expr::Ident(ident) => v.visit_ident_mut(ident),
+        Expr::AttrSet(set) => v.visit_expr_attr_set_mut(set),
+        Expr::Parenthesized(paren) => v.visit_expr_parenthesized_mut(paren),
+        Expr::List(list) => v.visit_expr_list_mut(list),
+        Expr::With(with) => v.visit_expr_with_mut(with),
+        Expr::If(if_) => v.visit_expr_if_mut(if_),
+        Expr::FunctionCall(call) => v.visit_expr_function_call_mut(call),
+        Expr::Binary(binary) => v.visit_expr_binary_mut(binary),
+        Expr::Unary(unary) => v.visit_expr_unary_mut(unary),
+        Expr::HasAttr(has_attr) => v.visit_expr_has_attr_mut(has_attr),
+        Expr::AttributeAccess(access) => v.visit_attribute_access_mut(access),
+        Expr::Path(path) => v.visit_path_mut(path),
+        Expr::Error(span) => v.visit_span_mut(span),
+    }
+}
+
+pub fn visit_expr_let_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprLet) {
+    for assignment in &mut node.assignments {
+        v.visit_assignment_mut(assignment);
+    }
+
+    v.visit_expr_mut(&mut node.body);
+}
+
+pub fn visit_expr_legacy_let_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprLegacyLet) {
+    for assignment in &mut node.assignments {
+        v.visit_assignment_mut(assignment);
+    }
+}
+
+pub fn visit_expr_lit_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut ExprLit) {}
+
+pub fn visit_expr_interpolated_str_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ExprInterpolatedStr,
+) {
+    for part in &mut node.parts {
+        v.visit_interpolated_str_part_mut(part);
+    }
+}
+
+pub fn visit_interpolated_str_part_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut InterpolatedStrPart,
+) {
+    match node {
+        InterpolatedStrPart::Str(_) => {}
+        InterpolatedStrPart::Interpolation(expr) => v.visit_expr_mut(expr),
+    }
+}
+
+pub fn visit_expr_lambda_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprLambda) {
+    v.visit_lambda_arg_mut(&mut node.arg);
+    v.visit_expr_mut(&mut node.body);
+}
+
+pub fn visit_lambda_arg_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut LambdaArg) {
+    match node {
+        LambdaArg::Ident(ident) => v.visit_ident_mut(ident),
+        LambdaArg::AttrSet(set) => v.visit_arg_attr_set_mut(set),
+    }
+}
+
+pub fn visit_arg_attr_set_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ArgAttrSet) {
+    if let Some(binds_to) = &mut node.binds_to {
+        v.visit_ident_mut(binds_to);
+    }
+
+    for arg in &mut node.args {
+        v.visit_arg_attr_set_value_mut(arg);
+    }
+}
+
+pub fn visit_arg_attr_set_value_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ArgAttrSetValue) {
+    v.visit_ident_mut(&mut node.ident);
+
+    if let Some(default) = &mut node.default {
+        v.visit_expr_mut(default);
+    }
+}
+
+pub fn visit_ident_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Ident) {}
+
+pub fn visit_expr_attr_set_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprAttrSet) {
+    for assignment in &mut node.assignments {
+        v.visit_assignment_mut(assignment);
+    }
+}
+
+pub fn visit_expr_parenthesized_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprParenthesized) {
+    v.visit_expr_mut(&mut node.inner);
+}
+
+pub fn visit_expr_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprList) {
+    for entry in &mut node.entries {
+        v.visit_expr_mut(entry);
+    }
+}
+
+pub fn visit_expr_with_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprWith) {
+    v.visit_expr_mut(&mut node.expr);
+    v.visit_expr_mut(&mut node.body);
+}
+
+pub fn visit_expr_if_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprIf) {
+    v.visit_expr_mut(&mut node.condition);
+    v.visit_expr_mut(&mut node.then_branch);
+    v.visit_expr_mut(&mut node.else_branch);
+}
+
+pub fn visit_expr_function_call_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprFunctionCall) {
+    v.visit_expr_mut(&mut node.head);
+    v.visit_expr_mut(&mut node.tail);
+}
+
+pub fn visit_expr_binary_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprBinary) {
+    v.visit_expr_mut(&mut node.lhs);
+    v.visit_operator_mut(&mut node.operator);
+    v.visit_expr_mut(&mut node.rhs);
+}
+
+pub fn visit_operator_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Operator) {}
+
+pub fn visit_expr_unary_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprUnary) {
+    v.visit_unary_operator_mut(&mut node.operator);
+    v.visit_expr_mut(&mut node.expr);
+}
+
+pub fn visit_unary_operator_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut UnaryOperator) {}
+
+pub fn visit_expr_has_attr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprHasAttr) {
+    v.visit_expr_mut(&mut node.set);
+    v.visit_literal_or_interpolated_ident_mut(&mut node.head);
+
+    for name in &mut node.tail {
+        v.visit_literal_or_interpolated_ident_mut(name);
+    }
+}
+
+pub fn visit_attribute_access_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AttributeAccess) {
+    v.visit_expr_mut(&mut node.set);
+
+    for accessor in &mut node.accessors {
+        v.visit_literal_or_interpolated_ident_mut(accessor);
+    }
+
+    if let Some((_, default)) = &mut node.default {
+        v.visit_expr_mut(default);
+    }
+}
+
+pub fn visit_path_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Path) {
+    match node {
+        Path::Lookup(lookup) => v.visit_lookup_path_mut(lookup),
+        Path::Normal(dir) => v.visit_dir_path_mut(dir),
+    }
+}
+
+pub fn visit_lookup_path_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut LookupPath) {
+    v.visit_ident_mut(&mut node.head);
+
+    for part in &mut node.tail {
+        v.visit_ident_mut(part);
+    }
+}
+
+pub fn visit_dir_path_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DirPath) {
+    v.visit_path_part_mut(&mut node.head);
+
+    for part in &mut node.tail {
+        v.visit_path_part_mut(part);
+    }
+}
+
+pub fn visit_path_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PathPart) {
+    v.visit_path_sub_part_mut(&mut node.head);
+
+    for sub_part in &mut node.tail {
+        v.visit_path_sub_part_mut(sub_part);
+    }
+}
+
+pub fn visit_path_sub_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PathSubPart) {
+    match node {
+        PathSubPart::Ident(ident) => v.visit_literal_or_interpolated_ident_mut(ident),
+        PathSubPart::LitInt(_) | PathSubPart::LitFloat(_) | PathSubPart::Dot(_) => {}
+    }
+}
+
+pub fn visit_assignment_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Assignment) {
+    match node {
+        Assignment::Inherit(inherit) => v.visit_assignment_inherit_mut(inherit),
+        Assignment::Named(named) => v.visit_assignment_named_mut(named),
+    }
+}
+
+pub fn visit_assignment_inherit_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AssignmentInherit) {
+    if let Some(base) = &mut node.base {
+        v.visit_expr_mut(base);
+    }
+
+    for name in &mut node.names {
+        v.visit_literal_or_interpolated_ident_mut(name);
+    }
+}
+
+pub fn visit_assignment_named_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AssignmentNamed) {
+    v.visit_literal_or_interpolated_ident_mut(&mut node.head);
+
+    for name in &mut node.tail {
+        v.visit_literal_or_interpolated_ident_mut(name);
+    }
+
+    v.visit_expr_mut(&mut node.value);
+}
+
+pub fn visit_literal_or_interpolated_ident_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut LiteralOrInterpolatedIdent,
+) {
+    match node {
+        LiteralOrInterpolatedIdent::Literal(ident) => v.visit_ident_mut(ident),
+        LiteralOrInterpolatedIdent::Interpolated(interpolated) => {
+            v.visit_interpolated_ident_mut(interpolated)
+        }
+        LiteralOrInterpolatedIdent::Str(_) => {}
+        LiteralOrInterpolatedIdent::InterpolatedStr(str) => v.visit_expr_interpolated_str_mut(str),
+    }
+}
+
+pub fn visit_interpolated_ident_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut InterpolatedIdent) {
+    v.visit_expr_mut(&mut node.value);
+}
+
+pub fn visit_span_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Span) {}
@@ -3,20 +3,19 @@ use synix_lexer::Span;
 use crate::*;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "extra-traits", derive(Clone))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprWith {
     pub with: Token![with],
     pub expr: Expr,
     pub semicolon: Token![;],
     pub body: Expr,
+    span: Span,
 }
 
 impl ExprWith {
     pub fn span(&self) -> Span {
-        self.with
-            .span
-            .join(&self.expr.span())
-            .join(&self.semicolon.span)
-            .join(&self.body.span())
+        self.span.clone()
     }
 }
 
@@ -24,23 +23,28 @@ impl Peek for ExprWith {
     fn peek(input: &ParseBuffer) -> bool {
         <Token![with]>::peek(input)
     }
+
+    fn display() -> &'static str {
+        "`with`"
+    }
 }
 
 impl Parse for ExprWith {
     fn parse(buffer: &mut ParseBuffer) -> Result<Self> {
-        let with = buffer.parse()?;
+        let with: Token![with] = buffer.parse()?;
 
-        // TODO: probably delimit by ; and paren or something
-        let expr = buffer.parse()?;
+        let expr = buffer.parse_expr_or_recover(<Token![;]>::peek)?;
 
         let semicolon = buffer.parse()?;
-        let body = buffer.parse()?;
+        let body: Expr = buffer.parse()?;
+        let span = with.span.join(&body.span());
 
         Ok(Self {
             with,
             expr,
             semicolon,
             body,
+            span,
         })
     }
 }
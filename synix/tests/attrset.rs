@@ -0,0 +1,90 @@
+mod common;
+use common::parse_or_pretty_err;
+use synix::{Assignment, AssignmentInherit, AssignmentNamed, Expr, LiteralOrInterpolatedIdent};
+
+fn assignments(nix: &str) -> Vec<Assignment> {
+    let parsed = match parse_or_pretty_err(nix) {
+        Ok(v) => v,
+        Err(e) => panic!("{e}"),
+    };
+
+    let Expr::AttrSet(set) = parsed else {
+        panic!("Not an attribute set");
+    };
+
+    set.assignments
+}
+
+fn named(assignment: &Assignment) -> &AssignmentNamed {
+    let Assignment::Named(named) = assignment else {
+        panic!("Not a named assignment");
+    };
+
+    named
+}
+
+fn inherit(assignment: &Assignment) -> &AssignmentInherit {
+    let Assignment::Inherit(inherit) = assignment else {
+        panic!("Not an inherit");
+    };
+
+    inherit
+}
+
+#[test]
+pub fn quoted_name() {
+    let assignments = assignments(r#"{ "foo-bar" = 1; }"#);
+
+    let named = named(&assignments[0]);
+    assert!(matches!(named.head, LiteralOrInterpolatedIdent::Str(_)));
+    assert_eq!(named.head.static_name(), Some("foo-bar"));
+}
+
+#[test]
+pub fn mixed_path() {
+    let assignments = assignments(r#"{ a."b c".${d}."e${f}" = 1; }"#);
+
+    let named = named(&assignments[0]);
+    assert_eq!(named.head.static_name(), Some("a"));
+    assert_eq!(named.tail[0].static_name(), Some("b c"));
+
+    assert!(matches!(
+        named.tail[1],
+        LiteralOrInterpolatedIdent::Interpolated(_)
+    ));
+    assert_eq!(named.tail[1].static_name(), None);
+
+    assert!(matches!(
+        named.tail[2],
+        LiteralOrInterpolatedIdent::InterpolatedStr(_)
+    ));
+    assert_eq!(named.tail[2].static_name(), None);
+}
+
+#[test]
+pub fn inherit_quoted() {
+    let assignments = assignments(r#"{ inherit (x) "a" b; }"#);
+
+    let inherit = inherit(&assignments[0]);
+    assert!(inherit.base.is_some());
+
+    let names: Vec<_> = inherit.names.iter().map(|n| n.static_name()).collect();
+    assert_eq!(names, [Some("a"), Some("b")]);
+}
+
+#[test]
+pub fn inherit_interpolated() {
+    assert!(synix::parse(r#"{ inherit "${a}"; }"#).is_err());
+    assert!(synix::parse("{ inherit ${a}; }").is_err());
+}
+
+#[test]
+pub fn select_quoted() {
+    let parsed = parse_or_pretty_err(r#"x."a b""#).unwrap();
+
+    let Expr::AttributeAccess(access) = parsed else {
+        panic!("Not an attribute access");
+    };
+
+    assert_eq!(access.accessors[0].static_name(), Some("a b"));
+}
@@ -0,0 +1,197 @@
+mod common;
+use common::parse_or_pretty_err;
+use synix::{
+    Expr, ParseOptions,
+    binary::{ExprBinary, Operator},
+};
+
+macro_rules! binary {
+    ($input:literal) => {{
+        let nix = $input;
+
+        let parsed = match parse_or_pretty_err(nix) {
+            Ok(v) => v,
+            Err(e) => panic!("{e}"),
+        };
+
+        let Expr::Binary(binary) = parsed else {
+            panic!("Not a binary expression");
+        };
+
+        *binary
+    }};
+}
+
+fn ident(expr: &Expr) -> &str {
+    let Expr::Ident(ident) = expr else {
+        panic!("Not an ident: {expr:?}");
+    };
+
+    ident.ident()
+}
+
+#[test]
+pub fn implies() {
+    let ExprBinary {
+        lhs, operator, rhs, ..
+    } = binary!("a -> b");
+
+    assert_eq!(operator, Operator::Implies);
+    assert_eq!(ident(&lhs), "a");
+    assert_eq!(ident(&rhs), "b");
+}
+
+#[test]
+pub fn implies_right_associative() {
+    let ExprBinary {
+        lhs, operator, rhs, ..
+    } = binary!("a -> b -> c");
+
+    assert_eq!(operator, Operator::Implies);
+    assert_eq!(ident(&lhs), "a");
+
+    let Expr::Binary(rhs) = rhs else {
+        panic!("Rhs is not a binary expression");
+    };
+
+    assert_eq!(rhs.operator, Operator::Implies);
+    assert_eq!(ident(&rhs.lhs), "b");
+    assert_eq!(ident(&rhs.rhs), "c");
+}
+
+#[test]
+pub fn implies_lowest_precedence() {
+    let ExprBinary {
+        lhs, operator, rhs, ..
+    } = binary!("a && b -> c");
+
+    assert_eq!(operator, Operator::Implies);
+    assert_eq!(ident(&rhs), "c");
+
+    let Expr::Binary(lhs) = lhs else {
+        panic!("Lhs is not a binary expression");
+    };
+
+    assert_eq!(lhs.operator, Operator::And);
+    assert_eq!(ident(&lhs.lhs), "a");
+    assert_eq!(ident(&lhs.rhs), "b");
+}
+
+#[test]
+pub fn comparison_non_associative() {
+    assert!(synix::parse("a == b == c").is_err());
+    assert!(synix::parse("a < b < c").is_err());
+    assert!(synix::parse("a ? b ? c").is_err());
+}
+
+#[test]
+pub fn division_is_not_path() {
+    let ExprBinary {
+        lhs, operator, rhs, ..
+    } = binary!("a / b");
+
+    assert_eq!(operator, Operator::Divide);
+    assert_eq!(ident(&lhs), "a");
+    assert_eq!(ident(&rhs), "b");
+
+    assert!(matches!(synix::parse("a/b"), Ok(Expr::Path(_))));
+}
+
+#[test]
+pub fn update_is_not_path() {
+    for input in ["a//b", "x//y.z", "/a//b", "./a//b"] {
+        let parsed = parse_or_pretty_err(input).unwrap_or_else(|e| panic!("{e}"));
+        let Expr::Binary(binary) = parsed else {
+            panic!("Not a binary expression: {input}");
+        };
+
+        assert_eq!(binary.operator, Operator::Update, "{input}");
+    }
+
+    let ExprBinary { lhs, rhs, .. } = binary!("/a//b");
+    assert!(matches!(lhs, Expr::Path(_)));
+    assert_eq!(ident(&rhs), "b");
+}
+
+#[test]
+pub fn operator_before_prefix_operator() {
+    // A punct joined to the next one is only rejected when the two form
+    // a longer operator, so these are all two separate operators.
+    let cases = [
+        ("a*-b", "a * -b"),
+        ("a==-1", "a == -1"),
+        ("a&&!b", "a && !b"),
+        ("a ->!b", "a -> !b"),
+        ("a++-b", "a ++ -b"),
+        ("!!a", "! !a"),
+        ("--1", "- -1"),
+        ("!-a", "! -a"),
+        ("{a=-1;}", "{ a = -1; }"),
+    ];
+
+    for (input, expected) in cases {
+        let expr = match parse_or_pretty_err(input) {
+            Ok(v) => v,
+            Err(e) => panic!("{e}"),
+        };
+
+        assert_eq!(synix::print(&expr).trim_end(), expected, "{input}");
+    }
+
+    assert!(matches!(binary!("a ->b").operator, Operator::Implies));
+    assert!(matches!(binary!("a>=b").operator, Operator::Ge));
+    assert!(matches!(binary!("a!=-1").operator, Operator::NotEquals));
+}
+
+fn parse_pipes(nix: &str) -> synix::Result<Expr> {
+    synix::parse_with_options(nix, ParseOptions::new().pipe_operators(true))
+}
+
+#[test]
+pub fn pipe_requires_option() {
+    assert!(synix::parse("a |> f").is_err());
+    assert!(synix::parse("f <| a").is_err());
+}
+
+#[test]
+pub fn pipe_into_left_associative() {
+    let Ok(Expr::Binary(binary)) = parse_pipes("a |> f x |> g") else {
+        panic!("Not a binary expression");
+    };
+
+    assert_eq!(binary.operator, Operator::PipeInto);
+    assert_eq!(ident(&binary.rhs), "g");
+
+    let Expr::Binary(lhs) = &binary.lhs else {
+        panic!("Lhs is not a binary expression");
+    };
+
+    assert_eq!(lhs.operator, Operator::PipeInto);
+    assert_eq!(ident(&lhs.lhs), "a");
+    assert!(matches!(lhs.rhs, Expr::FunctionCall(_)));
+}
+
+#[test]
+pub fn pipe_from_right_associative() {
+    let Ok(Expr::Binary(binary)) = parse_pipes("f <| g <| a -> b") else {
+        panic!("Not a binary expression");
+    };
+
+    assert_eq!(binary.operator, Operator::PipeFrom);
+    assert_eq!(ident(&binary.lhs), "f");
+
+    let Expr::Binary(rhs) = &binary.rhs else {
+        panic!("Rhs is not a binary expression");
+    };
+
+    assert_eq!(rhs.operator, Operator::PipeFrom);
+    assert_eq!(ident(&rhs.lhs), "g");
+    assert!(matches!(&rhs.rhs, Expr::Binary(b) if b.operator == Operator::Implies));
+}
+
+#[test]
+pub fn pipe_no_mixing() {
+    assert!(parse_pipes("a |> f <| b").is_err());
+    assert!(parse_pipes("f <| a |> g").is_err());
+    assert!(parse_pipes("(a |> f) <| b").is_ok());
+}
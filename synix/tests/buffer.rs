@@ -0,0 +1,66 @@
+use synix::{ParseBuffer, buffer::TokenBuffer};
+use synix_lexer::{TokenStream, TokenTree, group::Delimiter};
+
+fn error_span(input: &str) -> (usize, usize) {
+    let start = synix::parse(input).unwrap_err().span().start();
+    (start.line, start.column)
+}
+
+#[test]
+pub fn cursor() {
+    let lexed: TokenStream = "[ (a b) c ] d".parse().unwrap();
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let begin = tokens.begin();
+
+    let (inside, span, after) = begin.group(Delimiter::Bracket).unwrap();
+    assert_eq!(span.end().column, 11);
+    assert!(begin.group(Delimiter::Brace).is_none());
+
+    // Skipping a group skips everything inside it.
+    let (paren, rest) = inside.tree().unwrap();
+    assert!(matches!(paren, TokenTree::Group(_)));
+    let (c, rest) = rest.tree().unwrap();
+    assert!(matches!(c, TokenTree::Ident(c) if c.ident() == "c"));
+
+    // The end of a group is at its closing delimiter.
+    assert!(rest.eof());
+    assert!(rest.tree().is_none());
+    assert_eq!(rest.span().unwrap().start().column, 10);
+
+    assert_eq!(begin.skip().unwrap().span().unwrap().start().column, 12);
+    let end = after.skip().unwrap();
+    assert!(end.eof());
+    assert!(end.span().is_none());
+}
+
+#[test]
+pub fn parse_buffer() {
+    let lexed: TokenStream = "{ a } b".parse().unwrap();
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let mut input = ParseBuffer::new(&tokens);
+
+    let cursor = input.cursor();
+    assert!(input.group(Delimiter::Paren).is_none());
+
+    let mut inside = input.group(Delimiter::Brace).unwrap();
+    assert_eq!(inside.span().start().column, 2);
+    let _: synix::Ident = inside.parse().unwrap();
+    assert!(inside.is_empty());
+    assert_eq!(inside.span().start().column, 4);
+
+    let _: synix::Ident = input.parse().unwrap();
+    assert!(input.is_empty());
+
+    // Cursors are copies, the position they were taken at is kept.
+    assert!(cursor.group(Delimiter::Brace).is_some());
+}
+
+#[test]
+pub fn end_of_group() {
+    // Errors at the end of a group point at its closing delimiter,
+    // not past the last tree inside it.
+    assert_eq!(error_span("{ a = 1 }"), (0, 8));
+    assert_eq!(error_span("{\n  a = 1\n}"), (2, 0));
+    assert_eq!(error_span("[ (1 +) ]"), (0, 6));
+    assert_eq!(error_span("let a = 1; in (a.)"), (0, 17));
+}
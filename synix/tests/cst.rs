@@ -0,0 +1,181 @@
+use std::fs;
+
+use synix::ParseOptions;
+
+use synix::cst::{
+    self, AstNode, ExprAttrSet, ExprLet, GreenNode, GreenToken, SyntaxKind, SyntaxNode,
+};
+
+fn parse(nix: &str) -> SyntaxNode {
+    match cst::parse(nix) {
+        Ok(v) => v,
+        Err(e) => panic!("{}", e.message()),
+    }
+}
+
+/// Assert that the tree prints back to `nix`, and that every
+/// node's range matches its text.
+fn assert_lossless(nix: &str) {
+    let root = parse(nix);
+    assert_eq!(root.to_string(), nix);
+
+    for node in root.descendants() {
+        assert_eq!(&nix[node.text_range()], node.text(), "{node:?}");
+    }
+}
+
+#[test]
+pub fn lossless() {
+    assert_lossless(
+        "  # leading comment\nlet\n  a = 1; # trailing\n  b   =   [ 1 2 ];\nin\n  a + b\n",
+    );
+    assert_lossless("{ a, b ? { c = 1; }, ... }@args: with args; if a then b else c");
+    assert_lossless("f (g x) \"str ${ y }\" ./path/${z}.nix <nixpkgs> a.b or c");
+    assert_lossless("{ inherit (x) a \"b\"; ${c}.\"d\" = !e -f ? g; }");
+    assert_lossless("a\n");
+}
+
+#[test]
+pub fn lossless_corpus() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/precedence");
+
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.extension().is_some_and(|ext| ext == "nix") {
+            assert_lossless(&fs::read_to_string(path).unwrap());
+        }
+    }
+}
+
+#[test]
+pub fn structure() {
+    let root = parse("let a = 1; b = a; in a");
+    assert_eq!(root.kind(), SyntaxKind::Root);
+
+    let let_ = ExprLet::cast(root.children().next().unwrap()).unwrap();
+    assert_eq!(let_.assignments().count(), 2);
+    assert_eq!(let_.body().unwrap().kind(), SyntaxKind::ExprIdent);
+
+    assert!(ExprAttrSet::cast(let_.syntax().clone()).is_none());
+}
+
+#[test]
+pub fn typed_view_ast() {
+    let root = parse("{ a = 1; }");
+    let set = ExprAttrSet::cast(root.children().next().unwrap()).unwrap();
+
+    assert_eq!(set.ast_relative().unwrap().assignments.len(), 1);
+}
+
+#[test]
+pub fn typed_view_expr_types() {
+    let root = parse("[\n  (f x)\n  (a + b)\n  (s ? a)\n  s.a\n]");
+    let descendants = root.descendants();
+
+    let call = descendants
+        .iter()
+        .find_map(|node| cst::ExprFunctionCall::cast(node.clone()))
+        .unwrap();
+    assert!(matches!(
+        call.ast_relative().unwrap().head,
+        synix::Expr::Ident(_)
+    ));
+
+    let binary = descendants
+        .iter()
+        .find_map(|node| cst::ExprBinary::cast(node.clone()))
+        .unwrap();
+    let binary = binary.ast_relative().unwrap();
+    assert_eq!(binary.operator.as_str(), "+");
+
+    // The node starts on the third line, but spans are relative to it.
+    let start = binary.span().start();
+    assert_eq!((start.line, start.column), (0, 0));
+
+    let has_attr = descendants
+        .iter()
+        .find_map(|node| cst::ExprHasAttr::cast(node.clone()))
+        .unwrap();
+    assert!(has_attr.ast_relative().is_ok());
+
+    let access = descendants
+        .iter()
+        .find_map(|node| cst::AttributeAccess::cast(node.clone()))
+        .unwrap();
+    assert_eq!(access.ast_relative().unwrap().accessors.len(), 1);
+
+    // A node whose text is another kind of expression is an error.
+    let green = GreenNode::new(
+        SyntaxKind::ExprBinary,
+        vec![GreenToken::new(SyntaxKind::Ident, "a").into()],
+    );
+    let binary = cst::ExprBinary::cast(SyntaxNode::new_root(green)).unwrap();
+    assert_eq!(
+        binary.ast_relative().unwrap_err().message(),
+        "Expected a binary expression."
+    );
+}
+
+#[test]
+pub fn typed_view_options() {
+    let options = ParseOptions::new().url_literals(true).pipe_operators(true);
+    let root = cst::parse_with_options("{ a = https://nixos.org |> f; }", options).unwrap();
+    assert_eq!(root.options(), options);
+
+    let binary = root
+        .descendants()
+        .into_iter()
+        .find_map(cst::ExprBinary::cast)
+        .unwrap();
+    assert!(binary.ast_relative().is_ok());
+
+    // The whole text of the node must be parsed.
+    let green = GreenNode::new(
+        SyntaxKind::ExprIdent,
+        vec![
+            GreenToken::new(SyntaxKind::Ident, "a").into(),
+            GreenToken::new(SyntaxKind::Whitespace, " ").into(),
+            GreenToken::new(SyntaxKind::Ident, "b").into(),
+        ],
+    );
+    let ident = cst::ExprIdent::cast(SyntaxNode::new_root(green)).unwrap();
+    assert!(ident.ast_relative().is_err());
+}
+
+#[test]
+pub fn edit_preserves_formatting() {
+    let nix = "{\n  # the answer\n  a   = 1;\n  b = [ 1   2 ]; # list\n}\n";
+    let root = parse(nix);
+
+    let set = ExprAttrSet::cast(root.children().next().unwrap()).unwrap();
+    let assignment = set.assignments().next().unwrap();
+    let value = cst::AssignmentNamed::cast(assignment)
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let replacement = GreenNode::new(
+        SyntaxKind::ExprLit,
+        vec![GreenToken::new(SyntaxKind::Int, "42").into()],
+    );
+
+    let edited = SyntaxNode::new_root(value.replace_with(replacement));
+
+    assert_eq!(
+        edited.to_string(),
+        "{\n  # the answer\n  a   = 42;\n  b = [ 1   2 ]; # list\n}\n"
+    );
+}
+
+#[test]
+pub fn edit_token() {
+    let root = parse("a   +   b");
+
+    let rhs = root.descendants().into_iter().last().unwrap();
+    let token = rhs.tokens().next().unwrap();
+    assert_eq!(token.text(), "b");
+
+    let edited = token.replace_with(GreenToken::new(SyntaxKind::Ident, "c"));
+    assert_eq!(edited.to_string(), "a   +   c");
+}
@@ -0,0 +1,87 @@
+use synix::{Expr, ParseBuffer, Peek, Result, buffer::TokenBuffer};
+use synix_lexer::TokenStream;
+
+synix::custom_keyword!(Import = "import");
+synix::custom_punctuation!(Spaceship = <=>);
+synix::custom_punctuation!(
+    /// `=>`
+    FatArrow = =>
+);
+
+/// `import <expr>` or `<ident> <=> <ident>`.
+fn parse_statement(input: &mut ParseBuffer) -> Result<Expr> {
+    let lookahead = input.lookahead1();
+
+    if lookahead.peek::<Import>() {
+        let _import: Import = input.parse()?;
+        input.parse()
+    } else if lookahead.peek::<synix::Ident>() {
+        let lhs: synix::Ident = input.parse()?;
+        let _op: Spaceship = input.parse()?;
+        let _rhs: synix::Ident = input.parse()?;
+        Ok(Expr::Ident(lhs))
+    } else {
+        Err(lookahead.error())
+    }
+}
+
+fn statement(input: &str) -> Result<Expr> {
+    let lexed: TokenStream = input.parse().unwrap();
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    parse_statement(&mut ParseBuffer::new(&tokens))
+}
+
+fn token<T: synix::Parse>(input: &str) -> T {
+    let lexed: TokenStream = input.parse().unwrap();
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    ParseBuffer::new(&tokens).parse().unwrap()
+}
+
+#[test]
+pub fn parse() {
+    assert!(matches!(statement("import ./a.nix"), Ok(Expr::Path(_))));
+    assert!(matches!(statement("a <=> b"), Ok(Expr::Ident(_))));
+
+    let error = statement("a <= b").unwrap_err();
+    assert_eq!(error.message(), "Expected `<=>`");
+
+    let error = statement("a <=< b").unwrap_err();
+    assert_eq!(error.message(), "Expected `<=>`, got `<`");
+}
+
+#[test]
+pub fn peek() {
+    let lexed: TokenStream = "=> =".parse().unwrap();
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let mut input = ParseBuffer::new(&tokens);
+
+    assert!(FatArrow::peek(&input));
+    // Nix has no `=>`, so a `=` joined to `>` is still a `=`.
+    assert!(<synix::Token![=]>::peek(&input));
+
+    let arrow: FatArrow = input.parse().unwrap();
+    assert_eq!(arrow.span.end().column, 2);
+    assert!(!FatArrow::peek(&input));
+}
+
+#[test]
+pub fn lookahead() {
+    let error = statement("1").unwrap_err();
+    assert_eq!(
+        error.message(),
+        "Expected one of `import`, identifier, found `1`"
+    );
+}
+
+#[test]
+pub fn traits() {
+    let a: Spaceship = token("<=>");
+    let b = Spaceship::new();
+    assert_eq!(a, b);
+
+    let json = serde_json::to_value(&a).unwrap();
+    assert_eq!(json["span"]["end"]["column"], 3);
+
+    let back: Spaceship = serde_json::from_value(json).unwrap();
+    assert_eq!(back.span.end(), a.span.end());
+}
@@ -0,0 +1,64 @@
+use synix::{Error, ParseOptions, parse_with_diagnostics};
+use synix_lexer::{LineColumn, Span};
+
+fn span(column: usize) -> Span {
+    Span::new(
+        LineColumn { line: 0, column },
+        LineColumn {
+            line: 0,
+            column: column + 1,
+        },
+    )
+}
+
+#[test]
+pub fn combine() {
+    let mut error = Error::new(span(0), "first").with_note("a note");
+    error.combine(Error::new(span(2), "second").with_help("some help"));
+    let error = error.with_label(span(4), "a label");
+
+    assert_eq!(error.message(), "first");
+    assert_eq!(error.span().start().column, 0);
+
+    let diagnostics: Vec<_> = error.iter().collect();
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].notes(), ["a note"]);
+    assert!(diagnostics[0].help().is_empty());
+    assert!(diagnostics[0].labels().is_empty());
+
+    assert_eq!(diagnostics[1].message(), "second");
+    assert_eq!(diagnostics[1].help(), ["some help"]);
+    assert_eq!(diagnostics[1].labels()[0].message(), "a label");
+    assert_eq!(diagnostics[1].labels()[0].span().start().column, 4);
+
+    let messages: Vec<_> = error.into_iter().map(|d| d.message().to_string()).collect();
+    assert_eq!(messages, ["first", "second"]);
+}
+
+#[test]
+pub fn duplicate_argument() {
+    let error = synix::parse("{ a, b, a }: a").unwrap_err();
+    assert_eq!(error.message(), "Duplicate argument `a`.");
+    assert_eq!(error.span().start().column, 8);
+
+    let diagnostic = error.iter().next().unwrap();
+    assert_eq!(diagnostic.labels()[0].message(), "first defined here");
+    assert_eq!(diagnostic.labels()[0].span().start().column, 2);
+
+    // The argument is still parsed when recovering.
+    let (_, errors) = parse_with_diagnostics("{ a, a, a }: a", ParseOptions::default());
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+pub fn help() {
+    let error = synix::parse("a == b == c").unwrap_err();
+    let diagnostic = error.iter().next().unwrap();
+
+    assert_eq!(diagnostic.message(), "Operator `==` is not associative.");
+    assert_eq!(
+        diagnostic.help(),
+        ["Add parentheses to group the operations."]
+    );
+}
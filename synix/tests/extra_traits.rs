@@ -0,0 +1,57 @@
+use std::{
+    collections::HashSet,
+    hash::{BuildHasher, RandomState},
+};
+
+use synix::{Expr, ParseOptions, parse_with_diagnostics};
+
+fn parse(input: &str) -> Expr {
+    synix::parse(input).unwrap()
+}
+
+#[test]
+pub fn spans_are_ignored() {
+    assert_eq!(parse("{ a = 1; }"), parse("{\n  a   =  1 ;\n}"));
+    assert_eq!(parse("f x ./a/b.nix"), parse("  f  x  ./a/b.nix"));
+    assert_eq!(parse("a: (a) + <b>"), parse("a:  (a)  +  <b>"));
+
+    let state = RandomState::new();
+    assert_eq!(
+        state.hash_one(parse("[ 1 \"a${b}\" ]")),
+        state.hash_one(parse("[\n1\n\"a${b}\"\n]")),
+    );
+}
+
+#[test]
+pub fn contents_are_compared() {
+    assert_ne!(parse("a + b"), parse("a - b"));
+    assert_ne!(parse("{ a = 1; }"), parse("rec { a = 1; }"));
+    assert_ne!(parse("{ a, b, ... }: a"), parse("{ a, b }: a"));
+    assert_ne!(parse("(a)"), parse("a"));
+    assert_ne!(parse("1"), parse("2"));
+    assert_ne!(parse("true"), parse("false"));
+    assert_ne!(parse("./a"), parse("/a"));
+}
+
+#[test]
+pub fn clone() {
+    let expr = parse("let a = x: x.y or { }; in [ (a 1) ]");
+    assert_eq!(expr.clone(), expr);
+}
+
+#[test]
+pub fn errors_are_equal() {
+    let (lhs, _) = parse_with_diagnostics("[ a = ]", ParseOptions::default());
+    let (rhs, _) = parse_with_diagnostics("[ a ; ]", ParseOptions::default());
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+pub fn hash_set() {
+    let set: HashSet<Expr> = ["a.b", "a . b", "a.c", "a.\"b\""]
+        .into_iter()
+        .map(parse)
+        .collect();
+
+    assert_eq!(set.len(), 3);
+}
@@ -0,0 +1,93 @@
+mod common;
+use common::parse_or_pretty_err;
+use synix::{Expr, ExprFunctionCall, ExprIf, ExprLet};
+
+fn parse(nix: &str) -> Expr {
+    match parse_or_pretty_err(nix) {
+        Ok(v) => v,
+        Err(e) => panic!("{e}"),
+    }
+}
+
+fn ident(expr: &Expr) -> &str {
+    let Expr::Ident(ident) = expr else {
+        panic!("Not an ident: {expr:?}");
+    };
+
+    ident.ident()
+}
+
+fn call(expr: &Expr) -> &ExprFunctionCall {
+    let Expr::FunctionCall(call) = expr else {
+        panic!("Not a function call: {expr:?}");
+    };
+
+    call
+}
+
+#[test]
+pub fn left_associative() {
+    let parsed = parse("f a b");
+
+    let outer = call(&parsed);
+    assert_eq!(ident(&outer.tail), "b");
+
+    let inner = call(&outer.head);
+    assert_eq!(ident(&inner.head), "f");
+    assert_eq!(ident(&inner.tail), "a");
+}
+
+#[test]
+pub fn stops_at_in() {
+    let Expr::Let(let_) = parse("let a = f b c; in g a") else {
+        panic!("Not a let expression");
+    };
+
+    let ExprLet { body, .. } = *let_;
+
+    let body = call(&body);
+    assert_eq!(ident(&body.head), "g");
+    assert_eq!(ident(&body.tail), "a");
+}
+
+#[test]
+pub fn stops_at_then_else() {
+    let Expr::If(if_) = parse("if f a then g b else h c") else {
+        panic!("Not an if expression");
+    };
+
+    let ExprIf {
+        condition,
+        then_branch,
+        else_branch,
+        ..
+    } = *if_;
+
+    assert_eq!(ident(&call(&condition).head), "f");
+    assert_eq!(ident(&call(&then_branch).head), "g");
+    assert_eq!(ident(&call(&else_branch).head), "h");
+}
+
+#[test]
+pub fn stops_at_closing_delimiter() {
+    let parsed = parse("(f a) b");
+
+    let outer = call(&parsed);
+    assert_eq!(ident(&outer.tail), "b");
+
+    let Expr::Parenthesized(inner) = &outer.head else {
+        panic!("Head is not parenthesized");
+    };
+
+    let inner = call(&inner.inner);
+    assert_eq!(ident(&inner.head), "f");
+    assert_eq!(ident(&inner.tail), "a");
+}
+
+#[test]
+pub fn keyword_is_not_argument() {
+    // The call stops before `in`, which is then left over.
+    let error = synix::parse("f in").unwrap_err();
+    assert_eq!(error.message(), "Unexpected input after expression.");
+    assert_eq!(error.span().start().column, 2);
+}
@@ -0,0 +1,86 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use synix::instantiate::{self, Options};
+
+/// Compare the output for `tests/instantiate/*.nix` to the
+/// `.exp` file next to it.
+///
+/// The expected output is that of `nix-instantiate --parse`, with
+/// the paths of the directory and the home directory replaced:
+///
+/// ```sh
+/// nix-instantiate --parse a.nix | sed "s|$PWD|/base|g; s|$HOME|/home|g" > a.exp
+/// ```
+#[test]
+pub fn fixtures() {
+    let options = Options::new("/base", "/home");
+
+    for case in cases("tests/instantiate") {
+        let input = fs::read_to_string(&case).unwrap();
+        let expected = fs::read_to_string(case.with_extension("exp")).unwrap();
+
+        let expr = synix::parse(&input).unwrap();
+        let output = instantiate::print(&expr, &options).unwrap() + "\n";
+
+        assert_eq!(output, expected, "{}", case.display());
+    }
+}
+
+/// Compare the errors for `tests/instantiate/errors/*.nix` to the
+/// `.exp` file next to it, which has a `line:column: message` line
+/// for the error and each of its labels, counting from 1 like Nix.
+///
+/// These are the inputs that `nix-instantiate --parse` rejects.
+#[test]
+pub fn errors() {
+    let options = Options::new("/base", "/home");
+
+    for case in cases("tests/instantiate/errors") {
+        let input = fs::read_to_string(&case).unwrap();
+        let expected = fs::read_to_string(case.with_extension("exp")).unwrap();
+
+        let expr = synix::parse(&input).unwrap();
+        let error = instantiate::print(&expr, &options).unwrap_err();
+
+        let mut output = String::new();
+        for diagnostic in error.iter() {
+            let start = diagnostic.span().start();
+            output += &format!(
+                "{}:{}: {}\n",
+                start.line + 1,
+                start.column + 1,
+                diagnostic.message()
+            );
+
+            for label in diagnostic.labels() {
+                let start = label.span().start();
+                output += &format!(
+                    "{}:{}: {}\n",
+                    start.line + 1,
+                    start.column + 1,
+                    label.message()
+                );
+            }
+        }
+
+        assert_eq!(output, expected, "{}", case.display());
+    }
+}
+
+/// The `.nix` files in `dir`, sorted.
+fn cases(dir: &str) -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(dir);
+
+    let mut cases: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "nix"))
+        .collect();
+    cases.sort();
+
+    assert!(!cases.is_empty());
+    cases
+}
@@ -0,0 +1,12 @@
+rec {
+  z = 1;
+  a.b.c = 2;
+  a.b.d = 3;
+  a.e = { f = 4; };
+  "quoted" = 5;
+  ${"static"} = 6;
+  ${dyn}.x = 7;
+  inherit y x;
+  inherit (set) m;
+  inherit (set) n l;
+}
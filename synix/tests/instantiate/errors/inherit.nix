@@ -0,0 +1 @@
+{ inherit a; a = 1; }
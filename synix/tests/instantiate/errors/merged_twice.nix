@@ -0,0 +1,4 @@
+{
+  a.b = 1;
+  a = { b = 2; };
+}
@@ -0,0 +1,7 @@
+let
+  f = { b, a ? 1, ... }: a;
+  g = args@{ c }: args;
+  h = x: y: x;
+  i = { ... }: null;
+in
+  [ (f a b) ((f a) b) (h (g c)) (with builtins; map f [ ]) (if a then b else c) ]
@@ -0,0 +1,4 @@
+{
+  a.b = 1;
+  a = { c = 2; };
+}
@@ -0,0 +1,9 @@
+[
+  (1 + 2 * 3 - 4 / 5)
+  (a < b) (a > b) (a <= b) (a >= b)
+  (a == b || !c && d -> e)
+  (a // b ++ c)
+  (-a) (- 1)
+  (a.b or c)
+  (a ? b.${c})
+]
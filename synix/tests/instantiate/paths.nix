@@ -0,0 +1,9 @@
+[
+  ./a/b.nix
+  /absolute/path/file
+  ~/home.nix
+  relative/path
+  ./a/${b}/c.nix
+  <nixpkgs>
+  <nixpkgs/lib>
+]
@@ -0,0 +1,7 @@
+{
+  a = "plain \"quoted\" \\ \${not}";
+  b = "a${b}c";
+  c = "${d}";
+  d = "multi\nline\t${e}$";
+  e = "";
+}
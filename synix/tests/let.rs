@@ -0,0 +1,65 @@
+use synix::{Expr, ParseOptions, Warning};
+
+fn parse(nix: &str) -> (Expr, Vec<Warning>) {
+    match synix::parse_with_warnings(nix, ParseOptions::default()) {
+        Ok(v) => v,
+        Err(e) => panic!("{}", e.message()),
+    }
+}
+
+#[test]
+pub fn let_in_has_no_warnings() {
+    let (parsed, warnings) = parse("let a = 1; in a");
+
+    assert!(matches!(parsed, Expr::Let(_)));
+    assert!(warnings.is_empty());
+}
+
+#[test]
+pub fn legacy_let() {
+    let (parsed, warnings) = parse("let { a = 1; body = a; }");
+
+    let Expr::LegacyLet(legacy_let) = parsed else {
+        panic!("Not a legacy let expression: {parsed:?}");
+    };
+
+    assert_eq!(legacy_let.assignments.len(), 2);
+    assert!(matches!(legacy_let.body(), Some(Expr::Ident(i)) if i.ident() == "a"));
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message().contains("deprecated"));
+}
+
+#[test]
+pub fn legacy_let_without_body() {
+    let (parsed, _) = parse("let { a = 1; }");
+
+    let Expr::LegacyLet(legacy_let) = parsed else {
+        panic!("Not a legacy let expression: {parsed:?}");
+    };
+
+    assert!(legacy_let.body().is_none());
+}
+
+#[test]
+pub fn legacy_let_as_argument() {
+    let (parsed, warnings) = parse("f let { body = 1; }");
+
+    let Expr::FunctionCall(call) = parsed else {
+        panic!("Not a function call: {parsed:?}");
+    };
+
+    assert!(matches!(call.tail, Expr::LegacyLet(_)));
+    assert_eq!(warnings.len(), 1);
+}
+
+#[test]
+pub fn legacy_let_warns_once() {
+    // Lambda detection parses the argument speculatively, which
+    // must not report the warning a second time.
+    let (_, warnings) = parse("{ a ? let { body = 1; } }: a");
+    assert_eq!(warnings.len(), 1);
+
+    let (_, warnings) = parse("[ (let { body = 1; }) let { body = 2; } ]");
+    assert_eq!(warnings.len(), 2);
+}
@@ -0,0 +1,86 @@
+mod common;
+use common::parse_or_pretty_err;
+use synix::{
+    Expr,
+    lit::{ExprLit, InterpolatedStrPart},
+    path::Path,
+};
+
+macro_rules! list {
+    ($input:literal) => {{
+        let nix = $input;
+
+        let parsed = match parse_or_pretty_err(nix) {
+            Ok(v) => v,
+            Err(e) => panic!("{e}"),
+        };
+
+        let Expr::List(list) = parsed else {
+            panic!("Not a list");
+        };
+
+        list.entries
+    }};
+}
+
+#[test]
+pub fn application_is_two_entries() {
+    let entries = list!("[ f x ]");
+
+    assert_eq!(entries.len(), 2);
+    assert!(matches!(&entries[0], Expr::Ident(i) if i.ident() == "f"));
+    assert!(matches!(&entries[1], Expr::Ident(i) if i.ident() == "x"));
+}
+
+#[test]
+pub fn select_with_default() {
+    let entries = list!("[ a.b or c d ]");
+
+    assert_eq!(entries.len(), 2);
+
+    let Expr::AttributeAccess(access) = &entries[0] else {
+        panic!("Not an attribute access");
+    };
+
+    assert_eq!(access.accessors.len(), 1);
+    assert!(matches!(&access.default, Some((_, Expr::Ident(i))) if i.ident() == "c"));
+    assert!(matches!(&entries[1], Expr::Ident(i) if i.ident() == "d"));
+}
+
+#[test]
+pub fn interpolated_string() {
+    let entries = list!(r#"[ "a ${b}" "c" ]"#);
+
+    assert_eq!(entries.len(), 2);
+
+    let Expr::InterpolatedStr(str) = &entries[0] else {
+        panic!("Not an interpolated string");
+    };
+
+    assert!(matches!(&str.parts[0], InterpolatedStrPart::Str(s) if s == "a "));
+    assert!(
+        matches!(&str.parts[1], InterpolatedStrPart::Interpolation(Expr::Ident(i)) if i.ident() == "b")
+    );
+    assert!(matches!(&entries[1], Expr::Lit(ExprLit::Str(s)) if s.value == "c"));
+}
+
+#[test]
+pub fn rec_attrset_and_paths() {
+    let entries = list!("[ rec { } <nixpkgs> ./a.nix ]");
+
+    assert_eq!(entries.len(), 3);
+    assert!(matches!(&entries[0], Expr::AttrSet(set) if set.rec.is_some()));
+    assert!(matches!(&entries[1], Expr::Path(p) if matches!(**p, Path::Lookup(_))));
+    assert!(matches!(&entries[2], Expr::Path(p) if matches!(**p, Path::Normal(_))));
+}
+
+#[test]
+pub fn negation_must_be_parenthesized() {
+    let entries = list!("[ (-1) ]");
+
+    assert_eq!(entries.len(), 1);
+    assert!(matches!(&entries[0], Expr::Parenthesized(_)));
+
+    // Like in Nix, a negation is not a select expression.
+    assert!(synix::parse("[ -1 ]").is_err());
+}
@@ -0,0 +1,45 @@
+use synix::{ParseBuffer, Token, buffer::TokenBuffer, token::Brace};
+use synix_lexer::TokenStream;
+
+fn error(input: &str) -> String {
+    synix::parse(input).unwrap_err().message().to_string()
+}
+
+#[test]
+pub fn assignment() {
+    assert_eq!(error("let a in a"), "Expected one of `.`, `=`, found `in`");
+    assert_eq!(
+        error("{ ; }"),
+        "Expected one of `inherit`, attribute name, found `;`"
+    );
+}
+
+#[test]
+pub fn expr() {
+    assert_eq!(
+        error("in"),
+        "Expected one of `let`, lambda, `with`, `if`, unary operator, literal, string, \
+         attribute set, `(`, `[`, path, identifier, found `in`"
+    );
+    assert_eq!(
+        error("1 +"),
+        "Expected one of literal, string, attribute set, `let`, `(`, `[`, path, identifier, \
+         found end of input"
+    );
+}
+
+#[test]
+pub fn custom() {
+    let lexed: TokenStream = "in {}".parse().unwrap();
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let buffer = ParseBuffer::new(&tokens);
+
+    let lookahead = buffer.lookahead1();
+    assert!(!lookahead.peek::<Token![let]>());
+    assert!(!lookahead.peek::<Brace>());
+    assert!(!lookahead.peek::<Token![let]>());
+
+    let error = lookahead.error();
+    assert_eq!(error.message(), "Expected one of `let`, `{`, found `in`");
+    assert_eq!(error.span().start().column, 0);
+}
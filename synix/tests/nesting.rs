@@ -0,0 +1,169 @@
+//! Deeply nested and long inputs, such as generated lock files, must
+//! not overflow the stack while they are parsed, printed or dropped.
+//! Inputs that are nested too deeply for that are rejected.
+
+mod common;
+
+use common::parse_or_pretty_err;
+use synix::{
+    Expr, ParseOptions,
+    binary::Operator,
+    instantiate::{self, Options},
+};
+
+/// Far deeper than the stack of a test thread allows, were every
+/// level of nesting parsed on it.
+const DEPTH: usize = 2000;
+
+/// Deeper than any tree can be dropped recursively on the stack of
+/// a test thread.
+const TOO_DEEP: usize = 200_000;
+
+fn parse(input: &str) -> Expr {
+    match parse_or_pretty_err(input) {
+        Ok(expr) => expr,
+        Err(e) => panic!("{e}"),
+    }
+}
+
+/// The operators of a chain that is nested to the right, and its
+/// last operand.
+fn right_chain(mut expr: &Expr) -> (Vec<&Operator>, &Expr) {
+    let mut operators = Vec::new();
+
+    while let Expr::Binary(binary) = expr {
+        operators.push(&binary.operator);
+        expr = &binary.rhs;
+    }
+
+    (operators, expr)
+}
+
+#[test]
+pub fn long_chains() {
+    for operator in ["++", "//", "->", "+", "&&"] {
+        let input = format!("a{}", format!(" {operator} a").repeat(5000));
+        let expr = parse(&input);
+        assert_eq!(expr.span().end().column, input.len());
+
+        let printed = synix::print(&expr);
+        assert_eq!(printed.trim_end(), input);
+    }
+
+    let expr = parse(&format!("a{}", " ++ a".repeat(5000)));
+    let (operators, last) = right_chain(&expr);
+    assert_eq!(operators.len(), 5000);
+    assert!(operators.iter().all(|op| **op == Operator::Concat));
+    assert!(matches!(last, Expr::Ident(_)));
+
+    let options = ParseOptions::new().pipe_operators(true);
+    let input = format!("a{}", " |> f".repeat(5000));
+    assert!(synix::parse_with_options(&input, options).is_ok());
+}
+
+#[test]
+pub fn deep_nesting() {
+    let inputs = [
+        format!("{}1{}", "(".repeat(DEPTH), ")".repeat(DEPTH)),
+        format!("{}1{}", "[ ".repeat(DEPTH), " ]".repeat(DEPTH)),
+        format!("{}1{}", "{ a = ".repeat(DEPTH), "; }".repeat(DEPTH)),
+        format!("{}1", "let a = 1; in ".repeat(DEPTH)),
+        format!("{}1", "if a then 1 else ".repeat(DEPTH)),
+        format!("{}1", "a: ".repeat(DEPTH)),
+        format!("{}1", "with a; ".repeat(DEPTH)),
+        format!("{}a", "- ".repeat(DEPTH)),
+        format!("{}a", "! ".repeat(DEPTH)),
+        format!("{}a{}", "f (".repeat(DEPTH), ")".repeat(DEPTH)),
+        format!("{}a{}", "a.${".repeat(DEPTH), "}".repeat(DEPTH)),
+    ];
+
+    for input in inputs {
+        let expr = parse(&input);
+        assert_eq!(expr.span().end().column, input.len(), "{input:.20}...");
+    }
+
+    let input = format!("{}a{}", "\"${".repeat(500), "}\"".repeat(500));
+    parse(&input);
+}
+
+#[test]
+pub fn deep_errors() {
+    let input = format!("{}1 +{}", "(".repeat(DEPTH), ")".repeat(DEPTH));
+    let error = synix::parse(&input).unwrap_err();
+    assert_eq!(error.span().start().column, DEPTH + 3);
+
+    let input = format!("{}1", "(".repeat(DEPTH));
+    let error = synix::parse(&input).unwrap_err();
+    assert_eq!(error.span().start().column, DEPTH + 1);
+
+    let input = format!("{}1 +{}", "[ ".repeat(DEPTH), " ]".repeat(DEPTH));
+    let (_, diagnostics) = synix::parse_with_diagnostics(&input, ParseOptions::new());
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+pub fn deep_cst() {
+    let input = format!("{}1{}", "{ a =\n".repeat(DEPTH), ";\n}".repeat(DEPTH));
+    let root = synix::cst::parse(&input).unwrap();
+    assert_eq!(root.to_string(), input);
+
+    let input = format!("{}1{}", "[\n".repeat(9000), "\n]".repeat(9000));
+    let root = synix::cst::parse(&input).unwrap();
+    assert_eq!(root.to_string(), input);
+
+    // The innermost node keeps all of its ancestors alive.
+    let mut node = root;
+    loop {
+        let Some(child) = node.children().next() else {
+            break;
+        };
+        node = child;
+    }
+    assert_eq!(node.to_string(), "1");
+    drop(node);
+}
+
+#[test]
+pub fn deepest_trees() {
+    let inputs = [
+        format!("a{}", " ++ a".repeat(9000)),
+        format!("a{}", " + a".repeat(9000)),
+        format!("f{}", " a".repeat(9000)),
+        format!("{}-a", "- ".repeat(9000)),
+        format!("{}1{}", "[ ".repeat(9000), " ]".repeat(9000)),
+        format!("{}1{}", "(".repeat(4000), ")".repeat(4000)),
+        format!("{}1{}", "{ a = ".repeat(4000), "; }".repeat(4000)),
+    ];
+
+    for input in inputs {
+        let expr = parse(&input);
+        assert_eq!(synix::print(&expr).trim_end(), input);
+        assert!(instantiate::print(&expr, &Options::new("/base", "/home")).is_ok());
+        drop(expr);
+    }
+}
+
+#[test]
+pub fn too_deep() {
+    let inputs = [
+        format!("{}1{}", "(".repeat(TOO_DEEP), ")".repeat(TOO_DEEP)),
+        format!("a{}", " ++ a".repeat(TOO_DEEP)),
+        format!("a{}", " + a".repeat(TOO_DEEP)),
+        format!("f{}", " a".repeat(TOO_DEEP)),
+    ];
+
+    for input in inputs {
+        let error = synix::parse(&input).unwrap_err();
+        assert_eq!(error.message(), "Expression is nested too deeply.");
+
+        let (_, diagnostics) = synix::parse_with_diagnostics(&input, ParseOptions::new());
+        assert_eq!(diagnostics.len(), 1);
+
+        assert!(synix::cst::parse(&input).is_err());
+    }
+
+    // The tokens of the input are nested as deeply as it is.
+    let input = format!("{}{}", "[ ".repeat(TOO_DEEP), " ]".repeat(TOO_DEEP));
+    let tokens: synix_lexer::TokenStream = input.parse().unwrap();
+    drop(tokens);
+}
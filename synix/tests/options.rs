@@ -0,0 +1,96 @@
+use synix::{Expr, ParseOptions, Target, lit::ExprLit};
+
+fn parse(nix: &str, options: ParseOptions) -> synix::Result<Expr> {
+    synix::parse_with_options(nix, options)
+}
+
+#[test]
+pub fn url_literals() {
+    let options = ParseOptions::new().url_literals(true);
+
+    let Ok(Expr::FunctionCall(call)) = parse("fetchurl https://example.org/a.tar.gz", options)
+    else {
+        panic!("Not a function call");
+    };
+
+    let Expr::Lit(ExprLit::Url(url)) = &call.tail else {
+        panic!("Not a URL: {:?}", call.tail);
+    };
+
+    assert_eq!(url.url, "https://example.org/a.tar.gz");
+
+    assert!(matches!(
+        parse("x:x", options),
+        Ok(Expr::Lit(ExprLit::Url(_)))
+    ));
+    assert!(matches!(parse("x: x", options), Ok(Expr::Lambda(_))));
+    assert!(matches!(
+        parse("x:x", ParseOptions::default()),
+        Ok(Expr::Lambda(_))
+    ));
+}
+
+#[test]
+pub fn legacy_let() {
+    assert!(parse("let { body = 1; }", ParseOptions::default()).is_ok());
+
+    let options = ParseOptions::new().legacy_let(false);
+    assert!(parse("let { body = 1; }", options).is_err());
+    assert!(parse("let a = 1; in a", options).is_ok());
+}
+
+#[test]
+pub fn or_identifier() {
+    assert!(parse("{ or = 1; }.or", ParseOptions::default()).is_ok());
+
+    let options = ParseOptions::new().or_identifier(false);
+    assert!(parse("{ or = 1; }", options).is_err());
+    assert!(parse("a.b or c", options).is_ok());
+}
+
+#[test]
+pub fn strict() {
+    let options = ParseOptions::new().strict(true);
+
+    assert!(parse(r#""\q""#, ParseOptions::default()).is_ok());
+    assert!(parse(r#""\q""#, options).is_err());
+}
+
+#[test]
+pub fn trailing_input() {
+    // Like Nix, input after the expression is an error regardless
+    // of the options.
+    for options in [ParseOptions::default(), ParseOptions::new().strict(true)] {
+        assert!(parse("f in", options).is_err());
+        assert!(parse("a; b", options).is_err());
+        assert!(synix::cst::parse_with_options("a; b", options).is_err());
+    }
+}
+
+#[test]
+pub fn target() {
+    let options = ParseOptions::for_target(Target::Nix(2, 18));
+    assert_eq!(options.target(), Some(Target::Nix(2, 18)));
+    assert!(options.has_url_literals());
+    assert!(!options.has_pipe_operators());
+
+    assert!(parse("a |> f", options.pipe_operators(true)).is_err());
+
+    let options = ParseOptions::for_target(Target::Nix(2, 24)).pipe_operators(true);
+    assert!(parse("a |> f", options).is_ok());
+
+    let options = ParseOptions::for_target(Target::Lix(2, 91)).pipe_operators(true);
+    assert!(parse("a |> f", options).is_ok());
+}
+
+#[test]
+pub fn target_other_features() {
+    // Only the pipe operators depend on the release.
+    for target in [Target::Nix(2, 3), Target::Nix(2, 24), Target::Lix(2, 91)] {
+        let options = ParseOptions::for_target(target);
+        assert!(options.has_url_literals());
+        assert!(options.has_legacy_let());
+        assert!(options.has_or_identifier());
+        assert!(!options.is_strict());
+    }
+}
@@ -0,0 +1,71 @@
+use synix::{
+    Ident, Parse, ParseBuffer, ParseOptions, Peek, Token, binary::Operator, buffer::TokenBuffer,
+    lit::ExprLit, path::LookupPath,
+};
+use synix_lexer::TokenStream;
+
+/// Check that peeking at `T` agrees with parsing it.
+fn agrees<T: Parse + Peek>(inputs: &[&str], options: ParseOptions) {
+    for input in inputs {
+        let lexed: TokenStream = input.parse().unwrap();
+        let tokens = TokenBuffer::new(lexed.as_ref());
+        let buffer = ParseBuffer::new_with_options(&tokens, options);
+
+        let parses = T::parse(&mut buffer.fork()).is_ok();
+        assert_eq!(T::peek(&buffer), parses, "{}: {input:?}", T::display());
+    }
+}
+
+#[test]
+pub fn tokens() {
+    let inputs = [
+        "", ">", ">=", "> =", ">>", "> >", ">$", "//", "/ /", "...", "..", "let", "in", "a",
+    ];
+
+    agrees::<Token![>]>(&inputs, ParseOptions::default());
+    agrees::<Token![>=]>(&inputs, ParseOptions::default());
+    agrees::<synix::token::Update>(&inputs, ParseOptions::default());
+    agrees::<Token![...]>(&inputs, ParseOptions::default());
+    agrees::<Token![let]>(&inputs, ParseOptions::default());
+}
+
+#[test]
+pub fn idents() {
+    let inputs = ["a", "let", "or", "true", "1", "${a}", ""];
+
+    agrees::<Ident>(&inputs, ParseOptions::default());
+    agrees::<Ident>(&inputs, ParseOptions::new().or_identifier(false));
+}
+
+#[test]
+pub fn literals() {
+    agrees::<ExprLit>(
+        &["1", "\"a\"", "\"${a}\"", "true", "false", "a", "[ ]", ""],
+        ParseOptions::default(),
+    );
+}
+
+#[test]
+pub fn operators() {
+    let inputs = [
+        "+", "++", "//", "->", "-", "|>", "<|", "<", "<=", "=", "!", "a", "",
+    ];
+
+    agrees::<Operator>(&inputs, ParseOptions::new().pipe_operators(true));
+}
+
+#[test]
+pub fn lookup_paths() {
+    agrees::<LookupPath>(
+        &[
+            "<a>", "<a/b/c>", "<a/>", "<a", "<let>", "<a>=", "<a> = b", "<1>", "<a//b>",
+        ],
+        ParseOptions::default(),
+    );
+
+    // Unlike parsing, peeking requires `<` to be joint, as in `a < b`
+    // it is an operator.
+    let lexed: TokenStream = "< a>".parse().unwrap();
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    assert!(!LookupPath::peek(&ParseBuffer::new(&tokens)));
+}
@@ -0,0 +1,43 @@
+//! Checks operator precedence and associativity against the
+//! output of `nix-instantiate --parse`, stored in `tests/precedence`.
+
+mod common;
+
+use std::{fs, path::Path};
+
+use common::parse_or_pretty_err;
+use synix::instantiate::{self, Options};
+
+#[test]
+pub fn corpus() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/precedence");
+
+    let mut cases: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "nix"))
+        .collect();
+    cases.sort();
+
+    assert!(!cases.is_empty());
+
+    for case in cases {
+        let input = fs::read_to_string(&case).unwrap();
+        let expected = fs::read_to_string(case.with_extension("exp")).unwrap();
+
+        let parsed = match parse_or_pretty_err(&input) {
+            Ok(v) => v,
+            Err(e) => panic!("{}: {e}", case.display()),
+        };
+
+        let output = instantiate::print(&parsed, &Options::new("/base", "/home")).unwrap();
+
+        assert_eq!(
+            output,
+            expected.trim_end(),
+            "{}: {}",
+            case.display(),
+            input.trim_end()
+        );
+    }
+}
@@ -0,0 +1 @@
+f a b + g c
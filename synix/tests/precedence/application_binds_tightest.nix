@@ -0,0 +1 @@
+f a * g b
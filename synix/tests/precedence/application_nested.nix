@@ -0,0 +1 @@
+f (g x) y
@@ -0,0 +1 @@
+if f a then g b else h c d
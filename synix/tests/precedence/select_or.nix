@@ -0,0 +1 @@
+f a.b or c.d e
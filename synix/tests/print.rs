@@ -0,0 +1,143 @@
+mod common;
+
+use std::fs;
+
+use common::parse_or_pretty_err;
+use synix::{Expr, ParseOptions, print};
+
+fn parse(nix: &str) -> Expr {
+    match parse_or_pretty_err(nix) {
+        Ok(v) => v,
+        Err(e) => panic!("{e}"),
+    }
+}
+
+/// Print `$input` and check the output, and that the
+/// output prints the same once parsed again.
+macro_rules! print {
+    ($name:ident, $input:literal, $expected:literal) => {
+        #[test]
+        pub fn $name() {
+            let printed = print(&parse($input));
+            assert_eq!(printed, $expected);
+            assert_eq!(print(&parse(&printed)), printed);
+        }
+    };
+}
+
+print!(int, "  42 ", "42");
+print!(
+    string,
+    r#""a \"b\" \\ \n \${c}""#, r#""a \"b\" \\ \n \${c}""#
+);
+print!(
+    interpolated_string,
+    r#""a ${ b } $${c}""#, r#""a ${b} $${c}""#
+);
+print!(dollar_before_interpolation, r#""a\$${b}""#, r#""a\$${b}""#);
+print!(attrset, "rec {a=1;b.c=2;}", "rec { a = 1; b.c = 2; }");
+print!(empty_attrset, "{}", "{ }");
+print!(
+    attr_names,
+    r#"{ "a b" = 1; ${c} = 2; inherit (x) d "e"; }"#,
+    r#"{ "a b" = 1; ${c} = 2; inherit (x) d "e"; }"#
+);
+print!(let_, "let a=1;in a", "let a = 1; in a");
+print!(legacy_let, "let{body=1;}", "let { body = 1; }");
+print!(lambda, "x:y:x", "x: y: x");
+print!(
+    lambda_attrset,
+    "{a,b?1,...}@args:a", "args@{ a, b ? 1, ... }: a"
+);
+print!(lambda_empty, "{}:1", "{ }: 1");
+print!(with, "with a;b", "with a; b");
+print!(if_, "if a then b else c", "if a then b else c");
+print!(list, "[1 (f x) a.b]", "[ 1 (f x) a.b ]");
+print!(application, "f  a   b", "f a b");
+print!(select, "a.b.${c} or d", "a.b.${c} or d");
+print!(has_attr, "a?b.c", "a ? b.c");
+print!(unary, "- a + !b", "-a + !b");
+print!(double_negate, "-(-a)", "-(-a)");
+print!(binary, "a+b*c", "a + b * c");
+print!(parenthesized, "(a+b)*c", "(a + b) * c");
+print!(
+    paths,
+    "[./a/b.nix /c ~/d e/f <g/h>]", "[ ./a/b.nix /c ~/d e/f <g/h> ]"
+);
+print!(path_interpolation, "./a/${b}.nix", "./a/${b}.nix");
+
+#[test]
+pub fn url() {
+    let options = ParseOptions::new().url_literals(true);
+    let parsed = synix::parse_with_options("f https://example.org", options).unwrap();
+
+    assert_eq!(print(&parsed), "f https://example.org");
+}
+
+/// Remove the parentheses around `expr` and its operands.
+fn strip_parens(expr: Expr) -> Expr {
+    match expr {
+        Expr::Parenthesized(paren) => strip_parens(Expr::from(*paren)),
+        Expr::Binary(mut binary) => {
+            binary.lhs = strip_parens(binary.lhs);
+            binary.rhs = strip_parens(binary.rhs);
+            Expr::Binary(binary)
+        }
+        Expr::Unary(mut unary) => {
+            unary.expr = strip_parens(unary.expr);
+            Expr::Unary(unary)
+        }
+        Expr::FunctionCall(mut call) => {
+            call.head = strip_parens(call.head);
+            call.tail = strip_parens(call.tail);
+            Expr::FunctionCall(call)
+        }
+        expr => expr,
+    }
+}
+
+#[test]
+pub fn minimal_parentheses() {
+    let cases = [
+        ("((a + b)) * c", "(a + b) * c"),
+        ("a + (b * c)", "a + b * c"),
+        ("(a - b) - c", "a - b - c"),
+        ("a - (b - c)", "a - (b - c)"),
+        ("(a ++ b) ++ c", "(a ++ b) ++ c"),
+        ("a ++ (b ++ c)", "a ++ b ++ c"),
+        ("(a == b) == c", "(a == b) == c"),
+        ("(f a) (g b)", "f a (g b)"),
+        ("-(f a)", "-f a"),
+        ("(-a) + b", "-a + b"),
+        ("-(a + b)", "-(a + b)"),
+        ("!(a && b)", "!(a && b)"),
+        ("(x: x) a", "(x: x) a"),
+        ("a + (if b then c else d)", "a + (if b then c else d)"),
+        ("(a -> b) -> c", "(a -> b) -> c"),
+    ];
+
+    for (input, expected) in cases {
+        let printed = print(&strip_parens(parse(input)));
+        assert_eq!(printed, expected, "{input}");
+    }
+
+    let options = ParseOptions::new().pipe_operators(true);
+    let parsed = synix::parse_with_options("(a |> f) <| b", options).unwrap();
+    assert_eq!(print(&strip_parens(parsed)), "(a |> f) <| b");
+}
+
+#[test]
+pub fn corpus_round_trip() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/precedence");
+
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.extension().is_some_and(|ext| ext == "nix") {
+            let input = fs::read_to_string(&path).unwrap();
+            let printed = print(&parse(&input));
+
+            assert_eq!(print(&parse(&printed)), printed, "{}", path.display());
+        }
+    }
+}
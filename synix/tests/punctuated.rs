@@ -0,0 +1,92 @@
+use synix::{
+    Expr, Ident, ParseBuffer, ParseOptions, Token,
+    buffer::TokenBuffer,
+    lambda::LambdaArg,
+    punctuated::{Pair, Punctuated},
+};
+use synix_lexer::TokenStream;
+
+fn idents(input: &str, nonempty: bool) -> synix::Result<Punctuated<Ident, Token![,]>> {
+    let lexed: TokenStream = input.parse().unwrap();
+    let tokens = TokenBuffer::new(lexed.as_ref());
+    let mut buffer = ParseBuffer::new(&tokens);
+
+    if nonempty {
+        Punctuated::parse_separated_nonempty(&mut buffer)
+    } else {
+        Punctuated::parse_terminated(&mut buffer)
+    }
+}
+
+fn names(punctuated: &Punctuated<Ident, Token![,]>) -> Vec<&str> {
+    punctuated.iter().map(|ident| ident.ident()).collect()
+}
+
+#[test]
+pub fn parse_terminated() {
+    let list = idents("a, b, c", false).unwrap();
+    assert_eq!(names(&list), ["a", "b", "c"]);
+    assert!(!list.trailing_punct());
+
+    let list = idents("a, b,", false).unwrap();
+    assert_eq!(names(&list), ["a", "b"]);
+    assert!(list.trailing_punct());
+
+    assert!(idents("", false).unwrap().is_empty());
+    assert!(idents("a b", false).is_err());
+}
+
+#[test]
+pub fn parse_separated_nonempty() {
+    let list = idents("a, b", true).unwrap();
+    assert_eq!(names(&list), ["a", "b"]);
+
+    assert!(idents("", true).is_err());
+    assert!(idents("a, b,", true).is_err());
+}
+
+#[test]
+pub fn push_and_pop() {
+    let mut list: Punctuated<u32, Token![,]> = [1, 2].into_iter().collect();
+    assert_eq!(list.len(), 2);
+    assert!(!list.trailing_punct());
+
+    list.push_punct(Default::default());
+    assert!(list.trailing_punct());
+    list.push_value(3);
+
+    assert_eq!(list[2], 3);
+    assert_eq!(list.last(), Some(&3));
+    assert!(matches!(list.pop(), Some(Pair::End(3))));
+    assert!(matches!(list.pop(), Some(Pair::Punctuated(2, _))));
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), [1]);
+}
+
+#[test]
+pub fn lambda_arguments_keep_commas() {
+    let Expr::Lambda(lambda) = synix::parse("{ a, b ? 1, }: a").unwrap() else {
+        panic!("Not a lambda");
+    };
+    let LambdaArg::AttrSet(set) = &lambda.arg else {
+        panic!("Not an attribute set argument");
+    };
+
+    assert_eq!(set.args.len(), 2);
+    assert!(set.args.trailing_punct());
+
+    let commas: Vec<_> = set
+        .args
+        .pairs()
+        .map(|pair| pair.punct().unwrap().span.start().column)
+        .collect();
+    assert_eq!(commas, [3, 10]);
+}
+
+#[test]
+pub fn lambda_arguments_require_commas() {
+    assert!(synix::parse("{ a b }: a").is_err());
+
+    let (_, diagnostics) = synix::parse_with_diagnostics("{ a b, c }: a", ParseOptions::default());
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message(), "Expected `,`");
+}
@@ -0,0 +1,133 @@
+use synix::{Assignment, Expr, ParseOptions, lambda::LambdaArg};
+
+fn parse(nix: &str) -> (Expr, Vec<synix::Error>) {
+    synix::parse_with_diagnostics(nix, ParseOptions::default())
+}
+
+#[test]
+pub fn valid_input_has_no_diagnostics() {
+    let (expr, diagnostics) = parse("let a = 1; in { b = a; }");
+
+    assert!(matches!(expr, Expr::Let(_)));
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+pub fn attrset_recovers_at_semicolon() {
+    let (expr, diagnostics) = parse("{ a = 1; b = ; c = 3; = 4; d = 5; }");
+
+    let Expr::AttrSet(set) = expr else {
+        panic!("Not an attribute set: {expr:?}");
+    };
+
+    let names: Vec<_> = set
+        .assignments
+        .iter()
+        .map(|assignment| {
+            let Assignment::Named(named) = assignment else {
+                panic!("Not a named assignment");
+            };
+
+            (named.head.static_name().unwrap(), named.value.span())
+        })
+        .collect();
+
+    assert_eq!(
+        names.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+        ["a", "b", "c", "d"]
+    );
+
+    let Assignment::Named(b) = &set.assignments[1] else {
+        unreachable!()
+    };
+    assert!(matches!(b.value, Expr::Error(_)));
+
+    assert_eq!(diagnostics.len(), 2);
+}
+
+#[test]
+pub fn let_recovers_at_in() {
+    let (expr, diagnostics) = parse("let a = 1; b = in a");
+
+    let Expr::Let(let_) = expr else {
+        panic!("Not a let expression: {expr:?}");
+    };
+
+    assert_eq!(let_.assignments.len(), 2);
+    assert!(matches!(let_.body, Expr::Ident(_)));
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+pub fn let_without_body() {
+    let (expr, diagnostics) = parse("let a = 1;");
+
+    let Expr::Let(let_) = expr else {
+        panic!("Not a let expression: {expr:?}");
+    };
+
+    assert_eq!(let_.assignments.len(), 1);
+    assert!(matches!(let_.body, Expr::Error(_)));
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+pub fn recovers_at_closing_delimiter() {
+    let (expr, diagnostics) = parse("[ (a +) b ]");
+
+    let Expr::List(list) = expr else {
+        panic!("Not a list: {expr:?}");
+    };
+
+    assert_eq!(list.entries.len(), 2);
+
+    let Expr::Parenthesized(paren) = &list.entries[0] else {
+        panic!("Not parenthesized");
+    };
+    assert!(matches!(paren.inner, Expr::Error(_)));
+    assert!(matches!(list.entries[1], Expr::Ident(_)));
+
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+pub fn lambda_arguments_recover_at_comma() {
+    let (expr, diagnostics) = parse("{ a ? , b, 1, c }: a");
+
+    let Expr::Lambda(lambda) = expr else {
+        panic!("Not a lambda: {expr:?}");
+    };
+
+    let LambdaArg::AttrSet(set) = &lambda.arg else {
+        panic!("Not an attribute set argument");
+    };
+
+    let names: Vec<_> = set.args.iter().map(|arg| arg.ident.ident()).collect();
+    assert_eq!(names, ["a", "b", "c"]);
+    assert!(matches!(set.args[0].default, Some(Expr::Error(_))));
+
+    assert_eq!(diagnostics.len(), 2);
+}
+
+#[test]
+pub fn leftover_input() {
+    let (expr, diagnostics) = parse("f in");
+
+    assert!(matches!(expr, Expr::Ident(_)));
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+pub fn lexer_error() {
+    let (expr, diagnostics) = parse("{ a = \"1; }");
+
+    assert!(matches!(expr, Expr::Error(_)));
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+pub fn parse_does_not_recover() {
+    assert!(synix::parse("{ a = ; }").is_err());
+    assert!(synix::parse("{ a ? , b }: a").is_err());
+    assert!(synix::parse("(a b ;)").is_err());
+}
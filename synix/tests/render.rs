@@ -0,0 +1,137 @@
+use synix::{
+    Error,
+    render::{Renderer, SourceMap},
+};
+use synix_lexer::{LineColumn, Span};
+
+fn span(start: (usize, usize), end: (usize, usize)) -> Span {
+    let (line, column) = start;
+    let start = LineColumn { line, column };
+    let (line, column) = end;
+    Span::new(start, LineColumn { line, column })
+}
+
+fn render(source: &str, error: &Error) -> String {
+    Renderer::new().render(&SourceMap::new("test.nix", source), error)
+}
+
+#[test]
+pub fn single_line() {
+    let source = "let a in a";
+    let error = synix::parse(source).unwrap_err();
+
+    assert_eq!(
+        render(source, &error),
+        "\
+error: Expected one of `.`, `=`, found `in`
+ --> test.nix:1:7
+  |
+1 | let a in a
+  |       ^^
+"
+    );
+}
+
+#[test]
+pub fn labels_notes_and_help() {
+    let source = "abc d e f";
+    let error = Error::new(span((0, 0), (0, 3)), "message")
+        .with_label(span((0, 4), (0, 5)), "first")
+        .with_label(span((0, 6), (0, 7)), "second")
+        .with_note("a note")
+        .with_help("some help");
+
+    assert_eq!(
+        render(source, &error),
+        "\
+error: message
+ --> test.nix:1:1
+  |
+1 | abc d e f
+  | ^^^ - - second
+  |     |
+  |     first
+  |
+  = note: a note
+  = help: some help
+"
+    );
+}
+
+#[test]
+pub fn multi_line() {
+    let source = "let\n  a = {\n    b = 1;\n  };\nin a";
+    let error =
+        Error::new(span((1, 6), (3, 3)), "message").with_label(span((4, 3), (4, 4)), "label");
+
+    assert_eq!(
+        render(source, &error),
+        "\
+error: message
+ --> test.nix:2:7
+  |
+2 |     a = {
+  |  _______^
+3 | |     b = 1;
+4 | |   };
+  | |___^
+5 |   in a
+  |      - label
+"
+    );
+
+    // Spans that start at the indentation are marked with `/`.
+    let error = Error::new(span((1, 2), (3, 3)), "message");
+
+    assert_eq!(
+        render(source, &error),
+        "\
+error: message
+ --> test.nix:2:3
+  |
+2 | /   a = {
+3 | |     b = 1;
+4 | |   };
+  | |___^
+"
+    );
+}
+
+#[test]
+pub fn combined() {
+    let source = "a\nb\n\n\n\n\n\n\n\nc";
+    let mut error = Error::new(span((0, 0), (0, 1)), "first");
+    error.combine(Error::new(span((9, 0), (9, 1)), "second").with_label(span((1, 0), (1, 1)), "b"));
+
+    assert_eq!(
+        render(source, &error),
+        "\
+error: first
+ --> test.nix:1:1
+  |
+1 | a
+  | ^
+
+error: second
+  --> test.nix:10:1
+   |
+ 2 | b
+   | - b
+...
+10 | c
+   | ^
+"
+    );
+}
+
+#[test]
+pub fn color() {
+    let source = "a b";
+    let error = Error::new(span((0, 2), (0, 3)), "message");
+    let output = Renderer::new()
+        .color(true)
+        .render(&SourceMap::new("test.nix", source), &error);
+
+    assert!(output.starts_with("\x1b[1;31merror\x1b[0m"));
+    assert!(output.contains("\x1b[1;31m^\x1b[0m"));
+}
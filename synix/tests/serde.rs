@@ -0,0 +1,92 @@
+use serde_json::{Value, json};
+use synix::Expr;
+
+fn to_json(input: &str) -> Value {
+    serde_json::to_value(synix::parse(input).unwrap()).unwrap()
+}
+
+/// Remove all spans, to compare the shape of the JSON.
+fn strip_spans(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("span");
+            map.values_mut().for_each(strip_spans);
+        }
+        Value::Array(values) => values.iter_mut().for_each(strip_spans),
+        _ => {}
+    }
+}
+
+#[test]
+pub fn shape() {
+    let mut value = to_json("[ (-a) \"b${c}\" ]");
+    strip_spans(&mut value);
+
+    assert_eq!(
+        value,
+        json!({
+            "kind": "List",
+            "value": {
+                "entries": [
+                    {
+                        "kind": "Parenthesized",
+                        "value": {
+                            "inner": {
+                                "kind": "Unary",
+                                "value": {
+                                    "operator": "Negate",
+                                    "expr": { "kind": "Ident", "value": { "ident": "a" } },
+                                },
+                            },
+                        },
+                    },
+                    {
+                        "kind": "InterpolatedStr",
+                        "value": {
+                            "parts": [
+                                { "kind": "Str", "value": "b" },
+                                {
+                                    "kind": "Interpolation",
+                                    "value": { "kind": "Ident", "value": { "ident": "c" } },
+                                },
+                            ],
+                        },
+                    },
+                ],
+            },
+        })
+    );
+}
+
+#[test]
+pub fn spans() {
+    let value = to_json("a  +\n  b");
+
+    assert_eq!(
+        value["value"]["rhs"]["value"]["span"],
+        json!({
+            "start": { "line": 1, "column": 2 },
+            "end": { "line": 1, "column": 3 },
+        })
+    );
+}
+
+#[test]
+pub fn round_trip() {
+    let inputs = [
+        "let a = 1; in a",
+        "rec { inherit (x) y; a.${b}.\"c\" = x: -x + 1; }",
+        "{ a ? 1, ... }@args: args.a or null",
+        "[ ./a/${b}.nix <c> ~/d /e ]",
+        "if a ? b then with c; d else !e",
+    ];
+
+    for input in inputs {
+        let expr = synix::parse(input).unwrap();
+        let json = serde_json::to_string(&expr).unwrap();
+        let parsed: Expr = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, expr, "{input}");
+        assert_eq!(synix::print(&parsed), synix::print(&expr), "{input}");
+    }
+}
@@ -0,0 +1,166 @@
+//! Checks that the span of every kind of node covers
+//! exactly its source text.
+
+mod common;
+
+use common::parse_or_pretty_err;
+use synix::{
+    Assignment, Expr,
+    attrset::ExprAttrSet,
+    lambda::{ExprLambda, LambdaArg},
+    path::Path,
+};
+use synix_lexer::{LineColumn, Span};
+
+fn offset(input: &str, position: LineColumn) -> usize {
+    let line_start: usize = input
+        .split_inclusive('\n')
+        .take(position.line)
+        .map(|line| line.chars().count())
+        .sum();
+
+    line_start + position.column
+}
+
+/// The source text covered by `span`.
+fn text(input: &str, span: Span) -> String {
+    let start = offset(input, span.start());
+    let end = offset(input, span.end());
+
+    input.chars().skip(start).take(end - start).collect()
+}
+
+fn parse(input: &str) -> Expr {
+    match parse_or_pretty_err(input) {
+        Ok(v) => v,
+        Err(e) => panic!("{e}"),
+    }
+}
+
+/// Parse `$input`, select a node using `$select` and assert
+/// that its span covers exactly `$expected`.
+macro_rules! span {
+    ($name:ident, $input:literal, $expected:literal) => {
+        span!($name, $input, |expr: Expr| expr.span(), $expected);
+    };
+    ($name:ident, $input:literal, $select:expr, $expected:literal) => {
+        #[test]
+        pub fn $name() {
+            let input = $input;
+            let span = ($select)(parse(input));
+
+            assert_eq!(text(input, span), $expected);
+        }
+    };
+}
+
+fn as_attrset(expr: Expr) -> ExprAttrSet {
+    let Expr::AttrSet(set) = expr else {
+        panic!("Not an attribute set: {expr:?}");
+    };
+
+    set
+}
+
+fn as_lambda(expr: Expr) -> ExprLambda {
+    let Expr::Lambda(lambda) = expr else {
+        panic!("Not a lambda: {expr:?}");
+    };
+
+    *lambda
+}
+
+span!(int, "  42  ", "42");
+span!(string, r#" "a string" "#, r#""a string""#);
+span!(bool, " true ", "true");
+span!(ident, " an-ident ", "an-ident");
+span!(attrset, " { a = 1; } ", "{ a = 1; }");
+span!(attrset_rec, " rec { a = 1; } ", "rec { a = 1; }");
+span!(attrset_multiline, "{\n  a = 1;\n}\n", "{\n  a = 1;\n}");
+span!(
+    assignment_named,
+    "{ a.b = 1; c = 2; }",
+    |expr| as_attrset(expr).assignments[0].span(),
+    "a.b = 1;"
+);
+span!(
+    assignment_inherit,
+    "{ inherit (x) a b; c = 2; }",
+    |expr| as_attrset(expr).assignments[0].span(),
+    "inherit (x) a b;"
+);
+span!(
+    interpolated_ident,
+    "{ ${a} = 1; }",
+    |expr| {
+        let Assignment::Named(named) = as_attrset(expr).assignments.remove(0) else {
+            panic!("Not a named assignment");
+        };
+        named.head.span()
+    },
+    "${a}"
+);
+span!(list, " [ 1 2 ] ", "[ 1 2 ]");
+span!(list_multiline, "[\n  1\n  2\n] ", "[\n  1\n  2\n]");
+span!(parenthesized, " ( a ) ", "( a )");
+span!(lambda_ident, " a: a ", "a: a");
+span!(
+    lambda_attrset,
+    " { a, b ? 1 }@args: a ",
+    "{ a, b ? 1 }@args: a"
+);
+span!(
+    lambda_arg_postbind,
+    " { a, b ? 1 }@args: a ",
+    |expr| as_lambda(expr).arg.span(),
+    "{ a, b ? 1 }@args"
+);
+span!(
+    lambda_arg_prebind,
+    " args@{ a, ... }: a ",
+    |expr| as_lambda(expr).arg.span(),
+    "args@{ a, ... }"
+);
+span!(
+    lambda_arg_value,
+    " { a, b ? 1 }: a ",
+    |expr| {
+        let LambdaArg::AttrSet(set) = as_lambda(expr).arg else {
+            panic!("Not an attribute set argument");
+        };
+        set.args[1].span()
+    },
+    "b ? 1"
+);
+span!(let_, " let a = 1; in a ", "let a = 1; in a");
+span!(legacy_let, " let { body = 1; } ", "let { body = 1; }");
+span!(with, " with a; b ", "with a; b");
+span!(if_, " if a then b else c ", "if a then b else c");
+span!(binary, " a + b * c ", "a + b * c");
+span!(binary_rhs, " a * b + c ", "a * b + c");
+span!(unary, " -a ", "-a");
+span!(unary_not, " !a && b ", "!a && b");
+span!(has_attr, " a ? b.c ", "a ? b.c");
+span!(function_call, " f a b ", "f a b");
+span!(attribute_access, " a.b.c ", "a.b.c");
+span!(path_relative, " ./foo/bar.nix ", "./foo/bar.nix");
+span!(path_absolute, " /foo/bar ", "/foo/bar");
+span!(path_no_prefix, " foo/bar ", "foo/bar");
+span!(path_home, " ~/foo ", "~/foo");
+span!(path_lookup, " <nixpkgs/lib> ", "<nixpkgs/lib>");
+span!(
+    path_part,
+    " ./foo/bar.nix ",
+    |expr| {
+        let Expr::Path(path) = expr else {
+            panic!("Not a path");
+        };
+        let Path::Normal(path) = *path else {
+            panic!("Not a normal path");
+        };
+        path.tail[0].span()
+    },
+    "bar.nix"
+);
+span!(interpolated_str, r#" "a ${b} c" "#, r#""a ${b} c""#);
+span!(attribute_access_default, " a.b or c ", "a.b or c");
@@ -0,0 +1,104 @@
+use synix::{
+    Expr, Ident,
+    fold::{self, Fold},
+    lit::ExprLit,
+    print,
+    visit::Visit,
+    visit_mut::{self, VisitMut},
+};
+
+/// Collects every identifier.
+#[derive(Default)]
+struct Idents<'ast>(Vec<&'ast str>);
+
+impl<'ast> Visit<'ast> for Idents<'ast> {
+    fn visit_ident(&mut self, node: &'ast Ident) {
+        self.0.push(node.ident());
+    }
+}
+
+fn idents(input: &str) -> Vec<String> {
+    let expr = synix::parse(input).unwrap();
+    let mut idents = Idents::default();
+    idents.visit_expr(&expr);
+    idents.0.into_iter().map(String::from).collect()
+}
+
+#[test]
+pub fn visit_reaches_every_expression() {
+    assert_eq!(idents("[ a (b c) ]"), ["a", "b", "c"]);
+    assert_eq!(
+        idents("{ inherit (a) b; c.${d} = e; }"),
+        ["a", "b", "c", "d", "e"]
+    );
+    assert_eq!(idents("a@{ b, c ? d, ... }: e"), ["a", "b", "c", "d", "e"]);
+    assert_eq!(idents("./a/${b}.nix"), ["a", "b", "nix"]);
+    assert_eq!(idents("\"${a}\" + b.\"${c}\" or d"), ["a", "b", "c", "d"]);
+    assert_eq!(
+        idents("if a then with b; c else -d ? e"),
+        ["a", "b", "c", "d", "e"]
+    );
+}
+
+/// Counts the lambdas, without looking inside them.
+#[derive(Default)]
+struct OuterLambdas(usize);
+
+impl Visit<'_> for OuterLambdas {
+    fn visit_expr_lambda(&mut self, _node: &synix::lambda::ExprLambda) {
+        self.0 += 1;
+    }
+}
+
+#[test]
+pub fn visit_override_stops_recursion() {
+    let expr = synix::parse("[ (a: b: a) (c: c) ]").unwrap();
+    let mut lambdas = OuterLambdas::default();
+    lambdas.visit_expr(&expr);
+
+    assert_eq!(lambdas.0, 2);
+}
+
+/// Increments every integer literal.
+struct Increment;
+
+impl VisitMut for Increment {
+    fn visit_expr_lit_mut(&mut self, node: &mut ExprLit) {
+        if let ExprLit::Int(int) = node {
+            int.digits = (int.digits.parse::<u64>().unwrap() + 1).to_string();
+        }
+
+        visit_mut::visit_expr_lit_mut(self, node);
+    }
+}
+
+#[test]
+pub fn visit_mut() {
+    let mut expr = synix::parse("let a = 1; in { b = [ 2 ]; c = x: \"${3}\"; }").unwrap();
+    Increment.visit_expr_mut(&mut expr);
+
+    assert_eq!(
+        print(&expr),
+        "let a = 2; in { b = [ 3 ]; c = x: \"${4}\"; }"
+    );
+}
+
+/// Replaces `a` by `(b)`.
+struct Replace;
+
+impl Fold for Replace {
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        match node {
+            Expr::Ident(ident) if ident.ident() == "a" => synix::parse("(b)").unwrap(),
+            node => fold::fold_expr(self, node),
+        }
+    }
+}
+
+#[test]
+pub fn fold() {
+    let expr = synix::parse("f a [ a ] { c = a; }").unwrap();
+    let expr = Replace.fold_expr(expr);
+
+    assert_eq!(print(&expr), "f (b) [ (b) ] { c = (b); }");
+}